@@ -1,12 +1,28 @@
 mod parser;
+mod reorganize;
 mod split;
 
+use std::path::Path;
+
 pub use parser::{FunctionInfo, FunctionParser, get_parser};
+pub use reorganize::{
+    BucketKind, ExistingSubdirs, ReorganizeAnalyzer, ReorganizeBucket, ReorganizeSuggestion,
+};
 pub use split::{SplitAnalyzer, SplitChunk, SplitSuggestion};
 
-use crate::checker::CheckResult;
+use crate::checker::{CheckResult, StructureChecker, ViolationCategory, ViolationType};
 use crate::counter::LineStats;
 use crate::language::LanguageRegistry;
+use crate::scanner::{ScanResult, StructureScanConfig};
+
+/// Suggestions attached to a warning or failure by `--suggest`.
+#[derive(Debug, Clone)]
+pub enum Suggestion {
+    /// Function-based split of an oversized file.
+    Split(SplitSuggestion),
+    /// Subdirectories for a directory with too many files.
+    Reorganize(ReorganizeSuggestion),
+}
 
 /// Generate split suggestions for failed or warning results.
 pub fn generate_split_suggestions(results: &mut [CheckResult], registry: &LanguageRegistry) {
@@ -33,23 +49,104 @@ pub fn generate_split_suggestions(results: &mut [CheckResult], registry: &Langua
             analyzer.analyze(result.path(), &content, &language.name, result.limit())
             && suggestion.has_suggestions()
         {
-            // Replace the result with its version containing suggestions
-            let owned = std::mem::replace(
-                result,
-                CheckResult::Passed {
-                    path: std::path::PathBuf::new(),
-                    stats: LineStats::default(),
-                    raw_stats: None,
-                    limit: 0,
-                    override_reason: None,
-                    violation_category: None,
-                },
-            );
-            *result = owned.with_suggestions(suggestion);
+            *result = take_result(result).with_suggestions(suggestion);
         }
     }
 }
 
+/// Generate reorganisation suggestions for directories over their file limit.
+///
+/// Files come from `scan_result`, without those `count_exclude` leaves out of the count.
+/// Suggested subdirectories avoid existing ones and respect the directory's `max_dirs`.
+pub fn generate_reorganize_suggestions(
+    results: &mut [CheckResult],
+    scan_result: &ScanResult,
+    structure_config: Option<&StructureScanConfig>,
+    structure_checker: Option<&StructureChecker>,
+) {
+    let analyzer = ReorganizeAnalyzer::default();
+
+    for result in results.iter_mut() {
+        if !result.is_failed() && !result.is_warning() {
+            continue;
+        }
+
+        if !matches!(
+            result.violation_category(),
+            Some(ViolationCategory::Structure {
+                violation_type: ViolationType::FileCount,
+                ..
+            })
+        ) {
+            continue;
+        }
+
+        let dir = result.path();
+        let file_names = counted_file_names(scan_result, structure_config, dir);
+        let subdirs = ExistingSubdirs {
+            names: subdir_names(scan_result, dir),
+            max_dirs: structure_checker.and_then(|checker| checker.max_dirs_for(dir)),
+        };
+
+        if let Some(suggestion) = analyzer.analyze(
+            dir,
+            &file_names,
+            result.stats().sloc(),
+            result.limit(),
+            &subdirs,
+        ) && suggestion.has_suggestions()
+        {
+            *result = take_result(result).with_reorganization(suggestion);
+        }
+    }
+}
+
+/// Sorted names of the scanned files directly inside `dir` that count toward its limit.
+fn counted_file_names(
+    scan_result: &ScanResult,
+    structure_config: Option<&StructureScanConfig>,
+    dir: &Path,
+) -> Vec<String> {
+    let mut names: Vec<String> = scan_result
+        .files
+        .iter()
+        .filter(|path| path.parent() == Some(dir))
+        .filter(|path| !structure_config.is_some_and(|cfg| cfg.is_count_excluded(path)))
+        .filter_map(|path| path.file_name())
+        .map(|name| name.to_string_lossy().into_owned())
+        .collect();
+    names.sort();
+    names
+}
+
+/// Sorted names of the scanned subdirectories directly inside `dir`.
+fn subdir_names(scan_result: &ScanResult, dir: &Path) -> Vec<String> {
+    let mut names: Vec<String> = scan_result
+        .dir_stats
+        .keys()
+        .filter(|path| path.parent() == Some(dir))
+        .filter_map(|path| path.file_name())
+        .map(|name| name.to_string_lossy().into_owned())
+        .collect();
+    names.sort();
+    names
+}
+
+/// Move a result out of `result` so it can be rebuilt with suggestions.
+fn take_result(result: &mut CheckResult) -> CheckResult {
+    std::mem::replace(
+        result,
+        CheckResult::Passed {
+            path: std::path::PathBuf::new(),
+            stats: LineStats::default(),
+            raw_stats: None,
+            limit: 0,
+            override_reason: None,
+            violation_category: None,
+        },
+    )
+}
+
 #[cfg(test)]
 #[path = "mod_tests.rs"]
 mod tests;
@@ -1,7 +1,7 @@
 use std::path::PathBuf;
 
 use super::*;
-use crate::checker::CheckResult;
+use crate::checker::{CheckResult, DirStats};
 use crate::counter::LineStats;
 use crate::language::LanguageRegistry;
 
@@ -69,3 +69,120 @@ fn generate_split_suggestions_skips_missing_file() {
 
     assert!(results[0].suggestions().is_none());
 }
+
+fn file_count_failure(dir: &str, count: usize, limit: usize) -> CheckResult {
+    use crate::checker::{ViolationCategory, ViolationType};
+
+    CheckResult::Failed {
+        path: PathBuf::from(dir),
+        stats: LineStats {
+            total: count,
+            code: count,
+            ..LineStats::default()
+        },
+        raw_stats: None,
+        limit,
+        override_reason: None,
+        suggestions: None,
+        violation_category: Some(ViolationCategory::Structure {
+            violation_type: ViolationType::FileCount,
+            triggering_rule: None,
+        }),
+    }
+}
+
+fn scan_of(files: &[&str], dirs: &[&str]) -> ScanResult {
+    ScanResult {
+        files: files.iter().map(PathBuf::from).collect(),
+        dir_stats: dirs
+            .iter()
+            .map(|dir| (PathBuf::from(dir), DirStats::default()))
+            .collect(),
+        allowlist_violations: Vec::new(),
+    }
+}
+
+#[test]
+fn generate_reorganize_suggestions_uses_scanned_files() {
+    let scan = scan_of(
+        &[
+            "src/user_a.rs",
+            "src/user_b.rs",
+            "src/user_c.rs",
+            "src/lib.rs",
+            "src/user_nested/inner.rs",
+        ],
+        &["src", "src/user_nested"],
+    );
+    let mut results = vec![file_count_failure("src", 4, 3)];
+
+    generate_reorganize_suggestions(&mut results, &scan, None, None);
+
+    let suggestion = results[0].reorganization().unwrap();
+    assert_eq!(suggestion.buckets.len(), 1);
+    assert_eq!(suggestion.buckets[0].directory, "user");
+    assert_eq!(
+        suggestion.buckets[0].files,
+        vec!["user_a.rs", "user_b.rs", "user_c.rs"]
+    );
+    assert!(results[0].suggestions().is_none());
+}
+
+#[test]
+fn generate_reorganize_suggestions_skips_count_excluded_files() {
+    let config = StructureScanConfig::builder()
+        .count_exclude(vec!["*.md".to_string()])
+        .build()
+        .unwrap();
+    let scan = scan_of(
+        &[
+            "src/user_a.rs",
+            "src/user_b.rs",
+            "src/README.md",
+            "src/CHANGES.md",
+            "src/lib.rs",
+        ],
+        &["src"],
+    );
+    let mut results = vec![file_count_failure("src", 3, 2)];
+
+    generate_reorganize_suggestions(&mut results, &scan, Some(&config), None);
+
+    let suggestion = results[0].reorganization().unwrap();
+    let moved: Vec<&str> = suggestion
+        .buckets
+        .iter()
+        .flat_map(|bucket| bucket.files.iter().map(String::as_str))
+        .collect();
+    assert!(!moved.contains(&"README.md"));
+    assert!(!moved.contains(&"CHANGES.md"));
+}
+
+#[test]
+fn generate_reorganize_suggestions_avoids_existing_subdirs() {
+    let scan = scan_of(
+        &[
+            "src/user_a.rs",
+            "src/user_b.rs",
+            "src/user_c.rs",
+            "src/lib.rs",
+        ],
+        &["src", "src/user"],
+    );
+    let mut results = vec![file_count_failure("src", 4, 3)];
+
+    generate_reorganize_suggestions(&mut results, &scan, None, None);
+
+    let suggestion = results[0].reorganization().unwrap();
+    assert_eq!(suggestion.buckets[0].directory, "user-2");
+}
+
+#[test]
+fn generate_reorganize_suggestions_skips_content_results() {
+    let scan = scan_of(&[], &[]);
+    let mut results = vec![make_failed_result(".", 600, 500)];
+
+    generate_reorganize_suggestions(&mut results, &scan, None, None);
+
+    assert!(results[0].reorganization().is_none());
+}
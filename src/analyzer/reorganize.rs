@@ -0,0 +1,373 @@
+//! Directory reorganisation suggestions for directories with too many files.
+//!
+//! Files are clustered by a shared leading or trailing name word (`user_*.rs`,
+//! `*_handler.go`), then by extension, until enough of them move into new
+//! subdirectories to bring the directory under its limit. Files that share a stem
+//! (`Button.tsx`, `Button.test.tsx`, `button_test.go`) always move together.
+
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::config::split_words;
+
+/// How the files of a suggested subdirectory were grouped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BucketKind {
+    /// Shared leading name word (`user_*`).
+    Prefix,
+    /// Shared trailing name word (`*_handler`).
+    Suffix,
+    /// Shared extension (`*.json`).
+    Extension,
+    /// Files without a usable common name, moved to reach the limit.
+    Remainder,
+}
+
+/// A suggested subdirectory and the files to move into it.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReorganizeBucket {
+    /// Name of the new subdirectory
+    pub directory: String,
+    /// How the files were grouped
+    pub kind: BucketKind,
+    /// Name pattern shared by the files, e.g. `user*` or `*.json`
+    pub pattern: String,
+    /// File names to move into the subdirectory
+    pub files: Vec<String>,
+}
+
+/// Reorganisation suggestions for a directory that exceeds its file limit.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReorganizeSuggestion {
+    /// Directory with too many files
+    pub directory: PathBuf,
+    /// Files counted in the directory
+    pub file_count: usize,
+    /// File limit for the directory
+    pub limit: usize,
+    /// Suggested subdirectories
+    pub buckets: Vec<ReorganizeBucket>,
+    /// Suggestions stopped early because more subdirectories would exceed `max_dirs`
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub dir_limit_reached: bool,
+}
+
+impl ReorganizeSuggestion {
+    #[must_use]
+    pub const fn new(directory: PathBuf, file_count: usize, limit: usize) -> Self {
+        Self {
+            directory,
+            file_count,
+            limit,
+            buckets: Vec::new(),
+            dir_limit_reached: false,
+        }
+    }
+
+    #[must_use]
+    pub fn with_buckets(mut self, buckets: Vec<ReorganizeBucket>) -> Self {
+        self.buckets = buckets;
+        self
+    }
+
+    /// Check if any suggestions were generated.
+    #[must_use]
+    pub const fn has_suggestions(&self) -> bool {
+        !self.buckets.is_empty()
+    }
+
+    /// Files left in the directory once every bucket has been moved out.
+    #[must_use]
+    pub fn remaining_files(&self) -> usize {
+        let moved: usize = self.buckets.iter().map(|bucket| bucket.files.len()).sum();
+        self.file_count.saturating_sub(moved)
+    }
+}
+
+/// Subdirectories a directory already has, which new subdirectories must respect.
+#[derive(Debug, Clone, Default)]
+pub struct ExistingSubdirs {
+    /// Names of the current subdirectories; suggested names never reuse them
+    pub names: Vec<String>,
+    /// `max_dirs` limit of the directory, if any
+    pub max_dirs: Option<usize>,
+}
+
+/// Files that move together: every file sharing a stem.
+#[derive(Debug)]
+struct Unit {
+    words: Vec<String>,
+    files: Vec<String>,
+}
+
+impl Unit {
+    fn extension(&self) -> &str {
+        self.files
+            .iter()
+            .find_map(|file| Path::new(file).extension().and_then(|ext| ext.to_str()))
+            .unwrap_or_default()
+    }
+}
+
+/// Analyze a directory's file names and suggest subdirectories to move them into.
+pub struct ReorganizeAnalyzer {
+    min_group_size: usize,
+}
+
+impl ReorganizeAnalyzer {
+    /// Create a new analyzer; name and extension groups need at least `min_group_size` files.
+    #[must_use]
+    pub const fn new(min_group_size: usize) -> Self {
+        Self { min_group_size }
+    }
+
+    /// Suggest subdirectories for the files of `directory`, which has `file_count` files
+    /// against a limit of `limit`. Returns `None` when the directory is within its limit.
+    ///
+    /// New subdirectory names avoid those in `subdirs`, and no more are suggested than
+    /// its `max_dirs` leaves room for.
+    #[must_use]
+    pub fn analyze(
+        &self,
+        directory: &Path,
+        file_names: &[String],
+        file_count: usize,
+        limit: usize,
+        subdirs: &ExistingSubdirs,
+    ) -> Option<ReorganizeSuggestion> {
+        let excess = file_count.saturating_sub(limit);
+        if excess == 0 {
+            return None;
+        }
+
+        let mut units = group_units(file_names);
+        let mut plan = BucketPlan::new(subdirs, limit);
+        let mut moved = 0;
+
+        while moved < excess && !plan.is_full() {
+            let Some((kind, word)) = self.best_name_group(&units) else {
+                break;
+            };
+            let members = take_units(&mut units, |unit| match kind {
+                BucketKind::Prefix => unit.words.first() == Some(&word),
+                _ => unit.words.len() > 1 && unit.words.last() == Some(&word),
+            });
+            let pattern = if kind == BucketKind::Prefix {
+                format!("{word}*")
+            } else {
+                format!("*{word}")
+            };
+            moved += plan.push(&word, kind, &pattern, members);
+        }
+
+        while moved < excess && !plan.is_full() {
+            let Some(ext) = self.best_extension_group(&units) else {
+                break;
+            };
+            let members = take_units(&mut units, |unit| unit.extension() == ext);
+            let pattern = format!("*.{ext}");
+            moved += plan.push(&ext, BucketKind::Extension, &pattern, members);
+        }
+
+        if moved < excess && !plan.is_full() {
+            let mut members = Vec::new();
+            for unit in units {
+                if moved >= excess {
+                    break;
+                }
+                moved += unit.files.len();
+                members.push(unit);
+            }
+            plan.push("misc", BucketKind::Remainder, "*", members);
+        }
+
+        let mut suggestion = ReorganizeSuggestion::new(directory.to_path_buf(), file_count, limit)
+            .with_buckets(plan.buckets);
+        suggestion.dir_limit_reached = plan.limit_reached;
+        Some(suggestion)
+    }
+
+    /// The leading or trailing word shared by the most files, preferring prefixes.
+    fn best_name_group(&self, units: &[Unit]) -> Option<(BucketKind, String)> {
+        let mut counts: BTreeMap<(BucketKind, &str), usize> = BTreeMap::new();
+        for unit in units {
+            if let Some(first) = unit.words.first() {
+                *counts.entry((BucketKind::Prefix, first)).or_default() += unit.files.len();
+            }
+            if unit.words.len() > 1
+                && let Some(last) = unit.words.last()
+            {
+                *counts.entry((BucketKind::Suffix, last)).or_default() += unit.files.len();
+            }
+        }
+        counts
+            .into_iter()
+            .filter(|&(_, count)| count >= self.min_group_size)
+            // Ties go to the first key in (kind, word) order
+            .max_by(|(a_key, a_count), (b_key, b_count)| {
+                a_count.cmp(b_count).then_with(|| b_key.cmp(a_key))
+            })
+            .map(|((kind, word), _)| (kind, word.to_string()))
+    }
+
+    /// The extension shared by the most files.
+    fn best_extension_group(&self, units: &[Unit]) -> Option<String> {
+        let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+        for unit in units.iter().filter(|unit| !unit.extension().is_empty()) {
+            *counts.entry(unit.extension()).or_default() += unit.files.len();
+        }
+        counts
+            .into_iter()
+            .filter(|&(_, count)| count >= self.min_group_size)
+            .max_by(|(a_ext, a_count), (b_ext, b_count)| {
+                a_count.cmp(b_count).then_with(|| b_ext.cmp(a_ext))
+            })
+            .map(|(ext, _)| ext.to_string())
+    }
+}
+
+impl Default for ReorganizeAnalyzer {
+    fn default() -> Self {
+        Self::new(3)
+    }
+}
+
+/// Trailing words that mark a test or spec file of the same stem.
+const TEST_WORDS: &[&str] = &["test", "tests", "spec"];
+
+/// Group files by stem (up to the first dot, without a trailing `_test`/`_spec` word).
+fn group_units(file_names: &[String]) -> Vec<Unit> {
+    let mut units: BTreeMap<Vec<String>, Vec<String>> = BTreeMap::new();
+    for name in file_names {
+        let stem = name
+            .trim_start_matches('.')
+            .split('.')
+            .next()
+            .unwrap_or_default();
+        let mut words: Vec<String> = split_words(stem)
+            .into_iter()
+            .map(str::to_lowercase)
+            .collect();
+        if words.len() > 1
+            && words
+                .last()
+                .is_some_and(|w| TEST_WORDS.contains(&w.as_str()))
+        {
+            words.pop();
+        }
+        let key = if words.is_empty() {
+            vec![name.clone()]
+        } else {
+            words
+        };
+        units.entry(key).or_default().push(name.clone());
+    }
+    units
+        .into_iter()
+        .map(|(words, mut files)| {
+            files.sort();
+            Unit { words, files }
+        })
+        .collect()
+}
+
+fn take_units(units: &mut Vec<Unit>, belongs: impl Fn(&Unit) -> bool) -> Vec<Unit> {
+    let (taken, kept) = std::mem::take(units).into_iter().partition(belongs);
+    *units = kept;
+    taken
+}
+
+/// Buckets suggested so far, with the names taken and the room left under `max_dirs`.
+struct BucketPlan {
+    buckets: Vec<ReorganizeBucket>,
+    /// Lowercased names of existing and suggested subdirectories
+    taken: HashSet<String>,
+    /// How many more subdirectories fit, if `max_dirs` is set
+    room: Option<usize>,
+    limit: usize,
+    limit_reached: bool,
+}
+
+impl BucketPlan {
+    fn new(subdirs: &ExistingSubdirs, limit: usize) -> Self {
+        Self {
+            buckets: Vec::new(),
+            taken: subdirs
+                .names
+                .iter()
+                .map(|name| name.to_lowercase())
+                .collect(),
+            room: subdirs
+                .max_dirs
+                .map(|max| max.saturating_sub(subdirs.names.len())),
+            limit,
+            limit_reached: false,
+        }
+    }
+
+    fn is_full(&self) -> bool {
+        self.room.is_some_and(|room| self.buckets.len() >= room)
+    }
+
+    /// Add buckets for `units`, split into `name-part1`, `name-part2`, ... when they exceed
+    /// the limit. Stops once `max_dirs` is reached. Returns the number of files moved.
+    fn push(&mut self, name: &str, kind: BucketKind, pattern: &str, units: Vec<Unit>) -> usize {
+        let limit = self.limit.max(1);
+        let mut parts: Vec<Vec<String>> = Vec::new();
+        for unit in units {
+            match parts.last_mut() {
+                Some(current) if current.len() + unit.files.len() <= limit => {
+                    current.extend(unit.files);
+                }
+                // A stem with more files than the limit cannot move as one
+                _ if unit.files.len() > limit => {
+                    parts.extend(unit.files.chunks(limit).map(<[String]>::to_vec));
+                }
+                _ => parts.push(unit.files),
+            }
+        }
+        let split = parts.len() > 1;
+        let mut moved = 0;
+        let mut suffix = 0;
+        for mut files in parts {
+            if self.is_full() {
+                self.limit_reached = true;
+                break;
+            }
+            files.sort();
+            moved += files.len();
+            let directory = self.unique_name(name, split, &mut suffix);
+            self.buckets.push(ReorganizeBucket {
+                directory,
+                kind,
+                pattern: pattern.to_string(),
+                files,
+            });
+        }
+        moved
+    }
+
+    /// `name-partN` for split buckets, otherwise `name`, or `name-N` when it is already taken.
+    fn unique_name(&mut self, name: &str, split: bool, suffix: &mut usize) -> String {
+        loop {
+            let candidate = if split {
+                format!("{name}-part{}", *suffix + 1)
+            } else if *suffix > 0 {
+                format!("{name}-{}", *suffix + 1)
+            } else {
+                name.to_string()
+            };
+            *suffix += 1;
+            if self.taken.insert(candidate.to_lowercase()) {
+                return candidate;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "reorganize_tests.rs"]
+mod tests;
@@ -0,0 +1,240 @@
+use std::path::Path;
+
+use super::*;
+
+fn names(files: &[&str]) -> Vec<String> {
+    files.iter().map(ToString::to_string).collect()
+}
+
+fn analyze(files: &[&str], limit: usize) -> ReorganizeSuggestion {
+    analyze_with(files, limit, &ExistingSubdirs::default())
+}
+
+fn analyze_with(files: &[&str], limit: usize, subdirs: &ExistingSubdirs) -> ReorganizeSuggestion {
+    ReorganizeAnalyzer::default()
+        .analyze(
+            Path::new("src/utils"),
+            &names(files),
+            files.len(),
+            limit,
+            subdirs,
+        )
+        .unwrap()
+}
+
+fn summary(suggestion: &ReorganizeSuggestion) -> Vec<(&str, BucketKind, usize)> {
+    suggestion
+        .buckets
+        .iter()
+        .map(|bucket| (bucket.directory.as_str(), bucket.kind, bucket.files.len()))
+        .collect()
+}
+
+#[test]
+fn within_limit_returns_none() {
+    let analyzer = ReorganizeAnalyzer::default();
+    let files = names(&["a.rs", "b.rs"]);
+
+    assert!(
+        analyzer
+            .analyze(Path::new("src"), &files, 2, 2, &ExistingSubdirs::default())
+            .is_none()
+    );
+}
+
+#[test]
+fn groups_by_common_prefix() {
+    let suggestion = analyze(
+        &[
+            "user_create.rs",
+            "user_delete.rs",
+            "user_update.rs",
+            "config.rs",
+            "lib.rs",
+        ],
+        3,
+    );
+
+    assert_eq!(summary(&suggestion), vec![("user", BucketKind::Prefix, 3)]);
+    assert_eq!(suggestion.buckets[0].pattern, "user*");
+    assert_eq!(suggestion.remaining_files(), 2);
+}
+
+#[test]
+fn groups_by_common_suffix() {
+    let suggestion = analyze(
+        &[
+            "auth_handler.go",
+            "order_handler.go",
+            "user_handler.go",
+            "main.go",
+        ],
+        3,
+    );
+
+    assert_eq!(
+        summary(&suggestion),
+        vec![("handler", BucketKind::Suffix, 3)]
+    );
+    assert_eq!(suggestion.buckets[0].pattern, "*handler");
+}
+
+#[test]
+fn keeps_files_with_the_same_stem_together() {
+    let suggestion = analyze(
+        &[
+            "Button.tsx",
+            "Button.test.tsx",
+            "ButtonGroup.tsx",
+            "button_test.go",
+            "card.tsx",
+        ],
+        4,
+    );
+
+    let files = &suggestion.buckets[0].files;
+    assert_eq!(suggestion.buckets[0].directory, "button");
+    assert!(files.contains(&"Button.test.tsx".to_string()));
+    assert!(files.contains(&"button_test.go".to_string()));
+    assert!(files.contains(&"ButtonGroup.tsx".to_string()));
+}
+
+#[test]
+fn falls_back_to_extension_then_remainder() {
+    let suggestion = analyze(
+        &[
+            "alpha.json",
+            "beta.json",
+            "gamma.json",
+            "delta.rs",
+            "omega.md",
+            "zeta.txt",
+            "eta.toml",
+        ],
+        3,
+    );
+
+    assert_eq!(
+        summary(&suggestion),
+        vec![
+            ("json", BucketKind::Extension, 3),
+            ("misc", BucketKind::Remainder, 1),
+        ]
+    );
+    assert_eq!(suggestion.buckets[1].files, vec!["delta.rs"]);
+    assert_eq!(suggestion.remaining_files(), 3);
+}
+
+#[test]
+fn splits_groups_larger_than_the_limit() {
+    let suggestion = analyze(
+        &[
+            "test_a.py",
+            "test_b.py",
+            "test_c.py",
+            "test_d.py",
+            "test_e.py",
+            "main.py",
+        ],
+        2,
+    );
+
+    assert_eq!(
+        summary(&suggestion),
+        vec![
+            ("test-part1", BucketKind::Prefix, 2),
+            ("test-part2", BucketKind::Prefix, 2),
+            ("test-part3", BucketKind::Prefix, 1),
+        ]
+    );
+    assert!(suggestion.buckets.iter().all(|b| b.files.len() <= 2));
+}
+
+#[test]
+fn splits_a_stem_with_more_files_than_the_limit() {
+    let suggestion = analyze(
+        &[
+            "user.rs",
+            "user_test.rs",
+            "user.ts",
+            "user.test.ts",
+            "user.md",
+            "main.rs",
+        ],
+        2,
+    );
+
+    assert_eq!(
+        summary(&suggestion),
+        vec![
+            ("user-part1", BucketKind::Prefix, 2),
+            ("user-part2", BucketKind::Prefix, 2),
+            ("user-part3", BucketKind::Prefix, 1),
+        ]
+    );
+    assert!(suggestion.buckets.iter().all(|b| b.files.len() <= 2));
+}
+
+#[test]
+fn stops_once_the_directory_fits() {
+    let suggestion = analyze(
+        &[
+            "api_a.rs", "api_b.rs", "api_c.rs", "db_a.rs", "db_b.rs", "db_c.rs", "db_d.rs",
+        ],
+        5,
+    );
+
+    assert_eq!(summary(&suggestion), vec![("db", BucketKind::Prefix, 4)]);
+    assert_eq!(suggestion.remaining_files(), 3);
+}
+
+#[test]
+fn skips_names_of_existing_subdirs() {
+    let subdirs = ExistingSubdirs {
+        names: vec!["User".to_string(), "user-2".to_string()],
+        max_dirs: None,
+    };
+    let suggestion = analyze_with(
+        &["user_a.rs", "user_b.rs", "user_c.rs", "lib.rs"],
+        3,
+        &subdirs,
+    );
+
+    assert_eq!(suggestion.buckets[0].directory, "user-3");
+    assert!(!suggestion.dir_limit_reached);
+}
+
+#[test]
+fn stops_at_max_dirs() {
+    let subdirs = ExistingSubdirs {
+        names: vec!["core".to_string()],
+        max_dirs: Some(2),
+    };
+    let suggestion = analyze_with(
+        &[
+            "user_a.rs",
+            "user_b.rs",
+            "user_c.rs",
+            "order_a.rs",
+            "order_b.rs",
+            "order_c.rs",
+            "lib.rs",
+        ],
+        2,
+        &subdirs,
+    );
+
+    assert_eq!(suggestion.buckets.len(), 1);
+    assert!(suggestion.dir_limit_reached);
+}
+
+#[test]
+fn no_suggestions_when_max_dirs_already_reached() {
+    let subdirs = ExistingSubdirs {
+        names: vec!["core".to_string()],
+        max_dirs: Some(1),
+    };
+    let suggestion = analyze_with(&["user_a.rs", "user_b.rs", "lib.rs"], 2, &subdirs);
+
+    assert!(!suggestion.has_suggestions());
+}
@@ -27,7 +27,7 @@ fn set_content_and_get_entry() {
             assert_eq!(*lines, 100);
             assert_eq!(h, &hash);
         }
-        BaselineEntry::Structure { .. } => panic!("Expected Content entry"),
+        _ => panic!("Expected Content entry"),
     }
 }
 
@@ -45,7 +45,7 @@ fn set_structure_and_get_entry() {
             assert_eq!(*violation_type, StructureViolationType::Files);
             assert_eq!(*count, 25);
         }
-        BaselineEntry::Content { .. } => panic!("Expected Structure entry"),
+        _ => panic!("Expected Structure entry"),
     }
 }
 
@@ -123,11 +123,11 @@ fn save_and_load_baseline() {
 
     match loaded.get("src/main.rs").unwrap() {
         BaselineEntry::Content { lines, .. } => assert_eq!(*lines, 100),
-        BaselineEntry::Structure { .. } => panic!("Expected Content entry"),
+        _ => panic!("Expected Content entry"),
     }
     match loaded.get("src/lib.rs").unwrap() {
         BaselineEntry::Content { lines, .. } => assert_eq!(*lines, 200),
-        BaselineEntry::Structure { .. } => panic!("Expected Content entry"),
+        _ => panic!("Expected Content entry"),
     }
 }
 
@@ -182,7 +182,7 @@ fn baseline_entry_content_constructor() {
             assert_eq!(lines, 100);
             assert_eq!(hash, "hash123");
         }
-        BaselineEntry::Structure { .. } => panic!("Expected Content entry"),
+        _ => panic!("Expected Content entry"),
     }
 }
 
@@ -199,10 +199,42 @@ fn baseline_entry_structure_constructor() {
             assert_eq!(violation_type, StructureViolationType::Dirs);
             assert_eq!(count, 10);
         }
-        BaselineEntry::Content { .. } => panic!("Expected Structure entry"),
+        _ => panic!("Expected Structure entry"),
     }
 }
 
+#[test]
+fn baseline_entry_comment_ratio_constructor() {
+    let entry = BaselineEntry::comment_ratio(CommentRatioBound::Min, 3, 240);
+    assert!(entry.is_comment_ratio());
+    assert!(!entry.is_content());
+    assert!(!entry.is_structure());
+}
+
+#[test]
+fn comment_ratio_entry_round_trips() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("baseline.json");
+
+    let mut baseline = Baseline::new();
+    baseline.set_comment_ratio("src/parser.rs", CommentRatioBound::Max, 300, 120);
+    baseline.save(&path).unwrap();
+
+    let json = fs::read_to_string(&path).unwrap();
+    assert!(json.contains("\"type\": \"comment_ratio\""));
+    assert!(json.contains("\"bound\": \"max\""));
+
+    let loaded = Baseline::load(&path).unwrap();
+    assert_eq!(
+        loaded.get("src/parser.rs"),
+        Some(&BaselineEntry::comment_ratio(
+            CommentRatioBound::Max,
+            300,
+            120
+        ))
+    );
+}
+
 #[test]
 fn default_creates_new_baseline() {
     let baseline = Baseline::default();
@@ -222,7 +254,7 @@ fn set_content_updates_existing_entry() {
             assert_eq!(*lines, 150);
             assert_eq!(hash, "hash2");
         }
-        BaselineEntry::Structure { .. } => panic!("Expected Content entry"),
+        _ => panic!("Expected Content entry"),
     }
 }
 
@@ -6,6 +6,7 @@ use std::path::Path;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 
+use crate::checker::CommentRatioBound;
 #[cfg(test)]
 use crate::state::atomic_write_with_lock_timeout;
 use crate::state::{DEFAULT_LOCK_TIMEOUT_MS, SaveOutcome, SharedLockGuard, atomic_write_with_lock};
@@ -19,6 +20,10 @@ const BASELINE_VERSION: u32 = 2;
 pub enum StructureViolationType {
     Files,
     Dirs,
+    /// Recursive `max_total_files` violation.
+    TotalFiles,
+    /// Recursive `max_total_dirs` violation.
+    TotalDirs,
 }
 
 /// Entry for a single path in the baseline (V2 format).
@@ -32,6 +37,12 @@ pub enum BaselineEntry {
         violation_type: StructureViolationType,
         count: usize,
     },
+    /// Comment ratio violation entry (raw counts at the time of grandfathering)
+    CommentRatio {
+        bound: CommentRatioBound,
+        comment: usize,
+        code: usize,
+    },
 }
 
 impl BaselineEntry {
@@ -48,6 +59,15 @@ impl BaselineEntry {
         }
     }
 
+    #[must_use]
+    pub const fn comment_ratio(bound: CommentRatioBound, comment: usize, code: usize) -> Self {
+        Self::CommentRatio {
+            bound,
+            comment,
+            code,
+        }
+    }
+
     /// Returns true if this is a content entry.
     #[must_use]
     pub const fn is_content(&self) -> bool {
@@ -59,6 +79,12 @@ impl BaselineEntry {
     pub const fn is_structure(&self) -> bool {
         matches!(self, Self::Structure { .. })
     }
+
+    /// Returns true if this is a comment ratio entry.
+    #[must_use]
+    pub const fn is_comment_ratio(&self) -> bool {
+        matches!(self, Self::CommentRatio { .. })
+    }
 }
 
 /// Baseline file structure for tracking grandfathered violations.
@@ -150,6 +176,20 @@ impl Baseline {
         );
     }
 
+    /// Add or update a comment ratio entry in the baseline.
+    pub fn set_comment_ratio(
+        &mut self,
+        path: &str,
+        bound: CommentRatioBound,
+        comment: usize,
+        code: usize,
+    ) {
+        self.files.insert(
+            path.to_string(),
+            BaselineEntry::comment_ratio(bound, comment, code),
+        );
+    }
+
     /// Add or update an entry in the baseline.
     pub fn set(&mut self, path: &str, entry: BaselineEntry) {
         self.files.insert(path.to_string(), entry);
@@ -0,0 +1,106 @@
+use serde::{Deserialize, Serialize};
+
+use crate::config::ContentRule;
+use crate::counter::LineStats;
+
+/// Code lines a file needs before comment ratio limits apply, unless a rule overrides it.
+/// Small files are exempt because a handful of lines makes the ratio meaningless.
+pub const DEFAULT_COMMENT_RATIO_MIN_CODE: usize = 100;
+
+/// Which side of the comment ratio range was violated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CommentRatioBound {
+    /// Too few comments (`min_comment_ratio`).
+    Min,
+    /// Too many comments (`max_comment_ratio`), typically commented-out code.
+    Max,
+}
+
+impl CommentRatioBound {
+    /// Config key that defines this bound.
+    #[must_use]
+    pub const fn config_key(self) -> &'static str {
+        match self {
+            Self::Min => "min_comment_ratio",
+            Self::Max => "max_comment_ratio",
+        }
+    }
+
+    /// Human-readable description, e.g. "comment ratio 4.2% is below minimum 10.0%".
+    #[must_use]
+    pub fn describe(self, ratio: f64, threshold: f64) -> String {
+        let relation = match self {
+            Self::Min => "below minimum",
+            Self::Max => "above maximum",
+        };
+        format!(
+            "comment ratio {:.1}% is {relation} {:.1}%",
+            ratio * 100.0,
+            threshold * 100.0
+        )
+    }
+}
+
+/// Comment ratio limits compiled from a `[[content.rules]]` entry.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct CommentRatioLimits {
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    /// Files with fewer code lines are not checked.
+    pub min_code: usize,
+}
+
+impl CommentRatioLimits {
+    /// Returns `None` when the rule sets neither ratio bound.
+    #[must_use]
+    pub fn from_rule(rule: &ContentRule) -> Option<Self> {
+        if rule.min_comment_ratio.is_none() && rule.max_comment_ratio.is_none() {
+            return None;
+        }
+        Some(Self {
+            min: rule.min_comment_ratio,
+            max: rule.max_comment_ratio,
+            min_code: rule
+                .comment_ratio_min_code
+                .unwrap_or(DEFAULT_COMMENT_RATIO_MIN_CODE),
+        })
+    }
+
+    /// Returns `(bound, ratio, threshold)` for the violated bound, if any.
+    ///
+    /// `stats` must be the raw counts: effective stats may have folded comments into code.
+    #[must_use]
+    pub fn evaluate(&self, stats: &LineStats) -> Option<(CommentRatioBound, f64, f64)> {
+        if stats.code < self.min_code {
+            return None;
+        }
+        let ratio = comment_ratio(stats);
+        if let Some(min) = self.min
+            && ratio < min
+        {
+            return Some((CommentRatioBound::Min, ratio, min));
+        }
+        if let Some(max) = self.max
+            && ratio > max
+        {
+            return Some((CommentRatioBound::Max, ratio, max));
+        }
+        None
+    }
+}
+
+/// Share of comment lines among code and comment lines (blank and ignored lines excluded).
+#[must_use]
+#[allow(clippy::cast_precision_loss)] // Line counts are far below f64 precision limits
+pub fn comment_ratio(stats: &LineStats) -> f64 {
+    let denominator = stats.code + stats.comment;
+    if denominator == 0 {
+        return 0.0;
+    }
+    stats.comment as f64 / denominator as f64
+}
+
+#[cfg(test)]
+#[path = "comment_ratio_tests.rs"]
+mod tests;
@@ -0,0 +1,96 @@
+use super::*;
+
+fn stats(code: usize, comment: usize) -> LineStats {
+    LineStats {
+        total: code + comment + 10,
+        code,
+        comment,
+        blank: 10,
+        ignored: 0,
+    }
+}
+
+fn limits(min: Option<f64>, max: Option<f64>, min_code: usize) -> CommentRatioLimits {
+    CommentRatioLimits { min, max, min_code }
+}
+
+#[test]
+fn comment_ratio_ignores_blank_lines() {
+    let ratio = comment_ratio(&stats(90, 10));
+    assert!((ratio - 0.1).abs() < f64::EPSILON);
+}
+
+#[test]
+fn comment_ratio_empty_file_is_zero() {
+    assert!(comment_ratio(&LineStats::default()).abs() < f64::EPSILON);
+}
+
+#[test]
+fn evaluate_below_min_reports_min_bound() {
+    let result = limits(Some(0.1), None, 100).evaluate(&stats(190, 10));
+    let (bound, ratio, threshold) = result.unwrap();
+    assert_eq!(bound, CommentRatioBound::Min);
+    assert!((ratio - 0.05).abs() < f64::EPSILON);
+    assert!((threshold - 0.1).abs() < f64::EPSILON);
+}
+
+#[test]
+fn evaluate_above_max_reports_max_bound() {
+    let result = limits(None, Some(0.5), 100).evaluate(&stats(100, 150));
+    assert_eq!(result.unwrap().0, CommentRatioBound::Max);
+}
+
+#[test]
+fn evaluate_within_range_passes() {
+    let result = limits(Some(0.1), Some(0.5), 100).evaluate(&stats(150, 50));
+    assert!(result.is_none());
+}
+
+#[test]
+fn evaluate_small_files_are_exempt() {
+    let result = limits(Some(0.1), None, 100).evaluate(&stats(99, 0));
+    assert!(result.is_none());
+}
+
+#[test]
+fn from_rule_without_ratios_is_none() {
+    let rule = ContentRule {
+        pattern: "**".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
+        max_lines: 500,
+        warn_threshold: None,
+        warn_at: None,
+        skip_comments: None,
+        skip_blank: None,
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
+        reason: None,
+        expires: None,
+        on_expire: None,
+    };
+    assert!(CommentRatioLimits::from_rule(&rule).is_none());
+
+    let with_min = ContentRule {
+        min_comment_ratio: Some(0.2),
+        ..rule
+    };
+    let compiled = CommentRatioLimits::from_rule(&with_min).unwrap();
+    assert_eq!(compiled.min_code, DEFAULT_COMMENT_RATIO_MIN_CODE);
+}
+
+#[test]
+fn describe_formats_percentages() {
+    assert_eq!(
+        CommentRatioBound::Min.describe(0.042, 0.1),
+        "comment ratio 4.2% is below minimum 10.0%"
+    );
+    assert_eq!(
+        CommentRatioBound::Max.describe(0.75, 0.5),
+        "comment ratio 75.0% is above maximum 50.0%"
+    );
+}
@@ -0,0 +1,283 @@
+use std::path::Path;
+
+use globset::{Glob, GlobMatcher, GlobSet, GlobSetBuilder};
+use serde::Serialize;
+
+use crate::config::{Config, ContentRule};
+use crate::language::LanguageRegistry;
+
+use super::rule_resolution::PatternSpecificity;
+
+/// Which selector of a content rule decided whether a file is selected.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SelectorOutcome {
+    /// All selectors matched.
+    Selected {
+        /// Most specific matching `pattern`/`patterns` glob (None if the rule has no path patterns).
+        pattern: Option<String>,
+        /// Matching entry of `languages` (None if the rule has no language filter).
+        language: Option<String>,
+    },
+    /// None of `pattern`/`patterns` matched the path.
+    NoPatternMatch,
+    /// The path matched but an `exclude_patterns` glob removed it.
+    ExcludedBy { pattern: String },
+    /// The path matched but the file's language is not in `languages`.
+    LanguageMismatch { language: Option<String> },
+}
+
+/// Compiled `pattern`/`patterns`/`exclude_patterns`/`languages` of one content rule.
+///
+/// Fields combine with AND; entries within each list combine with OR.
+pub struct ContentSelector {
+    include: Vec<(String, GlobMatcher, PatternSpecificity)>,
+    exclude: Vec<(String, GlobMatcher)>,
+    languages: Vec<String>,
+}
+
+/// Selector decision by list position, so the hot path avoids cloning strings.
+enum Decision {
+    Selected {
+        include: Option<usize>,
+        language: Option<usize>,
+    },
+    NoPatternMatch,
+    ExcludedBy(usize),
+    LanguageMismatch,
+}
+
+impl ContentSelector {
+    /// Compile the selectors of a content rule.
+    ///
+    /// # Errors
+    /// Returns `SlocGuardError::InvalidPattern` for the first invalid include or exclude glob.
+    pub fn new(rule: &ContentRule) -> crate::Result<Self> {
+        let include = rule
+            .path_patterns()
+            .map(|pattern| {
+                let matcher = compile(pattern)?;
+                Ok((
+                    pattern.to_string(),
+                    matcher,
+                    PatternSpecificity::of(pattern),
+                ))
+            })
+            .collect::<crate::Result<_>>()?;
+        let exclude = rule
+            .exclude_patterns
+            .iter()
+            .map(|pattern| Ok((pattern.clone(), compile(pattern)?)))
+            .collect::<crate::Result<_>>()?;
+        Ok(Self {
+            include,
+            exclude,
+            languages: rule.languages.clone(),
+        })
+    }
+
+    /// Specificity of the rule's most specific include pattern (default if it has none).
+    #[must_use]
+    pub fn specificity(&self) -> PatternSpecificity {
+        self.include
+            .iter()
+            .map(|(_, _, specificity)| *specificity)
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Specificity of the selecting include pattern, or `None` if the file is not selected.
+    ///
+    /// `normalized` must come from `normalize_for_matching`; `language` is the file's
+    /// language name from the registry, if known.
+    #[must_use]
+    pub fn select(&self, normalized: &Path, language: Option<&str>) -> Option<PatternSpecificity> {
+        match self.decide(normalized, language) {
+            Decision::Selected { include, .. } => {
+                Some(include.map_or_else(PatternSpecificity::default, |i| self.include[i].2))
+            }
+            _ => None,
+        }
+    }
+
+    /// Like [`Self::select`], but reports which selector matched or rejected the file.
+    #[must_use]
+    pub fn evaluate(&self, normalized: &Path, language: Option<&str>) -> SelectorOutcome {
+        match self.decide(normalized, language) {
+            Decision::Selected {
+                include,
+                language: matched_language,
+            } => SelectorOutcome::Selected {
+                pattern: include.map(|i| self.include[i].0.clone()),
+                language: matched_language.map(|i| self.languages[i].clone()),
+            },
+            Decision::NoPatternMatch => SelectorOutcome::NoPatternMatch,
+            Decision::ExcludedBy(i) => SelectorOutcome::ExcludedBy {
+                pattern: self.exclude[i].0.clone(),
+            },
+            Decision::LanguageMismatch => SelectorOutcome::LanguageMismatch {
+                language: language.map(String::from),
+            },
+        }
+    }
+
+    fn decide(&self, normalized: &Path, language: Option<&str>) -> Decision {
+        let include = if self.include.is_empty() {
+            None
+        } else {
+            let best = self
+                .include
+                .iter()
+                .enumerate()
+                .filter(|(_, (_, matcher, _))| matcher.is_match(normalized))
+                .max_by_key(|(_, (_, _, specificity))| *specificity);
+            match best {
+                Some((i, _)) => Some(i),
+                None => return Decision::NoPatternMatch,
+            }
+        };
+
+        if let Some(i) = self
+            .exclude
+            .iter()
+            .position(|(_, matcher)| matcher.is_match(normalized))
+        {
+            return Decision::ExcludedBy(i);
+        }
+
+        let language = if self.languages.is_empty() {
+            None
+        } else {
+            let position = language.and_then(|name| {
+                self.languages
+                    .iter()
+                    .position(|wanted| wanted.eq_ignore_ascii_case(name))
+            });
+            match position {
+                Some(i) => Some(i),
+                None => return Decision::LanguageMismatch,
+            }
+        };
+
+        Decision::Selected { include, language }
+    }
+}
+
+/// Selectors of all `[[content.rules]]`, with a combined `GlobSet` to pre-filter candidates.
+pub struct SelectorIndex {
+    selectors: Vec<ContentSelector>,
+    /// Every rule's include patterns; `glob_owners[i]` is the rule owning glob `i`.
+    include_set: GlobSet,
+    glob_owners: Vec<usize>,
+    /// Rules without path patterns (selected by `languages` alone).
+    pathless_rules: Vec<usize>,
+    /// Language lookup for `languages` selectors (None when no rule uses them).
+    languages: Option<LanguageRegistry>,
+}
+
+impl SelectorIndex {
+    /// Compile the selectors of `config.content.rules`.
+    ///
+    /// # Errors
+    /// Returns `SlocGuardError::InvalidPattern` for the first invalid glob (fail-fast).
+    pub fn new(config: &Config) -> crate::Result<Self> {
+        let mut selectors = Vec::new();
+        let mut builder = GlobSetBuilder::new();
+        let mut glob_owners = Vec::new();
+        let mut pathless_rules = Vec::new();
+
+        for (index, rule) in config.content.rules.iter().enumerate() {
+            let selector = ContentSelector::new(rule)?;
+            for (_, matcher, _) in &selector.include {
+                builder.add(matcher.glob().clone());
+                glob_owners.push(index);
+            }
+            if selector.include.is_empty() {
+                pathless_rules.push(index);
+            }
+            selectors.push(selector);
+        }
+
+        let include_set =
+            builder
+                .build()
+                .map_err(|source| crate::error::SlocGuardError::InvalidPattern {
+                    pattern: "<combined globset>".to_string(),
+                    source,
+                })?;
+        let languages = config
+            .content
+            .rules
+            .iter()
+            .any(|rule| !rule.languages.is_empty())
+            .then(|| LanguageRegistry::with_custom_languages(&config.languages));
+        Ok(Self {
+            selectors,
+            include_set,
+            glob_owners,
+            pathless_rules,
+            languages,
+        })
+    }
+
+    /// Rules selecting a pre-normalized path, in declaration order, with the
+    /// specificity of each rule's selecting pattern.
+    #[must_use]
+    pub fn selected(&self, normalized: &Path) -> Vec<(usize, PatternSpecificity)> {
+        let language = self.language_of(normalized);
+        let mut candidates: Vec<usize> = self
+            .include_set
+            .matches(normalized)
+            .into_iter()
+            .map(|glob| self.glob_owners[glob])
+            .chain(self.pathless_rules.iter().copied())
+            .collect();
+        candidates.sort_unstable();
+        candidates.dedup();
+        candidates
+            .into_iter()
+            .filter_map(|index| {
+                let specificity = self.selectors[index].select(normalized, language)?;
+                Some((index, specificity))
+            })
+            .collect()
+    }
+
+    /// Outcome of rule `index` for a pre-normalized path, with the specificity to rank it by
+    /// (its most specific pattern when the file is not selected).
+    #[must_use]
+    pub fn evaluate(
+        &self,
+        index: usize,
+        normalized: &Path,
+    ) -> (SelectorOutcome, PatternSpecificity) {
+        let selector = &self.selectors[index];
+        let language = self.language_of(normalized);
+        let specificity = selector
+            .select(normalized, language)
+            .unwrap_or_else(|| selector.specificity());
+        (selector.evaluate(normalized, language), specificity)
+    }
+
+    /// Language name of a path by extension, only when some rule filters on `languages`.
+    fn language_of(&self, path: &Path) -> Option<&str> {
+        let registry = self.languages.as_ref()?;
+        let ext = path.extension()?.to_str()?;
+        registry
+            .get_by_extension(ext)
+            .map(|lang| lang.name.as_str())
+    }
+}
+
+fn compile(pattern: &str) -> crate::Result<GlobMatcher> {
+    Glob::new(pattern)
+        .map(|glob| glob.compile_matcher())
+        .map_err(|source| crate::error::SlocGuardError::InvalidPattern {
+            pattern: pattern.to_string(),
+            source,
+        })
+}
+
+#[cfg(test)]
+#[path = "content_selector_tests.rs"]
+mod tests;
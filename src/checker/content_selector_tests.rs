@@ -0,0 +1,137 @@
+use std::path::Path;
+
+use super::*;
+
+fn rule(pattern: &str) -> ContentRule {
+    ContentRule {
+        pattern: pattern.to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
+        max_lines: 500,
+        warn_threshold: None,
+        warn_at: None,
+        skip_comments: None,
+        skip_blank: None,
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
+        reason: None,
+        expires: None,
+        on_expire: None,
+    }
+}
+
+fn strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(ToString::to_string).collect()
+}
+
+#[test]
+fn any_include_pattern_selects() {
+    let mut typescript = rule("");
+    typescript.patterns = strings(&["packages/*/src/**/*.ts", "packages/*/src/**/*.tsx"]);
+    let selector = ContentSelector::new(&typescript).unwrap();
+
+    assert!(
+        selector
+            .select(Path::new("packages/web/src/app.tsx"), None)
+            .is_some()
+    );
+    assert_eq!(
+        selector.evaluate(Path::new("packages/web/lib/app.ts"), None),
+        SelectorOutcome::NoPatternMatch
+    );
+}
+
+#[test]
+fn exclude_pattern_removes_selected_file() {
+    let mut typescript = rule("packages/*/src/**/*.ts");
+    typescript.exclude_patterns = strings(&["**/*.d.ts"]);
+    let selector = ContentSelector::new(&typescript).unwrap();
+
+    assert_eq!(
+        selector.evaluate(Path::new("packages/web/src/types.d.ts"), None),
+        SelectorOutcome::ExcludedBy {
+            pattern: "**/*.d.ts".to_string()
+        }
+    );
+    assert!(
+        selector
+            .select(Path::new("packages/web/src/main.ts"), None)
+            .is_some()
+    );
+}
+
+#[test]
+fn languages_match_case_insensitively() {
+    let mut rust = rule("");
+    rust.languages = strings(&["rust", "Go"]);
+    let selector = ContentSelector::new(&rust).unwrap();
+
+    assert_eq!(
+        selector.evaluate(Path::new("anything/main.rs"), Some("Rust")),
+        SelectorOutcome::Selected {
+            pattern: None,
+            language: Some("rust".to_string()),
+        }
+    );
+    assert_eq!(
+        selector.evaluate(Path::new("app.py"), Some("Python")),
+        SelectorOutcome::LanguageMismatch {
+            language: Some("Python".to_string())
+        }
+    );
+    assert!(selector.select(Path::new("README"), None).is_none());
+}
+
+#[test]
+fn fields_combine_with_and() {
+    let mut scoped = rule("src/**");
+    scoped.languages = strings(&["Rust"]);
+    let selector = ContentSelector::new(&scoped).unwrap();
+
+    assert!(
+        selector
+            .select(Path::new("src/lib.rs"), Some("Rust"))
+            .is_some()
+    );
+    assert!(
+        selector
+            .select(Path::new("src/app.py"), Some("Python"))
+            .is_none()
+    );
+    assert!(
+        selector
+            .select(Path::new("tests/it.rs"), Some("Rust"))
+            .is_none()
+    );
+}
+
+#[test]
+fn selected_pattern_is_most_specific_match() {
+    let mut api = rule("src/**");
+    api.patterns = strings(&["src/api/**"]);
+    let selector = ContentSelector::new(&api).unwrap();
+
+    assert_eq!(
+        selector.evaluate(Path::new("src/api/routes.rs"), None),
+        SelectorOutcome::Selected {
+            pattern: Some("src/api/**".to_string()),
+            language: None,
+        }
+    );
+    assert_eq!(
+        selector.select(Path::new("src/api/routes.rs"), None),
+        Some(PatternSpecificity::of("src/api/**"))
+    );
+}
+
+#[test]
+fn invalid_exclude_pattern_is_rejected() {
+    let mut broken = rule("src/**");
+    broken.exclude_patterns = strings(&["[invalid"]);
+
+    assert!(ContentSelector::new(&broken).is_err());
+}
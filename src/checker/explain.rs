@@ -1,7 +1,14 @@
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
 use serde::Serialize;
 
+use crate::config::{ContentMetric, RuleResolution};
+
+use super::comment_ratio::CommentRatioLimits;
+use super::content_selector::SelectorOutcome;
+use super::rule_resolution::{RuleRank, RuleWinReason};
+
 /// Source of the effective `warn_at` value for debugging.
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -26,6 +33,8 @@ pub enum MatchStatus {
     Superseded,
     /// Pattern did not match the path
     NoMatch,
+    /// Pattern matched but the rule lapsed (`on_expire = "revert"` past its `expires` date)
+    Expired,
 }
 
 /// Which type of content rule matched for a file.
@@ -53,8 +62,13 @@ pub struct ContentRuleCandidate {
     pub pattern: Option<String>,
     /// Line limit for this rule
     pub limit: usize,
+    /// Priority and pattern specificity (None for the defaults entry)
+    pub rank: Option<RuleRank>,
     /// Match status
     pub status: MatchStatus,
+    /// Which selector matched or rejected the file (None for the defaults entry)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selector: Option<SelectorOutcome>,
 }
 
 /// Explanation of which content rule matched for a file.
@@ -78,8 +92,19 @@ pub struct ContentExplanation {
     pub skip_comments: bool,
     /// Whether blank lines are skipped
     pub skip_blank: bool,
+    /// Explicit line metric (overrides `skip_comments`/`skip_blank` when set)
+    pub metric: Option<ContentMetric>,
+    /// Comment ratio limits from the matched rule (None if not configured)
+    pub comment_ratio: Option<CommentRatioLimits>,
+    /// Tie-breaking mode used to pick among matching rules
+    pub rule_resolution: RuleResolution,
+    /// Why the matched rule beat the other matching rules (None if no rule matched)
+    pub win_reason: Option<RuleWinReason>,
     /// All candidates evaluated (for debugging)
     pub rule_chain: Vec<ContentRuleCandidate>,
+    /// Set when the explained path is itself a symlink
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symlink: Option<SymlinkInfo>,
 }
 
 /// Which type of structure rule matched for a directory.
@@ -109,8 +134,22 @@ pub struct StructureRuleCandidate {
     pub max_dirs: Option<i64>,
     /// Max depth limit (-1 for unlimited)
     pub max_depth: Option<i64>,
+    /// Max files in the whole subtree (-1 for unlimited)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_total_files: Option<i64>,
+    /// Max directories in the whole subtree (-1 for unlimited)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_total_dirs: Option<i64>,
+    /// Per-extension file limits (-1 for unlimited)
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub max_files_by_ext: BTreeMap<String, i64>,
+    /// Priority and pattern specificity (None for the defaults entry)
+    pub rank: Option<RuleRank>,
     /// Match status
     pub status: MatchStatus,
+    /// Which scope included or excluded the directory (None for the defaults entry)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selector: Option<SelectorOutcome>,
 }
 
 /// Explanation of which structure rule matched for a directory.
@@ -126,10 +165,35 @@ pub struct StructureExplanation {
     pub effective_max_dirs: Option<i64>,
     /// Effective max depth limit (-1 for unlimited)
     pub effective_max_depth: Option<i64>,
+    /// Effective max files in the whole subtree (-1 for unlimited)
+    pub effective_max_total_files: Option<i64>,
+    /// Effective max directories in the whole subtree (-1 for unlimited)
+    pub effective_max_total_dirs: Option<i64>,
+    /// Effective per-extension file limits (-1 for unlimited)
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub effective_max_files_by_ext: BTreeMap<String, i64>,
     /// Warning threshold (0.0-1.0)
     pub warn_threshold: f64,
     /// Override reason if applicable
     pub override_reason: Option<String>,
+    /// Tie-breaking mode used to pick among matching rules
+    pub rule_resolution: RuleResolution,
+    /// Why the matched rule beat the other matching rules (None if no rule matched)
+    pub win_reason: Option<RuleWinReason>,
     /// All candidates evaluated (for debugging)
     pub rule_chain: Vec<StructureRuleCandidate>,
+    /// Set when the explained path is itself a symlink
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symlink: Option<SymlinkInfo>,
+}
+
+/// Where a symlinked path points; filled in by the `explain` command, not the checkers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SymlinkInfo {
+    /// Link target as stored in the symlink
+    pub target: PathBuf,
+    /// Target with all links resolved (lexically, for dangling links)
+    pub resolved: PathBuf,
+    /// Resolved target lies outside the project root
+    pub outside_project: bool,
 }
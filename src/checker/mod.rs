@@ -1,15 +1,28 @@
+mod comment_ratio;
+mod content_selector;
 mod explain;
 mod result;
+mod rule_resolution;
+mod scope_selector;
 mod structure;
 mod threshold;
 
+pub use comment_ratio::{
+    CommentRatioBound, CommentRatioLimits, DEFAULT_COMMENT_RATIO_MIN_CODE, comment_ratio,
+};
+pub use content_selector::SelectorOutcome;
 pub use explain::{
     ContentExplanation, ContentRuleCandidate, ContentRuleMatch, MatchStatus, StructureExplanation,
-    StructureRuleCandidate, StructureRuleMatch, WarnAtSource,
+    StructureRuleCandidate, StructureRuleMatch, SymlinkInfo, WarnAtSource,
 };
 pub use result::CheckResult;
-pub use structure::StructureChecker;
-pub use structure::violation::{DirStats, StructureViolation, ViolationCategory, ViolationType};
+pub(crate) use rule_resolution::select_winner;
+pub use rule_resolution::{PatternSpecificity, RuleRank, RuleWinReason};
+pub use scope_selector::ScopeSelector;
+pub use structure::violation::{
+    LayoutProblem, StructureViolation, SymlinkProblem, ViolationCategory, ViolationType,
+};
+pub use structure::{DirStats, StructureChecker};
 pub use threshold::ThresholdChecker;
 
 use std::path::Path;
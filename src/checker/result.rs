@@ -1,6 +1,7 @@
 use std::path::{Path, PathBuf};
 
-use crate::analyzer::SplitSuggestion;
+use crate::analyzer::{ReorganizeSuggestion, SplitSuggestion, Suggestion};
+use crate::config::ContentMetric;
 use crate::counter::LineStats;
 
 use super::structure::violation::ViolationCategory;
@@ -32,7 +33,7 @@ pub enum CheckResult {
         raw_stats: Option<LineStats>,
         limit: usize,
         override_reason: Option<String>,
-        suggestions: Option<SplitSuggestion>,
+        suggestions: Option<Suggestion>,
         violation_category: Option<ViolationCategory>,
     },
     Failed {
@@ -41,7 +42,7 @@ pub enum CheckResult {
         raw_stats: Option<LineStats>,
         limit: usize,
         override_reason: Option<String>,
-        suggestions: Option<SplitSuggestion>,
+        suggestions: Option<Suggestion>,
         violation_category: Option<ViolationCategory>,
     },
     Grandfathered {
@@ -129,6 +130,23 @@ impl CheckResult {
     #[must_use]
     #[allow(clippy::missing_const_for_fn)] // Accessing option reference isn't const
     pub fn suggestions(&self) -> Option<&SplitSuggestion> {
+        match self.suggestion() {
+            Some(Suggestion::Split(split)) => Some(split),
+            _ => None,
+        }
+    }
+
+    /// Directory reorganisation suggestions for a structure file-count violation.
+    #[must_use]
+    pub fn reorganization(&self) -> Option<&ReorganizeSuggestion> {
+        match self.suggestion() {
+            Some(Suggestion::Reorganize(reorganize)) => Some(reorganize),
+            _ => None,
+        }
+    }
+
+    #[allow(clippy::missing_const_for_fn)] // Accessing option reference isn't const
+    fn suggestion(&self) -> Option<&Suggestion> {
         match self {
             Self::Warning { suggestions, .. } | Self::Failed { suggestions, .. } => {
                 suggestions.as_ref()
@@ -156,6 +174,51 @@ impl CheckResult {
         }
     }
 
+    /// Returns the line metric a content result was counted with.
+    ///
+    /// Legacy `skip_comments`/`skip_blank` settings report their equivalent metric;
+    /// `None` means no metric matches them (or the result is not a line count).
+    #[must_use]
+    pub fn metric(&self) -> Option<ContentMetric> {
+        match self.violation_category() {
+            Some(ViolationCategory::Content { metric, .. }) => Some(*metric),
+            _ => None,
+        }
+    }
+
+    /// Returns the line metric only when it was explicitly configured, for labelling counts.
+    #[must_use]
+    pub fn configured_metric(&self) -> Option<ContentMetric> {
+        match self.violation_category() {
+            Some(ViolationCategory::Content {
+                metric,
+                configured: true,
+            }) => Some(*metric),
+            _ => None,
+        }
+    }
+
+    /// Description of a violation that is not a plain line count, e.g.
+    /// "comment ratio 4.2% is below minimum 10.0%" or
+    /// "content.rules[1] (pattern 'src/legacy/**') expired on 2025-06-01".
+    #[must_use]
+    pub fn category_message(&self) -> Option<String> {
+        match self.violation_category() {
+            Some(ViolationCategory::CommentRatio {
+                bound,
+                ratio,
+                threshold,
+            }) => Some(bound.describe(*ratio, *threshold)),
+            Some(ViolationCategory::ExpiredExemption {
+                rule,
+                pattern,
+                expires,
+                ..
+            }) => Some(format!("{rule} (pattern '{pattern}') expired on {expires}")),
+            _ => None,
+        }
+    }
+
     // Predicate methods
 
     #[must_use]
@@ -229,6 +292,17 @@ impl CheckResult {
     /// Returns self unchanged if Passed or Grandfathered.
     #[must_use]
     pub fn with_suggestions(self, new_suggestions: SplitSuggestion) -> Self {
+        self.with_suggestion(Suggestion::Split(new_suggestions))
+    }
+
+    /// Add directory reorganisation suggestions to a Warning or Failed result.
+    /// Returns self unchanged if Passed or Grandfathered.
+    #[must_use]
+    pub fn with_reorganization(self, new_suggestions: ReorganizeSuggestion) -> Self {
+        self.with_suggestion(Suggestion::Reorganize(new_suggestions))
+    }
+
+    fn with_suggestion(self, new_suggestions: Suggestion) -> Self {
         match self {
             Self::Warning {
                 path,
@@ -0,0 +1,140 @@
+use serde::Serialize;
+
+use crate::config::RuleResolution;
+
+/// Specificity of a glob pattern, compared by `RuleResolution::MostSpecific`.
+///
+/// Ordering is lexicographic: literal length first, then literal depth.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct PatternSpecificity {
+    /// Characters outside wildcard tokens (`*`, `?`, `[...]`, `{...}`).
+    pub literal_len: usize,
+    /// Leading path segments that contain no wildcard.
+    pub literal_depth: usize,
+}
+
+impl PatternSpecificity {
+    /// Compute the specificity of a glob pattern.
+    #[must_use]
+    pub fn of(pattern: &str) -> Self {
+        let mut literal_len = 0;
+        let mut group_depth = 0usize;
+        for c in pattern.chars() {
+            match c {
+                '[' | '{' => group_depth += 1,
+                ']' | '}' => group_depth = group_depth.saturating_sub(1),
+                '*' | '?' => {}
+                _ if group_depth == 0 => literal_len += 1,
+                _ => {}
+            }
+        }
+
+        let literal_depth = pattern
+            .split('/')
+            .take_while(|segment| !segment.is_empty() && !has_wildcard(segment))
+            .count();
+
+        Self {
+            literal_len,
+            literal_depth,
+        }
+    }
+}
+
+fn has_wildcard(segment: &str) -> bool {
+    segment.contains(['*', '?', '[', '{'])
+}
+
+/// Ordering inputs for a single rule.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct RuleRank {
+    /// Explicit `priority` (0 when unset).
+    pub priority: i32,
+    pub specificity: PatternSpecificity,
+}
+
+impl RuleRank {
+    #[must_use]
+    pub fn new(pattern: &str, priority: Option<i32>) -> Self {
+        Self {
+            priority: priority.unwrap_or(0),
+            specificity: PatternSpecificity::of(pattern),
+        }
+    }
+
+    /// Rank from a precomputed specificity (e.g. the matching one of several patterns).
+    #[must_use]
+    pub const fn with_specificity(specificity: PatternSpecificity, priority: Option<i32>) -> Self {
+        Self {
+            priority: match priority {
+                Some(priority) => priority,
+                None => 0,
+            },
+            specificity,
+        }
+    }
+
+    /// Sort key: priority, then specificity (`most_specific` only), then declaration order.
+    fn key(self, index: usize, mode: RuleResolution) -> (i32, PatternSpecificity, usize) {
+        let specificity = match mode {
+            RuleResolution::LastMatch => PatternSpecificity::default(),
+            RuleResolution::MostSpecific => self.specificity,
+        };
+        (self.priority, specificity, index)
+    }
+}
+
+/// Why the winning rule was chosen over the strongest other matching rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RuleWinReason {
+    /// No other rule matched.
+    OnlyMatch,
+    /// Higher explicit `priority` than the runner-up.
+    HigherPriority { runner_up: usize },
+    /// Same priority, more specific pattern than the runner-up (`most_specific` mode).
+    MoreSpecific { runner_up: usize },
+    /// Tied on everything else; declared after the runner-up.
+    LaterDeclaration { runner_up: usize },
+}
+
+/// Pick the winning rule index among `matching`, or `None` if nothing matched.
+pub fn select_winner(
+    matching: impl IntoIterator<Item = usize>,
+    rank_of: impl Fn(usize) -> RuleRank,
+    mode: RuleResolution,
+) -> Option<usize> {
+    matching
+        .into_iter()
+        .max_by_key(|&index| rank_of(index).key(index, mode))
+}
+
+/// Pick the winning rule index and explain why it beat the runner-up.
+pub fn explain_winner(
+    matching: &[usize],
+    rank_of: impl Fn(usize) -> RuleRank,
+    mode: RuleResolution,
+) -> Option<(usize, RuleWinReason)> {
+    let winner = select_winner(matching.iter().copied(), &rank_of, mode)?;
+    let Some(runner_up) = select_winner(
+        matching.iter().copied().filter(|&index| index != winner),
+        &rank_of,
+        mode,
+    ) else {
+        return Some((winner, RuleWinReason::OnlyMatch));
+    };
+
+    let (won, lost) = (rank_of(winner), rank_of(runner_up));
+    let reason = if won.priority != lost.priority {
+        RuleWinReason::HigherPriority { runner_up }
+    } else if mode == RuleResolution::MostSpecific && won.specificity != lost.specificity {
+        RuleWinReason::MoreSpecific { runner_up }
+    } else {
+        RuleWinReason::LaterDeclaration { runner_up }
+    };
+    Some((winner, reason))
+}
+
+#[cfg(test)]
+#[path = "rule_resolution_tests.rs"]
+mod tests;
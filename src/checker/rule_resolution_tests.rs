@@ -0,0 +1,96 @@
+use super::*;
+
+const PATTERNS: &[&str] = &["**/*.rs", "src/api/**", "src/api/v1/*.rs"];
+
+fn ranks(priorities: &[Option<i32>]) -> Vec<RuleRank> {
+    PATTERNS
+        .iter()
+        .zip(priorities)
+        .map(|(pattern, priority)| RuleRank::new(pattern, *priority))
+        .collect()
+}
+
+#[test]
+fn specificity_counts_literal_characters_and_leading_segments() {
+    assert_eq!(
+        PatternSpecificity::of("src/api/**"),
+        PatternSpecificity {
+            literal_len: 8,
+            literal_depth: 2,
+        }
+    );
+    assert_eq!(
+        PatternSpecificity::of("**/*.rs"),
+        PatternSpecificity {
+            literal_len: 4,
+            literal_depth: 0,
+        }
+    );
+}
+
+#[test]
+fn specificity_ignores_bracket_and_brace_groups() {
+    let spec = PatternSpecificity::of("src/*.{rs,toml}");
+    assert_eq!(spec.literal_len, 5);
+    assert_eq!(spec.literal_depth, 1);
+}
+
+#[test]
+fn last_match_picks_highest_index() {
+    let ranks = ranks(&[None, None, None]);
+    let winner = select_winner([1, 0], |i| ranks[i], RuleResolution::LastMatch);
+    assert_eq!(winner, Some(1));
+}
+
+#[test]
+fn most_specific_ignores_declaration_order() {
+    let ranks = ranks(&[None, None, None]);
+    // `src/api/**` wins whether it is declared before or after `**/*.rs`
+    let winner = select_winner([1, 0], |i| ranks[i], RuleResolution::MostSpecific);
+    assert_eq!(winner, Some(1));
+
+    let mut reordered = ranks;
+    reordered.swap(0, 1);
+    let winner = select_winner([1, 0], |i| reordered[i], RuleResolution::MostSpecific);
+    assert_eq!(winner, Some(0));
+}
+
+#[test]
+fn priority_beats_resolution_mode() {
+    let ranks = ranks(&[Some(5), None, None]);
+    for mode in [RuleResolution::LastMatch, RuleResolution::MostSpecific] {
+        assert_eq!(select_winner([0, 1, 2], |i| ranks[i], mode), Some(0));
+    }
+}
+
+#[test]
+fn no_matches_selects_nothing() {
+    let ranks = ranks(&[None, None, None]);
+    assert_eq!(
+        select_winner(std::iter::empty(), |i| ranks[i], RuleResolution::LastMatch),
+        None
+    );
+}
+
+#[test]
+fn explain_winner_reports_reason() {
+    let ranks = ranks(&[None, Some(1), None]);
+    let explain = |matching: &[usize], mode| explain_winner(matching, |i| ranks[i], mode);
+
+    assert_eq!(
+        explain(&[0], RuleResolution::LastMatch),
+        Some((0, RuleWinReason::OnlyMatch))
+    );
+    assert_eq!(
+        explain(&[0, 1, 2], RuleResolution::LastMatch),
+        Some((1, RuleWinReason::HigherPriority { runner_up: 2 }))
+    );
+    assert_eq!(
+        explain(&[0, 2], RuleResolution::MostSpecific),
+        Some((2, RuleWinReason::MoreSpecific { runner_up: 0 }))
+    );
+    assert_eq!(
+        explain(&[0, 2], RuleResolution::LastMatch),
+        Some((2, RuleWinReason::LaterDeclaration { runner_up: 0 }))
+    );
+}
@@ -0,0 +1,172 @@
+use std::path::Path;
+
+use globset::{GlobBuilder, GlobMatcher};
+
+use crate::config::StructureRule;
+
+use super::content_selector::SelectorOutcome;
+use super::rule_resolution::PatternSpecificity;
+
+/// Compiled `scope`/`scopes`/`exclude_scopes` of one structure rule.
+///
+/// A directory is in scope when it matches any include glob and no exclude glob.
+#[derive(Debug, Clone)]
+pub struct ScopeSelector {
+    label: String,
+    include: Vec<(String, GlobMatcher, PatternSpecificity)>,
+    exclude: Vec<(String, GlobMatcher)>,
+}
+
+impl ScopeSelector {
+    /// Compile the scopes of a structure rule.
+    ///
+    /// # Errors
+    /// Returns `SlocGuardError::InvalidPattern` for the first invalid include or exclude glob.
+    pub fn new(rule: &StructureRule) -> crate::Result<Self> {
+        Self::compile(
+            rule.scope_label(),
+            rule.scope_patterns(),
+            &rule.exclude_scopes,
+            false,
+        )
+    }
+
+    /// Like [`Self::new`], but `*`, `?` and `[...]` never match `/`: `crates/*` selects
+    /// the directories directly under `crates`, not their subdirectories.
+    ///
+    /// Used for checks on a directory's own entries (`require_files`, layouts).
+    ///
+    /// # Errors
+    /// Returns `SlocGuardError::InvalidPattern` for the first invalid include or exclude glob.
+    pub fn new_exact(rule: &StructureRule) -> crate::Result<Self> {
+        Self::compile(
+            rule.scope_label(),
+            rule.scope_patterns(),
+            &rule.exclude_scopes,
+            true,
+        )
+    }
+
+    /// Selector for a single `scope` glob without exclusions.
+    ///
+    /// # Errors
+    /// Returns `SlocGuardError::InvalidPattern` if the glob is invalid.
+    pub fn from_glob(scope: &str) -> crate::Result<Self> {
+        Self::compile(scope.to_string(), std::iter::once(scope), &[], false)
+    }
+
+    /// Like [`Self::from_glob`], with the separator rules of [`Self::new_exact`].
+    ///
+    /// # Errors
+    /// Returns `SlocGuardError::InvalidPattern` if the glob is invalid.
+    pub fn from_glob_exact(scope: &str) -> crate::Result<Self> {
+        Self::compile(scope.to_string(), std::iter::once(scope), &[], true)
+    }
+
+    fn compile<'a>(
+        label: String,
+        include: impl Iterator<Item = &'a str>,
+        exclude: &[String],
+        literal_separator: bool,
+    ) -> crate::Result<Self> {
+        let include = include
+            .map(|pattern| {
+                Ok((
+                    pattern.to_string(),
+                    compile(pattern, literal_separator)?,
+                    PatternSpecificity::of(pattern),
+                ))
+            })
+            .collect::<crate::Result<_>>()?;
+        let exclude = exclude
+            .iter()
+            .map(|pattern| Ok((pattern.clone(), compile(pattern, literal_separator)?)))
+            .collect::<crate::Result<_>>()?;
+        Ok(Self {
+            label,
+            include,
+            exclude,
+        })
+    }
+
+    /// The rule's scope label (see [`StructureRule::scope_label`]).
+    #[must_use]
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Whether `dir` is in scope.
+    #[must_use]
+    pub fn is_match(&self, dir: impl AsRef<Path>) -> bool {
+        self.matching_scope(dir.as_ref()).is_some()
+    }
+
+    /// Most specific include glob selecting `dir`, or `None` if `dir` is out of scope.
+    #[must_use]
+    pub fn matching_scope(&self, dir: &Path) -> Option<&str> {
+        self.decide(dir).ok().map(|i| self.include[i].0.as_str())
+    }
+
+    /// Specificity to rank the rule by for `dir`: that of the selecting include glob,
+    /// or of the most specific one when `dir` is out of scope.
+    #[must_use]
+    pub fn specificity(&self, dir: &Path) -> PatternSpecificity {
+        self.decide(dir).map_or_else(
+            |_| {
+                self.include
+                    .iter()
+                    .map(|(_, _, specificity)| *specificity)
+                    .max()
+                    .unwrap_or_default()
+            },
+            |i| self.include[i].2,
+        )
+    }
+
+    /// Like [`Self::is_match`], but reports which glob included or excluded `dir`.
+    #[must_use]
+    pub fn evaluate(&self, dir: &Path) -> SelectorOutcome {
+        match self.decide(dir) {
+            Ok(i) => SelectorOutcome::Selected {
+                pattern: Some(self.include[i].0.clone()),
+                language: None,
+            },
+            Err(Some(i)) => SelectorOutcome::ExcludedBy {
+                pattern: self.exclude[i].0.clone(),
+            },
+            Err(None) => SelectorOutcome::NoPatternMatch,
+        }
+    }
+
+    /// Index of the selecting include glob, or the excluding glob (`None` when no
+    /// include glob matches).
+    fn decide(&self, dir: &Path) -> Result<usize, Option<usize>> {
+        let include = self
+            .include
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, matcher, _))| matcher.is_match(dir))
+            .max_by_key(|(_, (_, _, specificity))| *specificity)
+            .map(|(i, _)| i)
+            .ok_or(None)?;
+        self.exclude
+            .iter()
+            .position(|(_, matcher)| matcher.is_match(dir))
+            .map_or(Ok(include), |i| Err(Some(i)))
+    }
+}
+
+fn compile(pattern: &str, literal_separator: bool) -> crate::Result<GlobMatcher> {
+    GlobBuilder::new(pattern)
+        .literal_separator(literal_separator)
+        .build()
+        .map(|glob| glob.compile_matcher())
+        .map_err(|source| crate::error::SlocGuardError::InvalidPattern {
+            pattern: pattern.to_string(),
+            source,
+        })
+}
+
+#[cfg(test)]
+#[path = "scope_selector_tests.rs"]
+mod tests;
@@ -0,0 +1,82 @@
+use std::path::Path;
+
+use super::*;
+
+fn rule(scope: &str, scopes: &[&str], exclude_scopes: &[&str]) -> StructureRule {
+    StructureRule {
+        scope: scope.to_string(),
+        scopes: scopes.iter().map(ToString::to_string).collect(),
+        exclude_scopes: exclude_scopes.iter().map(ToString::to_string).collect(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn any_scope_selects() {
+    let selector = ScopeSelector::new(&rule("", &["src/**", "lib/**"], &[])).unwrap();
+
+    assert!(selector.is_match(Path::new("src/core")));
+    assert!(selector.is_match(Path::new("lib/util")));
+    assert!(!selector.is_match(Path::new("tests/unit")));
+    assert_eq!(selector.label(), "src/**, lib/**");
+}
+
+#[test]
+fn scope_and_scopes_combine() {
+    let selector = ScopeSelector::new(&rule("src/**", &["lib/**"], &[])).unwrap();
+
+    assert!(selector.is_match(Path::new("src/core")));
+    assert!(selector.is_match(Path::new("lib/util")));
+}
+
+#[test]
+fn exclude_scope_removes_selected_directory() {
+    let selector = ScopeSelector::new(&rule("src/**", &[], &["src/generated/**"])).unwrap();
+
+    assert!(selector.is_match(Path::new("src/core")));
+    assert!(!selector.is_match(Path::new("src/generated/api")));
+    assert_eq!(
+        selector.evaluate(Path::new("src/generated/api")),
+        SelectorOutcome::ExcludedBy {
+            pattern: "src/generated/**".to_string()
+        }
+    );
+    assert_eq!(selector.label(), "src/** [exclude: src/generated/**]");
+}
+
+#[test]
+fn most_specific_scope_is_reported() {
+    let selector = ScopeSelector::new(&rule("", &["**", "src/components/*"], &[])).unwrap();
+    let dir = Path::new("src/components/Button");
+
+    assert_eq!(selector.matching_scope(dir), Some("src/components/*"));
+    assert_eq!(
+        selector.specificity(dir),
+        PatternSpecificity::of("src/components/*")
+    );
+    assert_eq!(
+        selector.evaluate(dir),
+        SelectorOutcome::Selected {
+            pattern: Some("src/components/*".to_string()),
+            language: None,
+        }
+    );
+    assert_eq!(selector.matching_scope(Path::new("docs")), Some("**"));
+}
+
+#[test]
+fn unmatched_directory_reports_no_match() {
+    let selector = ScopeSelector::from_glob("src/**").unwrap();
+
+    assert_eq!(
+        selector.evaluate(Path::new("docs")),
+        SelectorOutcome::NoPatternMatch
+    );
+    assert_eq!(selector.matching_scope(Path::new("docs")), None);
+}
+
+#[test]
+fn invalid_scope_globs_are_rejected() {
+    assert!(ScopeSelector::new(&rule("", &["[bad"], &[])).is_err());
+    assert!(ScopeSelector::new(&rule("src/**", &[], &["[bad"])).is_err());
+}
@@ -2,37 +2,32 @@
 
 use globset::Glob;
 
-use crate::config::StructureRule;
+use crate::config::{Placeholder, StructureRule, Template, revert_after};
 use crate::error::{Result, SlocGuardError};
 
-use super::compiled_rules::{CompiledSiblingRule, CompiledStructureRule};
+use super::super::scope_selector::ScopeSelector;
+use super::compiled_rules::{CompiledSiblingRule, CompiledStructureRule, TotalLimits};
 
 /// Build compiled structure rules from config rules.
 pub(super) fn build_rules(rules: &[StructureRule]) -> Result<Vec<CompiledStructureRule>> {
     rules
         .iter()
         .map(|rule| {
-            let glob = Glob::new(&rule.scope).map_err(|e| SlocGuardError::InvalidPattern {
-                pattern: rule.scope.clone(),
-                source: e,
-            })?;
-
-            // Calculate base_depth: count path components before first glob metacharacter
-            let base_depth = calculate_base_depth(&rule.scope);
-
             Ok(CompiledStructureRule {
-                scope: rule.scope.clone(),
-                matcher: glob.compile_matcher(),
+                selector: ScopeSelector::new(rule)?,
                 max_files: rule.max_files,
                 max_dirs: rule.max_dirs,
                 max_depth: rule.max_depth,
                 relative_depth: rule.relative_depth,
-                base_depth,
                 warn_threshold: rule.warn_threshold,
                 warn_files_at: rule.warn_files_at,
                 warn_dirs_at: rule.warn_dirs_at,
                 warn_files_threshold: rule.warn_files_threshold,
                 warn_dirs_threshold: rule.warn_dirs_threshold,
+                totals: TotalLimits::from_rule(rule),
+                max_files_by_ext: rule.max_files_by_ext.clone(),
+                priority: rule.priority,
+                revert_after: revert_after(rule.expires.as_deref(), rule.on_expire),
                 reason: rule.reason.clone(),
             })
         })
@@ -46,11 +41,8 @@ pub(super) fn build_sibling_rules(rules: &[StructureRule]) -> Result<Vec<Compile
     let mut compiled_rules = Vec::new();
 
     for rule in rules {
-        let dir_glob = Glob::new(&rule.scope).map_err(|e| SlocGuardError::InvalidPattern {
-            pattern: rule.scope.clone(),
-            source: e,
-        })?;
-        let dir_matcher = dir_glob.compile_matcher();
+        let dir_matcher = ScopeSelector::new(rule)?;
+        let revert_after = revert_after(rule.expires.as_deref(), rule.on_expire);
 
         for sibling in &rule.siblings {
             match sibling {
@@ -66,23 +58,75 @@ pub(super) fn build_sibling_rules(rules: &[StructureRule]) -> Result<Vec<Compile
                         })?;
 
                     compiled_rules.push(CompiledSiblingRule::Directed {
-                        dir_scope: rule.scope.clone(),
+                        dir_scope: dir_matcher.label().to_string(),
                         dir_matcher: dir_matcher.clone(),
                         file_matcher: file_glob.compile_matcher(),
-                        sibling_templates: require
-                            .as_patterns()
-                            .iter()
-                            .map(|s| (*s).to_string())
-                            .collect(),
+                        sibling_templates: parse_templates(
+                            require.as_patterns(),
+                            Placeholder::SIBLING,
+                        )?,
                         is_warning: *severity == SiblingSeverity::Warn,
+                        revert_after,
                     });
                 }
                 SiblingRule::Group { group, severity } => {
                     compiled_rules.push(CompiledSiblingRule::Group {
-                        dir_scope: rule.scope.clone(),
+                        dir_scope: dir_matcher.label().to_string(),
+                        dir_matcher: dir_matcher.clone(),
+                        group_patterns: parse_templates(
+                            group.iter().map(String::as_str),
+                            Placeholder::GROUP,
+                        )?,
+                        is_warning: *severity == SiblingSeverity::Warn,
+                        revert_after,
+                    });
+                }
+                SiblingRule::Forbid {
+                    match_pattern,
+                    forbid,
+                    severity,
+                } => {
+                    let file_glob =
+                        Glob::new(match_pattern).map_err(|e| SlocGuardError::InvalidPattern {
+                            pattern: match_pattern.clone(),
+                            source: e,
+                        })?;
+
+                    compiled_rules.push(CompiledSiblingRule::Forbid {
+                        dir_scope: dir_matcher.label().to_string(),
+                        dir_matcher: dir_matcher.clone(),
+                        file_matcher: file_glob.compile_matcher(),
+                        sibling_templates: parse_templates(
+                            forbid.as_patterns(),
+                            Placeholder::SIBLING,
+                        )?,
+                        is_warning: *severity == SiblingSeverity::Warn,
+                        revert_after,
+                    });
+                }
+                SiblingRule::Exclusive {
+                    exclusive,
+                    severity,
+                } => {
+                    let matchers = exclusive
+                        .iter()
+                        .map(|pattern| {
+                            Glob::new(pattern)
+                                .map(|glob| glob.compile_matcher())
+                                .map_err(|e| SlocGuardError::InvalidPattern {
+                                    pattern: pattern.clone(),
+                                    source: e,
+                                })
+                        })
+                        .collect::<Result<Vec<_>>>()?;
+
+                    compiled_rules.push(CompiledSiblingRule::Exclusive {
+                        dir_scope: dir_matcher.label().to_string(),
                         dir_matcher: dir_matcher.clone(),
-                        group_patterns: group.clone(),
+                        patterns: exclusive.clone(),
+                        matchers,
                         is_warning: *severity == SiblingSeverity::Warn,
+                        revert_after,
                     });
                 }
             }
@@ -92,6 +136,20 @@ pub(super) fn build_sibling_rules(rules: &[StructureRule]) -> Result<Vec<Compile
     Ok(compiled_rules)
 }
 
+fn parse_templates<'a>(
+    patterns: impl IntoIterator<Item = &'a str>,
+    allowed: &[Placeholder],
+) -> Result<Vec<Template>> {
+    patterns
+        .into_iter()
+        .map(|pattern| {
+            Template::parse(pattern, allowed).map_err(|e| {
+                SlocGuardError::Config(format!("Invalid sibling pattern '{pattern}': {e}"))
+            })
+        })
+        .collect()
+}
+
 /// Calculate the depth of the pattern's base directory.
 /// This is the number of path components before the first glob metacharacter.
 /// Examples:
@@ -67,6 +67,10 @@ fn check_under_limit_returns_no_violations() {
             file_count: 5,
             dir_count: 2,
             depth: 0,
+            count_excluded: false,
+            entry_count: 7,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -85,6 +89,10 @@ fn violations_sorted_by_path() {
             file_count: 10,
             dir_count: 0,
             depth: 0,
+            count_excluded: false,
+            entry_count: 10,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
     stats.insert(
@@ -93,6 +101,10 @@ fn violations_sorted_by_path() {
             file_count: 10,
             dir_count: 0,
             depth: 0,
+            count_excluded: false,
+            entry_count: 10,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
     stats.insert(
@@ -101,6 +113,10 @@ fn violations_sorted_by_path() {
             file_count: 10,
             dir_count: 0,
             depth: 0,
+            count_excluded: false,
+            entry_count: 10,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -25,6 +25,10 @@ fn check_depth_under_limit_returns_no_violations() {
             file_count: 0,
             dir_count: 1,
             depth: 0,
+            count_excluded: false,
+            entry_count: 1,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
     stats.insert(
@@ -33,6 +37,10 @@ fn check_depth_under_limit_returns_no_violations() {
             file_count: 0,
             dir_count: 1,
             depth: 1,
+            count_excluded: false,
+            entry_count: 1,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
     stats.insert(
@@ -41,6 +49,10 @@ fn check_depth_under_limit_returns_no_violations() {
             file_count: 0,
             dir_count: 0,
             depth: 2,
+            count_excluded: false,
+            entry_count: 0,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -58,6 +70,10 @@ fn check_depth_over_limit_returns_violation() {
             file_count: 0,
             dir_count: 1,
             depth: 0,
+            count_excluded: false,
+            entry_count: 1,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
     stats.insert(
@@ -66,6 +82,10 @@ fn check_depth_over_limit_returns_violation() {
             file_count: 0,
             dir_count: 1,
             depth: 1,
+            count_excluded: false,
+            entry_count: 1,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
     stats.insert(
@@ -74,6 +94,10 @@ fn check_depth_over_limit_returns_violation() {
             file_count: 0,
             dir_count: 1,
             depth: 2,
+            count_excluded: false,
+            entry_count: 1,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
     stats.insert(
@@ -82,6 +106,10 @@ fn check_depth_over_limit_returns_violation() {
             file_count: 0,
             dir_count: 0,
             depth: 3, // Exceeds limit of 2
+            count_excluded: false,
+            entry_count: 0,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -106,6 +134,10 @@ fn unlimited_depth_skips_check() {
             file_count: 0,
             dir_count: 0,
             depth: 100, // Very deep, but unlimited
+            count_excluded: false,
+            entry_count: 0,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -132,6 +164,10 @@ fn rule_overrides_global_depth_limit() {
             file_count: 0,
             dir_count: 0,
             depth: 4, // Exceeds global (2), but within rule (5)
+            count_excluded: false,
+            entry_count: 0,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -154,6 +190,10 @@ fn depth_warn_threshold() {
             file_count: 0,
             dir_count: 0,
             depth: 4, // Above 3 (warn), below 5 (limit)
+            count_excluded: false,
+            entry_count: 0,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -180,6 +220,10 @@ fn depth_warn_threshold_does_not_warn_at_threshold_boundary() {
             file_count: 0,
             dir_count: 0,
             depth: 3, // Exactly at warn threshold
+            count_excluded: false,
+            entry_count: 0,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -248,6 +292,10 @@ fn calculate_base_depth_simple_pattern() {
             file_count: 0,
             dir_count: 0,
             depth: 3,
+            count_excluded: false,
+            entry_count: 0,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -278,6 +326,10 @@ fn relative_depth_allows_deep_nesting_within_base() {
             file_count: 0,
             dir_count: 0,
             depth: 4,
+            count_excluded: false,
+            entry_count: 0,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -307,6 +359,10 @@ fn relative_depth_violates_when_too_deep() {
             file_count: 0,
             dir_count: 0,
             depth: 5, // absolute depth
+            count_excluded: false,
+            entry_count: 0,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -339,6 +395,10 @@ fn relative_depth_false_uses_absolute_depth() {
             file_count: 0,
             dir_count: 0,
             depth: 3,
+            count_excluded: false,
+            entry_count: 0,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -371,6 +431,10 @@ fn relative_depth_with_wildcard_in_middle() {
             file_count: 0,
             dir_count: 0,
             depth: 4,
+            count_excluded: false,
+            entry_count: 0,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -401,6 +465,10 @@ fn relative_depth_with_double_star_at_start() {
             file_count: 0,
             dir_count: 0,
             depth: 4,
+            count_excluded: false,
+            entry_count: 0,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -432,6 +500,10 @@ fn relative_depth_warn_threshold() {
             file_count: 0,
             dir_count: 0,
             depth: 6, // abs depth
+            count_excluded: false,
+            entry_count: 0,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -468,6 +540,10 @@ fn relative_depth_moving_base_works() {
             file_count: 0,
             dir_count: 0,
             depth: 6,
+            count_excluded: false,
+            entry_count: 0,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -498,6 +574,10 @@ fn relative_depth_saturating_sub_for_shallow_paths() {
             file_count: 0,
             dir_count: 0,
             depth: 2,
+            count_excluded: false,
+            entry_count: 0,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -0,0 +1,98 @@
+//! `deny_empty_dirs` tests.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use super::*;
+use crate::config::DenyEmptyDirs;
+
+fn dir(file_count: usize, dir_count: usize, entry_count: usize, depth: usize) -> DirStats {
+    DirStats {
+        file_count,
+        dir_count,
+        depth,
+        count_excluded: false,
+        entry_count,
+        files_by_ext: HashMap::new(),
+        is_symlink: false,
+    }
+}
+
+/// `src` -> `src/empty` (nothing) and `src/kept` (only `.gitkeep`, count-excluded).
+fn stats() -> HashMap<PathBuf, DirStats> {
+    HashMap::from([
+        (PathBuf::from("."), dir(0, 1, 1, 0)),
+        (PathBuf::from("src"), dir(1, 2, 3, 1)),
+        (PathBuf::from("src/empty"), dir(0, 0, 0, 2)),
+        (PathBuf::from("src/kept"), dir(0, 0, 1, 2)),
+    ])
+}
+
+fn checker(deny_empty_dirs: DenyEmptyDirs) -> StructureChecker {
+    let config = StructureConfig {
+        deny_empty_dirs,
+        ..Default::default()
+    };
+    StructureChecker::new(&config).unwrap()
+}
+
+#[test]
+fn deny_empty_dirs_enables_checker() {
+    assert!(!checker(DenyEmptyDirs::Off).is_enabled());
+    assert!(checker(DenyEmptyDirs::Empty).is_enabled());
+    assert!(checker(DenyEmptyDirs::IgnoreCountExclude).is_enabled());
+}
+
+#[test]
+fn empty_mode_reports_only_directories_without_entries() {
+    let violations = checker(DenyEmptyDirs::Empty).check(&stats());
+
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].path, PathBuf::from("src/empty"));
+    assert_eq!(violations[0].violation_type, ViolationType::EmptyDirectory);
+    assert_eq!(violations[0].actual, 0);
+    assert!(!violations[0].is_warning);
+}
+
+#[test]
+fn ignore_count_exclude_mode_reports_placeholder_only_directories() {
+    let violations = checker(DenyEmptyDirs::IgnoreCountExclude).check(&stats());
+
+    let paths: Vec<_> = violations.iter().map(|v| v.path.clone()).collect();
+    assert_eq!(
+        paths,
+        vec![PathBuf::from("src/empty"), PathBuf::from("src/kept")]
+    );
+    assert_eq!(violations[1].actual, 1);
+}
+
+#[test]
+fn scan_root_is_never_reported() {
+    let stats = HashMap::from([(PathBuf::from("."), dir(0, 0, 0, 0))]);
+
+    assert!(checker(DenyEmptyDirs::Empty).check(&stats).is_empty());
+}
+
+#[test]
+fn count_excluded_directories_are_not_reported() {
+    let mut stats = stats();
+    stats
+        .get_mut(&PathBuf::from("src/empty"))
+        .unwrap()
+        .count_excluded = true;
+
+    let violations = checker(DenyEmptyDirs::Empty).check(&stats);
+
+    assert!(violations.is_empty());
+}
+
+#[test]
+fn off_reports_nothing() {
+    let config = StructureConfig {
+        max_files: Some(10),
+        ..Default::default()
+    };
+    let checker = StructureChecker::new(&config).unwrap();
+
+    assert!(checker.check(&stats()).is_empty());
+}
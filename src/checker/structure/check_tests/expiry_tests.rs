@@ -0,0 +1,90 @@
+//! `on_expire = "revert"` structure rules: limits, siblings and explain after expiry.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::checker::explain::{MatchStatus, StructureRuleMatch};
+use crate::config::{OnExpire, ParsedDate, SiblingRequire, SiblingRule, SiblingSeverity};
+
+use super::*;
+
+fn reverting_rule() -> StructureRule {
+    StructureRule {
+        scope: "src/legacy".to_string(),
+        max_files: Some(50),
+        siblings: vec![SiblingRule::Directed {
+            match_pattern: "*.ts".to_string(),
+            require: SiblingRequire::Single("{stem}.spec".to_string()),
+            severity: SiblingSeverity::Error,
+        }],
+        expires: Some("2025-06-01".to_string()),
+        on_expire: Some(OnExpire::Revert),
+        ..Default::default()
+    }
+}
+
+fn checker_as_of(as_of: &str) -> StructureChecker {
+    let config = StructureConfig {
+        max_files: Some(10),
+        rules: vec![reverting_rule()],
+        ..Default::default()
+    };
+    StructureChecker::new(&config)
+        .unwrap()
+        .with_as_of(ParsedDate::parse(as_of).unwrap())
+}
+
+fn legacy_dir_stats() -> HashMap<PathBuf, DirStats> {
+    HashMap::from([(
+        PathBuf::from("src/legacy"),
+        DirStats {
+            file_count: 30,
+            dir_count: 0,
+            depth: 1,
+            count_excluded: false,
+            entry_count: 30,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
+        },
+    )])
+}
+
+#[test]
+fn revert_rule_limits_apply_until_expiry() {
+    let checker = checker_as_of("2025-06-01");
+    assert!(checker.check(&legacy_dir_stats()).is_empty());
+}
+
+#[test]
+fn revert_rule_falls_back_to_defaults_after_expiry() {
+    let checker = checker_as_of("2025-06-02");
+    let violations = checker.check(&legacy_dir_stats());
+
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].violation_type, ViolationType::FileCount);
+    assert_eq!(violations[0].limit, 10);
+}
+
+#[test]
+fn revert_rule_siblings_stop_applying_after_expiry() {
+    let files = vec![PathBuf::from("src/legacy/app.ts")];
+
+    assert_eq!(checker_as_of("2025-05-01").check_siblings(&files).len(), 1);
+    assert!(
+        checker_as_of("2025-07-01")
+            .check_siblings(&files)
+            .is_empty()
+    );
+}
+
+#[test]
+fn explain_marks_reverted_structure_rule_as_expired() {
+    let explanation = checker_as_of("2025-07-01").explain(Path::new("src/legacy"));
+
+    assert!(matches!(
+        explanation.matched_rule,
+        StructureRuleMatch::Default
+    ));
+    assert_eq!(explanation.effective_max_files, Some(10));
+    assert_eq!(explanation.rule_chain[0].status, MatchStatus::Expired);
+}
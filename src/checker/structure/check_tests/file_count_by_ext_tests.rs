@@ -0,0 +1,130 @@
+//! `max_files_by_ext` per-extension file limit tests.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+
+use super::*;
+
+fn dir_with_exts(exts: &[(&str, usize)]) -> HashMap<PathBuf, DirStats> {
+    let files_by_ext: HashMap<String, usize> = exts
+        .iter()
+        .map(|(ext, count)| ((*ext).to_string(), *count))
+        .collect();
+    let file_count = files_by_ext.values().sum();
+    HashMap::from([(
+        PathBuf::from("src/module"),
+        DirStats {
+            file_count,
+            dir_count: 0,
+            depth: 1,
+            count_excluded: false,
+            entry_count: file_count,
+            files_by_ext,
+            is_symlink: false,
+        },
+    )])
+}
+
+fn ext_limits(limits: &[(&str, i64)]) -> BTreeMap<String, i64> {
+    limits
+        .iter()
+        .map(|(ext, limit)| ((*ext).to_string(), *limit))
+        .collect()
+}
+
+fn by_ext(violation: &StructureViolation) -> &str {
+    match &violation.violation_type {
+        ViolationType::FileCountByExtension { extension } => extension,
+        other => panic!("expected FileCountByExtension, got {other:?}"),
+    }
+}
+
+#[test]
+fn extension_limit_reports_its_bucket() {
+    let config = StructureConfig {
+        max_files_by_ext: ext_limits(&[(".rs", 15), (".sql", 1)]),
+        ..Default::default()
+    };
+    let checker = StructureChecker::new(&config).unwrap();
+    assert!(checker.is_enabled());
+
+    let violations = checker.check(&dir_with_exts(&[(".rs", 16), (".snap", 40), (".sql", 1)]));
+
+    assert_eq!(violations.len(), 1);
+    assert_eq!(by_ext(&violations[0]), ".rs");
+    assert_eq!(violations[0].actual, 16);
+    assert_eq!(violations[0].limit, 15);
+    assert!(!violations[0].is_warning);
+}
+
+#[test]
+fn extension_limit_warns_at_file_threshold() {
+    let config = StructureConfig {
+        max_files_by_ext: ext_limits(&[(".rs", 10)]),
+        warn_files_threshold: Some(0.5),
+        ..Default::default()
+    };
+    let checker = StructureChecker::new(&config).unwrap();
+
+    let violations = checker.check(&dir_with_exts(&[(".rs", 6)]));
+
+    assert_eq!(violations.len(), 1);
+    assert!(violations[0].is_warning);
+    assert_eq!(by_ext(&violations[0]), ".rs");
+}
+
+#[test]
+fn zero_limit_prohibits_extension() {
+    let config = StructureConfig {
+        max_files_by_ext: ext_limits(&[(".sql", 0)]),
+        ..Default::default()
+    };
+    let checker = StructureChecker::new(&config).unwrap();
+
+    assert!(checker.check(&dir_with_exts(&[(".rs", 3)])).is_empty());
+    assert_eq!(checker.check(&dir_with_exts(&[(".sql", 1)])).len(), 1);
+}
+
+#[test]
+fn rule_overrides_extension_limits_key_by_key() {
+    let config = StructureConfig {
+        max_files_by_ext: ext_limits(&[(".rs", 15), (".sql", 1)]),
+        rules: vec![StructureRule {
+            scope: "src/module".to_string(),
+            max_files_by_ext: ext_limits(&[(".rs", -1)]),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    let checker = StructureChecker::new(&config).unwrap();
+
+    let violations = checker.check(&dir_with_exts(&[(".rs", 100), (".sql", 2)]));
+
+    assert_eq!(violations.len(), 1);
+    assert_eq!(by_ext(&violations[0]), ".sql");
+    assert_eq!(violations[0].triggering_rule_pattern, None);
+}
+
+#[test]
+fn extension_key_without_dot_is_rejected() {
+    let config = StructureConfig {
+        max_files_by_ext: ext_limits(&[("rs", 15)]),
+        ..Default::default()
+    };
+    let err = StructureChecker::new(&config).err().unwrap();
+    assert!(err.to_string().contains("max_files_by_ext key"));
+}
+
+#[test]
+fn extension_limit_below_unlimited_is_rejected() {
+    let config = StructureConfig {
+        rules: vec![StructureRule {
+            scope: "src/**".to_string(),
+            max_files_by_ext: ext_limits(&[(".rs", -2)]),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    let err = StructureChecker::new(&config).err().unwrap();
+    assert!(err.to_string().contains("in rule 1"));
+}
@@ -19,6 +19,10 @@ fn check_over_file_limit_returns_violation() {
             file_count: 15,
             dir_count: 2,
             depth: 0,
+            count_excluded: false,
+            entry_count: 17,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -41,6 +45,10 @@ fn check_over_dir_limit_returns_violation() {
             file_count: 5,
             dir_count: 5,
             depth: 0,
+            count_excluded: false,
+            entry_count: 10,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -68,6 +76,10 @@ fn check_both_limits_exceeded_returns_both_violations() {
             file_count: 10,
             dir_count: 5,
             depth: 0,
+            count_excluded: false,
+            entry_count: 15,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -95,6 +107,10 @@ fn rule_overrides_global_limit() {
             file_count: 50,
             dir_count: 0,
             depth: 0,
+            count_excluded: false,
+            entry_count: 50,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -124,6 +140,10 @@ fn rule_inherits_unset_limit_from_global() {
             file_count: 50,
             dir_count: 5, // Exceeds inherited limit of 3
             depth: 0,
+            count_excluded: false,
+            entry_count: 55,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -153,6 +173,10 @@ fn warn_threshold_triggers_warning_below_hard_limit() {
             file_count: 47, // Above 45 (warn), below 50 (limit)
             dir_count: 0,
             depth: 0,
+            count_excluded: false,
+            entry_count: 47,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -182,6 +206,10 @@ fn warn_threshold_does_not_warn_at_threshold_boundary() {
             file_count: 45, // Exactly at warn threshold
             dir_count: 0,
             depth: 0,
+            count_excluded: false,
+            entry_count: 45,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -204,6 +232,10 @@ fn warn_threshold_no_warning_below_threshold() {
             file_count: 44, // Below 45 (warn threshold)
             dir_count: 0,
             depth: 0,
+            count_excluded: false,
+            entry_count: 44,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -227,6 +259,10 @@ fn warn_threshold_error_above_hard_limit() {
             file_count: 55, // Above 50 (hard limit)
             dir_count: 0,
             depth: 0,
+            count_excluded: false,
+            entry_count: 55,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -254,6 +290,10 @@ fn warn_threshold_dir_count() {
             file_count: 0,
             dir_count: 9, // Above 8 (warn), below 10 (limit)
             depth: 0,
+            count_excluded: false,
+            entry_count: 9,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -281,6 +321,10 @@ fn warn_threshold_dir_count_does_not_warn_at_threshold_boundary() {
             file_count: 0,
             dir_count: 8, // Exactly at warn threshold
             depth: 0,
+            count_excluded: false,
+            entry_count: 8,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -308,6 +352,10 @@ fn warn_threshold_rule_overrides_global() {
             file_count: 30, // Above 25 (rule warn), below 50 (limit)
             dir_count: 0,
             depth: 0,
+            count_excluded: false,
+            entry_count: 30,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -333,6 +381,10 @@ fn no_warn_threshold_uses_default_0_8() {
             file_count: 39, // Below 40 (80% of 50)
             dir_count: 0,
             depth: 0,
+            count_excluded: false,
+            entry_count: 39,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -361,6 +413,10 @@ fn unlimited_file_limit_skips_check() {
             file_count: 1000, // Would exceed any normal limit
             dir_count: 1,
             depth: 0,
+            count_excluded: false,
+            entry_count: 1001,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -385,6 +441,10 @@ fn unlimited_dir_limit_skips_check() {
             file_count: 3,
             dir_count: 100, // Would exceed any normal limit
             depth: 0,
+            count_excluded: false,
+            entry_count: 103,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -415,6 +475,10 @@ fn rule_can_set_unlimited_to_override_global() {
             file_count: 500, // Would exceed global limit but unlimited by rule
             dir_count: 5,    // Exceeds inherited limit of 2
             depth: 0,
+            count_excluded: false,
+            entry_count: 505,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -444,6 +508,10 @@ fn unconfigured_max_dirs_allows_unlimited_directories() {
             file_count: 5,
             dir_count: 99, // Large number of subdirectories
             depth: 0,
+            count_excluded: false,
+            entry_count: 104,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
     stats.insert(
@@ -452,6 +520,10 @@ fn unconfigured_max_dirs_allows_unlimited_directories() {
             file_count: 3,
             dir_count: 50, // Another directory with many subdirs
             depth: 0,
+            count_excluded: false,
+            entry_count: 53,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -554,6 +626,10 @@ fn warn_files_at_absolute_takes_precedence() {
             file_count: 42, // Above 40 (absolute), below 45 (percentage)
             dir_count: 0,
             depth: 0,
+            count_excluded: false,
+            entry_count: 42,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -581,6 +657,10 @@ fn warn_dirs_at_absolute_takes_precedence() {
             file_count: 0,
             dir_count: 7, // Above 6 (absolute), below 9 (percentage)
             depth: 0,
+            count_excluded: false,
+            entry_count: 7,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -608,6 +688,10 @@ fn warn_files_threshold_overrides_global() {
             file_count: 30, // Above 25 (per-metric), below 45 (global)
             dir_count: 0,
             depth: 0,
+            count_excluded: false,
+            entry_count: 30,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -634,6 +718,10 @@ fn warn_dirs_threshold_overrides_global() {
             file_count: 0,
             dir_count: 6, // Above 5 (per-metric), below 9 (global)
             depth: 0,
+            count_excluded: false,
+            entry_count: 6,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -663,6 +751,10 @@ fn granular_warn_different_for_files_and_dirs() {
             file_count: 46, // Above 45 (files warn)
             dir_count: 4,   // Below 5 (dirs OK)
             depth: 0,
+            count_excluded: false,
+            entry_count: 50,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -693,6 +785,10 @@ fn granular_warn_in_rule_overrides_global() {
             file_count: 30, // Above 25 (rule), below 45 (global)
             dir_count: 0,
             depth: 0,
+            count_excluded: false,
+            entry_count: 30,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -718,6 +814,10 @@ fn default_warn_threshold_is_0_8() {
             file_count: 9, // Above 8 (80% of 10), below 10
             dir_count: 0,
             depth: 0,
+            count_excluded: false,
+            entry_count: 9,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -748,6 +848,10 @@ fn rule_inherits_granular_threshold_from_global() {
             file_count: 42, // Above 40 (inherited)
             dir_count: 0,
             depth: 0,
+            count_excluded: false,
+            entry_count: 42,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
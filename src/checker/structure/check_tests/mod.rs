@@ -2,9 +2,16 @@
 
 mod basic_tests;
 mod depth_tests;
+mod empty_dir_tests;
+mod expiry_tests;
+mod file_count_by_ext_tests;
 mod limit_tests;
 mod rule_priority_tests;
+mod scope_tests;
+mod sibling_conflict_tests;
+mod sibling_template_tests;
 mod sibling_tests;
+mod total_limit_tests;
 mod validation_tests;
 
 use crate::checker::structure::*;
@@ -1,9 +1,11 @@
-//! Rule priority tests: last match wins behavior.
+//! Rule priority tests: last match wins, explicit `priority`, and `most_specific` resolution.
 
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
+use crate::checker::RuleWinReason;
 use crate::checker::explain::{MatchStatus, StructureRuleMatch};
+use crate::config::RuleResolution;
 
 use super::*;
 
@@ -36,6 +38,10 @@ fn multiple_matching_rules_last_match_wins() {
             file_count: 50, // Above 10 (first rule), below 100 (second rule)
             dir_count: 0,
             depth: 0,
+            count_excluded: false,
+            entry_count: 50,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -72,6 +78,10 @@ fn last_match_wins_more_restrictive_rule_last() {
             file_count: 15, // Above 10 (second rule), below 50 (first rule)
             dir_count: 0,
             depth: 0,
+            count_excluded: false,
+            entry_count: 15,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -113,6 +123,10 @@ fn three_rules_last_matching_wins() {
             file_count: 15, // Above 5, above 10, below 20
             dir_count: 0,
             depth: 0,
+            count_excluded: false,
+            entry_count: 15,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -148,6 +162,10 @@ fn non_matching_rules_skipped_in_priority() {
             file_count: 15, // Above 10
             dir_count: 0,
             depth: 0,
+            count_excluded: false,
+            entry_count: 15,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
         },
     );
 
@@ -202,3 +220,76 @@ fn explain_reports_last_matching_rule() {
     // Effective limit should be from the last rule (50)
     assert_eq!(explanation.effective_max_files, Some(50));
 }
+
+fn overlapping_rules(generic_priority: Option<i32>) -> StructureConfig {
+    StructureConfig {
+        max_files: Some(5),
+        rules: vec![
+            StructureRule {
+                scope: "src/generated/**".to_string(),
+                max_files: Some(100),
+                ..Default::default()
+            },
+            StructureRule {
+                scope: "src/**".to_string(),
+                max_files: Some(10),
+                priority: generic_priority,
+                ..Default::default()
+            },
+        ],
+        ..Default::default()
+    }
+}
+
+fn generated_dir_stats() -> HashMap<PathBuf, DirStats> {
+    let mut stats = HashMap::new();
+    stats.insert(
+        PathBuf::from("src/generated/protos"),
+        DirStats {
+            file_count: 50,
+            dir_count: 0,
+            depth: 0,
+            count_excluded: false,
+            entry_count: 50,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
+        },
+    );
+    stats
+}
+
+#[test]
+fn most_specific_resolution_ignores_declaration_order() {
+    let config = overlapping_rules(None);
+
+    // last_match: the later, generic rule (10 files) wins
+    let checker = StructureChecker::new(&config).unwrap();
+    assert_eq!(checker.check(&generated_dir_stats()).len(), 1);
+
+    // most_specific: "src/generated/**" wins despite being declared first
+    let checker = StructureChecker::new(&config)
+        .unwrap()
+        .with_rule_resolution(RuleResolution::MostSpecific);
+    assert!(checker.check(&generated_dir_stats()).is_empty());
+}
+
+#[test]
+fn explicit_priority_beats_specificity() {
+    let config = overlapping_rules(Some(1));
+    let checker = StructureChecker::new(&config)
+        .unwrap()
+        .with_rule_resolution(RuleResolution::MostSpecific);
+
+    assert_eq!(checker.check(&generated_dir_stats()).len(), 1);
+
+    let explanation = checker.explain(Path::new("src/generated/protos"));
+    assert!(matches!(
+        explanation.matched_rule,
+        StructureRuleMatch::Rule { index: 1, .. }
+    ));
+    assert_eq!(
+        explanation.win_reason,
+        Some(RuleWinReason::HigherPriority { runner_up: 0 })
+    );
+    assert_eq!(explanation.rule_chain[0].status, MatchStatus::Superseded);
+}
@@ -0,0 +1,116 @@
+//! Multi-scope rules: `scopes`, `exclude_scopes`, and their explain output.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::checker::SelectorOutcome;
+use crate::checker::explain::{MatchStatus, StructureRuleMatch};
+
+use super::*;
+
+fn dir(file_count: usize, depth: usize) -> DirStats {
+    DirStats {
+        file_count,
+        dir_count: 0,
+        depth,
+        count_excluded: false,
+        entry_count: file_count,
+        files_by_ext: HashMap::new(),
+        is_symlink: false,
+    }
+}
+
+fn multi_scope_config() -> StructureConfig {
+    StructureConfig {
+        max_files: Some(50),
+        rules: vec![StructureRule {
+            scopes: vec!["src/**".to_string(), "lib/**".to_string()],
+            exclude_scopes: vec!["src/generated/**".to_string()],
+            max_files: Some(5),
+            ..Default::default()
+        }],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn rule_applies_to_every_scope_except_excluded_ones() {
+    let checker = StructureChecker::new(&multi_scope_config()).unwrap();
+
+    let mut stats = HashMap::new();
+    stats.insert(PathBuf::from("src/core"), dir(10, 2));
+    stats.insert(PathBuf::from("lib/util"), dir(10, 2));
+    stats.insert(PathBuf::from("src/generated/api"), dir(10, 3));
+    stats.insert(PathBuf::from("docs"), dir(10, 1));
+
+    let mut paths: Vec<_> = checker.check(&stats).into_iter().map(|v| v.path).collect();
+    paths.sort();
+
+    // The excluded and unscoped directories fall back to the global limit
+    assert_eq!(
+        paths,
+        vec![PathBuf::from("lib/util"), PathBuf::from("src/core")]
+    );
+}
+
+#[test]
+fn relative_depth_uses_the_selecting_scope() {
+    let config = StructureConfig {
+        rules: vec![StructureRule {
+            scopes: vec!["src/**".to_string(), "packages/web/src/**".to_string()],
+            max_depth: Some(1),
+            relative_depth: true,
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    let checker = StructureChecker::new(&config).unwrap();
+
+    let mut stats = HashMap::new();
+    // Relative to "packages/web/src" (base depth 3): depth 1
+    stats.insert(PathBuf::from("packages/web/src/app"), dir(0, 4));
+    // Relative to "src" (base depth 1): depth 2
+    stats.insert(PathBuf::from("src/a/b"), dir(0, 3));
+
+    let violations = checker.check(&stats);
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].path, PathBuf::from("src/a/b"));
+}
+
+#[test]
+fn explain_reports_scope_decisions() {
+    let checker = StructureChecker::new(&multi_scope_config()).unwrap();
+
+    let explanation = checker.explain(Path::new("lib/util"));
+    assert!(matches!(
+        explanation.matched_rule,
+        StructureRuleMatch::Rule { ref pattern, .. }
+            if pattern == "src/**, lib/** [exclude: src/generated/**]"
+    ));
+    assert_eq!(
+        explanation.rule_chain[0].selector,
+        Some(SelectorOutcome::Selected {
+            pattern: Some("lib/**".to_string()),
+            language: None,
+        })
+    );
+
+    let explanation = checker.explain(Path::new("src/generated/api"));
+    assert!(matches!(
+        explanation.matched_rule,
+        StructureRuleMatch::Default
+    ));
+    assert_eq!(explanation.rule_chain[0].status, MatchStatus::NoMatch);
+    assert_eq!(
+        explanation.rule_chain[0].selector,
+        Some(SelectorOutcome::ExcludedBy {
+            pattern: "src/generated/**".to_string()
+        })
+    );
+
+    let explanation = checker.explain(Path::new("docs"));
+    assert_eq!(
+        explanation.rule_chain[0].selector,
+        Some(SelectorOutcome::NoPatternMatch)
+    );
+}
@@ -0,0 +1,258 @@
+//! Forbidden and mutually exclusive sibling tests.
+
+use std::path::PathBuf;
+
+use crate::config::{SiblingRequire, SiblingRule, SiblingSeverity};
+
+use super::*;
+
+fn config_with_sibling(sibling: SiblingRule) -> StructureConfig {
+    StructureConfig {
+        rules: vec![StructureRule {
+            scope: "src/**".to_string(),
+            siblings: vec![sibling],
+            ..Default::default()
+        }],
+        ..Default::default()
+    }
+}
+
+fn forbid_js_next_to_ts(severity: SiblingSeverity) -> StructureConfig {
+    config_with_sibling(SiblingRule::Forbid {
+        match_pattern: "*.ts".to_string(),
+        forbid: SiblingRequire::Single("{stem}.js".to_string()),
+        severity,
+    })
+}
+
+fn exclusive_index() -> StructureConfig {
+    config_with_sibling(SiblingRule::Exclusive {
+        exclusive: vec![
+            "index.js".to_string(),
+            "index.ts".to_string(),
+            "index.tsx".to_string(),
+        ],
+        severity: SiblingSeverity::Error,
+    })
+}
+
+// ============================================================================
+// Validation Tests
+// ============================================================================
+
+#[test]
+fn forbid_pattern_without_stem_returns_error() {
+    let config = config_with_sibling(SiblingRule::Forbid {
+        match_pattern: "*.ts".to_string(),
+        forbid: SiblingRequire::Single("legacy.js".to_string()),
+        severity: SiblingSeverity::Error,
+    });
+
+    let err = StructureChecker::new(&config).err().unwrap();
+    assert!(err.to_string().contains("'forbid' pattern 'legacy.js'"));
+}
+
+#[test]
+fn exclusive_with_one_pattern_returns_error() {
+    let config = config_with_sibling(SiblingRule::Exclusive {
+        exclusive: vec!["index.ts".to_string()],
+        severity: SiblingSeverity::Error,
+    });
+
+    let err = StructureChecker::new(&config).err().unwrap();
+    assert!(err.to_string().contains("at least 2 patterns"));
+}
+
+#[test]
+fn exclusive_with_stem_placeholder_returns_error() {
+    let config = config_with_sibling(SiblingRule::Exclusive {
+        exclusive: vec!["{stem}.js".to_string(), "{stem}.ts".to_string()],
+        severity: SiblingSeverity::Error,
+    });
+
+    let err = StructureChecker::new(&config).err().unwrap();
+    assert!(err.to_string().contains("must not contain {stem}"));
+}
+
+#[test]
+fn exclusive_invalid_glob_returns_error() {
+    let config = config_with_sibling(SiblingRule::Exclusive {
+        exclusive: vec!["index.ts".to_string(), "[invalid".to_string()],
+        severity: SiblingSeverity::Error,
+    });
+
+    assert!(StructureChecker::new(&config).is_err());
+}
+
+// ============================================================================
+// Forbid Tests
+// ============================================================================
+
+#[test]
+fn forbid_sibling_present_returns_violation() {
+    let checker = StructureChecker::new(&forbid_js_next_to_ts(SiblingSeverity::Error)).unwrap();
+
+    let files = vec![
+        PathBuf::from("src/lib/foo.ts"),
+        PathBuf::from("src/lib/foo.js"), // Leftover compiled output
+        PathBuf::from("src/lib/bar.ts"),
+    ];
+
+    let violations = checker.check_siblings(&files);
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].path, PathBuf::from("src/lib/foo.ts"));
+    assert_eq!(
+        violations[0].violation_type,
+        ViolationType::ForbiddenSibling {
+            forbidden_sibling_pattern: "{stem}.js".to_string(),
+            conflicting_file: "foo.js".to_string(),
+        }
+    );
+    assert_eq!(
+        violations[0].triggering_rule_pattern.as_deref(),
+        Some("src/**")
+    );
+    assert!(!violations[0].is_warning);
+}
+
+#[test]
+fn forbid_sibling_absent_no_violation() {
+    let checker = StructureChecker::new(&forbid_js_next_to_ts(SiblingSeverity::Error)).unwrap();
+
+    let files = vec![
+        PathBuf::from("src/lib/foo.ts"),
+        PathBuf::from("src/other/foo.js"), // Different directory
+        PathBuf::from("lib/bar.js"),
+    ];
+
+    assert!(checker.check_siblings(&files).is_empty());
+}
+
+#[test]
+fn forbid_with_warn_severity_creates_warning() {
+    let checker = StructureChecker::new(&forbid_js_next_to_ts(SiblingSeverity::Warn)).unwrap();
+
+    let files = vec![
+        PathBuf::from("src/lib/foo.ts"),
+        PathBuf::from("src/lib/foo.js"),
+    ];
+
+    let violations = checker.check_siblings(&files);
+    assert_eq!(violations.len(), 1);
+    assert!(violations[0].is_warning);
+}
+
+#[test]
+fn forbid_template_naming_the_file_itself_is_ignored() {
+    let config = config_with_sibling(SiblingRule::Forbid {
+        match_pattern: "*.ts".to_string(),
+        forbid: SiblingRequire::Single("{stem}.ts".to_string()),
+        severity: SiblingSeverity::Error,
+    });
+    let checker = StructureChecker::new(&config).unwrap();
+
+    let files = vec![PathBuf::from("src/lib/foo.ts")];
+
+    assert!(checker.check_siblings(&files).is_empty());
+}
+
+// ============================================================================
+// Exclusive Tests
+// ============================================================================
+
+#[test]
+fn exclusive_two_present_returns_one_violation_per_directory() {
+    let checker = StructureChecker::new(&exclusive_index()).unwrap();
+
+    let files = vec![
+        PathBuf::from("src/app/index.ts"),
+        PathBuf::from("src/app/index.js"),
+        PathBuf::from("src/app/util.ts"),
+    ];
+
+    let violations = checker.check_siblings(&files);
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].path, PathBuf::from("src/app"));
+    assert_eq!(
+        violations[0].violation_type,
+        ViolationType::ExclusiveConflict {
+            exclusive_patterns: vec![
+                "index.js".to_string(),
+                "index.ts".to_string(),
+                "index.tsx".to_string(),
+            ],
+            conflicting_files: vec!["index.js".to_string(), "index.ts".to_string()],
+        }
+    );
+    assert_eq!(violations[0].actual, 2);
+    assert_eq!(violations[0].limit, 1);
+}
+
+#[test]
+fn exclusive_single_present_no_violation() {
+    let checker = StructureChecker::new(&exclusive_index()).unwrap();
+
+    let files = vec![
+        PathBuf::from("src/a/index.ts"),
+        PathBuf::from("src/b/index.js"), // Different directory
+    ];
+
+    assert!(checker.check_siblings(&files).is_empty());
+}
+
+#[test]
+fn exclusive_glob_matching_several_files_is_one_choice() {
+    let config = config_with_sibling(SiblingRule::Exclusive {
+        exclusive: vec!["*.js".to_string(), "*.ts".to_string()],
+        severity: SiblingSeverity::Error,
+    });
+    let checker = StructureChecker::new(&config).unwrap();
+
+    let only_js = vec![PathBuf::from("src/lib/a.js"), PathBuf::from("src/lib/b.js")];
+    assert!(checker.check_siblings(&only_js).is_empty());
+
+    let mixed = vec![
+        PathBuf::from("src/lib/a.js"),
+        PathBuf::from("src/lib/b.js"),
+        PathBuf::from("src/lib/c.ts"),
+    ];
+    let violations = checker.check_siblings(&mixed);
+    assert_eq!(violations.len(), 1);
+    assert_eq!(
+        violations[0].violation_type,
+        ViolationType::ExclusiveConflict {
+            exclusive_patterns: vec!["*.js".to_string(), "*.ts".to_string()],
+            conflicting_files: vec!["a.js".to_string(), "b.js".to_string(), "c.ts".to_string()],
+        }
+    );
+}
+
+#[test]
+fn exclusive_overlapping_patterns_need_two_files() {
+    let config = config_with_sibling(SiblingRule::Exclusive {
+        exclusive: vec!["*.ts".to_string(), "index.*".to_string()],
+        severity: SiblingSeverity::Error,
+    });
+    let checker = StructureChecker::new(&config).unwrap();
+
+    // One file matching both patterns is a single choice
+    let single = vec![PathBuf::from("src/app/index.ts")];
+    assert!(checker.check_siblings(&single).is_empty());
+
+    let same_pattern = vec![
+        PathBuf::from("src/app/index.ts"),
+        PathBuf::from("src/app/util.ts"),
+    ];
+    let violations = checker.check_siblings(&same_pattern);
+    assert_eq!(violations.len(), 1);
+    assert_eq!(
+        violations[0].violation_type,
+        ViolationType::ExclusiveConflict {
+            exclusive_patterns: vec!["*.ts".to_string(), "index.*".to_string()],
+            conflicting_files: vec!["index.ts".to_string(), "util.ts".to_string()],
+        }
+    );
+
+    let only_ts = vec![PathBuf::from("src/lib/a.ts"), PathBuf::from("src/lib/b.ts")];
+    assert!(checker.check_siblings(&only_ts).is_empty());
+}
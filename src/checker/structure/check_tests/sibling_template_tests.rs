@@ -0,0 +1,185 @@
+//! Sibling templates with `{dir}`, `{parent}`, `{ext}` and case transforms.
+
+use std::path::PathBuf;
+
+use crate::config::{SiblingRequire, SiblingRule, SiblingSeverity};
+
+use super::*;
+
+fn checker_with_sibling(sibling: SiblingRule) -> StructureChecker {
+    StructureChecker::new(&StructureConfig {
+        rules: vec![StructureRule {
+            scope: "src/**".to_string(),
+            siblings: vec![sibling],
+            ..Default::default()
+        }],
+        ..Default::default()
+    })
+    .unwrap()
+}
+
+fn paths(files: &[&str]) -> Vec<PathBuf> {
+    files.iter().map(PathBuf::from).collect()
+}
+
+fn missing_siblings(violations: &[StructureViolation]) -> Vec<(PathBuf, String)> {
+    violations
+        .iter()
+        .map(|v| match &v.violation_type {
+            ViolationType::MissingSibling {
+                expected_sibling_pattern,
+            } => (v.path.clone(), expected_sibling_pattern.clone()),
+            other => panic!("expected a missing sibling, got {other:?}"),
+        })
+        .collect()
+}
+
+#[test]
+fn dir_placeholder_requires_file_named_after_directory() {
+    let checker = checker_with_sibling(SiblingRule::Directed {
+        match_pattern: "index.ts".to_string(),
+        require: SiblingRequire::Single("{dir}.tsx".to_string()),
+        severity: SiblingSeverity::Error,
+    });
+
+    let files = paths(&[
+        "src/components/Button/index.ts",
+        "src/components/Button/Button.tsx",
+        "src/components/Card/index.ts",
+    ]);
+
+    assert_eq!(
+        missing_siblings(&checker.check_siblings(&files)),
+        vec![(
+            PathBuf::from("src/components/Card/index.ts"),
+            "{dir}.tsx".to_string()
+        )]
+    );
+}
+
+#[test]
+fn ext_and_transform_placeholders_expand_from_source_file() {
+    let checker = checker_with_sibling(SiblingRule::Directed {
+        match_pattern: "*.go".to_string(),
+        require: SiblingRequire::Single("{stem}_test.{ext}".to_string()),
+        severity: SiblingSeverity::Error,
+    });
+    let files = paths(&[
+        "src/api/server.go",
+        "src/api/server_test.go",
+        "src/api/client.go",
+    ]);
+    let violations = checker.check_siblings(&files);
+    // server_test.go itself needs server_test_test.go; only client.go and it are reported
+    assert_eq!(violations.len(), 2);
+    assert_eq!(violations[0].path, PathBuf::from("src/api/client.go"));
+
+    let checker = checker_with_sibling(SiblingRule::Directed {
+        match_pattern: "*.tsx".to_string(),
+        require: SiblingRequire::Single("{stem:kebab}.module.css".to_string()),
+        severity: SiblingSeverity::Error,
+    });
+    let files = paths(&["src/ui/UserCard.tsx", "src/ui/user-card.module.css"]);
+    assert!(checker.check_siblings(&files).is_empty());
+}
+
+#[test]
+fn forbid_module_file_next_to_mod_rs_directory() {
+    let checker = checker_with_sibling(SiblingRule::Forbid {
+        match_pattern: "*.rs".to_string(),
+        forbid: SiblingRequire::Single("{stem}/mod.rs".to_string()),
+        severity: SiblingSeverity::Error,
+    });
+
+    let files = paths(&["src/lib/foo.rs", "src/lib/foo/mod.rs", "src/lib/bar.rs"]);
+    let violations = checker.check_siblings(&files);
+
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].path, PathBuf::from("src/lib/foo.rs"));
+    assert_eq!(
+        violations[0].violation_type,
+        ViolationType::ForbiddenSibling {
+            forbidden_sibling_pattern: "{stem}/mod.rs".to_string(),
+            conflicting_file: "mod.rs".to_string(),
+        }
+    );
+}
+
+#[test]
+fn group_patterns_expand_dir_around_stem() {
+    let checker = checker_with_sibling(SiblingRule::Group {
+        group: vec!["{stem}.tsx".to_string(), "{dir}.{stem}.css".to_string()],
+        severity: SiblingSeverity::Error,
+    });
+
+    let files = paths(&[
+        "src/button/Primary.tsx",
+        "src/button/button.Primary.css",
+        "src/button/Ghost.tsx",
+    ]);
+    let violations = checker.check_siblings(&files);
+
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].path, PathBuf::from("src/button/Ghost.tsx"));
+    assert!(matches!(
+        &violations[0].violation_type,
+        ViolationType::GroupIncomplete { missing_patterns, .. }
+            if missing_patterns == &vec!["{dir}.{stem}.css".to_string()]
+    ));
+}
+
+#[test]
+fn unknown_placeholder_returns_error() {
+    let config = StructureConfig {
+        rules: vec![StructureRule {
+            scope: "src/**".to_string(),
+            siblings: vec![SiblingRule::Directed {
+                match_pattern: "*.ts".to_string(),
+                require: SiblingRequire::Single("{name}.test.ts".to_string()),
+                severity: SiblingSeverity::Error,
+            }],
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+
+    let err = StructureChecker::new(&config).err().unwrap().to_string();
+    assert!(err.contains("unknown placeholder '{name}'"), "{err}");
+}
+
+#[test]
+fn group_stem_with_transform_returns_error() {
+    let config = StructureConfig {
+        rules: vec![StructureRule {
+            scope: "src/**".to_string(),
+            siblings: vec![SiblingRule::Group {
+                group: vec!["{stem}.tsx".to_string(), "{stem:kebab}.css".to_string()],
+                severity: SiblingSeverity::Error,
+            }],
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+
+    let err = StructureChecker::new(&config).err().unwrap().to_string();
+    assert!(err.contains("without a transform"), "{err}");
+}
+
+#[test]
+fn dot_slash_paths_match_rule_scope() {
+    let checker = checker_with_sibling(SiblingRule::Directed {
+        match_pattern: "*.ts".to_string(),
+        require: SiblingRequire::Single("{stem}.test.ts".to_string()),
+        severity: SiblingSeverity::Error,
+    });
+
+    let files = paths(&["./src/lib/parser.ts"]);
+
+    assert_eq!(
+        missing_siblings(&checker.check_siblings(&files)),
+        vec![(
+            PathBuf::from("./src/lib/parser.ts"),
+            "{stem}.test.ts".to_string()
+        )]
+    );
+}
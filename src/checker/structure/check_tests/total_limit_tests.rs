@@ -0,0 +1,218 @@
+//! Recursive `max_total_files`/`max_total_dirs` limit tests.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use super::*;
+
+fn dir(file_count: usize, dir_count: usize, depth: usize) -> DirStats {
+    DirStats {
+        file_count,
+        dir_count,
+        depth,
+        count_excluded: false,
+        entry_count: file_count + dir_count,
+        files_by_ext: HashMap::new(),
+        is_symlink: false,
+    }
+}
+
+/// `src` (2 files) -> `src/a` (3 files) -> `src/a/b` (4 files).
+fn nested_stats() -> HashMap<PathBuf, DirStats> {
+    HashMap::from([
+        (PathBuf::from("src"), dir(2, 1, 0)),
+        (PathBuf::from("src/a"), dir(3, 1, 1)),
+        (PathBuf::from("src/a/b"), dir(4, 0, 2)),
+    ])
+}
+
+#[test]
+fn total_file_limit_counts_whole_subtree() {
+    let config = StructureConfig {
+        max_total_files: Some(8),
+        ..Default::default()
+    };
+    let checker = StructureChecker::new(&config).unwrap();
+
+    let violations = checker.check(&nested_stats());
+
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].path, PathBuf::from("src"));
+    assert_eq!(violations[0].violation_type, ViolationType::TotalFileCount);
+    assert_eq!(violations[0].actual, 9);
+    assert_eq!(violations[0].limit, 8);
+    assert!(!violations[0].is_warning);
+}
+
+#[test]
+fn total_dir_limit_counts_whole_subtree() {
+    let config = StructureConfig {
+        max_total_dirs: Some(1),
+        ..Default::default()
+    };
+    let checker = StructureChecker::new(&config).unwrap();
+
+    let violations = checker.check(&nested_stats());
+
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].path, PathBuf::from("src"));
+    assert_eq!(violations[0].violation_type, ViolationType::TotalDirCount);
+    assert_eq!(violations[0].actual, 2);
+}
+
+#[test]
+fn total_limit_warns_at_absolute_threshold() {
+    let config = StructureConfig {
+        max_total_files: Some(20),
+        warn_total_files_at: Some(6),
+        ..Default::default()
+    };
+    let checker = StructureChecker::new(&config).unwrap();
+
+    let violations = checker.check(&nested_stats());
+
+    assert_eq!(violations.len(), 2);
+    assert!(violations.iter().all(|v| v.is_warning));
+    assert_eq!(violations[0].path, PathBuf::from("src"));
+    assert_eq!(violations[1].path, PathBuf::from("src/a"));
+}
+
+#[test]
+fn total_limit_warns_at_percentage_threshold() {
+    let config = StructureConfig {
+        max_total_files: Some(10),
+        warn_total_files_threshold: Some(0.8),
+        ..Default::default()
+    };
+    let checker = StructureChecker::new(&config).unwrap();
+
+    let violations = checker.check(&nested_stats());
+
+    assert_eq!(violations.len(), 1);
+    assert!(violations[0].is_warning);
+    assert_eq!(violations[0].actual, 9);
+}
+
+#[test]
+fn rule_total_limit_overrides_global() {
+    let config = StructureConfig {
+        max_total_files: Some(100),
+        rules: vec![StructureRule {
+            scope: "src/a".to_string(),
+            max_total_files: Some(5),
+            reason: Some("feature module".to_string()),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    let checker = StructureChecker::new(&config).unwrap();
+
+    let violations = checker.check(&nested_stats());
+
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].path, PathBuf::from("src/a"));
+    assert_eq!(violations[0].actual, 7);
+    assert_eq!(violations[0].limit, 5);
+    assert_eq!(
+        violations[0].override_reason.as_deref(),
+        Some("feature module")
+    );
+}
+
+#[test]
+fn rule_without_total_limit_inherits_global() {
+    let config = StructureConfig {
+        max_total_files: Some(6),
+        rules: vec![make_rule("src/a", Some(50))],
+        ..Default::default()
+    };
+    let checker = StructureChecker::new(&config).unwrap();
+
+    let violations = checker.check(&nested_stats());
+
+    let paths: Vec<_> = violations.iter().map(|v| v.path.clone()).collect();
+    assert_eq!(paths, vec![PathBuf::from("src"), PathBuf::from("src/a")]);
+}
+
+#[test]
+fn unlimited_total_limit_never_violates() {
+    let config = StructureConfig {
+        max_total_files: Some(UNLIMITED),
+        max_total_dirs: Some(UNLIMITED),
+        ..Default::default()
+    };
+    let checker = StructureChecker::new(&config).unwrap();
+
+    assert!(checker.check(&nested_stats()).is_empty());
+}
+
+#[test]
+fn count_excluded_subtree_is_left_out_of_totals() {
+    let mut stats = nested_stats();
+    stats.insert(
+        PathBuf::from("src/generated"),
+        DirStats {
+            file_count: 40,
+            dir_count: 0,
+            depth: 1,
+            count_excluded: true,
+            entry_count: 40,
+            files_by_ext: HashMap::new(),
+            is_symlink: false,
+        },
+    );
+    let config = StructureConfig {
+        max_total_files: Some(20),
+        ..Default::default()
+    };
+    let checker = StructureChecker::new(&config).unwrap();
+
+    let violations = checker.check(&stats);
+
+    // Only the excluded directory itself exceeds the limit
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].path, PathBuf::from("src/generated"));
+}
+
+#[test]
+fn total_limits_enable_checker() {
+    let config = StructureConfig {
+        max_total_dirs: Some(3),
+        ..Default::default()
+    };
+    let checker = StructureChecker::new(&config).unwrap();
+
+    assert!(checker.is_enabled());
+}
+
+#[test]
+fn invalid_total_limit_is_rejected() {
+    let config = StructureConfig {
+        max_total_files: Some(-5),
+        ..Default::default()
+    };
+
+    let err = StructureChecker::new(&config).err().unwrap();
+
+    assert!(err.to_string().contains("max_total_files"));
+}
+
+#[test]
+fn explain_reports_effective_total_limits() {
+    let config = StructureConfig {
+        max_total_dirs: Some(4),
+        rules: vec![StructureRule {
+            scope: "src/**".to_string(),
+            max_total_files: Some(12),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    let checker = StructureChecker::new(&config).unwrap();
+
+    let explanation = checker.explain(std::path::Path::new("src/a"));
+
+    assert_eq!(explanation.effective_max_total_files, Some(12));
+    assert_eq!(explanation.effective_max_total_dirs, Some(4));
+    assert_eq!(explanation.rule_chain[0].max_total_files, Some(12));
+}
@@ -139,3 +139,30 @@ fn group_pattern_with_stem_placeholder_passes() {
     let result = StructureChecker::new(&config);
     assert!(result.is_ok());
 }
+
+#[test]
+fn zero_path_limits_are_rejected() {
+    let global = StructureConfig {
+        max_filename_length: Some(0),
+        ..Default::default()
+    };
+    let err = StructureChecker::new(&global).err().unwrap();
+    assert!(
+        err.to_string()
+            .contains("Invalid max_filename_length value: 0")
+    );
+
+    let rule = StructureConfig {
+        rules: vec![StructureRule {
+            scope: "src/**".to_string(),
+            max_path_length: Some(-2),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    let err = StructureChecker::new(&rule).err().unwrap();
+    assert!(
+        err.to_string()
+            .contains("Invalid max_path_length value in rule 1: -2")
+    );
+}
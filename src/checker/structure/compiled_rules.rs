@@ -3,20 +3,24 @@
 //! Contains pre-compiled glob matchers and resolved limit structures
 //! used internally by the `StructureChecker` implementation.
 
+use std::collections::BTreeMap;
+use std::path::Path;
+
 use globset::GlobMatcher;
 
-/// Compiled structure rule with precompiled glob matcher.
+use crate::config::{ParsedDate, StructureConfig, StructureRule, Template};
+
+use super::super::rule_resolution::RuleRank;
+use super::super::scope_selector::ScopeSelector;
+
+/// Compiled structure rule with precompiled scope matchers.
 pub(super) struct CompiledStructureRule {
-    pub scope: String,
-    pub matcher: GlobMatcher,
+    pub selector: ScopeSelector,
     pub max_files: Option<i64>,
     pub max_dirs: Option<i64>,
     pub max_depth: Option<i64>,
-    /// When true, `max_depth` is relative to the pattern's base directory.
+    /// When true, `max_depth` is relative to the matching scope's base directory.
     pub relative_depth: bool,
-    /// Depth of the pattern's base directory (components before first glob).
-    /// Only meaningful when `relative_depth` is true.
-    pub base_depth: usize,
     pub warn_threshold: Option<f64>,
     /// Absolute file count at which to warn.
     pub warn_files_at: Option<i64>,
@@ -26,10 +30,25 @@ pub(super) struct CompiledStructureRule {
     pub warn_files_threshold: Option<f64>,
     /// Percentage threshold for directory count warnings.
     pub warn_dirs_threshold: Option<f64>,
+    /// Recursive subtree limits (`max_total_*`).
+    pub totals: TotalLimits,
+    /// Per-extension file limits (`max_files_by_ext`).
+    pub max_files_by_ext: BTreeMap<String, i64>,
+    /// Explicit `priority`, ranked together with the matching scope's specificity.
+    pub priority: Option<i32>,
+    /// Set for `on_expire = "revert"` rules; the rule stops matching after this date.
+    pub revert_after: Option<ParsedDate>,
     /// Optional reason for this rule (audit trail).
     pub reason: Option<String>,
 }
 
+impl CompiledStructureRule {
+    /// Priority and specificity of the scope matching `path`, to pick among overlapping rules.
+    pub fn rank(&self, path: &Path) -> RuleRank {
+        RuleRank::with_specificity(self.selector.specificity(path), self.priority)
+    }
+}
+
 /// Compiled sibling rule for file co-location checking.
 ///
 /// Contains pre-compiled glob matchers for efficient runtime matching.
@@ -38,20 +57,21 @@ pub(super) enum CompiledSiblingRule {
     /// Directed rule: if file matches, require sibling(s).
     ///
     /// When a file matches `file_matcher` in a directory matching `dir_matcher`,
-    /// each template in `sibling_templates` is expanded using `{stem}` substitution
-    /// to derive required sibling file paths.
+    /// each template in `sibling_templates` is expanded with the file's `{stem}`,
+    /// `{ext}`, `{dir}` and `{parent}` to derive required sibling file paths.
     Directed {
         /// Original directory scope string from config (for violation messages).
         dir_scope: String,
-        /// Pre-compiled matcher for directory (parent of files).
-        dir_matcher: GlobMatcher,
+        /// Pre-compiled scope for the directory (parent of files).
+        dir_matcher: ScopeSelector,
         /// Pre-compiled matcher for files that trigger the rule.
         file_matcher: GlobMatcher,
         /// Templates for deriving sibling filename(s), e.g., `"{stem}.test.tsx"`.
-        /// The `{stem}` placeholder is replaced with the source file's stem.
-        sibling_templates: Vec<String>,
+        sibling_templates: Vec<Template>,
         /// When `true`, violations are warnings instead of errors.
         is_warning: bool,
+        /// Lapse date inherited from the owning rule's `on_expire = "revert"`.
+        revert_after: Option<ParsedDate>,
     },
     /// Atomic group: if ANY file in the group exists, ALL must exist.
     ///
@@ -60,16 +80,79 @@ pub(super) enum CompiledSiblingRule {
     Group {
         /// Original directory scope string from config (for violation messages).
         dir_scope: String,
-        /// Pre-compiled matcher for directory (parent of files).
-        dir_matcher: GlobMatcher,
+        /// Pre-compiled scope for the directory (parent of files).
+        dir_matcher: ScopeSelector,
         /// Patterns that form an atomic set, e.g., `["{stem}.tsx", "{stem}.test.tsx"]`.
-        /// Each pattern must contain `{stem}` for stem extraction and expansion.
-        group_patterns: Vec<String>,
+        /// Each pattern must contain one untransformed `{stem}` for stem extraction.
+        group_patterns: Vec<Template>,
+        /// When `true`, violations are warnings instead of errors.
+        is_warning: bool,
+        /// Lapse date inherited from the owning rule's `on_expire = "revert"`.
+        revert_after: Option<ParsedDate>,
+    },
+    /// Forbidden sibling: if file matches, the derived sibling(s) must not exist.
+    ///
+    /// Templates are expanded exactly like [`Self::Directed`], but a violation is
+    /// reported when the derived file IS present.
+    Forbid {
+        /// Original directory scope string from config (for violation messages).
+        dir_scope: String,
+        /// Pre-compiled scope for the directory (parent of files).
+        dir_matcher: ScopeSelector,
+        /// Pre-compiled matcher for files that trigger the rule.
+        file_matcher: GlobMatcher,
+        /// Templates for deriving forbidden filename(s), e.g., `"{stem}.js"`.
+        sibling_templates: Vec<Template>,
+        /// When `true`, violations are warnings instead of errors.
+        is_warning: bool,
+        /// Lapse date inherited from the owning rule's `on_expire = "revert"`.
+        revert_after: Option<ParsedDate>,
+    },
+    /// Mutually exclusive files: at most one pattern may match within a directory.
+    Exclusive {
+        /// Original directory scope string from config (for violation messages).
+        dir_scope: String,
+        /// Pre-compiled scope for the directory (parent of files).
+        dir_matcher: ScopeSelector,
+        /// Original file name patterns, e.g., `["index.js", "index.ts"]`.
+        patterns: Vec<String>,
+        /// Pre-compiled matchers, parallel to `patterns`.
+        matchers: Vec<GlobMatcher>,
         /// When `true`, violations are warnings instead of errors.
         is_warning: bool,
+        /// Lapse date inherited from the owning rule's `on_expire = "revert"`.
+        revert_after: Option<ParsedDate>,
     },
 }
 
+impl CompiledSiblingRule {
+    /// Directory matcher and lapse date shared by every rule kind.
+    pub const fn dir_filter(&self) -> (&ScopeSelector, Option<ParsedDate>) {
+        match self {
+            Self::Directed {
+                dir_matcher,
+                revert_after,
+                ..
+            }
+            | Self::Group {
+                dir_matcher,
+                revert_after,
+                ..
+            }
+            | Self::Forbid {
+                dir_matcher,
+                revert_after,
+                ..
+            }
+            | Self::Exclusive {
+                dir_matcher,
+                revert_after,
+                ..
+            } => (dir_matcher, *revert_after),
+        }
+    }
+}
+
 /// Resolved limits for a directory path.
 #[derive(Debug, Clone, Default)]
 pub(super) struct StructureLimits {
@@ -78,7 +161,7 @@ pub(super) struct StructureLimits {
     pub max_depth: Option<i64>,
     /// When true, `max_depth` is relative to `base_depth`.
     pub relative_depth: bool,
-    /// Depth of the matched rule's base directory.
+    /// Depth of the matching scope's base directory.
     pub base_depth: usize,
     pub warn_threshold: Option<f64>,
     /// Absolute file count at which to warn.
@@ -89,5 +172,61 @@ pub(super) struct StructureLimits {
     pub warn_files_threshold: Option<f64>,
     /// Percentage threshold for directory count warnings.
     pub warn_dirs_threshold: Option<f64>,
+    /// Recursive subtree limits (`max_total_*`).
+    pub totals: TotalLimits,
+    /// Per-extension file limits, rule entries merged over the defaults.
+    pub max_files_by_ext: BTreeMap<String, i64>,
     pub override_reason: Option<String>,
 }
+
+/// Recursive subtree limits (`max_total_files`/`max_total_dirs`) and their warn thresholds.
+#[derive(Debug, Clone, Copy, Default)]
+pub(super) struct TotalLimits {
+    pub max_files: Option<i64>,
+    pub max_dirs: Option<i64>,
+    pub warn_files_at: Option<i64>,
+    pub warn_dirs_at: Option<i64>,
+    pub warn_files_threshold: Option<f64>,
+    pub warn_dirs_threshold: Option<f64>,
+}
+
+impl TotalLimits {
+    pub const fn from_config(config: &StructureConfig) -> Self {
+        Self {
+            max_files: config.max_total_files,
+            max_dirs: config.max_total_dirs,
+            warn_files_at: config.warn_total_files_at,
+            warn_dirs_at: config.warn_total_dirs_at,
+            warn_files_threshold: config.warn_total_files_threshold,
+            warn_dirs_threshold: config.warn_total_dirs_threshold,
+        }
+    }
+
+    pub const fn from_rule(rule: &StructureRule) -> Self {
+        Self {
+            max_files: rule.max_total_files,
+            max_dirs: rule.max_total_dirs,
+            warn_files_at: rule.warn_total_files_at,
+            warn_dirs_at: rule.warn_total_dirs_at,
+            warn_files_threshold: rule.warn_total_files_threshold,
+            warn_dirs_threshold: rule.warn_total_dirs_threshold,
+        }
+    }
+
+    /// Field-wise override: values set here win, unset ones come from `defaults`.
+    pub fn or(self, defaults: Self) -> Self {
+        Self {
+            max_files: self.max_files.or(defaults.max_files),
+            max_dirs: self.max_dirs.or(defaults.max_dirs),
+            warn_files_at: self.warn_files_at.or(defaults.warn_files_at),
+            warn_dirs_at: self.warn_dirs_at.or(defaults.warn_dirs_at),
+            warn_files_threshold: self.warn_files_threshold.or(defaults.warn_files_threshold),
+            warn_dirs_threshold: self.warn_dirs_threshold.or(defaults.warn_dirs_threshold),
+        }
+    }
+
+    /// Whether any recursive limit is configured.
+    pub const fn is_set(&self) -> bool {
+        self.max_files.is_some() || self.max_dirs.is_some()
+    }
+}
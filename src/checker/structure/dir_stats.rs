@@ -0,0 +1,18 @@
+use std::collections::HashMap;
+
+/// Counts of immediate children in a directory.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DirStats {
+    pub file_count: usize,
+    pub dir_count: usize,
+    /// Depth relative to scan root (root = 0).
+    pub depth: usize,
+    /// The directory matches `count_exclude`, so it is left out of its ancestors' totals.
+    pub count_excluded: bool,
+    /// Immediate children seen by the scan, including those matching `count_exclude`.
+    pub entry_count: usize,
+    /// Counted files by extension with leading dot (e.g., ".rs"), for `max_files_by_ext`.
+    pub files_by_ext: HashMap<String, usize>,
+    /// The directory was reached through a symlink (`scanner.follow_symlinks`).
+    pub is_symlink: bool,
+}
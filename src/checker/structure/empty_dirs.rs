@@ -0,0 +1,29 @@
+//! Empty directory detection (`deny_empty_dirs`) from per-directory `DirStats`.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use super::dir_stats::DirStats;
+use super::violation::StructureViolation;
+use crate::config::DenyEmptyDirs;
+
+/// Directories reported by `deny_empty_dirs`.
+///
+/// The scan root and directories matching `count_exclude` are never reported.
+/// With [`DenyEmptyDirs::IgnoreCountExclude`], entries matching `count_exclude`
+/// do not make a directory non-empty.
+pub(super) fn empty_dir_violations(
+    dir_stats: &HashMap<PathBuf, DirStats>,
+    mode: DenyEmptyDirs,
+) -> Vec<StructureViolation> {
+    dir_stats
+        .iter()
+        .filter(|(_, stats)| stats.depth > 0 && !stats.count_excluded)
+        .filter(|(_, stats)| match mode {
+            DenyEmptyDirs::Off => false,
+            DenyEmptyDirs::Empty => stats.entry_count == 0,
+            DenyEmptyDirs::IgnoreCountExclude => stats.file_count + stats.dir_count == 0,
+        })
+        .map(|(path, stats)| StructureViolation::empty_directory(path.clone(), stats.entry_count))
+        .collect()
+}
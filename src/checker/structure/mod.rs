@@ -5,26 +5,39 @@
 //! - Maximum files per directory
 //! - Maximum subdirectories per directory
 //! - Maximum directory depth
-//! - File co-location (sibling) requirements
+//! - Maximum files/subdirectories anywhere beneath a directory
+//! - Empty directories
+//! - File co-location (sibling) requirements and conflicts
 
 mod builder;
 mod compiled_rules;
+mod dir_stats;
+mod empty_dirs;
+mod sibling_conflicts;
+mod totals;
 mod validation;
 pub mod violation;
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::{Path, PathBuf};
 
-use crate::config::{StructureConfig, UNLIMITED};
+use crate::config::{
+    DenyEmptyDirs, ParsedDate, RuleResolution, StructureConfig, Template, TemplateValues, UNLIMITED,
+};
 use crate::error::Result;
+use crate::output::path::normalize_for_matching;
 
 use super::explain::{
     MatchStatus, StructureExplanation, StructureRuleCandidate, StructureRuleMatch,
 };
-pub use violation::{DirStats, StructureViolation, ViolationType};
-
-use builder::{build_rules, build_sibling_rules};
-use compiled_rules::{CompiledSiblingRule, CompiledStructureRule, StructureLimits};
+use super::rule_resolution::{explain_winner, select_winner};
+pub use dir_stats::DirStats;
+pub use violation::{StructureViolation, ViolationType};
+
+use builder::{build_rules, build_sibling_rules, calculate_base_depth};
+use compiled_rules::{CompiledSiblingRule, CompiledStructureRule, StructureLimits, TotalLimits};
+use empty_dirs::empty_dir_violations;
+use totals::subtree_totals;
 use validation::{validate_allow_deny_mutual_exclusion, validate_limits, validate_sibling_rules};
 
 /// Checker for directory structure limits.
@@ -37,8 +50,14 @@ pub struct StructureChecker {
     warn_dirs_at: Option<i64>,
     warn_files_threshold: Option<f64>,
     warn_dirs_threshold: Option<f64>,
+    totals: TotalLimits,
+    max_files_by_ext: BTreeMap<String, i64>,
+    deny_empty_dirs: DenyEmptyDirs,
     rules: Vec<CompiledStructureRule>,
+    rule_resolution: RuleResolution,
     sibling_rules: Vec<CompiledSiblingRule>,
+    /// Reference date for `on_expire = "revert"` rules.
+    as_of: ParsedDate,
 }
 
 /// Default warn threshold when none specified.
@@ -66,11 +85,53 @@ impl StructureChecker {
             warn_dirs_at: config.warn_dirs_at,
             warn_files_threshold: config.warn_files_threshold,
             warn_dirs_threshold: config.warn_dirs_threshold,
+            totals: TotalLimits::from_config(config),
+            max_files_by_ext: config.max_files_by_ext.clone(),
+            deny_empty_dirs: config.deny_empty_dirs,
             rules,
+            rule_resolution: RuleResolution::default(),
             sibling_rules,
+            as_of: ParsedDate::today(),
         })
     }
 
+    /// Set how overlapping `[[structure.rules]]` of equal priority are resolved.
+    #[must_use]
+    pub const fn with_rule_resolution(mut self, rule_resolution: RuleResolution) -> Self {
+        self.rule_resolution = rule_resolution;
+        self
+    }
+
+    /// Evaluate `on_expire = "revert"` rules as of `as_of` instead of today.
+    #[must_use]
+    pub const fn with_as_of(mut self, as_of: ParsedDate) -> Self {
+        self.as_of = as_of;
+        self
+    }
+
+    /// Whether a rule with the given lapse date no longer applies.
+    fn is_lapsed(&self, revert_after: Option<ParsedDate>) -> bool {
+        revert_after.is_some_and(|date| date < self.as_of)
+    }
+
+    /// Index of the winning structure rule for a directory, if any rule matches.
+    fn winning_rule_index(&self, path: &Path) -> Option<usize> {
+        select_winner(
+            self.matching_rule_indices(path),
+            |index| self.rules[index].rank(path),
+            self.rule_resolution,
+        )
+    }
+
+    fn matching_rule_indices(&self, path: &Path) -> Vec<usize> {
+        self.rules
+            .iter()
+            .enumerate()
+            .filter(|(_, rule)| rule.selector.is_match(path) && !self.is_lapsed(rule.revert_after))
+            .map(|(index, _)| index)
+            .collect()
+    }
+
     /// Returns true if structure checking is enabled (any limit is set).
     #[must_use]
     #[allow(clippy::missing_const_for_fn)] // Vec::is_empty() is not const
@@ -78,16 +139,24 @@ impl StructureChecker {
         self.max_files.is_some()
             || self.max_dirs.is_some()
             || self.max_depth.is_some()
+            || self.totals.is_set()
+            || !self.max_files_by_ext.is_empty()
+            || self.deny_empty_dirs.is_enabled()
             || !self.rules.is_empty()
     }
 
+    /// Whether any `max_total_*` limit is configured, globally or in a rule.
+    fn has_total_limits(&self) -> bool {
+        self.totals.is_set() || self.rules.iter().any(|rule| rule.totals.is_set())
+    }
+
     /// Get limits for a directory path.
     /// Returns a `StructureLimits` struct with all applicable limits.
     /// A limit of `-1` (UNLIMITED) means no check should be performed.
     ///
     /// # Priority Chain (high → low)
     ///
-    /// 1. `[[structure.rules]]` - glob pattern, last match wins
+    /// 1. `[[structure.rules]]` - glob pattern; highest `priority` wins, ties follow `rule_resolution`
     /// 2. `[structure]` defaults (lowest)
     ///
     /// # Glob Semantics (structure rules only match directories)
@@ -96,24 +165,32 @@ impl StructureChecker {
     /// - `src/components/**` — matches ALL descendants recursively
     /// - `src/features`      — exact directory match only
     fn resolve_limits(&self, path: &Path) -> StructureLimits {
-        // Check rules (glob patterns) - last match wins
-        // Iterate in reverse to find the last matching rule
-        for rule in self.rules.iter().rev() {
-            if rule.matcher.is_match(path) {
-                return StructureLimits {
-                    max_files: rule.max_files.or(self.max_files),
-                    max_dirs: rule.max_dirs.or(self.max_dirs),
-                    max_depth: rule.max_depth.or(self.max_depth),
-                    relative_depth: rule.relative_depth,
-                    base_depth: rule.base_depth,
-                    warn_threshold: rule.warn_threshold.or(self.warn_threshold),
-                    warn_files_at: rule.warn_files_at.or(self.warn_files_at),
-                    warn_dirs_at: rule.warn_dirs_at.or(self.warn_dirs_at),
-                    warn_files_threshold: rule.warn_files_threshold.or(self.warn_files_threshold),
-                    warn_dirs_threshold: rule.warn_dirs_threshold.or(self.warn_dirs_threshold),
-                    override_reason: rule.reason.clone(),
-                };
-            }
+        // Check rules (glob patterns) - the winning rule overrides the defaults
+        if let Some(index) = self.winning_rule_index(path) {
+            let rule = &self.rules[index];
+            return StructureLimits {
+                max_files: rule.max_files.or(self.max_files),
+                max_dirs: rule.max_dirs.or(self.max_dirs),
+                max_depth: rule.max_depth.or(self.max_depth),
+                relative_depth: rule.relative_depth,
+                base_depth: rule
+                    .selector
+                    .matching_scope(path)
+                    .map_or(0, calculate_base_depth),
+                warn_threshold: rule.warn_threshold.or(self.warn_threshold),
+                warn_files_at: rule.warn_files_at.or(self.warn_files_at),
+                warn_dirs_at: rule.warn_dirs_at.or(self.warn_dirs_at),
+                warn_files_threshold: rule.warn_files_threshold.or(self.warn_files_threshold),
+                warn_dirs_threshold: rule.warn_dirs_threshold.or(self.warn_dirs_threshold),
+                totals: rule.totals.or(self.totals),
+                max_files_by_ext: self
+                    .max_files_by_ext
+                    .iter()
+                    .chain(&rule.max_files_by_ext)
+                    .map(|(ext, &limit)| (ext.clone(), limit))
+                    .collect(),
+                override_reason: rule.reason.clone(),
+            };
         }
 
         // Fall back to global defaults
@@ -128,14 +205,26 @@ impl StructureChecker {
             warn_dirs_at: self.warn_dirs_at,
             warn_files_threshold: self.warn_files_threshold,
             warn_dirs_threshold: self.warn_dirs_threshold,
+            totals: self.totals,
+            max_files_by_ext: self.max_files_by_ext.clone(),
             override_reason: None,
         }
     }
 
+    /// The `max_dirs` limit that applies to `path`, or `None` when it is unlimited.
+    #[must_use]
+    pub fn max_dirs_for(&self, path: &Path) -> Option<usize> {
+        self.resolve_limits(path)
+            .max_dirs
+            .and_then(|max| usize::try_from(max).ok())
+    }
+
     /// Check directory stats against limits and return violations.
     ///
     /// Only directories are checked (files are not tracked in `dir_stats`).
-    /// Each directory's immediate children counts are compared against applicable limits.
+    /// Each directory's immediate children counts are compared against applicable limits;
+    /// `max_total_*` limits use counts aggregated over the whole subtree, and
+    /// `deny_empty_dirs` reports directories without (counted) entries.
     /// Limits of `-1` (UNLIMITED) are skipped.
     #[must_use]
     #[allow(
@@ -145,117 +234,134 @@ impl StructureChecker {
     )] // Limits are validated to be non-negative (if not UNLIMITED), so casting is safe.
     pub fn check(&self, dir_stats: &HashMap<PathBuf, DirStats>) -> Vec<StructureViolation> {
         let mut violations = Vec::new();
+        let totals = self.has_total_limits().then(|| subtree_totals(dir_stats));
 
         for (path, stats) in dir_stats {
             let limits = self.resolve_limits(path);
-
-            // Check file count (skip if unlimited)
-            if let Some(limit) = limits.max_files
-                && limit != UNLIMITED
-            {
-                let limit_usize = limit as usize;
-                // Warn threshold fallback: absolute → percentage → global → default 0.8
-                let warn_limit = Self::calculate_warn_limit(
-                    limit,
-                    limits.warn_files_at,
-                    limits.warn_files_threshold,
-                    limits.warn_threshold,
-                    DEFAULT_WARN_THRESHOLD,
-                );
-
-                if stats.file_count > limit_usize {
-                    violations.push(StructureViolation::new(
-                        path.clone(),
-                        ViolationType::FileCount,
-                        stats.file_count,
-                        limit_usize,
-                        limits.override_reason.clone(),
-                    ));
-                } else if stats.file_count > warn_limit {
-                    violations.push(StructureViolation::warning(
-                        path.clone(),
-                        ViolationType::FileCount,
-                        stats.file_count,
-                        limit_usize,
-                        limits.override_reason.clone(),
-                    ));
+            let reason = limits.override_reason.as_ref();
+            // Warn threshold fallback: absolute → percentage → global → default 0.8
+            let warn_limit = |absolute, percentage| {
+                move |limit| {
+                    Self::calculate_warn_limit(
+                        limit,
+                        absolute,
+                        percentage,
+                        limits.warn_threshold,
+                        DEFAULT_WARN_THRESHOLD,
+                    )
                 }
-            }
+            };
 
-            // Check directory count (skip if unlimited)
-            if let Some(limit) = limits.max_dirs
-                && limit != UNLIMITED
-            {
-                let limit_usize = limit as usize;
-                // Warn threshold fallback: absolute → percentage → global → default 0.8
-                let warn_limit = Self::calculate_warn_limit(
-                    limit,
-                    limits.warn_dirs_at,
-                    limits.warn_dirs_threshold,
-                    limits.warn_threshold,
-                    DEFAULT_WARN_THRESHOLD,
-                );
-
-                if stats.dir_count > limit_usize {
-                    violations.push(StructureViolation::new(
-                        path.clone(),
-                        ViolationType::DirCount,
-                        stats.dir_count,
-                        limit_usize,
-                        limits.override_reason.clone(),
-                    ));
-                } else if stats.dir_count > warn_limit {
-                    violations.push(StructureViolation::warning(
-                        path.clone(),
-                        ViolationType::DirCount,
-                        stats.dir_count,
-                        limit_usize,
-                        limits.override_reason.clone(),
-                    ));
-                }
+            violations.extend(Self::count_violation(
+                path,
+                ViolationType::FileCount,
+                stats.file_count,
+                limits.max_files,
+                warn_limit(limits.warn_files_at, limits.warn_files_threshold),
+                reason,
+            ));
+            // Extension buckets share the percentage file thresholds (no absolute warn_files_at)
+            for (extension, &limit) in &limits.max_files_by_ext {
+                violations.extend(Self::count_violation(
+                    path,
+                    ViolationType::FileCountByExtension {
+                        extension: extension.clone(),
+                    },
+                    stats.files_by_ext.get(extension).copied().unwrap_or(0),
+                    Some(limit),
+                    warn_limit(None, limits.warn_files_threshold),
+                    reason,
+                ));
             }
-
-            // Check depth (skip if unlimited)
-            // Note: depth uses global warn_threshold only (no granular threshold)
-            if let Some(limit) = limits.max_depth
-                && limit != UNLIMITED
-            {
-                let limit_usize = limit as usize;
-                let warn_limit = limits.warn_threshold.unwrap_or(DEFAULT_WARN_THRESHOLD);
-                let warn_limit = ((limit as f64) * warn_limit).ceil() as usize;
-
-                // Calculate effective depth: relative to base if relative_depth is set
-                let effective_depth = if limits.relative_depth {
-                    stats.depth.saturating_sub(limits.base_depth)
-                } else {
-                    stats.depth
-                };
-
-                if effective_depth > limit_usize {
-                    violations.push(StructureViolation::new(
-                        path.clone(),
-                        ViolationType::MaxDepth,
-                        effective_depth,
-                        limit_usize,
-                        limits.override_reason.clone(),
-                    ));
-                } else if effective_depth > warn_limit {
-                    violations.push(StructureViolation::warning(
-                        path.clone(),
-                        ViolationType::MaxDepth,
-                        effective_depth,
-                        limit_usize,
-                        limits.override_reason.clone(),
-                    ));
-                }
+            violations.extend(Self::count_violation(
+                path,
+                ViolationType::DirCount,
+                stats.dir_count,
+                limits.max_dirs,
+                warn_limit(limits.warn_dirs_at, limits.warn_dirs_threshold),
+                reason,
+            ));
+
+            // Depth uses global warn_threshold only (no granular threshold),
+            // measured relative to the rule's base if relative_depth is set
+            let effective_depth = if limits.relative_depth {
+                stats.depth.saturating_sub(limits.base_depth)
+            } else {
+                stats.depth
+            };
+            violations.extend(Self::count_violation(
+                path,
+                ViolationType::MaxDepth,
+                effective_depth,
+                limits.max_depth,
+                |limit| {
+                    let threshold = limits.warn_threshold.unwrap_or(DEFAULT_WARN_THRESHOLD);
+                    ((limit as f64) * threshold).ceil() as usize
+                },
+                reason,
+            ));
+
+            if let Some(subtree) = totals.as_ref().and_then(|t| t.get(path.as_path())) {
+                let total = limits.totals;
+                violations.extend(Self::count_violation(
+                    path,
+                    ViolationType::TotalFileCount,
+                    subtree.files,
+                    total.max_files,
+                    warn_limit(total.warn_files_at, total.warn_files_threshold),
+                    reason,
+                ));
+                violations.extend(Self::count_violation(
+                    path,
+                    ViolationType::TotalDirCount,
+                    subtree.dirs,
+                    total.max_dirs,
+                    warn_limit(total.warn_dirs_at, total.warn_dirs_threshold),
+                    reason,
+                ));
             }
         }
 
+        violations.extend(empty_dir_violations(dir_stats, self.deny_empty_dirs));
+
         // Sort by path for consistent output
         violations.sort_by(|a, b| a.path.cmp(&b.path));
         violations
     }
 
+    /// Failure above `limit`, warning above the warn limit, nothing if unset or unlimited.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // Limit is non-negative once UNLIMITED is ruled out
+    fn count_violation(
+        path: &Path,
+        violation_type: ViolationType,
+        actual: usize,
+        limit: Option<i64>,
+        warn_limit: impl FnOnce(i64) -> usize,
+        override_reason: Option<&String>,
+    ) -> Option<StructureViolation> {
+        let limit = limit.filter(|&limit| limit != UNLIMITED)?;
+        let limit_usize = limit as usize;
+        if actual > limit_usize {
+            Some(StructureViolation::new(
+                path.to_path_buf(),
+                violation_type,
+                actual,
+                limit_usize,
+                override_reason.cloned(),
+            ))
+        } else if actual > warn_limit(limit) {
+            Some(StructureViolation::warning(
+                path.to_path_buf(),
+                violation_type,
+                actual,
+                limit_usize,
+                override_reason.cloned(),
+            ))
+        } else {
+            None
+        }
+    }
+
     /// Calculate the warn limit using the fallback chain:
     /// absolute → percentage → global → default
     #[allow(
@@ -289,14 +395,17 @@ impl StructureChecker {
         ((limit as f64) * default_threshold).ceil() as usize
     }
 
-    /// Check files for missing siblings.
+    /// Check files for missing or conflicting siblings.
     ///
-    /// Supports two rule types:
+    /// Supports four rule types:
     /// - **Directed**: If a file matches the pattern, require specific sibling(s).
     /// - **Group**: If ANY file in the group exists, ALL must exist.
+    /// - **Forbid**: If a file matches the pattern, specific sibling(s) must not exist.
+    /// - **Exclusive**: At most one of the listed patterns may match in a directory.
     ///
     /// # Returns
-    /// A vector of sibling violations (`MissingSibling` or `GroupIncomplete`).
+    /// A vector of sibling violations (`MissingSibling`, `GroupIncomplete`,
+    /// `ForbiddenSibling` or `ExclusiveConflict`).
     #[must_use]
     pub fn check_siblings(&self, files: &[PathBuf]) -> Vec<StructureViolation> {
         if self.sibling_rules.is_empty() {
@@ -319,14 +428,13 @@ impl StructureChecker {
 
         for (parent, dir_files) in files_by_parent {
             // Find rules that apply to this directory
+            let normalized_parent = normalize_for_matching(parent);
             let applicable_rules: Vec<_> = self
                 .sibling_rules
                 .iter()
-                .filter(|rule| match rule {
-                    CompiledSiblingRule::Directed { dir_matcher, .. } => {
-                        dir_matcher.is_match(parent)
-                    }
-                    CompiledSiblingRule::Group { dir_matcher, .. } => dir_matcher.is_match(parent),
+                .filter(|rule| {
+                    let (dir_matcher, revert_after) = rule.dir_filter();
+                    dir_matcher.is_match(&normalized_parent) && !self.is_lapsed(revert_after)
                 })
                 .collect();
 
@@ -334,6 +442,13 @@ impl StructureChecker {
                 continue;
             }
 
+            // Exclusive rules are evaluated once per directory, not per file
+            violations.extend(sibling_conflicts::exclusive_violations(
+                parent,
+                &dir_files,
+                &applicable_rules,
+            ));
+
             for file_path in dir_files {
                 let Some(file_name) = file_path.file_name().and_then(|n| n.to_str()) else {
                     continue;
@@ -359,25 +474,24 @@ impl StructureChecker {
                                     Self::derive_sibling_path(file_path, template)
                                     && !file_set.contains(&expected_sibling)
                                 {
+                                    let template = template.source().to_string();
                                     let violation = if *is_warning {
                                         StructureViolation::missing_sibling_warning(
                                             file_path.clone(),
                                             dir_scope.clone(),
-                                            template.clone(),
+                                            template,
                                         )
                                     } else {
                                         StructureViolation::missing_sibling(
                                             file_path.clone(),
                                             dir_scope.clone(),
-                                            template.clone(),
+                                            template,
                                         )
                                     };
                                     violations.push(violation);
                                 }
                             }
                         }
-                        #[allow(clippy::literal_string_with_formatting_args)]
-                        // {stem} is template syntax, not a format arg
                         CompiledSiblingRule::Group {
                             dir_scope,
                             group_patterns,
@@ -386,10 +500,11 @@ impl StructureChecker {
                         } => {
                             // Try ALL patterns to extract possible stems, then find the best one
                             // (the one that results in the most complete group)
+                            let values = TemplateValues::for_file(file_path);
                             let stems: Vec<String> = group_patterns
                                 .iter()
                                 .filter_map(|pattern| {
-                                    Self::extract_stem_from_pattern(file_name, pattern)
+                                    Self::extract_stem_from_pattern(file_name, pattern, &values)
                                 })
                                 .collect();
 
@@ -404,9 +519,8 @@ impl StructureChecker {
                                 group_patterns
                                     .iter()
                                     .filter(|pattern| {
-                                        let expected_name = pattern.replace("{stem}", stem);
-                                        let expected_path = parent.join(&expected_name);
-                                        !file_set.contains(&expected_path)
+                                        let values = TemplateValues { stem, ..values };
+                                        !file_set.contains(&parent.join(pattern.expand(&values)))
                                     })
                                     .count()
                             });
@@ -416,14 +530,13 @@ impl StructureChecker {
                             };
 
                             // This file triggers the group check - find missing members using the best stem
+                            let values = TemplateValues { stem, ..values };
                             let missing: Vec<String> = group_patterns
                                 .iter()
                                 .filter(|pattern| {
-                                    let expected_name = pattern.replace("{stem}", stem);
-                                    let expected_path = parent.join(&expected_name);
-                                    !file_set.contains(&expected_path)
+                                    !file_set.contains(&parent.join(pattern.expand(&values)))
                                 })
-                                .cloned()
+                                .map(|pattern| pattern.source().to_string())
                                 .collect();
 
                             if !missing.is_empty() {
@@ -431,20 +544,38 @@ impl StructureChecker {
                                     StructureViolation::group_incomplete_warning(
                                         file_path.clone(),
                                         dir_scope.clone(),
-                                        group_patterns.clone(),
+                                        group_sources(group_patterns),
                                         missing,
                                     )
                                 } else {
                                     StructureViolation::group_incomplete(
                                         file_path.clone(),
                                         dir_scope.clone(),
-                                        group_patterns.clone(),
+                                        group_sources(group_patterns),
                                         missing,
                                     )
                                 };
                                 violations.push(violation);
                             }
                         }
+                        CompiledSiblingRule::Forbid {
+                            dir_scope,
+                            file_matcher,
+                            sibling_templates,
+                            is_warning,
+                            ..
+                        } => {
+                            if file_matcher.is_match(file_name) {
+                                violations.extend(sibling_conflicts::forbidden_violations(
+                                    file_path,
+                                    &file_set,
+                                    dir_scope,
+                                    sibling_templates,
+                                    *is_warning,
+                                ));
+                            }
+                        }
+                        CompiledSiblingRule::Exclusive { .. } => {}
                     }
                 }
             }
@@ -457,40 +588,37 @@ impl StructureChecker {
 
     /// Derive sibling path from source file and template.
     ///
-    /// Template syntax: `{stem}` is replaced with the source file's stem.
-    #[allow(clippy::literal_string_with_formatting_args)] // {stem} is template syntax, not a format arg
-    fn derive_sibling_path(source: &Path, template: &str) -> Option<PathBuf> {
+    /// Placeholders are expanded from the source file (`{stem}`, `{ext}`) and its
+    /// directory (`{dir}`, `{parent}`).
+    fn derive_sibling_path(source: &Path, template: &Template) -> Option<PathBuf> {
         let parent = source.parent()?;
-        let stem = source.file_stem()?.to_str()?;
+        source.file_stem()?.to_str()?;
 
-        let sibling_name = template.replace("{stem}", stem);
+        let sibling_name = template.expand(&TemplateValues::for_file(source));
         Some(parent.join(sibling_name))
     }
 
-    /// Extract the stem value from a file name matching a pattern.
+    /// Extract the stem value from a file name matching a group pattern.
     ///
-    /// Template syntax: `{stem}` is a placeholder. The function checks if the file name
-    /// matches the pattern and extracts what `{stem}` represents.
+    /// Placeholders other than `{stem}` are expanded from `values`; the file name
+    /// must start and end with the text around `{stem}`.
     ///
     /// Examples:
     /// - `Button.tsx` matching `{stem}.tsx` → `Some("Button")`
     /// - `Button.test.tsx` matching `{stem}.test.tsx` → `Some("Button")`
     /// - `Button.tsx` matching `{stem}.test.tsx` → `None` (no match)
-    #[allow(clippy::literal_string_with_formatting_args)] // {stem} is template syntax, not a format arg
-    fn extract_stem_from_pattern(file_name: &str, pattern: &str) -> Option<String> {
-        // Split the pattern by {stem} to get prefix and suffix
-        let parts: Vec<&str> = pattern.split("{stem}").collect();
-        if parts.len() != 2 {
-            // Pattern must contain exactly one {stem}
-            return None;
-        }
-        let (prefix, suffix) = (parts[0], parts[1]);
+    fn extract_stem_from_pattern(
+        file_name: &str,
+        pattern: &Template,
+        values: &TemplateValues,
+    ) -> Option<String> {
+        let (prefix, suffix) = pattern.stem_affixes(values)?;
 
         // Check if file_name starts with prefix and ends with suffix
-        if !file_name.starts_with(prefix) {
+        if !file_name.starts_with(&prefix) {
             return None;
         }
-        if !file_name.ends_with(suffix) {
+        if !file_name.ends_with(&suffix) {
             return None;
         }
 
@@ -516,42 +644,47 @@ impl StructureChecker {
         let mut found_match = false;
         let mut override_reason = None;
 
-        // Check rules (last match wins for consistency with content rules)
-        // First find the index of the last matching rule
-        let last_matching_rule_idx = self
-            .rules
-            .iter()
-            .enumerate()
-            .rev()
-            .find(|(_, rule)| rule.matcher.is_match(path))
-            .map(|(i, _)| i);
+        // Pick the winner among matching rules (priority, then rule_resolution)
+        let matching = self.matching_rule_indices(path);
+        let winner = explain_winner(
+            &matching,
+            |index| self.rules[index].rank(path),
+            self.rule_resolution,
+        );
+        let winner_idx = winner.map(|(index, _)| index);
 
-        // Then iterate forward to build rule chain with correct statuses
+        // Iterate forward to build rule chain with correct statuses
         for (i, rule) in self.rules.iter().enumerate() {
-            let matches = rule.matcher.is_match(path);
-            let is_last_match = last_matching_rule_idx == Some(i);
-            let status = if is_last_match && !found_match {
+            let status = if winner_idx == Some(i) {
                 found_match = true;
                 override_reason.clone_from(&rule.reason);
                 matched_rule = StructureRuleMatch::Rule {
                     index: i,
-                    pattern: rule.scope.clone(),
+                    pattern: rule.selector.label().to_string(),
                     reason: rule.reason.clone(),
                 };
                 MatchStatus::Matched
-            } else if matches {
+            } else if matching.contains(&i) {
                 MatchStatus::Superseded
+            } else if rule.selector.is_match(path) {
+                // Matches, but was filtered out as a lapsed `on_expire = "revert"` rule
+                MatchStatus::Expired
             } else {
                 MatchStatus::NoMatch
             };
 
             rule_chain.push(StructureRuleCandidate {
                 source: format!("structure.rules[{i}]"),
-                pattern: Some(rule.scope.clone()),
+                pattern: Some(rule.selector.label().to_string()),
                 max_files: rule.max_files,
                 max_dirs: rule.max_dirs,
                 max_depth: rule.max_depth,
+                max_total_files: rule.totals.max_files,
+                max_total_dirs: rule.totals.max_dirs,
+                max_files_by_ext: rule.max_files_by_ext.clone(),
+                rank: Some(rule.rank(path)),
                 status,
+                selector: Some(rule.selector.evaluate(path)),
             });
         }
 
@@ -562,6 +695,11 @@ impl StructureChecker {
             max_files: self.max_files,
             max_dirs: self.max_dirs,
             max_depth: self.max_depth,
+            max_total_files: self.totals.max_files,
+            max_total_dirs: self.totals.max_dirs,
+            max_files_by_ext: self.max_files_by_ext.clone(),
+            rank: None,
+            selector: None,
             status: if found_match {
                 MatchStatus::Superseded
             } else {
@@ -578,12 +716,26 @@ impl StructureChecker {
             effective_max_files: limits.max_files,
             effective_max_dirs: limits.max_dirs,
             effective_max_depth: limits.max_depth,
+            effective_max_total_files: limits.totals.max_files,
+            effective_max_total_dirs: limits.totals.max_dirs,
+            effective_max_files_by_ext: limits.max_files_by_ext,
             warn_threshold: limits.warn_threshold.unwrap_or(DEFAULT_WARN_THRESHOLD),
             override_reason,
+            rule_resolution: self.rule_resolution,
+            win_reason: winner.map(|(_, reason)| reason),
             rule_chain,
+            symlink: None,
         }
     }
 }
 
+/// Group patterns as written in the config, for violation messages.
+fn group_sources(group_patterns: &[Template]) -> Vec<String> {
+    group_patterns
+        .iter()
+        .map(|pattern| pattern.source().to_string())
+        .collect()
+}
+
 #[cfg(test)]
 mod check_tests;
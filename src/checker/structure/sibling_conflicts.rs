@@ -0,0 +1,118 @@
+//! Conflict checks for `forbid` and `exclusive` sibling rules.
+//!
+//! Unlike directed and group rules, which report missing files, these rules
+//! report files that are present but should not be.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use globset::GlobMatcher;
+
+use crate::config::Template;
+
+use super::StructureChecker;
+use super::compiled_rules::CompiledSiblingRule;
+use super::violation::StructureViolation;
+
+/// Violations for forbidden siblings of `file_path` that exist in `file_set`.
+pub(super) fn forbidden_violations(
+    file_path: &PathBuf,
+    file_set: &HashSet<&PathBuf>,
+    dir_scope: &str,
+    sibling_templates: &[Template],
+    is_warning: bool,
+) -> Vec<StructureViolation> {
+    sibling_templates
+        .iter()
+        .filter_map(|template| {
+            let forbidden = StructureChecker::derive_sibling_path(file_path, template)?;
+            if forbidden == *file_path || !file_set.contains(&forbidden) {
+                return None;
+            }
+            let conflicting_file = forbidden.file_name()?.to_string_lossy().into_owned();
+            Some(if is_warning {
+                StructureViolation::forbidden_sibling_warning(
+                    file_path.clone(),
+                    dir_scope.to_string(),
+                    template.source().to_string(),
+                    conflicting_file,
+                )
+            } else {
+                StructureViolation::forbidden_sibling(
+                    file_path.clone(),
+                    dir_scope.to_string(),
+                    template.source().to_string(),
+                    conflicting_file,
+                )
+            })
+        })
+        .collect()
+}
+
+/// One violation per `exclusive` rule for which more than one pattern matches in `parent`.
+pub(super) fn exclusive_violations(
+    parent: &Path,
+    dir_files: &[&PathBuf],
+    rules: &[&CompiledSiblingRule],
+) -> Vec<StructureViolation> {
+    rules
+        .iter()
+        .filter_map(|rule| {
+            let CompiledSiblingRule::Exclusive {
+                dir_scope,
+                patterns,
+                matchers,
+                is_warning,
+                ..
+            } = rule
+            else {
+                return None;
+            };
+            let conflicting_files = exclusive_conflicts(matchers, dir_files)?;
+            Some(if *is_warning {
+                StructureViolation::exclusive_conflict_warning(
+                    parent.to_path_buf(),
+                    dir_scope.clone(),
+                    patterns.clone(),
+                    conflicting_files,
+                )
+            } else {
+                StructureViolation::exclusive_conflict(
+                    parent.to_path_buf(),
+                    dir_scope.clone(),
+                    patterns.clone(),
+                    conflicting_files,
+                )
+            })
+        })
+        .collect()
+}
+
+/// File names matching an exclusive rule, or `None` unless two different files match
+/// different patterns.
+///
+/// Several files matching the same pattern (e.g. `*.js`) do not conflict with each other,
+/// and neither does one file matching several overlapping patterns (`*.ts`, `index.*`).
+fn exclusive_conflicts(matchers: &[GlobMatcher], dir_files: &[&PathBuf]) -> Option<Vec<String>> {
+    let mut matched_patterns = vec![false; matchers.len()];
+    let mut conflicting_files = Vec::new();
+    for name in dir_files
+        .iter()
+        .filter_map(|path| path.file_name()?.to_str())
+    {
+        let mut matched = false;
+        for (index, matcher) in matchers.iter().enumerate() {
+            if matcher.is_match(name) {
+                matched_patterns[index] = true;
+                matched = true;
+            }
+        }
+        if matched {
+            conflicting_files.push(name.to_string());
+        }
+    }
+    conflicting_files.sort();
+    conflicting_files.dedup();
+    let pattern_count = matched_patterns.iter().filter(|&&matched| matched).count();
+    (conflicting_files.len() >= 2 && pattern_count >= 2).then_some(conflicting_files)
+}
@@ -0,0 +1,54 @@
+//! Recursive file/directory totals aggregated from per-directory `DirStats`.
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use super::dir_stats::DirStats;
+
+/// Files and directories anywhere beneath a directory.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(super) struct SubtreeTotals {
+    pub files: usize,
+    pub dirs: usize,
+}
+
+/// Fold immediate-child counts bottom-up into recursive totals.
+///
+/// A directory marked `count_excluded` keeps its own totals but adds nothing to its
+/// ancestors, just as it is left out of its parent's `dir_count`.
+pub(super) fn subtree_totals(
+    dir_stats: &HashMap<PathBuf, DirStats>,
+) -> HashMap<&Path, SubtreeTotals> {
+    let mut totals: HashMap<&Path, SubtreeTotals> = dir_stats
+        .iter()
+        .map(|(path, stats)| {
+            let own = SubtreeTotals {
+                files: stats.file_count,
+                dirs: stats.dir_count,
+            };
+            (path.as_path(), own)
+        })
+        .collect();
+
+    let mut deepest_first: Vec<&PathBuf> = dir_stats.keys().collect();
+    deepest_first.sort_by_key(|path| Reverse(path.components().count()));
+
+    for path in deepest_first {
+        if dir_stats[path].count_excluded {
+            continue;
+        }
+        let (Some(parent), Some(&child)) = (path.parent(), totals.get(path.as_path())) else {
+            continue;
+        };
+        if let Some(parent_totals) = totals.get_mut(parent) {
+            parent_totals.files += child.files;
+            parent_totals.dirs += child.dirs;
+        }
+    }
+    totals
+}
+
+#[cfg(test)]
+#[path = "totals_tests.rs"]
+mod tests;
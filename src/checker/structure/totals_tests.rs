@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use super::*;
+
+fn dir(file_count: usize, dir_count: usize, count_excluded: bool) -> DirStats {
+    DirStats {
+        file_count,
+        dir_count,
+        depth: 0,
+        count_excluded,
+        entry_count: file_count + dir_count,
+        files_by_ext: HashMap::new(),
+        is_symlink: false,
+    }
+}
+
+#[test]
+fn leaf_directory_totals_equal_own_counts() {
+    let stats = HashMap::from([(PathBuf::from("src"), dir(3, 0, false))]);
+
+    let totals = subtree_totals(&stats);
+
+    assert_eq!(
+        totals[Path::new("src")],
+        SubtreeTotals { files: 3, dirs: 0 }
+    );
+}
+
+#[test]
+fn nested_counts_fold_into_every_ancestor() {
+    let stats = HashMap::from([
+        (PathBuf::from("src"), dir(1, 2, false)),
+        (PathBuf::from("src/a"), dir(2, 1, false)),
+        (PathBuf::from("src/a/deep"), dir(4, 0, false)),
+        (PathBuf::from("src/b"), dir(5, 0, false)),
+    ]);
+
+    let totals = subtree_totals(&stats);
+
+    assert_eq!(
+        totals[Path::new("src/a")],
+        SubtreeTotals { files: 6, dirs: 1 }
+    );
+    assert_eq!(
+        totals[Path::new("src")],
+        SubtreeTotals { files: 12, dirs: 3 }
+    );
+}
+
+#[test]
+fn count_excluded_directory_does_not_propagate() {
+    // `src/generated` is already left out of `src`'s dir_count by the scanner
+    let stats = HashMap::from([
+        (PathBuf::from("src"), dir(1, 1, false)),
+        (PathBuf::from("src/a"), dir(2, 0, false)),
+        (PathBuf::from("src/generated"), dir(50, 1, true)),
+        (PathBuf::from("src/generated/nested"), dir(10, 0, false)),
+    ]);
+
+    let totals = subtree_totals(&stats);
+
+    assert_eq!(
+        totals[Path::new("src")],
+        SubtreeTotals { files: 3, dirs: 1 }
+    );
+    assert_eq!(
+        totals[Path::new("src/generated")],
+        SubtreeTotals { files: 60, dirs: 1 }
+    );
+}
+
+#[test]
+fn missing_parent_entry_is_ignored() {
+    let stats = HashMap::from([(PathBuf::from("src/a"), dir(2, 0, false))]);
+
+    let totals = subtree_totals(&stats);
+
+    assert_eq!(totals.len(), 1);
+    assert_eq!(
+        totals[Path::new("src/a")],
+        SubtreeTotals { files: 2, dirs: 0 }
+    );
+}
@@ -1,6 +1,10 @@
 //! Validation logic for structure configuration.
 
-use crate::config::{StructureConfig, StructureRule, UNLIMITED};
+use std::collections::BTreeMap;
+
+use crate::config::{
+    Placeholder, SiblingRequire, StructureConfig, StructureRule, Template, UNLIMITED,
+};
 use crate::error::{Result, SlocGuardError};
 
 /// Validate that all limit values are >= -1.
@@ -11,6 +15,7 @@ pub(super) fn validate_limits(config: &StructureConfig) -> Result<()> {
 }
 
 fn validate_global_limits(config: &StructureConfig) -> Result<()> {
+    validate_files_by_ext(&config.max_files_by_ext, "")?;
     if let Some(limit) = config.max_files
         && limit < UNLIMITED
     {
@@ -32,11 +37,37 @@ fn validate_global_limits(config: &StructureConfig) -> Result<()> {
             "Invalid max_depth value: {limit}. Use -1 for unlimited, or a positive number."
         )));
     }
+    for (name, value) in [
+        ("max_total_files", config.max_total_files),
+        ("max_total_dirs", config.max_total_dirs),
+    ] {
+        if let Some(limit) = value
+            && limit < UNLIMITED
+        {
+            return Err(SlocGuardError::Config(format!(
+                "Invalid {name} value: {limit}. Use -1 for unlimited, 0 for prohibited, or a positive number."
+            )));
+        }
+    }
+    for (name, value) in [
+        ("max_path_length", config.max_path_length),
+        ("max_filename_length", config.max_filename_length),
+        ("max_path_components", config.max_path_components),
+    ] {
+        if let Some(limit) = value
+            && (limit < UNLIMITED || limit == 0)
+        {
+            return Err(SlocGuardError::Config(format!(
+                "Invalid {name} value: {limit}. Use -1 for unlimited, or a positive number."
+            )));
+        }
+    }
     Ok(())
 }
 
 fn validate_rule_limits(rules: &[StructureRule]) -> Result<()> {
     for (i, rule) in rules.iter().enumerate() {
+        validate_files_by_ext(&rule.max_files_by_ext, &format!(" in rule {}", i + 1))?;
         if let Some(limit) = rule.max_files
             && limit < UNLIMITED
         {
@@ -61,6 +92,51 @@ fn validate_rule_limits(rules: &[StructureRule]) -> Result<()> {
                 i + 1
             )));
         }
+        for (name, value) in [
+            ("max_total_files", rule.max_total_files),
+            ("max_total_dirs", rule.max_total_dirs),
+        ] {
+            if let Some(limit) = value
+                && limit < UNLIMITED
+            {
+                return Err(SlocGuardError::Config(format!(
+                    "Invalid {name} value in rule {}: {limit}. Use -1 for unlimited, 0 for prohibited, or a positive number.",
+                    i + 1
+                )));
+            }
+        }
+        for (name, value) in [
+            ("max_path_length", rule.max_path_length),
+            ("max_filename_length", rule.max_filename_length),
+            ("max_path_components", rule.max_path_components),
+        ] {
+            if let Some(limit) = value
+                && (limit < UNLIMITED || limit == 0)
+            {
+                return Err(SlocGuardError::Config(format!(
+                    "Invalid {name} value in rule {}: {limit}. Use -1 for unlimited, or a positive number.",
+                    i + 1
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Validate `max_files_by_ext` keys (`.ext`) and limits (>= -1).
+/// `location` is appended to the field name in errors (e.g., " in rule 2").
+fn validate_files_by_ext(limits: &BTreeMap<String, i64>, location: &str) -> Result<()> {
+    for (extension, &limit) in limits {
+        if extension.len() < 2 || !extension.starts_with('.') {
+            return Err(SlocGuardError::Config(format!(
+                "Invalid max_files_by_ext key{location}: '{extension}'. Use an extension with a leading dot, e.g. \".rs\"."
+            )));
+        }
+        if limit < UNLIMITED {
+            return Err(SlocGuardError::Config(format!(
+                "Invalid max_files_by_ext value for '{extension}'{location}: {limit}. Use -1 for unlimited, 0 for prohibited, or a positive number."
+            )));
+        }
     }
     Ok(())
 }
@@ -78,34 +154,42 @@ pub(super) fn validate_sibling_rules(rules: &[StructureRule]) -> Result<()> {
                     match_pattern,
                     require,
                     ..
-                } => {
-                    if match_pattern.is_empty() {
-                        return Err(SlocGuardError::Config(format!(
-                            "Rule {} sibling {} has empty 'match' pattern.",
-                            i + 1,
-                            j + 1
-                        )));
-                    }
-                    let patterns = require.as_patterns();
-                    if patterns.is_empty() {
+                } => validate_directed_sibling(i, j, match_pattern, "require", require)?,
+                SiblingRule::Forbid {
+                    match_pattern,
+                    forbid,
+                    ..
+                } => validate_directed_sibling(i, j, match_pattern, "forbid", forbid)?,
+                SiblingRule::Group { group, .. } => {
+                    if group.len() < 2 {
                         return Err(SlocGuardError::Config(format!(
-                            "Rule {} sibling {} has empty 'require' pattern.",
+                            "Rule {} sibling {} group must have at least 2 patterns.",
                             i + 1,
                             j + 1
                         )));
                     }
-                    for pattern in patterns {
+                    for (k, pattern) in group.iter().enumerate() {
                         if pattern.is_empty() {
                             return Err(SlocGuardError::Config(format!(
-                                "Rule {} sibling {} has empty pattern in 'require' array.",
+                                "Rule {} sibling {} has empty pattern at index {}.",
                                 i + 1,
-                                j + 1
+                                j + 1,
+                                k
                             )));
                         }
-                        // Require patterns must contain {stem} placeholder for sibling derivation
-                        if !pattern.contains("{stem}") {
+                        let template =
+                            Template::parse(pattern, Placeholder::GROUP).map_err(|e| {
+                                SlocGuardError::Config(format!(
+                                    "Rule {} sibling {} pattern '{pattern}': {e}.",
+                                    i + 1,
+                                    j + 1
+                                ))
+                            })?;
+                        // Group patterns need a single plain {stem} for stem extraction
+                        if template.stem_usage() != (1, false) {
                             return Err(SlocGuardError::Config(format!(
-                                "Rule {} sibling {} 'require' pattern '{}' must contain {{stem}} placeholder.",
+                                "Rule {} sibling {} pattern '{}' must contain {{stem}} placeholder \
+                                 exactly once and without a transform.",
                                 i + 1,
                                 j + 1,
                                 pattern
@@ -113,15 +197,15 @@ pub(super) fn validate_sibling_rules(rules: &[StructureRule]) -> Result<()> {
                         }
                     }
                 }
-                SiblingRule::Group { group, .. } => {
-                    if group.len() < 2 {
+                SiblingRule::Exclusive { exclusive, .. } => {
+                    if exclusive.len() < 2 {
                         return Err(SlocGuardError::Config(format!(
-                            "Rule {} sibling {} group must have at least 2 patterns.",
+                            "Rule {} sibling {} exclusive must have at least 2 patterns.",
                             i + 1,
                             j + 1
                         )));
                     }
-                    for (k, pattern) in group.iter().enumerate() {
+                    for (k, pattern) in exclusive.iter().enumerate() {
                         if pattern.is_empty() {
                             return Err(SlocGuardError::Config(format!(
                                 "Rule {} sibling {} has empty pattern at index {}.",
@@ -130,10 +214,12 @@ pub(super) fn validate_sibling_rules(rules: &[StructureRule]) -> Result<()> {
                                 k
                             )));
                         }
-                        // Group patterns must contain {stem} placeholder for stem extraction
-                        if !pattern.contains("{stem}") {
+                        // Exclusive entries are plain file name globs matched per directory
+                        if Template::parse(pattern, &[]).is_err() {
                             return Err(SlocGuardError::Config(format!(
-                                "Rule {} sibling {} pattern '{}' must contain {{stem}} placeholder.",
+                                "Rule {} sibling {} exclusive pattern '{}' must not contain {{stem}} \
+                                 or other placeholders; \
+                                 use a 'forbid' rule for stem-based conflicts.",
                                 i + 1,
                                 j + 1,
                                 pattern
@@ -147,6 +233,58 @@ pub(super) fn validate_sibling_rules(rules: &[StructureRule]) -> Result<()> {
     Ok(())
 }
 
+/// Validate the `match` pattern and `require`/`forbid` templates of a directed sibling rule.
+#[allow(clippy::literal_string_with_formatting_args)] // {stem} is template syntax, not a format arg
+fn validate_directed_sibling(
+    i: usize,
+    j: usize,
+    match_pattern: &str,
+    field: &str,
+    templates: &SiblingRequire,
+) -> Result<()> {
+    if match_pattern.is_empty() {
+        return Err(SlocGuardError::Config(format!(
+            "Rule {} sibling {} has empty 'match' pattern.",
+            i + 1,
+            j + 1
+        )));
+    }
+    let patterns = templates.as_patterns();
+    if patterns.is_empty() {
+        return Err(SlocGuardError::Config(format!(
+            "Rule {} sibling {} has empty '{field}' pattern.",
+            i + 1,
+            j + 1
+        )));
+    }
+    for pattern in patterns {
+        if pattern.is_empty() {
+            return Err(SlocGuardError::Config(format!(
+                "Rule {} sibling {} has empty pattern in '{field}' array.",
+                i + 1,
+                j + 1
+            )));
+        }
+        let template = Template::parse(pattern, Placeholder::SIBLING).map_err(|e| {
+            SlocGuardError::Config(format!(
+                "Rule {} sibling {} '{field}' pattern '{pattern}': {e}.",
+                i + 1,
+                j + 1
+            ))
+        })?;
+        // Templates must contain a placeholder for sibling derivation
+        if !template.has_placeholders() {
+            return Err(SlocGuardError::Config(format!(
+                "Rule {} sibling {} '{field}' pattern '{}' must contain a placeholder such as {{stem}}.",
+                i + 1,
+                j + 1,
+                pattern
+            )));
+        }
+    }
+    Ok(())
+}
+
 /// Validate mutual exclusion between allow and deny fields.
 /// At each level (global or rule), only allow-mode OR deny-mode is permitted, not both.
 pub(super) fn validate_allow_deny_mutual_exclusion(config: &StructureConfig) -> Result<()> {
@@ -192,7 +330,7 @@ fn validate_rule_allow_deny_exclusion(rules: &[StructureRule]) -> Result<()> {
                 "Rule {} (scope '{}') cannot mix allow_* and deny_* fields. \
                  Use either allowlist mode OR denylist mode, not both.",
                 i + 1,
-                rule.scope
+                rule.scope_label()
             )));
         }
     }
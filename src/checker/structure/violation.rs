@@ -2,13 +2,30 @@ use std::path::PathBuf;
 
 use serde::Serialize;
 
-/// Counts of immediate children in a directory.
-#[derive(Debug, Clone, Default, PartialEq, Eq)]
-pub struct DirStats {
-    pub file_count: usize,
-    pub dir_count: usize,
-    /// Depth relative to scan root (root = 0).
-    pub depth: usize,
+use crate::checker::comment_ratio::CommentRatioBound;
+use crate::config::ContentMetric;
+
+/// Why a symlink is reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SymlinkProblem {
+    /// Symlinks are not allowed (`deny_symlinks = true`).
+    Denied,
+    /// Target resolves outside the project root (`deny_symlinks = true` or `"external"`).
+    OutsideProject,
+    /// Followed link leads back to one of its own ancestors.
+    Loop,
+}
+
+/// How a directory departs from its `[[structure.layouts]]` template.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LayoutProblem {
+    /// A `required` entry is missing; the violation path is the directory.
+    Missing,
+    /// With `forbid_other`, an entry matches neither `required` nor `optional`;
+    /// the violation path is the entry.
+    Unexpected,
 }
 
 /// Type of structure violation.
@@ -18,6 +35,51 @@ pub enum ViolationType {
     FileCount,
     DirCount,
     MaxDepth,
+    /// Too many files anywhere beneath the directory (`max_total_files`).
+    TotalFileCount,
+    /// Too many files with one extension in the directory (`max_files_by_ext`).
+    FileCountByExtension {
+        /// The extension bucket, with leading dot (e.g., ".rs").
+        extension: String,
+    },
+    /// Too many directories anywhere beneath the directory (`max_total_dirs`).
+    TotalDirCount,
+    /// File path relative to the project root is too long (`max_path_length`).
+    PathLength,
+    /// File or directory name is too long (`max_filename_length`).
+    FilenameLength,
+    /// File path relative to the project root has too many components (`max_path_components`).
+    PathComponents,
+    /// File is larger than `max_file_bytes`; `actual` and `limit` are in bytes.
+    FileSize,
+    /// Sibling names that differ only by case (`detect_case_collisions`).
+    /// The violation path is the directory containing them.
+    CaseCollision {
+        /// The colliding paths, sorted.
+        colliding_paths: Vec<String>,
+    },
+    /// Name cannot be checked out on Windows (`detect_invalid_windows_names`).
+    InvalidWindowsName {
+        /// What makes the name invalid (e.g., "reserved name 'CON'", "trailing dot").
+        reason: String,
+    },
+    /// Directory has no entries, or only `count_exclude` ones (`deny_empty_dirs`).
+    EmptyDirectory,
+    /// Symlink rejected by `deny_symlinks`, or a loop found while following symlinks.
+    Symlink {
+        /// The link target as stored; for loops, the ancestor it leads back to.
+        target: String,
+        problem: SymlinkProblem,
+    },
+    /// Directory does not have the shape of a `[[structure.layouts]]` template.
+    Layout {
+        /// The template name.
+        layout: String,
+        /// The missing `required` entry, or the unexpected entry's name
+        /// (directories end with `/`).
+        entry: String,
+        problem: LayoutProblem,
+    },
     /// File type not allowed by allowlist (`allow_extensions`/`allow_patterns`).
     DisallowedFile,
     /// Directory not allowed by allowlist (`allow_dirs`).
@@ -32,16 +94,25 @@ pub enum ViolationType {
         /// The pattern that matched (e.g., "`**/node_modules/`").
         pattern: String,
     },
-    /// File name does not match required naming pattern (`file_naming_pattern`).
+    /// File or directory name does not match the required naming pattern or style
+    /// (`file_naming_pattern`/`file_naming`, `dir_naming_pattern`/`dir_naming`).
     NamingConvention {
-        /// The regex pattern that the filename should have matched.
+        /// The regex pattern or style name (e.g. "kebab-case") the name should have matched.
         expected_pattern: String,
+        /// The violating path is a directory rather than a file.
+        #[serde(skip_serializing_if = "std::ops::Not::not")]
+        is_directory: bool,
     },
     /// Required sibling file is missing (directed `siblings` rule).
     MissingSibling {
         /// The sibling pattern template that was expected (e.g., "{stem}.test.tsx").
         expected_sibling_pattern: String,
     },
+    /// Directory lacks a file required by `require_files`.
+    MissingRequiredFile {
+        /// The required file pattern, with `{dir}` expanded (e.g., "Cargo.toml").
+        required_pattern: String,
+    },
     /// Atomic group is incomplete (group `siblings` rule).
     /// If ANY file in the group exists, ALL must exist.
     GroupIncomplete {
@@ -50,17 +121,62 @@ pub enum ViolationType {
         /// Which patterns from the group are missing.
         missing_patterns: Vec<String>,
     },
+    /// A sibling that must not exist next to the file is present (`forbid` siblings rule).
+    ForbiddenSibling {
+        /// The forbidden sibling template (e.g., "{stem}.js").
+        forbidden_sibling_pattern: String,
+        /// File name of the sibling that exists (e.g., "index.js").
+        conflicting_file: String,
+    },
+    /// More than one of a set of mutually exclusive files exists (`exclusive` siblings rule).
+    /// The violation path is the directory.
+    ExclusiveConflict {
+        /// The patterns of which at most one may match.
+        exclusive_patterns: Vec<String>,
+        /// File names that matched, sorted.
+        conflicting_files: Vec<String>,
+    },
 }
 
 /// Category of violation for `CheckResult`.
 ///
 /// Distinguishes between content (SLOC) violations and structure violations,
 /// carrying the structured `ViolationType` for structure violations.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(tag = "category", rename_all = "snake_case")]
 pub enum ViolationCategory {
-    /// Content violation (SLOC limit exceeded).
-    Content,
+    /// Content (line count) result.
+    ///
+    /// Serializes as the bare `{"category": "content"}`; JSON output reports the
+    /// metric in a separate `metric` field.
+    Content {
+        /// Lines counted towards the limit.
+        #[serde(skip_serializing)]
+        metric: ContentMetric,
+        /// Whether `metric` was configured rather than derived from `skip_*`.
+        #[serde(skip_serializing)]
+        configured: bool,
+    },
+    /// Comment density outside the rule's `min_comment_ratio`/`max_comment_ratio` range.
+    CommentRatio {
+        bound: CommentRatioBound,
+        /// Actual `comment / (code + comment)` share.
+        ratio: f64,
+        /// The configured bound that was crossed.
+        threshold: f64,
+    },
+    /// A rule with `on_expire = "fail"` is past its `expires` date.
+    /// The result path is the config file that declares the rule.
+    ExpiredExemption {
+        /// Rule location, e.g. `content.rules[2]`.
+        rule: String,
+        /// Pattern (content) or scope (structure) of the expired rule.
+        pattern: String,
+        expires: String,
+        /// 1-based line of the rule in the config file, when it could be located.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        line: Option<usize>,
+    },
     /// Structure violation with specific type.
     Structure {
         violation_type: ViolationType,
@@ -124,32 +240,144 @@ impl StructureViolation {
         }
     }
 
-    /// Create a disallowed file violation.
-    #[must_use]
-    pub const fn disallowed_file(path: PathBuf, rule_pattern: String) -> Self {
+    /// Create a violation attributed to the rule scoped by `rule_pattern`.
+    const fn scoped(
+        path: PathBuf,
+        violation_type: ViolationType,
+        actual: usize,
+        limit: usize,
+        rule_pattern: Option<String>,
+    ) -> Self {
         Self {
             path,
-            violation_type: ViolationType::DisallowedFile,
-            actual: 1,
-            limit: 0,
+            violation_type,
+            actual,
+            limit,
             is_warning: false,
             override_reason: None,
-            triggering_rule_pattern: Some(rule_pattern),
+            triggering_rule_pattern: rule_pattern,
         }
     }
 
+    /// Create a path length, name length or path component violation.
+    #[must_use]
+    pub const fn path_limit(
+        path: PathBuf,
+        violation_type: ViolationType,
+        actual: usize,
+        limit: usize,
+        rule_pattern: Option<String>,
+    ) -> Self {
+        Self::scoped(path, violation_type, actual, limit, rule_pattern)
+    }
+
+    /// Create a file size violation; sizes are in bytes.
+    #[must_use]
+    pub const fn file_size(
+        path: PathBuf,
+        actual: usize,
+        limit: usize,
+        rule_pattern: Option<String>,
+    ) -> Self {
+        Self::scoped(path, ViolationType::FileSize, actual, limit, rule_pattern)
+    }
+
+    /// Create a case collision violation for a directory.
+    #[must_use]
+    pub const fn case_collision(path: PathBuf, colliding_paths: Vec<String>) -> Self {
+        let actual = colliding_paths.len();
+        Self::new(
+            path,
+            ViolationType::CaseCollision { colliding_paths },
+            actual,
+            1,
+            None,
+        )
+    }
+
+    /// Create an invalid Windows name violation.
+    #[must_use]
+    pub const fn invalid_windows_name(path: PathBuf, reason: String) -> Self {
+        Self::new(
+            path,
+            ViolationType::InvalidWindowsName { reason },
+            1,
+            0,
+            None,
+        )
+    }
+
+    /// Create a symlink violation (`deny_symlinks`).
+    #[must_use]
+    pub const fn symlink(path: PathBuf, target: String, problem: SymlinkProblem) -> Self {
+        Self::new(path, ViolationType::Symlink { target, problem }, 1, 0, None)
+    }
+
+    /// Create a warning for a followed symlink that leads back to `ancestor`.
+    #[must_use]
+    pub const fn symlink_loop(path: PathBuf, ancestor: String) -> Self {
+        let problem = SymlinkProblem::Loop;
+        let violation_type = ViolationType::Symlink {
+            target: ancestor,
+            problem,
+        };
+        Self::warning(path, violation_type, 1, 0, None)
+    }
+
+    /// Create an empty directory violation; `entry_count` counts `count_exclude` entries.
+    #[must_use]
+    pub const fn empty_directory(path: PathBuf, entry_count: usize) -> Self {
+        Self::new(path, ViolationType::EmptyDirectory, entry_count, 0, None)
+    }
+
+    /// Create a `[[structure.layouts]]` violation; `rule_pattern` is the template's scope.
+    #[must_use]
+    pub const fn layout(
+        path: PathBuf,
+        rule_pattern: String,
+        layout: String,
+        entry: String,
+        problem: LayoutProblem,
+    ) -> Self {
+        let (actual, limit) = match problem {
+            LayoutProblem::Missing => (0, 1),
+            LayoutProblem::Unexpected => (1, 0),
+        };
+        Self::scoped(
+            path,
+            ViolationType::Layout {
+                layout,
+                entry,
+                problem,
+            },
+            actual,
+            limit,
+            Some(rule_pattern),
+        )
+    }
+
+    /// Create a disallowed file violation.
+    #[must_use]
+    pub const fn disallowed_file(path: PathBuf, rule_pattern: String) -> Self {
+        Self::scoped(
+            path,
+            ViolationType::DisallowedFile,
+            1,
+            0,
+            Some(rule_pattern),
+        )
+    }
+
     /// Create a disallowed directory violation.
     #[must_use]
     pub const fn disallowed_directory(path: PathBuf, rule_pattern: String) -> Self {
-        Self {
+        Self::scoped(
             path,
-            violation_type: ViolationType::DisallowedDirectory,
-            actual: 1,
-            limit: 0,
-            is_warning: false,
-            override_reason: None,
-            triggering_rule_pattern: Some(rule_pattern),
-        }
+            ViolationType::DisallowedDirectory,
+            1,
+            0,
+            Some(rule_pattern),
+        )
     }
 
     /// Create a denied file violation.
@@ -159,17 +387,15 @@ impl StructureViolation {
         rule_pattern: String,
         pattern_or_extension: String,
     ) -> Self {
-        Self {
+        Self::scoped(
             path,
-            violation_type: ViolationType::DeniedFile {
+            ViolationType::DeniedFile {
                 pattern_or_extension,
             },
-            actual: 1,
-            limit: 0,
-            is_warning: false,
-            override_reason: None,
-            triggering_rule_pattern: Some(rule_pattern),
-        }
+            1,
+            0,
+            Some(rule_pattern),
+        )
     }
 
     /// Create a naming convention violation.
@@ -179,17 +405,35 @@ impl StructureViolation {
         rule_pattern: String,
         expected_naming_pattern: String,
     ) -> Self {
-        Self {
+        Self::scoped(
             path,
-            violation_type: ViolationType::NamingConvention {
+            ViolationType::NamingConvention {
                 expected_pattern: expected_naming_pattern,
+                is_directory: false,
             },
-            actual: 1,
-            limit: 0,
-            is_warning: false,
-            override_reason: None,
-            triggering_rule_pattern: Some(rule_pattern),
-        }
+            1,
+            0,
+            Some(rule_pattern),
+        )
+    }
+
+    /// Create a directory naming convention violation.
+    #[must_use]
+    pub const fn dir_naming_convention(
+        path: PathBuf,
+        rule_pattern: String,
+        expected_naming_pattern: String,
+    ) -> Self {
+        Self::scoped(
+            path,
+            ViolationType::NamingConvention {
+                expected_pattern: expected_naming_pattern,
+                is_directory: true,
+            },
+            1,
+            0,
+            Some(rule_pattern),
+        )
     }
 
     /// Create a missing sibling violation (directed rule).
@@ -199,17 +443,15 @@ impl StructureViolation {
         rule_pattern: String,
         expected_sibling_pattern: String,
     ) -> Self {
-        Self {
+        Self::scoped(
             path,
-            violation_type: ViolationType::MissingSibling {
+            ViolationType::MissingSibling {
                 expected_sibling_pattern,
             },
-            actual: 1,
-            limit: 1,
-            is_warning: false,
-            override_reason: None,
-            triggering_rule_pattern: Some(rule_pattern),
-        }
+            1,
+            1,
+            Some(rule_pattern),
+        )
     }
 
     /// Create a missing sibling violation as a warning.
@@ -219,17 +461,37 @@ impl StructureViolation {
         rule_pattern: String,
         expected_sibling_pattern: String,
     ) -> Self {
-        Self {
+        let mut violation = Self::missing_sibling(path, rule_pattern, expected_sibling_pattern);
+        violation.is_warning = true;
+        violation
+    }
+
+    /// Create a missing required file violation (`require_files`).
+    #[must_use]
+    pub const fn missing_required_file(
+        path: PathBuf,
+        rule_pattern: String,
+        required_pattern: String,
+    ) -> Self {
+        Self::scoped(
             path,
-            violation_type: ViolationType::MissingSibling {
-                expected_sibling_pattern,
-            },
-            actual: 1,
-            limit: 1,
-            is_warning: true,
-            override_reason: None,
-            triggering_rule_pattern: Some(rule_pattern),
-        }
+            ViolationType::MissingRequiredFile { required_pattern },
+            0,
+            1,
+            Some(rule_pattern),
+        )
+    }
+
+    /// Create a missing required file violation as a warning.
+    #[must_use]
+    pub const fn missing_required_file_warning(
+        path: PathBuf,
+        rule_pattern: String,
+        required_pattern: String,
+    ) -> Self {
+        let mut violation = Self::missing_required_file(path, rule_pattern, required_pattern);
+        violation.is_warning = true;
+        violation
     }
 
     /// Create a group incomplete violation (atomic group rule).
@@ -240,18 +502,16 @@ impl StructureViolation {
         group_patterns: Vec<String>,
         missing_patterns: Vec<String>,
     ) -> Self {
-        Self {
+        Self::scoped(
             path,
-            violation_type: ViolationType::GroupIncomplete {
+            ViolationType::GroupIncomplete {
                 group_patterns,
                 missing_patterns,
             },
-            actual: 1,
-            limit: 1,
-            is_warning: false,
-            override_reason: None,
-            triggering_rule_pattern: Some(rule_pattern),
-        }
+            1,
+            1,
+            Some(rule_pattern),
+        )
     }
 
     /// Create a group incomplete violation as a warning.
@@ -262,31 +522,94 @@ impl StructureViolation {
         group_patterns: Vec<String>,
         missing_patterns: Vec<String>,
     ) -> Self {
-        Self {
+        let mut violation =
+            Self::group_incomplete(path, rule_pattern, group_patterns, missing_patterns);
+        violation.is_warning = true;
+        violation
+    }
+
+    /// Create a forbidden sibling violation (`forbid` rule).
+    #[must_use]
+    pub const fn forbidden_sibling(
+        path: PathBuf,
+        rule_pattern: String,
+        forbidden_sibling_pattern: String,
+        conflicting_file: String,
+    ) -> Self {
+        Self::scoped(
             path,
-            violation_type: ViolationType::GroupIncomplete {
-                group_patterns,
-                missing_patterns,
+            ViolationType::ForbiddenSibling {
+                forbidden_sibling_pattern,
+                conflicting_file,
             },
-            actual: 1,
-            limit: 1,
-            is_warning: true,
-            override_reason: None,
-            triggering_rule_pattern: Some(rule_pattern),
-        }
+            1,
+            0,
+            Some(rule_pattern),
+        )
+    }
+
+    /// Create a forbidden sibling violation as a warning.
+    #[must_use]
+    pub const fn forbidden_sibling_warning(
+        path: PathBuf,
+        rule_pattern: String,
+        forbidden_sibling_pattern: String,
+        conflicting_file: String,
+    ) -> Self {
+        let mut violation = Self::forbidden_sibling(
+            path,
+            rule_pattern,
+            forbidden_sibling_pattern,
+            conflicting_file,
+        );
+        violation.is_warning = true;
+        violation
+    }
+
+    /// Create an exclusive conflict violation (`exclusive` rule).
+    #[must_use]
+    pub const fn exclusive_conflict(
+        path: PathBuf,
+        rule_pattern: String,
+        exclusive_patterns: Vec<String>,
+        conflicting_files: Vec<String>,
+    ) -> Self {
+        let actual = conflicting_files.len();
+        Self::scoped(
+            path,
+            ViolationType::ExclusiveConflict {
+                exclusive_patterns,
+                conflicting_files,
+            },
+            actual,
+            1,
+            Some(rule_pattern),
+        )
+    }
+
+    /// Create an exclusive conflict violation as a warning.
+    #[must_use]
+    pub const fn exclusive_conflict_warning(
+        path: PathBuf,
+        rule_pattern: String,
+        exclusive_patterns: Vec<String>,
+        conflicting_files: Vec<String>,
+    ) -> Self {
+        let mut violation =
+            Self::exclusive_conflict(path, rule_pattern, exclusive_patterns, conflicting_files);
+        violation.is_warning = true;
+        violation
     }
 
     /// Create a denied directory violation (for patterns ending with `/`).
     #[must_use]
     pub const fn denied_directory(path: PathBuf, rule_pattern: String, pattern: String) -> Self {
-        Self {
+        Self::scoped(
             path,
-            violation_type: ViolationType::DeniedDirectory { pattern },
-            actual: 1,
-            limit: 0,
-            is_warning: false,
-            override_reason: None,
-            triggering_rule_pattern: Some(rule_pattern),
-        }
+            ViolationType::DeniedDirectory { pattern },
+            1,
+            0,
+            Some(rule_pattern),
+        )
     }
 }
@@ -3,15 +3,19 @@ use std::path::Path;
 
 use globset::{Glob, GlobSet, GlobSetBuilder};
 
-use crate::config::Config;
+use crate::config::{Config, ContentMetric, ParsedDate, revert_after};
 use crate::counter::LineStats;
 use crate::output::path::normalize_for_matching;
 
 use super::Checker;
+use super::comment_ratio::CommentRatioLimits;
+use super::content_selector::{SelectorIndex, SelectorOutcome};
 use super::explain::{
     ContentExplanation, ContentRuleCandidate, ContentRuleMatch, MatchStatus, WarnAtSource,
 };
 use super::result::CheckResult;
+use super::rule_resolution::{RuleRank, explain_winner, select_winner};
+use super::structure::violation::ViolationCategory;
 
 /// Compiled rule data (pattern metadata stored separately from matcher).
 struct CompiledPathRule {
@@ -20,6 +24,11 @@ struct CompiledPathRule {
     warn_at: Option<usize>,
     skip_comments: Option<bool>,
     skip_blank: Option<bool>,
+    metric: Option<ContentMetric>,
+    comment_ratio: Option<CommentRatioLimits>,
+    priority: Option<i32>,
+    /// Set for `on_expire = "revert"` rules; the rule stops matching after this date.
+    revert_after: Option<ParsedDate>,
     reason: Option<String>,
 }
 
@@ -28,25 +37,27 @@ pub struct ThresholdChecker {
     warning_threshold: f64,
     /// Rule data indexed by rule position.
     path_rules: Vec<CompiledPathRule>,
-    /// Combined `GlobSet` for O(1) "any rule matches" check.
-    /// Indices correspond to `path_rules` positions.
-    path_rules_set: GlobSet,
+    /// Compiled rule selectors, indexed like `path_rules`.
+    selectors: SelectorIndex,
     /// Extensions to process (from content.extensions).
     /// Empty set means process all files.
     allowed_extensions: HashSet<String>,
     /// Glob patterns for files to exclude from content checks.
     /// These files skip SLOC counting but remain visible for structure checks.
     content_exclude: GlobSet,
+    /// Reference date for `on_expire = "revert"` rules.
+    as_of: ParsedDate,
 }
 
 impl ThresholdChecker {
     /// Creates a new `ThresholdChecker` from configuration.
     ///
     /// # Errors
-    /// Returns `SlocGuardError::InvalidPattern` if any glob pattern in
-    /// `content.exclude` or `content.rules[].pattern` is invalid.
+    /// Returns `SlocGuardError::InvalidPattern` if any glob pattern in `content.exclude`
+    /// or a rule's `pattern`/`patterns`/`exclude_patterns` is invalid.
     pub fn new(config: Config) -> crate::Result<Self> {
-        let (path_rules, path_rules_set) = Self::build_path_rules(&config)?;
+        let path_rules = Self::build_path_rules(&config);
+        let selectors = SelectorIndex::new(&config)?;
         let allowed_extensions: HashSet<String> =
             config.content.extensions.iter().cloned().collect();
         let content_exclude = Self::build_content_exclude(&config)?;
@@ -55,9 +66,10 @@ impl ThresholdChecker {
             config,
             warning_threshold,
             path_rules,
-            path_rules_set,
+            selectors,
             allowed_extensions,
             content_exclude,
+            as_of: ParsedDate::today(),
         })
     }
 
@@ -89,13 +101,20 @@ impl ThresholdChecker {
         self
     }
 
+    /// Evaluate `on_expire = "revert"` rules as of `as_of` instead of today.
+    #[must_use]
+    pub const fn with_as_of(mut self, as_of: ParsedDate) -> Self {
+        self.as_of = as_of;
+        self
+    }
+
     /// Check if a file should be processed based on extension or rule match.
     ///
     /// A file is processed if:
     /// - NOT in `content.exclude` patterns, AND
     /// - (`content.extensions` is empty (no filter), OR
     ///   File extension is in `content.extensions`, OR
-    ///   File matches any rule pattern)
+    ///   File is selected by any rule)
     ///
     /// This ensures extension-less files (Dockerfile, Jenkinsfile, etc.) can be
     /// checked if there's an explicit rule targeting them.
@@ -121,8 +140,8 @@ impl ThresholdChecker {
             return true;
         }
 
-        // Check if file matches any rule pattern (O(1) via GlobSet)
-        self.path_rules_set.is_match(&normalized)
+        // Check if any rule still in effect selects the file
+        !self.active_rule_matches(&normalized).is_empty()
     }
 
     /// Check if a file is excluded from content checks via `content.exclude`.
@@ -132,40 +151,62 @@ impl ThresholdChecker {
         self.content_exclude.is_match(&normalized)
     }
 
-    /// Build path rules and a combined `GlobSet` for efficient matching.
-    /// Returns `(rules_data, globset)` where `GlobSet` indices correspond to `rules_data` positions.
-    /// First invalid pattern fails immediately (fail-fast).
-    fn build_path_rules(config: &Config) -> crate::Result<(Vec<CompiledPathRule>, GlobSet)> {
-        let mut rules = Vec::new();
-        let mut builder = GlobSetBuilder::new();
-
-        // Process content.rules (V2 format)
-        for rule in &config.content.rules {
-            let glob = Glob::new(&rule.pattern).map_err(|source| {
-                crate::error::SlocGuardError::InvalidPattern {
-                    pattern: rule.pattern.clone(),
-                    source,
-                }
-            })?;
-            builder.add(glob);
-            rules.push(CompiledPathRule {
+    /// Build per-rule limits; selectors are compiled separately by [`SelectorIndex`].
+    fn build_path_rules(config: &Config) -> Vec<CompiledPathRule> {
+        config
+            .content
+            .rules
+            .iter()
+            .map(|rule| CompiledPathRule {
                 max_lines: rule.max_lines,
                 warn_threshold: rule.warn_threshold,
                 warn_at: rule.warn_at,
                 skip_comments: rule.skip_comments,
                 skip_blank: rule.skip_blank,
+                metric: rule.metric,
+                comment_ratio: CommentRatioLimits::from_rule(rule),
+                priority: rule.priority,
+                revert_after: revert_after(rule.expires.as_deref(), rule.on_expire),
                 reason: rule.reason.clone(),
-            });
-        }
+            })
+            .collect()
+    }
 
-        let globset =
-            builder
-                .build()
-                .map_err(|source| crate::error::SlocGuardError::InvalidPattern {
-                    pattern: "<combined globset>".to_string(),
-                    source,
-                })?;
-        Ok((rules, globset))
+    /// Index of the winning content rule for a pre-normalized path.
+    ///
+    /// Explicit `priority` wins first; ties follow `rule_resolution`.
+    fn winning_rule_index(&self, normalized: &Path) -> Option<usize> {
+        let matches = self.active_rule_matches(normalized);
+        select_winner(
+            matches.iter().map(|&(index, _)| index),
+            |index| rank_in(&matches, index),
+            self.config.rule_resolution,
+        )
+    }
+
+    /// Rules selecting a pre-normalized path, ranked by their most specific matching pattern.
+    fn selected_rules(&self, normalized: &Path) -> Vec<(usize, RuleRank)> {
+        self.selectors
+            .selected(normalized)
+            .into_iter()
+            .map(|(index, specificity)| {
+                let priority = self.path_rules[index].priority;
+                (index, RuleRank::with_specificity(specificity, priority))
+            })
+            .collect()
+    }
+
+    /// Selected rules, skipping rules reverted by `on_expire = "revert"`.
+    fn active_rule_matches(&self, normalized: &Path) -> Vec<(usize, RuleRank)> {
+        let mut matches = self.selected_rules(normalized);
+        matches.retain(|&(index, _)| !self.is_reverted(index));
+        matches
+    }
+
+    fn is_reverted(&self, index: usize) -> bool {
+        self.path_rules[index]
+            .revert_after
+            .is_some_and(|date| date < self.as_of)
     }
 
     /// Returns (`max_lines`, `override_reason`) for a path.
@@ -176,11 +217,9 @@ impl ThresholdChecker {
 
     /// Internal implementation accepting a path already processed by `normalize_for_matching`.
     fn get_limit_for_path_impl(&self, normalized: &Path) -> (usize, Option<String>) {
-        // Check path_rules (glob patterns) - last match wins
-        // Use GlobSet::matches() for O(n) matching where n = path length
-        let matches = self.path_rules_set.matches(normalized);
-        if let Some(&last_idx) = matches.last() {
-            let rule = &self.path_rules[last_idx];
+        // Check path_rules (glob patterns) - winning rule overrides the default
+        if let Some(index) = self.winning_rule_index(normalized) {
+            let rule = &self.path_rules[index];
             return (rule.max_lines, rule.reason.clone());
         }
 
@@ -190,10 +229,9 @@ impl ThresholdChecker {
 
     /// Returns warn threshold for a pre-normalized path.
     fn get_warn_threshold_for_path_impl(&self, normalized: &Path) -> f64 {
-        // 1. Check path_rules (last match wins) via GlobSet
-        let matches = self.path_rules_set.matches(normalized);
-        if let Some(&last_idx) = matches.last() {
-            return self.path_rules[last_idx]
+        // 1. Check path_rules (winning rule)
+        if let Some(index) = self.winning_rule_index(normalized) {
+            return self.path_rules[index]
                 .warn_threshold
                 .unwrap_or(self.warning_threshold);
         }
@@ -238,24 +276,20 @@ impl ThresholdChecker {
         normalized: &Path,
         effective_limit: usize,
     ) -> (usize, WarnAtSource) {
-        // Check path_rules (last match wins) via GlobSet
-        let matches = self.path_rules_set.matches(normalized);
-        if let Some(&last_idx) = matches.last() {
-            let rule = &self.path_rules[last_idx];
+        // Check path_rules (winning rule)
+        if let Some(index) = self.winning_rule_index(normalized) {
+            let rule = &self.path_rules[index];
 
             // 1. rule.warn_at takes highest precedence
             if let Some(warn_at) = rule.warn_at {
-                return (warn_at, WarnAtSource::RuleAbsolute { index: last_idx });
+                return (warn_at, WarnAtSource::RuleAbsolute { index });
             }
 
             // 2. rule.warn_threshold (percentage of rule's max_lines)
             if let Some(threshold) = rule.warn_threshold {
                 return (
                     (rule.max_lines as f64 * threshold).ceil() as usize,
-                    WarnAtSource::RulePercentage {
-                        index: last_idx,
-                        threshold,
-                    },
+                    WarnAtSource::RulePercentage { index, threshold },
                 );
             }
         }
@@ -275,7 +309,7 @@ impl ThresholdChecker {
     }
 
     /// Returns (`skip_comments`, `skip_blank`) settings for a path.
-    /// Priority: `path_rules` (last match) > global defaults
+    /// Priority: `path_rules` (winning rule) > global defaults
     #[must_use]
     pub fn get_skip_settings_for_path(&self, path: &Path) -> (bool, bool) {
         let normalized = normalize_for_matching(path);
@@ -284,10 +318,9 @@ impl ThresholdChecker {
 
     /// Internal implementation accepting a path already processed by `normalize_for_matching`.
     fn get_skip_settings_for_path_impl(&self, normalized: &Path) -> (bool, bool) {
-        // Check path_rules (last match wins) via GlobSet
-        let matches = self.path_rules_set.matches(normalized);
-        if let Some(&last_idx) = matches.last() {
-            let path_rule = &self.path_rules[last_idx];
+        // Check path_rules (winning rule)
+        if let Some(index) = self.winning_rule_index(normalized) {
+            let path_rule = &self.path_rules[index];
             let skip_comments = path_rule
                 .skip_comments
                 .unwrap_or(self.config.content.skip_comments);
@@ -304,6 +337,57 @@ impl ThresholdChecker {
         )
     }
 
+    /// Returns the explicitly configured line metric for a path, if any.
+    ///
+    /// Priority: rule `metric` > rule `skip_*` overrides > `content.metric`.
+    /// A rule that only sets `skip_*` opts back into boolean counting, so
+    /// `None` means callers should fall back to [`Self::get_skip_settings_for_path`].
+    #[must_use]
+    pub fn get_metric_for_path(&self, path: &Path) -> Option<ContentMetric> {
+        let normalized = normalize_for_matching(path);
+        self.get_metric_for_path_impl(&normalized)
+    }
+
+    /// Internal implementation accepting a path already processed by `normalize_for_matching`.
+    fn get_metric_for_path_impl(&self, normalized: &Path) -> Option<ContentMetric> {
+        if let Some(index) = self.winning_rule_index(normalized) {
+            let path_rule = &self.path_rules[index];
+            if path_rule.metric.is_some() {
+                return path_rule.metric;
+            }
+            if path_rule.skip_comments.is_some() || path_rule.skip_blank.is_some() {
+                return None;
+            }
+        }
+
+        self.config.content.metric
+    }
+
+    /// Returns the comment ratio limits of the winning rule for a pre-normalized path.
+    fn get_comment_ratio_for_path_impl(&self, normalized: &Path) -> Option<CommentRatioLimits> {
+        self.winning_rule_index(normalized)
+            .and_then(|index| self.path_rules[index].comment_ratio)
+    }
+
+    /// Content category for a path: its configured metric, or the metric equivalent
+    /// to its `skip_*` settings.
+    fn content_category(&self, path: &Path) -> Option<ViolationCategory> {
+        let normalized = normalize_for_matching(path);
+        if let Some(metric) = self.get_metric_for_path_impl(&normalized) {
+            return Some(ViolationCategory::Content {
+                metric,
+                configured: true,
+            });
+        }
+        let (skip_comments, skip_blank) = self.get_skip_settings_for_path_impl(&normalized);
+        ContentMetric::from_skip_settings(skip_comments, skip_blank).map(|metric| {
+            ViolationCategory::Content {
+                metric,
+                configured: false,
+            }
+        })
+    }
+
     /// Explain which rule matches a given file path.
     ///
     /// Returns a detailed breakdown of all evaluated rules and which one won.
@@ -326,35 +410,44 @@ impl ThresholdChecker {
                 warn_threshold: self.get_warn_threshold_for_path_impl(&normalized),
                 skip_comments,
                 skip_blank,
+                metric: self.get_metric_for_path_impl(&normalized),
+                comment_ratio: None,
+                rule_resolution: self.config.rule_resolution,
+                win_reason: None,
                 rule_chain: Vec::new(),
+                symlink: None,
             };
         }
 
         let mut rule_chain = Vec::new();
         let mut matched_rule = ContentRuleMatch::Default;
-        let mut found_match = false;
 
-        // Check content.rules (last match wins) via GlobSet
-        // Get all matching indices in one pass
-        let rule_matches = self.path_rules_set.matches(&normalized);
-        let matching_set: HashSet<usize> = rule_matches.iter().copied().collect();
-        let last_match_idx = rule_matches.last().copied();
+        // Pick the winner among selecting content.rules (priority, then rule_resolution)
+        let rule_matches = self.active_rule_matches(&normalized);
+        let active: Vec<usize> = rule_matches.iter().map(|&(index, _)| index).collect();
+        let winner = explain_winner(
+            &active,
+            |index| rank_in(&rule_matches, index),
+            self.config.rule_resolution,
+        );
+        let winner_idx = winner.map(|(index, _)| index);
+        let found_match = winner.is_some();
 
         // Now build the chain with correct status
         for (i, rule) in self.path_rules.iter().enumerate() {
-            let matches = matching_set.contains(&i);
-            let is_selected = !found_match && last_match_idx == Some(i);
-
+            let is_selected = winner_idx == Some(i);
+            let (selector, specificity) = self.selectors.evaluate(i, &normalized);
             let status = if is_selected {
-                found_match = true;
                 MatchStatus::Matched
-            } else if matches {
-                MatchStatus::Superseded
-            } else {
+            } else if !matches!(selector, SelectorOutcome::Selected { .. }) {
                 MatchStatus::NoMatch
+            } else if self.is_reverted(i) {
+                MatchStatus::Expired
+            } else {
+                MatchStatus::Superseded
             };
 
-            let pattern = self.config.content.rules[i].pattern.clone();
+            let pattern = self.config.content.rules[i].selector_label();
             let source = format!("content.rules[{i}]");
 
             if is_selected {
@@ -369,7 +462,9 @@ impl ThresholdChecker {
                 source,
                 pattern: Some(pattern),
                 limit: rule.max_lines,
+                rank: Some(RuleRank::with_specificity(specificity, rule.priority)),
                 status,
+                selector: Some(selector),
             });
         }
 
@@ -378,6 +473,8 @@ impl ThresholdChecker {
             source: "content.max_lines (default)".to_string(),
             pattern: None,
             limit: self.config.content.max_lines,
+            rank: None,
+            selector: None,
             status: if found_match {
                 MatchStatus::Superseded
             } else {
@@ -400,7 +497,12 @@ impl ThresholdChecker {
             warn_threshold: self.get_warn_threshold_for_path_impl(&normalized),
             skip_comments,
             skip_blank,
+            metric: self.get_metric_for_path_impl(&normalized),
+            comment_ratio: self.get_comment_ratio_for_path_impl(&normalized),
+            rule_resolution: self.config.rule_resolution,
+            win_reason: winner.map(|(_, reason)| reason),
             rule_chain,
+            symlink: None,
         }
     }
 
@@ -413,6 +515,15 @@ impl ThresholdChecker {
     }
 }
 
+/// Rank recorded for `index` in a list of selected rules.
+fn rank_in(matches: &[(usize, RuleRank)], index: usize) -> RuleRank {
+    matches
+        .iter()
+        .find(|&&(i, _)| i == index)
+        .map(|&(_, rank)| rank)
+        .unwrap_or_default()
+}
+
 impl Checker for ThresholdChecker {
     fn check(
         &self,
@@ -422,12 +533,37 @@ impl Checker for ThresholdChecker {
     ) -> CheckResult {
         let (limit, override_reason) = self.get_limit_for_path(path);
         let warn_limit = self.get_warn_limit_for_path(path, limit);
+        let violation_category = self.content_category(path);
         let sloc = line_stats.sloc();
+
+        // Size failures take precedence; otherwise a comment ratio breach fails the file.
+        // Ratios use raw counts since effective stats may have folded comments into code.
+        let ratio_breach = if sloc > limit {
+            None
+        } else {
+            self.get_comment_ratio_for_path_impl(&normalize_for_matching(path))
+                .and_then(|ratio| ratio.evaluate(raw_stats.unwrap_or(line_stats)))
+        };
+
         let path = path.to_path_buf();
         let stats = line_stats.clone();
         let raw_stats = raw_stats.cloned();
 
-        if sloc > limit {
+        if let Some((bound, ratio, threshold)) = ratio_breach {
+            CheckResult::Failed {
+                path,
+                stats,
+                raw_stats,
+                limit,
+                override_reason,
+                suggestions: None,
+                violation_category: Some(ViolationCategory::CommentRatio {
+                    bound,
+                    ratio,
+                    threshold,
+                }),
+            }
+        } else if sloc > limit {
             CheckResult::Failed {
                 path,
                 stats,
@@ -435,7 +571,7 @@ impl Checker for ThresholdChecker {
                 limit,
                 override_reason,
                 suggestions: None,
-                violation_category: None, // Content violations don't need explicit category
+                violation_category,
             }
         } else if sloc >= warn_limit {
             CheckResult::Warning {
@@ -445,7 +581,7 @@ impl Checker for ThresholdChecker {
                 limit,
                 override_reason,
                 suggestions: None,
-                violation_category: None,
+                violation_category,
             }
         } else {
             CheckResult::Passed {
@@ -454,7 +590,7 @@ impl Checker for ThresholdChecker {
                 raw_stats,
                 limit,
                 override_reason,
-                violation_category: None,
+                violation_category,
             }
         }
     }
@@ -41,13 +41,22 @@ fn check_uses_rule_specific_limit() {
     // Use V2 content.rules format instead of legacy config.rules
     config.content.rules.push(crate::config::ContentRule {
         pattern: "**/*.rs".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
         max_lines: 300,
         warn_threshold: None,
         warn_at: None,
         skip_comments: None,
         skip_blank: None,
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
         reason: None,
         expires: None,
+        on_expire: None,
     });
 
     let checker = ThresholdChecker::new(config).unwrap();
@@ -64,13 +73,22 @@ fn check_uses_rule_with_reason() {
     let mut config = default_config();
     config.content.rules.push(crate::config::ContentRule {
         pattern: "**/legacy.rs".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
         max_lines: 800,
         warn_threshold: None,
         warn_at: None,
         skip_comments: None,
         skip_blank: None,
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
         reason: Some("Legacy code".to_string()),
         expires: None,
+        on_expire: None,
     });
 
     let checker = ThresholdChecker::new(config).unwrap();
@@ -88,13 +106,22 @@ fn check_rule_without_reason() {
     let mut config = default_config();
     config.content.rules.push(crate::config::ContentRule {
         pattern: "**/special.rs".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
         max_lines: 800,
         warn_threshold: None,
         warn_at: None,
         skip_comments: None,
         skip_blank: None,
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
         reason: None,
         expires: None,
+        on_expire: None,
     });
 
     let checker = ThresholdChecker::new(config).unwrap();
@@ -0,0 +1,116 @@
+//! Tests for `min_comment_ratio` / `max_comment_ratio` on content rules.
+
+use std::path::Path;
+
+use super::*;
+use crate::checker::{CommentRatioBound, ViolationCategory};
+use crate::config::ContentRule;
+
+fn ratio_rule(pattern: &str, min: Option<f64>, max: Option<f64>) -> ContentRule {
+    ContentRule {
+        pattern: pattern.to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
+        max_lines: 500,
+        warn_threshold: None,
+        warn_at: None,
+        skip_comments: None,
+        skip_blank: None,
+        metric: None,
+        min_comment_ratio: min,
+        max_comment_ratio: max,
+        comment_ratio_min_code: None,
+        priority: None,
+        reason: None,
+        expires: None,
+        on_expire: None,
+    }
+}
+
+fn stats(code: usize, comment: usize) -> LineStats {
+    LineStats {
+        total: code + comment,
+        code,
+        comment,
+        blank: 0,
+        ignored: 0,
+    }
+}
+
+fn checker_with(rule: ContentRule) -> ThresholdChecker {
+    let mut config = default_config();
+    config.content.rules.push(rule);
+    ThresholdChecker::new(config).unwrap()
+}
+
+#[test]
+fn below_min_ratio_fails_with_comment_ratio_category() {
+    let checker = checker_with(ratio_rule("**/*.rs", Some(0.1), None));
+    let result = checker.check(Path::new("src/lib.rs"), &stats(200, 5), None);
+
+    assert!(result.is_failed());
+    match result.violation_category() {
+        Some(ViolationCategory::CommentRatio {
+            bound, threshold, ..
+        }) => {
+            assert_eq!(*bound, CommentRatioBound::Min);
+            assert!((threshold - 0.1).abs() < f64::EPSILON);
+        }
+        other => panic!("Expected CommentRatio category, got {other:?}"),
+    }
+}
+
+#[test]
+fn above_max_ratio_fails() {
+    let checker = checker_with(ratio_rule("**/*.rs", None, Some(0.5)));
+    let result = checker.check(Path::new("src/lib.rs"), &stats(100, 200), None);
+
+    assert!(result.is_failed());
+    assert!(
+        result
+            .category_message()
+            .unwrap()
+            .contains("above maximum 50.0%")
+    );
+}
+
+#[test]
+fn ratio_within_range_passes() {
+    let checker = checker_with(ratio_rule("**/*.rs", Some(0.1), Some(0.5)));
+    let result = checker.check(Path::new("src/lib.rs"), &stats(200, 50), None);
+    assert!(result.is_passed());
+}
+
+#[test]
+fn ratio_uses_raw_stats_when_comments_are_skipped() {
+    let checker = checker_with(ratio_rule("**/*.rs", Some(0.1), None));
+    // Effective stats report no comments; the ratio must come from the raw counts
+    let effective = stats(200, 0);
+    let raw = stats(200, 50);
+    let result = checker.check(Path::new("src/lib.rs"), &effective, Some(&raw));
+    assert!(result.is_passed());
+}
+
+#[test]
+fn small_files_are_exempt() {
+    let checker = checker_with(ratio_rule("**/*.rs", Some(0.1), None));
+    let result = checker.check(Path::new("src/lib.rs"), &stats(50, 0), None);
+    assert!(result.is_passed());
+}
+
+#[test]
+fn size_failure_takes_precedence_over_ratio() {
+    let checker = checker_with(ratio_rule("**/*.rs", Some(0.1), None));
+    let result = checker.check(Path::new("src/lib.rs"), &stats(600, 0), None);
+
+    assert!(result.is_failed());
+    assert!(result.category_message().is_none());
+}
+
+#[test]
+fn ratio_only_applies_to_matching_rule() {
+    let checker = checker_with(ratio_rule("src/api/**", Some(0.1), None));
+    let result = checker.check(Path::new("src/main.rs"), &stats(200, 0), None);
+    assert!(result.is_passed());
+}
@@ -30,13 +30,22 @@ fn invalid_content_rule_pattern_returns_error() {
     config.content.rules.push(ContentRule {
         // Invalid glob pattern: unclosed bracket
         pattern: "src/[invalid".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
         max_lines: 500,
         warn_threshold: None,
         warn_at: None,
         skip_comments: None,
         skip_blank: None,
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
         reason: None,
         expires: None,
+        on_expire: None,
     });
 
     let result = ThresholdChecker::new(config);
@@ -56,24 +65,42 @@ fn first_invalid_pattern_fails_immediately() {
     // First pattern is invalid
     config.content.rules.push(ContentRule {
         pattern: "[invalid1".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
         max_lines: 500,
         warn_threshold: None,
         warn_at: None,
         skip_comments: None,
         skip_blank: None,
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
         reason: None,
         expires: None,
+        on_expire: None,
     });
     // Second pattern is also invalid (should not be reached)
     config.content.rules.push(ContentRule {
         pattern: "[invalid2".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
         max_lines: 600,
         warn_threshold: None,
         warn_at: None,
         skip_comments: None,
         skip_blank: None,
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
         reason: None,
         expires: None,
+        on_expire: None,
     });
 
     let result = ThresholdChecker::new(config);
@@ -94,13 +121,22 @@ fn valid_patterns_succeed() {
     config.content.exclude = vec!["**/*.generated.ts".to_string(), "**/vendor/**".to_string()];
     config.content.rules.push(ContentRule {
         pattern: "src/**/*.rs".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
         max_lines: 500,
         warn_threshold: None,
         warn_at: None,
         skip_comments: None,
         skip_blank: None,
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
         reason: None,
         expires: None,
+        on_expire: None,
     });
 
     let result = ThresholdChecker::new(config);
@@ -0,0 +1,121 @@
+//! Tests for `on_expire = "revert"` content rules.
+
+use std::path::Path;
+
+use super::*;
+use crate::checker::explain::MatchStatus;
+use crate::config::{ContentRule, OnExpire, ParsedDate};
+
+fn exemption(pattern: &str, max_lines: usize, on_expire: Option<OnExpire>) -> ContentRule {
+    ContentRule {
+        pattern: pattern.to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
+        max_lines,
+        warn_threshold: None,
+        warn_at: None,
+        skip_comments: None,
+        skip_blank: None,
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
+        reason: Some("Legacy code".to_string()),
+        expires: Some("2025-06-01".to_string()),
+        on_expire,
+    }
+}
+
+fn checker_as_of(rules: Vec<ContentRule>, as_of: &str) -> ThresholdChecker {
+    let mut config = default_config();
+    config.content.max_lines = 300;
+    config.content.rules = rules;
+    ThresholdChecker::new(config)
+        .unwrap()
+        .with_as_of(ParsedDate::parse(as_of).unwrap())
+}
+
+#[test]
+fn revert_rule_applies_until_expiry_date() {
+    let checker = checker_as_of(
+        vec![exemption("src/legacy/**", 1500, Some(OnExpire::Revert))],
+        "2025-06-01",
+    );
+    let result = checker.check(Path::new("src/legacy/old.rs"), &stats_with_code(800), None);
+
+    assert!(result.is_passed());
+    assert_eq!(result.limit(), 1500);
+    assert_eq!(result.override_reason(), Some("Legacy code"));
+}
+
+#[test]
+fn revert_rule_falls_back_to_default_after_expiry() {
+    let checker = checker_as_of(
+        vec![exemption("src/legacy/**", 1500, Some(OnExpire::Revert))],
+        "2025-06-02",
+    );
+    let result = checker.check(Path::new("src/legacy/old.rs"), &stats_with_code(800), None);
+
+    assert!(result.is_failed());
+    assert_eq!(result.limit(), 300);
+    assert_eq!(result.override_reason(), None);
+}
+
+#[test]
+fn revert_rule_falls_back_to_next_matching_rule() {
+    let mut fallback = exemption("src/**", 500, None);
+    fallback.expires = None;
+    let checker = checker_as_of(
+        vec![
+            fallback,
+            exemption("src/legacy/**", 1500, Some(OnExpire::Revert)),
+        ],
+        "2026-01-01",
+    );
+    let result = checker.check(Path::new("src/legacy/old.rs"), &stats_with_code(100), None);
+
+    assert_eq!(result.limit(), 500);
+}
+
+#[test]
+fn warn_and_fail_rules_keep_matching_after_expiry() {
+    for on_expire in [None, Some(OnExpire::Warn), Some(OnExpire::Fail)] {
+        let checker = checker_as_of(
+            vec![exemption("src/legacy/**", 1500, on_expire)],
+            "2026-01-01",
+        );
+        let result = checker.check(Path::new("src/legacy/old.rs"), &stats_with_code(800), None);
+        assert_eq!(result.limit(), 1500, "on_expire = {on_expire:?}");
+    }
+}
+
+#[test]
+fn explain_marks_reverted_rule_as_expired() {
+    let checker = checker_as_of(
+        vec![exemption("src/legacy/**", 1500, Some(OnExpire::Revert))],
+        "2026-01-01",
+    );
+    let explanation = checker.explain(Path::new("src/legacy/old.rs"));
+
+    assert!(matches!(
+        explanation.matched_rule,
+        ContentRuleMatch::Default
+    ));
+    assert_eq!(explanation.effective_limit, 300);
+    assert_eq!(explanation.rule_chain[0].status, MatchStatus::Expired);
+    assert_eq!(explanation.rule_chain[1].status, MatchStatus::Matched);
+}
+
+#[test]
+fn reverted_rule_no_longer_selects_files_outside_extensions() {
+    let rules = vec![exemption("migrations/**", 1500, Some(OnExpire::Revert))];
+    let path = Path::new("migrations/001_init.sql");
+
+    let active = checker_as_of(rules.clone(), "2025-06-01");
+    assert!(active.should_process(path));
+
+    let reverted = checker_as_of(rules, "2026-01-01");
+    assert!(!reverted.should_process(path));
+}
@@ -47,13 +47,22 @@ fn should_process_extension_less_file_with_content_rule() {
     config.content.extensions = vec!["rs".to_string()];
     config.content.rules.push(crate::config::ContentRule {
         pattern: "**/Dockerfile".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
         max_lines: 100,
         warn_threshold: None,
         warn_at: None,
         skip_comments: None,
         skip_blank: None,
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
         reason: None,
         expires: None,
+        on_expire: None,
     });
 
     let checker = ThresholdChecker::new(config).unwrap();
@@ -71,13 +80,22 @@ fn should_process_extension_less_file_with_rule_exact_path() {
     config.content.extensions = vec!["rs".to_string()];
     config.content.rules.push(crate::config::ContentRule {
         pattern: "**/Jenkinsfile".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
         max_lines: 200,
         warn_threshold: None,
         warn_at: None,
         skip_comments: None,
         skip_blank: None,
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
         reason: Some("CI pipeline".to_string()),
         expires: None,
+        on_expire: None,
     });
 
     let checker = ThresholdChecker::new(config).unwrap();
@@ -95,13 +113,22 @@ fn should_process_extension_less_file_with_content_rule_exact_match() {
     config.content.extensions = vec!["rs".to_string()];
     config.content.rules.push(crate::config::ContentRule {
         pattern: "**/Makefile".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
         max_lines: 300,
         warn_threshold: None,
         warn_at: None,
         skip_comments: None,
         skip_blank: None,
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
         reason: Some("Build config".to_string()),
         expires: None,
+        on_expire: None,
     });
 
     let checker = ThresholdChecker::new(config).unwrap();
@@ -119,13 +146,22 @@ fn should_process_extension_less_file_with_glob_rule() {
     // Rule that matches any file in scripts/ directory
     config.content.rules.push(crate::config::ContentRule {
         pattern: "scripts/**".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
         max_lines: 100,
         warn_threshold: None,
         warn_at: None,
         skip_comments: None,
         skip_blank: None,
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
         reason: None,
         expires: None,
+        on_expire: None,
     });
 
     let checker = ThresholdChecker::new(config).unwrap();
@@ -0,0 +1,182 @@
+//! Tests for the `metric` setting and its precedence over `skip_*` booleans.
+
+use std::path::Path;
+
+use super::*;
+use crate::checker::ViolationCategory;
+use crate::config::{ContentMetric, ContentRule};
+
+fn rule(pattern: &str, metric: Option<ContentMetric>, skip_comments: Option<bool>) -> ContentRule {
+    ContentRule {
+        pattern: pattern.to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
+        max_lines: 1000,
+        warn_threshold: None,
+        warn_at: None,
+        skip_comments,
+        skip_blank: None,
+        metric,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
+        reason: None,
+        expires: None,
+        on_expire: None,
+    }
+}
+
+#[test]
+fn metric_is_none_by_default() {
+    let checker = ThresholdChecker::new(default_config()).unwrap();
+    assert_eq!(checker.get_metric_for_path(Path::new("src/lib.rs")), None);
+}
+
+#[test]
+fn global_metric_applies_without_rules() {
+    let mut config = default_config();
+    config.content.metric = Some(ContentMetric::Total);
+
+    let checker = ThresholdChecker::new(config).unwrap();
+    assert_eq!(
+        checker.get_metric_for_path(Path::new("src/lib.rs")),
+        Some(ContentMetric::Total)
+    );
+}
+
+#[test]
+fn rule_metric_overrides_global_metric() {
+    let mut config = default_config();
+    config.content.metric = Some(ContentMetric::Total);
+    config
+        .content
+        .rules
+        .push(rule("**/*.md", Some(ContentMetric::NonBlank), None));
+
+    let checker = ThresholdChecker::new(config).unwrap();
+    assert_eq!(
+        checker.get_metric_for_path(Path::new("docs/guide.md")),
+        Some(ContentMetric::NonBlank)
+    );
+    assert_eq!(
+        checker.get_metric_for_path(Path::new("src/lib.rs")),
+        Some(ContentMetric::Total)
+    );
+}
+
+#[test]
+fn rule_skip_override_opts_out_of_global_metric() {
+    let mut config = default_config();
+    config.content.metric = Some(ContentMetric::Total);
+    config
+        .content
+        .rules
+        .push(rule("src/generated/**", None, Some(false)));
+
+    let checker = ThresholdChecker::new(config).unwrap();
+    let path = Path::new("src/generated/parser.rs");
+    assert_eq!(checker.get_metric_for_path(path), None);
+    assert_eq!(checker.get_skip_settings_for_path(path), (false, true));
+}
+
+#[test]
+fn rule_without_counting_settings_inherits_global_metric() {
+    let mut config = default_config();
+    config.content.metric = Some(ContentMetric::CodeAndComments);
+    config
+        .content
+        .rules
+        .push(rule("src/generated/**", None, None));
+
+    let checker = ThresholdChecker::new(config).unwrap();
+    assert_eq!(
+        checker.get_metric_for_path(Path::new("src/generated/parser.rs")),
+        Some(ContentMetric::CodeAndComments)
+    );
+}
+
+#[test]
+fn check_result_carries_metric() {
+    let mut config = default_config();
+    config.content.max_lines = 100;
+    config.content.metric = Some(ContentMetric::Total);
+
+    let checker = ThresholdChecker::new(config).unwrap();
+    let result = checker.check(Path::new("src/lib.rs"), &stats_with_code(150), None);
+
+    assert!(result.is_failed());
+    assert_eq!(result.metric(), Some(ContentMetric::Total));
+    assert_eq!(
+        result.violation_category(),
+        Some(&ViolationCategory::Content {
+            metric: ContentMetric::Total,
+            configured: true,
+        })
+    );
+    assert_eq!(result.configured_metric(), Some(ContentMetric::Total));
+}
+
+#[test]
+fn check_result_reports_metric_equivalent_to_skip_settings() {
+    let checker = ThresholdChecker::new(default_config()).unwrap();
+    let result = checker.check(Path::new("src/lib.rs"), &stats_with_code(10), None);
+
+    assert!(result.is_passed());
+    assert_eq!(result.metric(), Some(ContentMetric::Code));
+    assert_eq!(result.configured_metric(), None);
+
+    let mut config = default_config();
+    config.content.skip_comments = false;
+    let checker = ThresholdChecker::new(config).unwrap();
+    let result = checker.check(Path::new("src/lib.rs"), &stats_with_code(10), None);
+    assert_eq!(result.metric(), Some(ContentMetric::CodeAndComments));
+}
+
+#[test]
+fn check_result_counting_blanks_has_no_metric() {
+    let mut config = default_config();
+    config.content.skip_blank = false;
+
+    let checker = ThresholdChecker::new(config).unwrap();
+    let result = checker.check(Path::new("src/lib.rs"), &stats_with_code(10), None);
+
+    assert_eq!(result.metric(), None);
+    assert!(result.violation_category().is_none());
+}
+
+#[test]
+fn explain_reports_metric() {
+    let mut config = default_config();
+    config
+        .content
+        .rules
+        .push(rule("**/*.md", Some(ContentMetric::NonBlank), None));
+
+    let checker = ThresholdChecker::new(config).unwrap();
+    let explanation = checker.explain(Path::new("docs/guide.md"));
+    assert_eq!(explanation.metric, Some(ContentMetric::NonBlank));
+}
+
+#[test]
+fn metric_deserializes_from_toml() {
+    let config: Config = toml::from_str(
+        r#"
+        [content]
+        metric = "non_blank"
+
+        [[content.rules]]
+        pattern = "**/*.md"
+        max_lines = 300
+        metric = "code_and_comments"
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(config.content.metric, Some(ContentMetric::NonBlank));
+    assert_eq!(
+        config.content.rules[0].metric,
+        Some(ContentMetric::CodeAndComments)
+    );
+}
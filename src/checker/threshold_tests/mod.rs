@@ -1,10 +1,14 @@
 //! Threshold checker tests organized by domain.
 
 mod check_result_tests;
+mod comment_ratio_tests;
 mod content_exclude_tests;
 mod error_handling_tests;
+mod expiry_tests;
 mod file_filter_tests;
+mod metric_tests;
 mod rule_matching_tests;
+mod selector_tests;
 mod skip_settings_tests;
 mod warn_threshold_tests;
 
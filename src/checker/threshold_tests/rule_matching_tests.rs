@@ -9,13 +9,22 @@ fn path_rule_matches_glob_pattern() {
     let mut config = default_config();
     config.content.rules.push(crate::config::ContentRule {
         pattern: "src/generated/**".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
         max_lines: 1000,
         warn_threshold: None,
         warn_at: None,
         skip_comments: None,
         skip_blank: None,
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
         reason: None,
         expires: None,
+        on_expire: None,
     });
 
     let checker = ThresholdChecker::new(config).unwrap();
@@ -32,13 +41,22 @@ fn path_rule_does_not_match_unrelated_path() {
     let mut config = default_config();
     config.content.rules.push(crate::config::ContentRule {
         pattern: "src/generated/**".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
         max_lines: 1000,
         warn_threshold: None,
         warn_at: None,
         skip_comments: None,
         skip_blank: None,
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
         reason: None,
         expires: None,
+        on_expire: None,
     });
 
     let checker = ThresholdChecker::new(config).unwrap();
@@ -55,24 +73,42 @@ fn later_rule_overrides_earlier_rule() {
     let mut config = default_config();
     config.content.rules.push(crate::config::ContentRule {
         pattern: "src/generated/**".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
         max_lines: 1000,
         warn_threshold: None,
         warn_at: None,
         skip_comments: None,
         skip_blank: None,
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
         reason: None,
         expires: None,
+        on_expire: None,
     });
     // More specific rule added later - last match wins
     config.content.rules.push(crate::config::ContentRule {
         pattern: "**/special.rs".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
         max_lines: 2000,
         warn_threshold: None,
         warn_at: None,
         skip_comments: None,
         skip_blank: None,
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
         reason: None,
         expires: None,
+        on_expire: None,
     });
 
     let checker = ThresholdChecker::new(config).unwrap();
@@ -91,23 +127,41 @@ fn path_rule_has_higher_priority_than_extension_rule() {
     // Since "last match wins", the path rule should override the extension rule
     config.content.rules.push(crate::config::ContentRule {
         pattern: "**/*.rs".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
         max_lines: 300,
         warn_threshold: None,
         warn_at: None,
         skip_comments: None,
         skip_blank: None,
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
         reason: None,
         expires: None,
+        on_expire: None,
     });
     config.content.rules.push(crate::config::ContentRule {
         pattern: "**/proto/**".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
         max_lines: 800,
         warn_threshold: None,
         warn_at: None,
         skip_comments: None,
         skip_blank: None,
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
         reason: None,
         expires: None,
+        on_expire: None,
     });
 
     let checker = ThresholdChecker::new(config).unwrap();
@@ -129,23 +183,41 @@ fn multiple_path_rules_last_match_wins() {
     let mut config = default_config();
     config.content.rules.push(crate::config::ContentRule {
         pattern: "src/**".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
         max_lines: 600,
         warn_threshold: None,
         warn_at: None,
         skip_comments: None,
         skip_blank: None,
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
         reason: None,
         expires: None,
+        on_expire: None,
     });
     config.content.rules.push(crate::config::ContentRule {
         pattern: "src/generated/**".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
         max_lines: 1000,
         warn_threshold: None,
         warn_at: None,
         skip_comments: None,
         skip_blank: None,
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
         reason: None,
         expires: None,
+        on_expire: None,
     });
 
     let checker = ThresholdChecker::new(config).unwrap();
@@ -156,3 +228,76 @@ fn multiple_path_rules_last_match_wins() {
     assert!(result.is_passed()); // 700 < 1000
     assert_eq!(result.limit(), 1000);
 }
+
+fn sized_rule(
+    pattern: &str,
+    max_lines: usize,
+    priority: Option<i32>,
+) -> crate::config::ContentRule {
+    crate::config::ContentRule {
+        pattern: pattern.to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
+        max_lines,
+        warn_threshold: None,
+        warn_at: None,
+        skip_comments: None,
+        skip_blank: None,
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority,
+        reason: None,
+        expires: None,
+        on_expire: None,
+    }
+}
+
+#[test]
+fn most_specific_resolution_prefers_narrower_pattern() {
+    let mut config = default_config();
+    config.rule_resolution = crate::config::RuleResolution::MostSpecific;
+    config.content.rules = vec![
+        sized_rule("src/generated/**", 2000, None),
+        sized_rule("**/*.rs", 300, None),
+    ];
+
+    let checker = ThresholdChecker::new(config).unwrap();
+    let result = checker.check(
+        Path::new("src/generated/parser.rs"),
+        &stats_with_code(10),
+        None,
+    );
+    assert_eq!(result.limit(), 2000);
+
+    let explanation = checker.explain(Path::new("src/generated/parser.rs"));
+    assert_eq!(
+        explanation.win_reason,
+        Some(crate::checker::RuleWinReason::MoreSpecific { runner_up: 1 })
+    );
+}
+
+#[test]
+fn explicit_priority_overrides_last_match() {
+    let mut config = default_config();
+    config.content.rules = vec![
+        sized_rule("src/legacy/**", 1500, Some(10)),
+        sized_rule("src/**", 400, None),
+    ];
+
+    let checker = ThresholdChecker::new(config).unwrap();
+    let result = checker.check(Path::new("src/legacy/old.rs"), &stats_with_code(10), None);
+    assert_eq!(result.limit(), 1500);
+
+    let explanation = checker.explain(Path::new("src/legacy/old.rs"));
+    assert!(matches!(
+        explanation.matched_rule,
+        ContentRuleMatch::Rule { index: 0, .. }
+    ));
+    assert_eq!(
+        explanation.win_reason,
+        Some(crate::checker::RuleWinReason::HigherPriority { runner_up: 1 })
+    );
+}
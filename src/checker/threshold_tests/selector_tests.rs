@@ -0,0 +1,206 @@
+//! Tests for `patterns`, `exclude_patterns` and `languages` content rule selectors.
+
+use std::path::Path;
+
+use super::*;
+use crate::checker::SelectorOutcome;
+use crate::checker::explain::MatchStatus;
+use crate::config::{ContentRule, RuleResolution};
+
+fn rule(pattern: &str, max_lines: usize) -> ContentRule {
+    ContentRule {
+        pattern: pattern.to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
+        max_lines,
+        warn_threshold: None,
+        warn_at: None,
+        skip_comments: None,
+        skip_blank: None,
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
+        reason: None,
+        expires: None,
+        on_expire: None,
+    }
+}
+
+fn strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(ToString::to_string).collect()
+}
+
+/// "All TypeScript under `packages/*/src` except `*.d.ts`" as a single rule.
+fn typescript_rule() -> ContentRule {
+    let mut typescript = rule("", 800);
+    typescript.patterns = strings(&["packages/*/src/**"]);
+    typescript.exclude_patterns = strings(&["**/*.d.ts"]);
+    typescript.languages = strings(&["TypeScript"]);
+    typescript
+}
+
+fn checker(rules: Vec<ContentRule>) -> ThresholdChecker {
+    let mut config = default_config();
+    config.content.max_lines = 300;
+    config.content.rules = rules;
+    ThresholdChecker::new(config).unwrap()
+}
+
+#[test]
+fn combined_selectors_apply_single_rule() {
+    let checker = checker(vec![typescript_rule()]);
+
+    let selected = checker.check(
+        Path::new("packages/web/src/app.ts"),
+        &stats_with_code(500),
+        None,
+    );
+    assert_eq!(selected.limit(), 800);
+
+    for path in [
+        "packages/web/src/types.d.ts",
+        "packages/web/src/build.js",
+        "packages/web/test/app.ts",
+    ] {
+        let result = checker.check(Path::new(path), &stats_with_code(500), None);
+        assert_eq!(result.limit(), 300, "{path}");
+    }
+}
+
+#[test]
+fn language_only_rule_selects_by_extension() {
+    let mut rust = rule("", 900);
+    rust.languages = strings(&["rust"]);
+    let checker = checker(vec![rust]);
+
+    assert_eq!(
+        checker
+            .check(Path::new("crates/a/src/lib.rs"), &stats_with_code(10), None)
+            .limit(),
+        900
+    );
+    assert_eq!(
+        checker
+            .check(Path::new("scripts/run.py"), &stats_with_code(10), None)
+            .limit(),
+        300
+    );
+}
+
+#[test]
+fn language_rule_uses_custom_languages() {
+    let mut config = default_config();
+    config.languages.insert(
+        "Jsonnet".to_string(),
+        crate::config::CustomLanguageConfig {
+            extensions: strings(&["jsonnet"]),
+            single_line_comments: strings(&["//"]),
+            multi_line_comments: vec![],
+        },
+    );
+    let mut jsonnet = rule("", 2000);
+    jsonnet.languages = strings(&["Jsonnet"]);
+    config.content.rules = vec![jsonnet];
+    let checker = ThresholdChecker::new(config).unwrap();
+
+    assert_eq!(
+        checker
+            .check(Path::new("deploy/main.jsonnet"), &stats_with_code(10), None)
+            .limit(),
+        2000
+    );
+}
+
+#[test]
+fn excluded_file_falls_through_to_next_rule() {
+    let mut generated = rule("**/*.d.ts", 5000);
+    generated.priority = Some(-1);
+    let checker = checker(vec![generated, typescript_rule()]);
+
+    let result = checker.check(
+        Path::new("packages/web/src/types.d.ts"),
+        &stats_with_code(10),
+        None,
+    );
+    assert_eq!(result.limit(), 5000);
+}
+
+#[test]
+fn most_specific_uses_matching_pattern() {
+    let mut broad = rule("", 400);
+    broad.patterns = strings(&["**/*.rs", "src/api/handlers/**"]);
+    let api = rule("src/api/**", 600);
+    let mut config = default_config();
+    config.rule_resolution = RuleResolution::MostSpecific;
+    config.content.rules = vec![broad, api];
+    let checker = ThresholdChecker::new(config).unwrap();
+
+    // `src/api/handlers/**` is the more specific match here...
+    let handlers = checker.check(
+        Path::new("src/api/handlers/user.rs"),
+        &stats_with_code(10),
+        None,
+    );
+    assert_eq!(handlers.limit(), 400);
+    // ...while only `**/*.rs` selects this file, so `src/api/**` wins.
+    let routes = checker.check(Path::new("src/api/routes.rs"), &stats_with_code(10), None);
+    assert_eq!(routes.limit(), 600);
+}
+
+#[test]
+fn should_process_uses_full_selector() {
+    let mut config = default_config();
+    config.content.extensions = strings(&["rs"]);
+    let mut dockerfiles = rule("", 100);
+    dockerfiles.patterns = strings(&["**/Dockerfile", "**/Dockerfile.*"]);
+    dockerfiles.exclude_patterns = strings(&["vendor/**"]);
+    config.content.rules = vec![dockerfiles];
+    let checker = ThresholdChecker::new(config).unwrap();
+
+    assert!(checker.should_process(Path::new("services/api/Dockerfile")));
+    assert!(!checker.should_process(Path::new("vendor/lib/Dockerfile")));
+}
+
+#[test]
+fn explain_reports_deciding_selector() {
+    let checker = checker(vec![typescript_rule(), rule("**/*.ts", 500)]);
+
+    let declaration = checker.explain(Path::new("packages/web/src/types.d.ts"));
+    assert_eq!(declaration.rule_chain[0].status, MatchStatus::NoMatch);
+    assert_eq!(
+        declaration.rule_chain[0].selector,
+        Some(SelectorOutcome::ExcludedBy {
+            pattern: "**/*.d.ts".to_string()
+        })
+    );
+    assert_eq!(declaration.rule_chain[1].status, MatchStatus::Matched);
+
+    let source = checker.explain(Path::new("packages/web/src/app.ts"));
+    assert!(matches!(
+        source.matched_rule,
+        ContentRuleMatch::Rule { index: 1, .. }
+    ));
+    assert_eq!(source.rule_chain[0].status, MatchStatus::Superseded);
+    assert_eq!(
+        source.rule_chain[0].selector,
+        Some(SelectorOutcome::Selected {
+            pattern: Some("packages/*/src/**".to_string()),
+            language: Some("TypeScript".to_string()),
+        })
+    );
+    assert_eq!(
+        source.rule_chain[0].pattern.as_deref(),
+        Some("packages/*/src/** [languages: TypeScript] [exclude: **/*.d.ts]")
+    );
+
+    let script = checker.explain(Path::new("packages/web/src/app.js"));
+    assert_eq!(
+        script.rule_chain[0].selector,
+        Some(SelectorOutcome::LanguageMismatch {
+            language: Some("JavaScript".to_string())
+        })
+    );
+}
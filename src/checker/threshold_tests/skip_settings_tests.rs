@@ -11,13 +11,22 @@ fn get_skip_settings_from_path_rule() {
     config.content.skip_blank = true;
     config.content.rules.push(crate::config::ContentRule {
         pattern: "src/generated/**".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
         max_lines: 1000,
         warn_threshold: None,
         warn_at: None,
         skip_comments: Some(false),
         skip_blank: Some(false),
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
         reason: None,
         expires: None,
+        on_expire: None,
     });
 
     let checker = ThresholdChecker::new(config).unwrap();
@@ -36,13 +45,22 @@ fn get_skip_settings_falls_back_to_global() {
     config.content.skip_blank = false;
     config.content.rules.push(crate::config::ContentRule {
         pattern: "src/generated/**".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
         max_lines: 1000,
         warn_threshold: None,
         warn_at: None,
         skip_comments: None, // Not specified
         skip_blank: None,    // Not specified
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
         reason: None,
         expires: None,
+        on_expire: None,
     });
 
     let checker = ThresholdChecker::new(config).unwrap();
@@ -75,23 +93,41 @@ fn get_skip_settings_last_match_wins() {
     config.content.skip_blank = true;
     config.content.rules.push(crate::config::ContentRule {
         pattern: "src/**".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
         max_lines: 500,
         warn_threshold: None,
         warn_at: None,
         skip_comments: Some(false),
         skip_blank: Some(false),
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
         reason: None,
         expires: None,
+        on_expire: None,
     });
     config.content.rules.push(crate::config::ContentRule {
         pattern: "src/generated/**".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
         max_lines: 1000,
         warn_threshold: None,
         warn_at: None,
         skip_comments: Some(true),
         skip_blank: Some(false),
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
         reason: None,
         expires: None,
+        on_expire: None,
     });
 
     let checker = ThresholdChecker::new(config).unwrap();
@@ -119,13 +155,22 @@ fn language_rule_expanded_skip_settings_work() {
     // Simulate expanded language rule (what loader does)
     config.content.rules.push(crate::config::ContentRule {
         pattern: "**/*.rs".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
         max_lines: 300,
         warn_threshold: None,
         warn_at: None,
         skip_comments: Some(false),
         skip_blank: Some(true),
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
         reason: None,
         expires: None,
+        on_expire: None,
     });
 
     let checker = ThresholdChecker::new(config).unwrap();
@@ -9,13 +9,22 @@ fn path_rule_warn_threshold_overrides_default() {
     let mut config = default_config();
     config.content.rules.push(crate::config::ContentRule {
         pattern: "src/generated/**".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
         max_lines: 1000,
         warn_threshold: Some(1.0), // Disable warnings
         warn_at: None,
         skip_comments: None,
         skip_blank: None,
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
         reason: None,
         expires: None,
+        on_expire: None,
     });
 
     let checker = ThresholdChecker::new(config).unwrap();
@@ -31,13 +40,22 @@ fn path_rule_without_warn_threshold_uses_default() {
     let mut config = default_config();
     config.content.rules.push(crate::config::ContentRule {
         pattern: "src/generated/**".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
         max_lines: 1000,
         warn_threshold: None,
         warn_at: None,
         skip_comments: None,
         skip_blank: None,
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
         reason: None,
         expires: None,
+        on_expire: None,
     });
 
     let checker = ThresholdChecker::new(config)
@@ -56,13 +74,22 @@ fn rule_warn_threshold_overrides_default() {
     // Use V2 content.rules format
     config.content.rules.push(crate::config::ContentRule {
         pattern: "**/*.rs".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
         max_lines: 500,
         warn_threshold: Some(0.8),
         warn_at: None,
         skip_comments: None,
         skip_blank: None,
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
         reason: None,
         expires: None,
+        on_expire: None,
     });
 
     let checker = ThresholdChecker::new(config)
@@ -81,13 +108,22 @@ fn rule_without_warn_threshold_uses_default() {
     // Use V2 content.rules format
     config.content.rules.push(crate::config::ContentRule {
         pattern: "**/*.rs".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
         max_lines: 500,
         warn_threshold: None,
         warn_at: None,
         skip_comments: None,
         skip_blank: None,
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
         reason: None,
         expires: None,
+        on_expire: None,
     });
 
     let checker = ThresholdChecker::new(config)
@@ -106,23 +142,41 @@ fn path_rule_warn_threshold_overrides_extension_rule() {
     // Use V2 content.rules format: extension rule first, then specific path rule
     config.content.rules.push(crate::config::ContentRule {
         pattern: "**/*.rs".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
         max_lines: 500,
         warn_threshold: Some(0.8),
         warn_at: None,
         skip_comments: None,
         skip_blank: None,
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
         reason: None,
         expires: None,
+        on_expire: None,
     });
     config.content.rules.push(crate::config::ContentRule {
         pattern: "src/generated/**".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
         max_lines: 500,
         warn_threshold: Some(1.0), // Disable warnings
         warn_at: None,
         skip_comments: None,
         skip_blank: None,
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
         reason: None,
         expires: None,
+        on_expire: None,
     });
 
     let checker = ThresholdChecker::new(config).unwrap();
@@ -145,25 +199,43 @@ fn multiple_rules_winner_takes_all_warn_threshold() {
     // Rule 1: Warn strict (0.5)
     config.content.rules.push(crate::config::ContentRule {
         pattern: "**/*.rs".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
         max_lines: 1000,
         warn_threshold: Some(0.5),
         warn_at: None,
         skip_comments: None,
         skip_blank: None,
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
         reason: None,
         expires: None,
+        on_expire: None,
     });
 
     // Rule 2: Override for specific file, default warn threshold (None)
     config.content.rules.push(crate::config::ContentRule {
         pattern: "src/main.rs".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
         max_lines: 1000,
         warn_threshold: None,
         warn_at: None,
         skip_comments: None,
         skip_blank: None,
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
         reason: None,
         expires: None,
+        on_expire: None,
     });
 
     let checker = ThresholdChecker::new(config).unwrap();
@@ -187,13 +259,22 @@ fn rule_warn_at_takes_precedence_over_percentage_threshold() {
     config.content.warn_threshold = 0.9; // Would warn at 450 for 500 lines
     config.content.rules.push(crate::config::ContentRule {
         pattern: "**/*.rs".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
         max_lines: 500,
         warn_threshold: Some(0.8), // Would warn at 400
         warn_at: Some(350),        // Absolute: warn at 350 (takes precedence)
         skip_comments: None,
         skip_blank: None,
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
         reason: None,
         expires: None,
+        on_expire: None,
     });
 
     let checker = ThresholdChecker::new(config).unwrap();
@@ -245,13 +326,22 @@ fn rule_warn_at_overrides_global_warn_at() {
 
     config.content.rules.push(crate::config::ContentRule {
         pattern: "src/**".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
         max_lines: 500,
         warn_threshold: None,
         warn_at: Some(350), // Rule absolute (overrides global)
         skip_comments: None,
         skip_blank: None,
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
         reason: None,
         expires: None,
+        on_expire: None,
     });
 
     let checker = ThresholdChecker::new(config).unwrap();
@@ -291,13 +381,22 @@ fn rule_warn_threshold_used_when_no_warn_at() {
 
     config.content.rules.push(crate::config::ContentRule {
         pattern: "src/**".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
         max_lines: 1000,
         warn_threshold: Some(0.8), // Should warn at 800 (1000 * 0.8)
         warn_at: None,             // No rule absolute → use rule percentage
         skip_comments: None,
         skip_blank: None,
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
         reason: None,
         expires: None,
+        on_expire: None,
     });
 
     let checker = ThresholdChecker::new(config).unwrap();
@@ -318,13 +417,22 @@ fn warn_at_explain_shows_effective_warn_at() {
 
     config.content.rules.push(crate::config::ContentRule {
         pattern: "src/**".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
         max_lines: 1000,
         warn_threshold: None,
         warn_at: Some(750),
         skip_comments: None,
         skip_blank: None,
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
         reason: None,
         expires: None,
+        on_expire: None,
     });
 
     let checker = ThresholdChecker::new(config).unwrap();
@@ -77,6 +77,17 @@ pub enum RatchetMode {
     Strict,
 }
 
+/// Which directories `check --diff`/`--staged` evaluates structure rules for
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum StructureScope {
+    /// Every scanned directory (default without --diff/--staged)
+    #[default]
+    All,
+    /// Only directories whose entries the diff adds, removes or renames,
+    /// plus their ancestors for depth and recursive limits
+    Changed,
+}
+
 /// Remote config fetch policy for `--extends-policy`
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
 pub enum ExtendsPolicy {
@@ -184,11 +195,11 @@ pub struct CheckArgs {
     #[arg(long, short = 'I')]
     pub include: Vec<String>,
 
-    /// Count comment lines as code (disables `skip_comments`)
+    /// Count comment lines as code (disables `skip_comments`, replaces `[content] metric`)
     #[arg(long)]
     pub count_comments: bool,
 
-    /// Count blank lines as code (disables `skip_blank`)
+    /// Count blank lines as code (disables `skip_blank`, replaces `[content] metric`)
     #[arg(long)]
     pub count_blank: bool,
 
@@ -208,19 +219,49 @@ pub struct CheckArgs {
     #[arg(long)]
     pub warn_only: bool,
 
+    /// Evaluate rule `expires` dates as of this day (YYYY-MM-DD) instead of today.
+    /// Previews which exemptions lapse: `on_expire = "revert"` rules stop matching
+    /// and `on_expire = "fail"` rules report expired exemptions.
+    #[arg(long, value_name = "DATE")]
+    pub as_of: Option<String>,
+
     /// Compare files changed between git references.
-    /// Supports single ref (compared to HEAD) or explicit range (base..target).
-    /// Examples: --diff main, --diff origin/main..HEAD, --diff v1.0..v2.0
+    /// Supports single ref (compared to HEAD), explicit range (base..target), or
+    /// merge-base range (base...target, compared from where target branched off base).
+    /// Examples: --diff main, --diff origin/main..HEAD, --diff main...HEAD
     /// Defaults to HEAD when no value provided. Note: compares committed trees only,
     /// not the working directory. Use --staged for uncommitted staged files.
-    /// Structure checks use full directory state.
+    /// Structure checks are limited by --structure-scope.
     #[arg(long, num_args = 0..=1, default_missing_value = "HEAD")]
     pub diff: Option<String>,
 
     /// Check only staged files (git staging area). Mutually exclusive with --diff.
+    /// Content is read from the index, and structure checks see the index tree.
     #[arg(long, conflicts_with = "diff")]
     pub staged: bool,
 
+    /// Check files that differ between HEAD and the working directory: staged,
+    /// modified but unstaged, and untracked files that are not ignored.
+    #[arg(long, visible_alias = "worktree", conflicts_with_all = ["diff", "staged", "rev"])]
+    pub changed: bool,
+
+    /// Check a git revision (commit, tag or tree) without checking it out.
+    /// Files and content are read from the repository, so this also works in bare
+    /// repositories. `.gitignore` does not apply: the tree only holds tracked files.
+    #[arg(
+        long,
+        value_name = "REF",
+        conflicts_with_all = ["diff", "staged", "files", "suggest", "update_baseline"]
+    )]
+    pub rev: Option<String>,
+
+    /// Which directories structure checks report on with --diff/--staged/--changed.
+    /// `all` (default) evaluates the full tree.
+    /// `changed` keeps changed files and directories whose entries were added,
+    /// removed or renamed, and their ancestors for depth and recursive limits.
+    #[arg(long, value_enum, value_name = "SCOPE")]
+    pub structure_scope: Option<StructureScope>,
+
     /// Treat warnings as failures (exit code 1).
     /// Equivalent to `[check] warnings_as_errors = true` in config.
     #[arg(long)]
@@ -259,7 +300,8 @@ pub struct CheckArgs {
     #[arg(long)]
     pub no_gitignore: bool,
 
-    /// Show split suggestions for files exceeding thresholds
+    /// Show split suggestions for files exceeding thresholds and subdirectory groupings for
+    /// directories with too many files
     #[arg(long)]
     pub suggest: bool,
 
@@ -378,6 +420,7 @@ pub struct CommonStatsArgs {
 
 /// Arguments for the `snapshot` command
 #[derive(Parser, Debug)]
+#[allow(clippy::struct_excessive_bools)] // CLI arguments often require many boolean flags
 pub struct SnapshotArgs {
     #[command(flatten)]
     pub common: CommonStatsArgs,
@@ -393,6 +436,23 @@ pub struct SnapshotArgs {
     /// Dry-run: show what would be recorded without saving
     #[arg(long)]
     pub dry_run: bool,
+
+    /// Record snapshots of past commits on the current branch instead of the working tree.
+    /// Commits already in the history are skipped.
+    #[arg(long)]
+    pub backfill: bool,
+
+    /// With --backfill: only commits newer than this (e.g., 90d, 26w, 1y)
+    #[arg(long, value_name = "DURATION", requires = "backfill")]
+    pub since: Option<String>,
+
+    /// With --backfill: minimum time between recorded commits (e.g., 1d, 1w)
+    #[arg(long, value_name = "DURATION", requires = "backfill")]
+    pub every: Option<String>,
+
+    /// With --backfill: follow only the first parent of merge commits
+    #[arg(long, requires = "backfill")]
+    pub first_parent: bool,
 }
 
 #[derive(Parser, Debug)]
@@ -410,6 +470,9 @@ pub enum StatsAction {
     /// File list with sorting and filtering options
     Files(FilesArgs),
 
+    /// Largest files by size on disk, including non-code files
+    Sizes(SizesArgs),
+
     /// Grouped statistics by language or directory
     Breakdown(BreakdownArgs),
 
@@ -451,6 +514,20 @@ pub struct FilesArgs {
     pub format: StatsOutputFormat,
 }
 
+#[derive(Parser, Debug)]
+pub struct SizesArgs {
+    #[command(flatten)]
+    pub common: CommonStatsArgs,
+
+    /// Number of largest files to show
+    #[arg(long, default_value = "20")]
+    pub top: usize,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value = "text")]
+    pub format: StatsOutputFormat,
+}
+
 #[derive(Parser, Debug)]
 pub struct BreakdownArgs {
     #[command(flatten)]
@@ -476,7 +553,7 @@ pub struct TrendArgs {
 
     /// Compare against a specific time ago (e.g., 7d, 30d, 1w, 12h).
     /// Finds the nearest entry before the specified time point.
-    /// Supported units: s, m, h, d, w.
+    /// Supported units: s, m, h, d, w, y.
     #[arg(long, value_name = "DURATION")]
     pub since: Option<String>,
 
@@ -121,6 +121,27 @@ fn cli_check_with_staged() {
     }
 }
 
+#[test]
+fn cli_check_structure_scope() {
+    let cli = Cli::parse_from(["sloc-guard", "check", "--diff", "main"]);
+    match cli.command {
+        Commands::Check(args) => assert!(args.structure_scope.is_none()),
+        _ => panic!("Expected Check command"),
+    }
+
+    let cli = Cli::parse_from([
+        "sloc-guard",
+        "check",
+        "--staged",
+        "--structure-scope",
+        "all",
+    ]);
+    match cli.command {
+        Commands::Check(args) => assert_eq!(args.structure_scope, Some(StructureScope::All)),
+        _ => panic!("Expected Check command"),
+    }
+}
+
 // ============================================================================
 // Stats Subcommand Tests
 // ============================================================================
@@ -262,6 +283,33 @@ fn cli_stats_files_format_markdown() {
     }
 }
 
+#[test]
+fn cli_stats_sizes_command() {
+    let cli = Cli::parse_from(["sloc-guard", "stats", "sizes", "--top", "5", "-f", "json"]);
+    match cli.command {
+        Commands::Stats(args) => match args.action {
+            StatsAction::Sizes(sizes_args) => {
+                assert_eq!(sizes_args.top, 5);
+                assert_eq!(sizes_args.format, StatsOutputFormat::Json);
+            }
+            _ => panic!("Expected Sizes action"),
+        },
+        _ => panic!("Expected Stats command"),
+    }
+}
+
+#[test]
+fn cli_stats_sizes_defaults_to_top_20() {
+    let cli = Cli::parse_from(["sloc-guard", "stats", "sizes"]);
+    match cli.command {
+        Commands::Stats(args) => match args.action {
+            StatsAction::Sizes(sizes_args) => assert_eq!(sizes_args.top, 20),
+            _ => panic!("Expected Sizes action"),
+        },
+        _ => panic!("Expected Stats command"),
+    }
+}
+
 #[test]
 fn cli_stats_breakdown_command() {
     let cli = Cli::parse_from(["sloc-guard", "stats", "breakdown", "--by", "dir"]);
@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use crate::cli::CheckArgs;
+use crate::cli::{CheckArgs, StructureScope};
 
 /// Validate structure params require explicit path and return resolved paths.
 ///
@@ -23,6 +23,21 @@ pub fn validate_and_resolve_paths(args: &CheckArgs) -> crate::Result<Vec<PathBuf
     }
 }
 
+/// Resolve `--structure-scope`, defaulting to `all`.
+///
+/// # Errors
+/// Returns an error if `changed` is requested without `--diff` or `--staged`.
+pub fn resolve_structure_scope(args: &CheckArgs) -> crate::Result<StructureScope> {
+    let has_diff = args.diff.is_some() || args.staged || args.changed;
+    match args.structure_scope {
+        Some(StructureScope::Changed) if !has_diff => Err(crate::SlocGuardError::Config(
+            "--structure-scope changed requires --diff, --staged or --changed".to_string(),
+        )),
+        Some(scope) => Ok(scope),
+        None => Ok(StructureScope::All),
+    }
+}
+
 /// Apply CLI argument overrides to configuration.
 ///
 /// CLI flags take precedence over config file values.
@@ -31,6 +46,11 @@ pub const fn apply_cli_overrides(config: &mut crate::config::Config, args: &Chec
         config.content.max_lines = max_lines;
     }
 
+    // Counting flags are expressed as skip booleans, so they replace any global metric
+    if args.count_comments || args.count_blank {
+        config.content.metric = None;
+    }
+
     if args.count_comments {
         config.content.skip_comments = false;
     }
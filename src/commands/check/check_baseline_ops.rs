@@ -55,7 +55,7 @@ pub fn load_baseline_optional(baseline_path: Option<&Path>) -> crate::Result<Opt
 
 pub fn apply_baseline_comparison(results: &mut [CheckResult], baseline: &Baseline) {
     for result in results.iter_mut() {
-        if !result.is_failed() {
+        if !result.is_failed() || is_expired_exemption_result(result) {
             continue;
         }
 
@@ -96,7 +96,8 @@ pub fn update_baseline_from_results(
     };
 
     for result in results {
-        if !result.is_failed() {
+        // Expired exemptions are config problems; grandfathering them would defeat `on_expire`
+        if !result.is_failed() || is_expired_exemption_result(result) {
             continue;
         }
 
@@ -123,6 +124,11 @@ pub fn update_baseline_from_results(
             if let Some((vtype, count)) = parse_structure_violation_from_result(result) {
                 new_baseline.set_structure(&path_str, vtype, count);
             }
+        } else if let Some(ViolationCategory::CommentRatio { bound, .. }) =
+            result.violation_category()
+        {
+            let raw = result.raw_stats();
+            new_baseline.set_comment_ratio(&path_str, *bound, raw.comment, raw.code);
         } else {
             // Content violation - compute file hash
             let hash = compute_file_hash(result.path()).unwrap_or_default();
@@ -141,6 +147,14 @@ pub fn is_structure_violation_result(result: &CheckResult) -> bool {
     )
 }
 
+/// Check if a check result is an `on_expire = "fail"` expired exemption.
+pub fn is_expired_exemption_result(result: &CheckResult) -> bool {
+    matches!(
+        result.violation_category(),
+        Some(ViolationCategory::ExpiredExemption { .. })
+    )
+}
+
 /// Check if a check result represents a structure violation (legacy string-based check).
 /// Used for backwards compatibility during migration and in tests.
 #[cfg_attr(not(test), allow(dead_code))]
@@ -158,6 +172,8 @@ pub fn parse_structure_violation_from_result(
             let vtype = match violation_type {
                 ViolationType::FileCount => StructureViolationType::Files,
                 ViolationType::DirCount => StructureViolationType::Dirs,
+                ViolationType::TotalFileCount => StructureViolationType::TotalFiles,
+                ViolationType::TotalDirCount => StructureViolationType::TotalDirs,
                 // Other structure violations don't have baseline support yet
                 _ => return None,
             };
@@ -182,7 +198,11 @@ pub fn parse_structure_violation(
         return None;
     }
 
-    let vtype = if reason.contains("files") {
+    let vtype = if reason.contains("total files") {
+        StructureViolationType::TotalFiles
+    } else if reason.contains("total subdirs") {
+        StructureViolationType::TotalDirs
+    } else if reason.contains("files") {
         StructureViolationType::Files
     } else if reason.contains("subdirs") {
         StructureViolationType::Dirs
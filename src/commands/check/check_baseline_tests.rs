@@ -79,8 +79,12 @@ impl CheckArgsBuilder {
             format: OutputFormat::Text,
             output: None,
             warn_only: false,
+            as_of: None,
             diff: None,
             staged: false,
+            changed: false,
+            rev: None,
+            structure_scope: None,
             warnings_as_errors: false,
             fail_fast: false,
             strict: false,
@@ -389,6 +393,18 @@ fn parse_structure_violation_parses_subdirs_correctly() {
     assert_eq!(count, 10);
 }
 
+#[test]
+fn parse_structure_violation_parses_totals_correctly() {
+    use crate::baseline::StructureViolationType;
+
+    let files =
+        super::parse_structure_violation(Some("structure: total files count exceeded"), 120);
+    assert_eq!(files, Some((StructureViolationType::TotalFiles, 120)));
+    let dirs =
+        super::parse_structure_violation(Some("structure: total subdirs count exceeded"), 30);
+    assert_eq!(dirs, Some((StructureViolationType::TotalDirs, 30)));
+}
+
 #[test]
 fn parse_structure_violation_returns_none_for_non_structure() {
     assert!(super::parse_structure_violation(None, 10).is_none());
@@ -577,8 +593,82 @@ fn update_baseline_mode_new_preserves_existing_entries() {
             assert_eq!(*lines, 200);
             assert_eq!(hash, "oldhash");
         }
-        BaselineEntry::Structure { .. } => panic!("Expected Content entry"),
+        _ => panic!("Expected Content entry"),
+    }
+}
+
+#[test]
+fn update_baseline_records_comment_ratio_violation() {
+    use crate::baseline::BaselineEntry;
+    use crate::checker::CommentRatioBound;
+    use crate::cli::BaselineUpdateMode;
+
+    let temp_dir = TempDir::new().unwrap();
+    let test_file_path = temp_dir.path().join("undocumented.rs");
+    let content = "fn main() {\n".to_string() + &"let x = 1;\n".repeat(120) + "}\n";
+    std::fs::write(&test_file_path, &content).unwrap();
+
+    let config_path = temp_dir.path().join(".sloc-guard.toml");
+    let config_content = "version = \"2\"\n\n[content]\nmax_lines = 500\nextensions = [\"rs\"]\n\n\
+                          [[content.rules]]\npattern = \"**/*.rs\"\nmax_lines = 500\nmin_comment_ratio = 0.1\n";
+    std::fs::write(&config_path, config_content).unwrap();
+    let baseline_path = temp_dir.path().join(".sloc-guard-baseline.json");
+
+    let args = make_check_args_with_baseline(
+        vec![temp_dir.path().to_path_buf()],
+        Some(config_path.clone()),
+        Some(baseline_path.clone()),
+        Some(BaselineUpdateMode::Content),
+    );
+    let cli = make_cli_for_check(ColorChoice::Never, 0, true, false);
+    let exit_code = run_check_impl(&args, &cli).expect("Check should succeed");
+    assert_eq!(exit_code, EXIT_THRESHOLD_EXCEEDED);
+
+    let baseline = Baseline::load(&baseline_path).unwrap();
+    let entry = baseline.files().values().next().expect("one entry");
+    assert_eq!(
+        entry,
+        &BaselineEntry::comment_ratio(CommentRatioBound::Min, 0, 122)
+    );
+
+    // The recorded violation is grandfathered on the next run
+    let args = make_check_args_with_baseline(
+        vec![temp_dir.path().to_path_buf()],
+        Some(config_path),
+        Some(baseline_path),
+        None,
+    );
+    let exit_code = run_check_impl(&args, &cli).expect("Check should succeed");
+    assert_eq!(exit_code, EXIT_SUCCESS);
+}
+
+#[test]
+fn update_baseline_never_grandfathers_expired_exemption() {
+    use crate::cli::BaselineUpdateMode;
+
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(temp_dir.path().join("main.rs"), "fn main() {}\n").unwrap();
+
+    let config_path = temp_dir.path().join(".sloc-guard.toml");
+    let config_content = "version = \"2\"\n\n[content]\nextensions = [\"rs\"]\n\n\
+                          [[content.rules]]\npattern = \"src/legacy/**\"\nmax_lines = 2000\n\
+                          expires = \"2020-01-01\"\non_expire = \"fail\"\n";
+    std::fs::write(&config_path, config_content).unwrap();
+    let baseline_path = temp_dir.path().join(".sloc-guard-baseline.json");
+
+    let cli = make_cli_for_check(ColorChoice::Never, 0, true, false);
+    for update_baseline in [Some(BaselineUpdateMode::All), None] {
+        let args = make_check_args_with_baseline(
+            vec![temp_dir.path().to_path_buf()],
+            Some(config_path.clone()),
+            Some(baseline_path.clone()),
+            update_baseline,
+        );
+        let exit_code = run_check_impl(&args, &cli).expect("Check should succeed");
+        assert_eq!(exit_code, EXIT_THRESHOLD_EXCEEDED);
     }
+
+    assert!(Baseline::load(&baseline_path).unwrap().files().is_empty());
 }
 
 // =============================================================================
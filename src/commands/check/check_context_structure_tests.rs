@@ -7,7 +7,7 @@ use tempfile::TempDir;
 use crate::EXIT_THRESHOLD_EXCEEDED;
 use crate::cache::Cache;
 use crate::checker::ThresholdChecker;
-use crate::cli::{CheckArgs, Cli, ColorChoice, Commands, ExtendsPolicy, InitArgs};
+use crate::cli::{CheckArgs, Cli, ColorChoice, Commands, ExtendsPolicy, InitArgs, StructureScope};
 use crate::config::Config;
 use crate::language::LanguageRegistry;
 use crate::output::OutputFormat;
@@ -47,8 +47,12 @@ fn make_check_args(paths: Vec<PathBuf>) -> CheckArgs {
         format: OutputFormat::Text,
         output: None,
         warn_only: false,
+        as_of: None,
         diff: None,
         staged: false,
+        changed: false,
+        rev: None,
+        structure_scope: None,
         warnings_as_errors: false,
         fail_fast: false,
         strict: false,
@@ -144,6 +148,8 @@ fn run_check_with_context_uses_injected_threshold_checker() {
         ctx: &ctx,
         cache: &cache,
         baseline: None,
+        expired_rules: &[],
+        config_path: None,
         project_root: temp_dir.path(),
     };
     let result = run_check_with_context(&options);
@@ -184,6 +190,8 @@ fn run_check_with_context_uses_injected_structure_checker() {
         ctx: &ctx,
         cache: &cache,
         baseline: None,
+        expired_rules: &[],
+        config_path: None,
         project_root: temp_dir.path(),
     };
     let result = run_check_with_context(&options);
@@ -243,6 +251,46 @@ fn validate_and_resolve_paths_max_files_with_path_succeeds() {
     assert_eq!(paths[0], PathBuf::from("src"));
 }
 
+#[test]
+fn resolve_structure_scope_defaults_to_all() {
+    let mut args = make_check_args(vec![]);
+    assert_eq!(resolve_structure_scope(&args).unwrap(), StructureScope::All);
+
+    args.diff = Some("main".to_string());
+    assert_eq!(resolve_structure_scope(&args).unwrap(), StructureScope::All);
+
+    args.structure_scope = Some(StructureScope::Changed);
+    assert_eq!(
+        resolve_structure_scope(&args).unwrap(),
+        StructureScope::Changed
+    );
+}
+
+#[test]
+fn resolve_structure_scope_changed_without_diff_returns_error() {
+    let mut args = make_check_args(vec![]);
+    args.structure_scope = Some(StructureScope::Changed);
+
+    let err = resolve_structure_scope(&args).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("--structure-scope changed requires --diff, --staged or --changed")
+    );
+
+    args.staged = true;
+    assert_eq!(
+        resolve_structure_scope(&args).unwrap(),
+        StructureScope::Changed
+    );
+
+    args.staged = false;
+    args.changed = true;
+    assert_eq!(
+        resolve_structure_scope(&args).unwrap(),
+        StructureScope::Changed
+    );
+}
+
 #[test]
 fn apply_cli_overrides_structure_max_files() {
     let mut config = Config::default();
@@ -0,0 +1,139 @@
+use std::fs;
+use std::path::Path;
+
+use toml::de::{DeTable, DeValue};
+
+use crate::checker::{CheckResult, ViolationCategory};
+use crate::cli::CheckArgs;
+use crate::config::{Config, ExpiredRule, ExpiredRuleType, OnExpire, ParsedDate, RESET_MARKER};
+use crate::counter::LineStats;
+
+/// Reference date for rule expiry: `--as-of` if given, otherwise today.
+///
+/// # Errors
+/// Returns a config error if `--as-of` is not a valid YYYY-MM-DD date.
+pub fn resolve_as_of(args: &CheckArgs) -> crate::Result<ParsedDate> {
+    args.as_of.as_deref().map_or_else(
+        || Ok(ParsedDate::today()),
+        |date| {
+            ParsedDate::parse(date)
+                .map_err(|e| crate::SlocGuardError::Config(format!("--as-of: {e}")))
+        },
+    )
+}
+
+/// Print a warning for each expired `warn` or `revert` rule.
+///
+/// `fail` rules are reported as violations by [`expired_exemption_results`] instead.
+pub fn report_expired_rules(expired_rules: &[ExpiredRule]) {
+    for expired in expired_rules {
+        let consequence = match expired.on_expire {
+            OnExpire::Warn => "",
+            OnExpire::Revert => "; rule no longer applies (on_expire = \"revert\")",
+            OnExpire::Fail => continue,
+        };
+        let reason_suffix = expired
+            .reason
+            .as_ref()
+            .map_or(String::new(), |r| format!(" (reason: {r})"));
+        crate::output::print_warning_full(
+            &format!(
+                "{}.rules[{}] (pattern: '{}') expired on {}{}{}",
+                expired.rule_type,
+                expired.index,
+                expired.pattern,
+                expired.expires,
+                reason_suffix,
+                consequence
+            ),
+            None,
+            Some("Update the expiration date or remove the rule"),
+        );
+    }
+}
+
+/// Build a failing result for each expired `on_expire = "fail"` rule.
+///
+/// Results point at `config_path`, the file the configuration was loaded from, with
+/// the rule's line when it is declared there (rules inherited via `extends` are
+/// reported without a line). Without a config file there are no rules to report.
+pub fn expired_exemption_results(
+    expired_rules: &[ExpiredRule],
+    config: &Config,
+    config_path: Option<&Path>,
+) -> Vec<CheckResult> {
+    let failing: Vec<_> = expired_rules
+        .iter()
+        .filter(|expired| expired.on_expire == OnExpire::Fail)
+        .collect();
+    let Some(config_path) = config_path.filter(|_| !failing.is_empty()) else {
+        return Vec::new();
+    };
+
+    let content = fs::read_to_string(config_path).unwrap_or_default();
+    failing
+        .into_iter()
+        .map(|expired| CheckResult::Failed {
+            path: config_path.to_path_buf(),
+            stats: LineStats::default(),
+            raw_stats: None,
+            limit: 0,
+            override_reason: expired.reason.clone(),
+            suggestions: None,
+            violation_category: Some(ViolationCategory::ExpiredExemption {
+                rule: format!("{}.rules[{}]", expired.rule_type, expired.index),
+                pattern: expired.pattern.clone(),
+                expires: expired.expires.clone(),
+                line: locate_rule_line(&content, expired, merged_rule_count(config, expired)),
+            }),
+        })
+        .collect()
+}
+
+/// Number of rules of the expired rule's type in the merged configuration.
+const fn merged_rule_count(config: &Config, expired: &ExpiredRule) -> usize {
+    match expired.rule_type {
+        ExpiredRuleType::Content => config.content.rules.len(),
+        ExpiredRuleType::Structure => config.structure.rules.len(),
+    }
+}
+
+/// 1-based line declaring the expired rule in `content`: its `[[content.rules]]` /
+/// `[[structure.rules]]` header, or its inline table.
+///
+/// `extends` appends the file's own rules after the inherited ones, so the rule at
+/// `expired.index` of `merged_count` merged rules is found by its position among the
+/// file's rules. Returns `None` for inherited rules.
+pub fn locate_rule_line(
+    content: &str,
+    expired: &ExpiredRule,
+    merged_count: usize,
+) -> Option<usize> {
+    let section = match expired.rule_type {
+        ExpiredRuleType::Content => "content",
+        ExpiredRuleType::Structure => "structure",
+    };
+    let document = DeTable::parse(content).ok()?;
+    let rules = document.get_ref().get(section)?.get_ref().get("rules")?;
+    let DeValue::Array(rules) = rules.get_ref() else {
+        return None;
+    };
+
+    // A leading `$reset` entry is dropped when the config is loaded
+    let skipped = usize::from(rules.first().is_some_and(|rule| is_reset(rule.get_ref())));
+    let local_count = rules.len() - skipped;
+    let local_index = expired
+        .index
+        .checked_sub(merged_count.checked_sub(local_count)?)?;
+    let rule = rules.get(local_index + skipped)?;
+    Some(content[..rule.span().start].matches('\n').count() + 1)
+}
+
+/// Whether a rule table is the `$reset` marker (`pattern` or `scope` set to it).
+fn is_reset(rule: &DeValue<'_>) -> bool {
+    ["pattern", "scope"].into_iter().any(|key| {
+        rule.get(key)
+            .and_then(|value| value.get_ref().as_str())
+            .is_some_and(|value| value == RESET_MARKER)
+    })
+}
@@ -0,0 +1,184 @@
+use std::path::PathBuf;
+
+use tempfile::TempDir;
+
+use crate::checker::ViolationCategory;
+use crate::config::{Config, ExpiredRule, ExpiredRuleType, OnExpire};
+
+use super::{expired_exemption_results, locate_rule_line};
+
+const CONFIG: &str = r#"version = "2"
+
+[[content.rules]]
+pattern = "src/legacy/**"
+max_lines = 1500
+expires = "2025-06-01"
+on_expire = "fail"
+
+[[structure.rules]]
+scope = 'vendor'
+max_files = 200
+"#;
+
+fn parsed_config() -> Config {
+    toml::from_str(CONFIG).unwrap()
+}
+
+fn expired(rule_type: ExpiredRuleType, pattern: &str, on_expire: OnExpire) -> ExpiredRule {
+    ExpiredRule {
+        rule_type,
+        index: 0,
+        pattern: pattern.to_string(),
+        expires: "2025-06-01".to_string(),
+        reason: Some("Legacy code".to_string()),
+        on_expire,
+    }
+}
+
+fn expired_at(rule_type: ExpiredRuleType, index: usize) -> ExpiredRule {
+    ExpiredRule {
+        index,
+        ..expired(rule_type, "", OnExpire::Fail)
+    }
+}
+
+#[test]
+fn locate_rule_line_finds_content_rule_header() {
+    let rule = expired(ExpiredRuleType::Content, "src/legacy/**", OnExpire::Fail);
+    assert_eq!(locate_rule_line(CONFIG, &rule, 1), Some(3));
+}
+
+#[test]
+fn locate_rule_line_finds_structure_rule_header() {
+    let rule = expired(ExpiredRuleType::Structure, "vendor", OnExpire::Fail);
+    assert_eq!(locate_rule_line(CONFIG, &rule, 1), Some(9));
+}
+
+#[test]
+fn locate_rule_line_finds_inline_table() {
+    let config =
+        "[content]\nrules = [\n  { pattern = \"a/**\" },\n  { pattern = \"gen/**\" },\n]\n";
+    assert_eq!(
+        locate_rule_line(config, &expired_at(ExpiredRuleType::Content, 1), 2),
+        Some(4)
+    );
+}
+
+#[test]
+fn locate_rule_line_uses_rule_index_not_pattern() {
+    // Same pattern twice, and selectors without a plain `pattern`/`scope` string
+    let config = r#"[[content.rules]]
+pattern = "src/**"
+max_lines = 100
+
+[[content.rules]]
+pattern = "src/**"
+max_lines = 200
+
+[[content.rules]]
+patterns = ["gen/**", "build/**"]
+max_lines = 300
+
+[[structure.rules]]
+scope = { include = ["vendor/**"], exclude = ["vendor/keep"] }
+max_files = 50
+"#;
+    assert_eq!(
+        locate_rule_line(config, &expired_at(ExpiredRuleType::Content, 1), 3),
+        Some(5)
+    );
+    assert_eq!(
+        locate_rule_line(config, &expired_at(ExpiredRuleType::Content, 2), 3),
+        Some(9)
+    );
+    assert_eq!(
+        locate_rule_line(config, &expired_at(ExpiredRuleType::Structure, 0), 1),
+        Some(13)
+    );
+}
+
+#[test]
+fn locate_rule_line_skips_inherited_rules_and_reset_marker() {
+    let config = r#"extends = "base.toml"
+
+[[content.rules]]
+pattern = "$reset"
+
+[[content.rules]]
+pattern = "own/**"
+max_lines = 100
+"#;
+    // `$reset` drops inherited rules, so the merged config holds only `own/**`
+    assert_eq!(
+        locate_rule_line(config, &expired_at(ExpiredRuleType::Content, 0), 1),
+        Some(6)
+    );
+
+    let config = "extends = \"base.toml\"\n\n[[content.rules]]\npattern = \"own/**\"\n";
+    // Two inherited rules come first in the merged config
+    assert_eq!(
+        locate_rule_line(config, &expired_at(ExpiredRuleType::Content, 1), 3),
+        None
+    );
+    assert_eq!(
+        locate_rule_line(config, &expired_at(ExpiredRuleType::Content, 2), 3),
+        Some(3)
+    );
+}
+
+#[test]
+fn locate_rule_line_without_rules_of_that_type() {
+    let config = "[[structure.rules]]\nscope = \"src\"\n";
+    assert_eq!(
+        locate_rule_line(config, &expired_at(ExpiredRuleType::Content, 0), 1),
+        None
+    );
+}
+
+#[test]
+fn expired_exemption_results_only_for_fail_rules() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_path = temp_dir.path().join(".sloc-guard.toml");
+    std::fs::write(&config_path, CONFIG).unwrap();
+
+    let rules = vec![
+        expired(ExpiredRuleType::Content, "src/legacy/**", OnExpire::Fail),
+        expired(ExpiredRuleType::Structure, "vendor", OnExpire::Warn),
+        expired(ExpiredRuleType::Content, "gen/**", OnExpire::Revert),
+    ];
+    let results = expired_exemption_results(&rules, &parsed_config(), Some(&config_path));
+
+    assert_eq!(results.len(), 1);
+    let result = &results[0];
+    assert!(result.is_failed());
+    assert_eq!(result.path(), config_path.as_path());
+    assert_eq!(result.override_reason(), Some("Legacy code"));
+    assert_eq!(
+        result.violation_category(),
+        Some(&ViolationCategory::ExpiredExemption {
+            rule: "content.rules[0]".to_string(),
+            pattern: "src/legacy/**".to_string(),
+            expires: "2025-06-01".to_string(),
+            line: Some(3),
+        })
+    );
+}
+
+#[test]
+fn expired_exemption_results_without_readable_config_have_no_line() {
+    let rules = vec![expired(
+        ExpiredRuleType::Content,
+        "src/legacy/**",
+        OnExpire::Fail,
+    )];
+    let results = expired_exemption_results(
+        &rules,
+        &parsed_config(),
+        Some(&PathBuf::from("missing/.sloc-guard.toml")),
+    );
+
+    assert!(matches!(
+        results[0].violation_category(),
+        Some(ViolationCategory::ExpiredExemption { line: None, .. })
+    ));
+}
@@ -1,44 +1,50 @@
 use std::collections::HashSet;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 
+use crate::checker::{LayoutProblem, StructureViolation, ViolationType};
 use crate::git::GitDiff;
 
-/// Represents a parsed diff range (base..target).
+/// Represents a parsed diff range (`base..target` or `base...target`).
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DiffRange {
     pub base: String,
     pub target: String,
+    /// Compare from the merge-base of `base` and `target` instead of `base` itself.
+    pub merge_base: bool,
 }
 
 /// Parse a diff reference string into a `DiffRange`.
 ///
 /// Supports:
-/// - `ref` → base=ref, target=HEAD
+/// - `ref` → base=ref, target=HEAD (merge-base when `merge_base_default` is set)
 /// - `base..target` → base=base, target=target
-/// - `base..` → base=base, target=HEAD
+/// - `base...target` → merge-base of base and target, target=target
+/// - `base..` / `base...` → target=HEAD
 ///
 /// # Errors
 /// Returns an error if:
-/// - Input starts with `..` (no base specified)
+/// - Input starts with `..` or `...` (no base specified)
 /// - Input is empty
-pub fn parse_diff_range(diff_ref: &str) -> crate::Result<DiffRange> {
+pub fn parse_diff_range(diff_ref: &str, merge_base_default: bool) -> crate::Result<DiffRange> {
     if diff_ref.is_empty() {
         return Err(crate::SlocGuardError::Config(
             "--diff requires a git reference".to_string(),
         ));
     }
 
-    // Check for range syntax (contains "..")
-    if let Some(pos) = diff_ref.find("..") {
-        let base = &diff_ref[..pos];
-        let target = &diff_ref[pos + 2..];
+    // Check for range syntax; "..." contains "..", so look for it first
+    let range = ["...", ".."].into_iter().find_map(|separator| {
+        diff_ref
+            .split_once(separator)
+            .map(|(base, target)| (base, target, separator))
+    });
 
+    if let Some((base, target, separator)) = range {
         // Error if no base specified
         if base.is_empty() {
-            return Err(crate::SlocGuardError::Config(
-                "--diff range requires a base reference (e.g., 'main..feature', not '..feature')"
-                    .to_string(),
-            ));
+            return Err(crate::SlocGuardError::Config(format!(
+                "--diff range requires a base reference (e.g., 'main{separator}feature', not '{separator}feature')"
+            )));
         }
 
         // If target is empty, default to HEAD
@@ -51,23 +57,58 @@ pub fn parse_diff_range(diff_ref: &str) -> crate::Result<DiffRange> {
         Ok(DiffRange {
             base: base.to_string(),
             target,
+            merge_base: separator == "...",
         })
     } else {
         // Single reference: compare to HEAD
         Ok(DiffRange {
             base: diff_ref.to_string(),
             target: "HEAD".to_string(),
+            merge_base: merge_base_default,
         })
     }
 }
 
+/// Parse `--diff` and resolve a merge-base range to the commit it starts from.
+///
+/// The returned range compares `base` and `target` directly. With `verbose`,
+/// the resolved merge-base is printed.
+///
+/// # Errors
+/// Returns an error if the range is invalid or the merge-base cannot be found.
+pub fn resolve_diff_range(
+    diff_ref: &str,
+    merge_base_default: bool,
+    project_root: &Path,
+    verbose: bool,
+) -> crate::Result<DiffRange> {
+    let range = parse_diff_range(diff_ref, merge_base_default)?;
+    if !range.merge_base {
+        return Ok(range);
+    }
+
+    let merge_base = GitDiff::discover(project_root)?.merge_base(&range.base, &range.target)?;
+    if verbose {
+        crate::output::print_info(&format!(
+            "Diff base: merge-base of {} and {} is {merge_base}",
+            range.base, range.target
+        ));
+    }
+    Ok(DiffRange {
+        base: merge_base.to_string(),
+        target: range.target,
+        merge_base: false,
+    })
+}
+
 pub fn filter_by_git_diff(
     files: Vec<PathBuf>,
-    diff_ref: Option<&str>,
+    diff_range: Option<&DiffRange>,
     staged_only: bool,
+    worktree: bool,
     project_root: &Path,
 ) -> crate::Result<Vec<PathBuf>> {
-    if !staged_only && diff_ref.is_none() {
+    if !staged_only && !worktree && diff_range.is_none() {
         return Ok(files);
     }
 
@@ -75,8 +116,10 @@ pub fn filter_by_git_diff(
     let git_diff = GitDiff::discover(project_root)?;
     let changed_files = if staged_only {
         git_diff.get_staged_files()?
+    } else if worktree {
+        git_diff.get_worktree_changed_files()?
     } else {
-        let range = parse_diff_range(diff_ref.expect("diff_ref checked above"))?;
+        let range = diff_range.expect("diff_range checked above");
         git_diff.get_changed_files_range(&range.base, &range.target)?
     };
 
@@ -98,3 +141,144 @@ pub fn filter_by_git_diff(
 
     Ok(filtered)
 }
+
+/// Directories whose entries a diff adds, removes or renames, and the files it changes
+/// (`--structure-scope changed`).
+///
+/// Paths are relative to the repository workdir and compared lexically, so directories
+/// that only exist in the index or a revision still match.
+#[derive(Debug, Default)]
+pub struct ChangedDirs {
+    dirs: HashSet<PathBuf>,
+    files: HashSet<PathBuf>,
+    /// Spellings of the workdir that violation paths may start with.
+    workdirs: Vec<PathBuf>,
+    /// Base for relative violation paths.
+    current_dir: PathBuf,
+}
+
+impl ChangedDirs {
+    /// Collect the changed directories and files of `--diff`, `--staged` or `--changed`.
+    ///
+    /// # Errors
+    /// Returns an error if the git repository or references cannot be read.
+    pub fn from_git(
+        diff_range: Option<&DiffRange>,
+        staged_only: bool,
+        worktree: bool,
+        project_root: &Path,
+    ) -> crate::Result<Self> {
+        let git_diff = GitDiff::discover(project_root)?;
+        let (entries, files) = if staged_only {
+            (
+                git_diff.get_staged_added_removed()?,
+                git_diff.get_staged_files()?,
+            )
+        } else if worktree {
+            (
+                git_diff.get_worktree_added_removed()?,
+                git_diff.get_worktree_changed_files()?,
+            )
+        } else if let Some(range) = diff_range {
+            (
+                git_diff.get_added_removed_range(&range.base, &range.target)?,
+                git_diff.get_changed_files_range(&range.base, &range.target)?,
+            )
+        } else {
+            (HashSet::new(), HashSet::new())
+        };
+        Ok(Self::from_entries(git_diff.workdir(), entries, files))
+    }
+
+    /// Parent directories of the added or removed `entries`, and the `changed_files`,
+    /// all under `workdir`.
+    #[must_use]
+    pub fn from_entries(
+        workdir: &Path,
+        entries: impl IntoIterator<Item = PathBuf>,
+        changed_files: impl IntoIterator<Item = PathBuf>,
+    ) -> Self {
+        let relative = |path: PathBuf| path.strip_prefix(workdir).ok().map(Path::to_path_buf);
+        let dirs = entries
+            .into_iter()
+            .filter_map(relative)
+            .filter_map(|entry| entry.parent().map(Path::to_path_buf))
+            .collect();
+        let files = changed_files.into_iter().filter_map(relative).collect();
+
+        let current_dir = std::env::current_dir()
+            .map(|dir| dunce::canonicalize(&dir).unwrap_or(dir))
+            .unwrap_or_default();
+        let mut workdirs = vec![lexical_absolute(workdir, &current_dir)];
+        if let Ok(canonical) = dunce::canonicalize(workdir)
+            && !workdirs.contains(&canonical)
+        {
+            workdirs.push(canonical);
+        }
+        Self {
+            dirs,
+            files,
+            workdirs,
+            current_dir,
+        }
+    }
+
+    /// Whether the diff changes the path `violation` is reported for, or touches
+    /// its directory.
+    ///
+    /// Directory limits match the directory itself and entry violations match the
+    /// entry's parent. Depth and recursive limits also match ancestors of changed
+    /// directories.
+    #[must_use]
+    pub fn contains(&self, violation: &StructureViolation) -> bool {
+        let Some(path) = self.relative(&violation.path) else {
+            return false;
+        };
+        if self.files.contains(&path) {
+            return true;
+        }
+        match violation.violation_type {
+            ViolationType::MaxDepth
+            | ViolationType::TotalFileCount
+            | ViolationType::TotalDirCount => {
+                self.dirs.iter().any(|changed| changed.starts_with(&path))
+            }
+            ViolationType::FileCount
+            | ViolationType::DirCount
+            | ViolationType::FileCountByExtension { .. }
+            | ViolationType::CaseCollision { .. }
+            | ViolationType::EmptyDirectory
+            | ViolationType::MissingRequiredFile { .. }
+            | ViolationType::ExclusiveConflict { .. }
+            | ViolationType::Layout {
+                problem: LayoutProblem::Missing,
+                ..
+            } => self.dirs.contains(&path),
+            _ => path.parent().is_some_and(|dir| self.dirs.contains(dir)),
+        }
+    }
+
+    /// `path` relative to the workdir, without touching the filesystem.
+    fn relative(&self, path: &Path) -> Option<PathBuf> {
+        let absolute = lexical_absolute(path, &self.current_dir);
+        self.workdirs
+            .iter()
+            .find_map(|workdir| absolute.strip_prefix(workdir).ok())
+            .map(Path::to_path_buf)
+    }
+}
+
+/// `path` joined onto `base` with `.` and `..` resolved lexically.
+fn lexical_absolute(path: &Path, base: &Path) -> PathBuf {
+    let mut absolute = PathBuf::new();
+    for component in base.join(path).components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                absolute.pop();
+            }
+            other => absolute.push(other),
+        }
+    }
+    absolute
+}
@@ -1,68 +1,78 @@
-use super::{DiffRange, parse_diff_range};
+use std::path::Path;
+
+use tempfile::TempDir;
+
+use super::{ChangedDirs, DiffRange, parse_diff_range};
+use crate::checker::{StructureViolation, ViolationType};
 
 #[test]
 fn parse_diff_range_single_ref() {
-    let result = parse_diff_range("main").unwrap();
+    let result = parse_diff_range("main", false).unwrap();
     assert_eq!(
         result,
         DiffRange {
             base: "main".to_string(),
             target: "HEAD".to_string(),
+            merge_base: false,
         }
     );
 }
 
 #[test]
 fn parse_diff_range_explicit_range() {
-    let result = parse_diff_range("main..feature").unwrap();
+    let result = parse_diff_range("main..feature", false).unwrap();
     assert_eq!(
         result,
         DiffRange {
             base: "main".to_string(),
             target: "feature".to_string(),
+            merge_base: false,
         }
     );
 }
 
 #[test]
 fn parse_diff_range_origin_refs() {
-    let result = parse_diff_range("origin/main..origin/feature").unwrap();
+    let result = parse_diff_range("origin/main..origin/feature", false).unwrap();
     assert_eq!(
         result,
         DiffRange {
             base: "origin/main".to_string(),
             target: "origin/feature".to_string(),
+            merge_base: false,
         }
     );
 }
 
 #[test]
 fn parse_diff_range_tags() {
-    let result = parse_diff_range("v1.0..v2.0").unwrap();
+    let result = parse_diff_range("v1.0..v2.0", false).unwrap();
     assert_eq!(
         result,
         DiffRange {
             base: "v1.0".to_string(),
             target: "v2.0".to_string(),
+            merge_base: false,
         }
     );
 }
 
 #[test]
 fn parse_diff_range_trailing_dots_defaults_to_head() {
-    let result = parse_diff_range("main..").unwrap();
+    let result = parse_diff_range("main..", false).unwrap();
     assert_eq!(
         result,
         DiffRange {
             base: "main".to_string(),
             target: "HEAD".to_string(),
+            merge_base: false,
         }
     );
 }
 
 #[test]
 fn parse_diff_range_no_base_returns_error() {
-    let result = parse_diff_range("..feature");
+    let result = parse_diff_range("..feature", false);
     assert!(result.is_err());
     let err = result.unwrap_err().to_string();
     assert!(err.contains("base reference"));
@@ -70,7 +80,7 @@ fn parse_diff_range_no_base_returns_error() {
 
 #[test]
 fn parse_diff_range_empty_returns_error() {
-    let result = parse_diff_range("");
+    let result = parse_diff_range("", false);
     assert!(result.is_err());
     let err = result.unwrap_err().to_string();
     assert!(err.contains("requires a git reference"));
@@ -78,24 +88,167 @@ fn parse_diff_range_empty_returns_error() {
 
 #[test]
 fn parse_diff_range_head_tilde() {
-    let result = parse_diff_range("HEAD~3..HEAD").unwrap();
+    let result = parse_diff_range("HEAD~3..HEAD", false).unwrap();
     assert_eq!(
         result,
         DiffRange {
             base: "HEAD~3".to_string(),
             target: "HEAD".to_string(),
+            merge_base: false,
         }
     );
 }
 
 #[test]
 fn parse_diff_range_commit_hash() {
-    let result = parse_diff_range("abc123..def456").unwrap();
+    let result = parse_diff_range("abc123..def456", false).unwrap();
     assert_eq!(
         result,
         DiffRange {
             base: "abc123".to_string(),
             target: "def456".to_string(),
+            merge_base: false,
         }
     );
 }
+
+#[test]
+fn parse_diff_range_three_dots_uses_merge_base() {
+    let result = parse_diff_range("main...feature", false).unwrap();
+    assert_eq!(
+        result,
+        DiffRange {
+            base: "main".to_string(),
+            target: "feature".to_string(),
+            merge_base: true,
+        }
+    );
+}
+
+#[test]
+fn parse_diff_range_three_dots_defaults_to_head() {
+    let result = parse_diff_range("origin/main...", false).unwrap();
+    assert_eq!(
+        result,
+        DiffRange {
+            base: "origin/main".to_string(),
+            target: "HEAD".to_string(),
+            merge_base: true,
+        }
+    );
+}
+
+#[test]
+fn parse_diff_range_three_dots_no_base_returns_error() {
+    let err = parse_diff_range("...feature", false)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("'main...feature'"));
+}
+
+#[test]
+fn parse_diff_range_single_ref_merge_base_default() {
+    let result = parse_diff_range("main", true).unwrap();
+    assert!(result.merge_base);
+
+    // An explicit two-dot range keeps comparing the trees directly
+    let result = parse_diff_range("main..HEAD", true).unwrap();
+    assert!(!result.merge_base);
+}
+
+// =============================================================================
+// ChangedDirs Tests
+// =============================================================================
+
+fn violation(path: &Path, violation_type: ViolationType) -> StructureViolation {
+    StructureViolation::new(path.to_path_buf(), violation_type, 3, 2, None)
+}
+
+/// Repository with `a/b` and `c`, where the diff adds `a/b/new.rs`, removes `gone/old.rs`
+/// and modifies `c/edited.rs` in place.
+fn changed_dirs_fixture() -> (TempDir, ChangedDirs) {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    std::fs::create_dir_all(root.join("a/b")).unwrap();
+    std::fs::create_dir(root.join("c")).unwrap();
+    std::fs::write(root.join("a/b/new.rs"), "").unwrap();
+
+    let changed = ChangedDirs::from_entries(
+        root,
+        [root.join("a/b/new.rs"), root.join("gone/old.rs")],
+        [root.join("a/b/new.rs"), root.join("c/edited.rs")],
+    );
+    (temp_dir, changed)
+}
+
+#[test]
+fn changed_dirs_match_directory_limits_on_the_changed_directory_only() {
+    let (temp_dir, changed) = changed_dirs_fixture();
+    let root = temp_dir.path();
+
+    assert!(changed.contains(&violation(&root.join("a/b"), ViolationType::FileCount)));
+    assert!(!changed.contains(&violation(&root.join("a"), ViolationType::FileCount)));
+    assert!(!changed.contains(&violation(&root.join("c"), ViolationType::DirCount)));
+}
+
+#[test]
+fn changed_dirs_match_recursive_limits_on_ancestors() {
+    let (temp_dir, changed) = changed_dirs_fixture();
+    let root = temp_dir.path();
+
+    assert!(changed.contains(&violation(root, ViolationType::TotalFileCount)));
+    assert!(changed.contains(&violation(&root.join("a"), ViolationType::TotalDirCount)));
+    assert!(changed.contains(&violation(&root.join("a/b"), ViolationType::MaxDepth)));
+    assert!(!changed.contains(&violation(&root.join("c"), ViolationType::TotalFileCount)));
+}
+
+#[test]
+fn changed_dirs_match_entry_violations_by_parent_directory() {
+    let (temp_dir, changed) = changed_dirs_fixture();
+    let root = temp_dir.path();
+
+    assert!(changed.contains(&violation(
+        &root.join("a/b/new.rs"),
+        ViolationType::DisallowedFile
+    )));
+    assert!(!changed.contains(&violation(
+        &root.join("a/b"),
+        ViolationType::DisallowedDirectory
+    )));
+    assert!(!changed.contains(&violation(&root.join("c/x.rs"), ViolationType::FileSize)));
+}
+
+#[test]
+fn changed_dirs_match_entry_violations_on_changed_files() {
+    let (temp_dir, changed) = changed_dirs_fixture();
+    let root = temp_dir.path();
+
+    // Modified in place: its directory gained no entries, but the file itself changed
+    assert!(changed.contains(&violation(
+        &root.join("c/edited.rs"),
+        ViolationType::NamingConvention {
+            expected_pattern: "^[a-z]+$".to_string(),
+            is_directory: false,
+        }
+    )));
+    assert!(!changed.contains(&violation(
+        &root.join("c/other.rs"),
+        ViolationType::FileSize
+    )));
+}
+
+#[test]
+fn changed_dirs_match_directories_missing_on_disk() {
+    let (temp_dir, changed) = changed_dirs_fixture();
+    let root = temp_dir.path();
+
+    // `gone/` only exists in the diff's base, so it cannot be canonicalized
+    assert!(changed.contains(&violation(
+        &root.join("gone"),
+        ViolationType::EmptyDirectory
+    )));
+    assert!(changed.contains(&violation(
+        &root.join("a/./b/../b"),
+        ViolationType::FileCount
+    )));
+}
@@ -0,0 +1,22 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::commands::context::{CheckContext, GitTreeReader};
+use crate::git::GitTree;
+use crate::scanner::TreeScanner;
+
+/// Point the context's scanner and reader at the tree of `rev` (`check --rev`).
+///
+/// # Errors
+/// Returns an error if the repository cannot be opened or `rev` does not resolve to a tree.
+pub fn use_revision_tree(
+    ctx: &mut CheckContext,
+    rev: &str,
+    project_root: &Path,
+    exclude_patterns: Vec<String>,
+) -> crate::Result<()> {
+    let tree = Arc::new(GitTree::at_revision(project_root, rev)?);
+    ctx.scanner = Box::new(TreeScanner::new(tree.clone(), exclude_patterns));
+    ctx.file_reader = Box::new(GitTreeReader::new(tree));
+    Ok(())
+}
@@ -1,6 +1,9 @@
 use std::path::{Path, PathBuf};
 
-use crate::checker::{CheckResult, StructureViolation, ViolationCategory, ViolationType};
+use crate::checker::{
+    CheckResult, LayoutProblem, StructureViolation, SymlinkProblem, ViolationCategory,
+    ViolationType,
+};
 use crate::cli::{CheckArgs, Cli};
 use crate::commands::context::write_output;
 use crate::counter::LineStats;
@@ -66,23 +69,59 @@ pub fn structure_violation_to_check_result(violation: &StructureViolation) -> Ch
     });
 
     // Build human-readable description for override_reason (for backwards compatibility)
-    let override_reason = match &violation.violation_type {
-        ViolationType::FileCount => Some("structure: files count exceeded".to_string()),
-        ViolationType::DirCount => Some("structure: subdirs count exceeded".to_string()),
-        ViolationType::MaxDepth => Some("structure: depth count exceeded".to_string()),
+    let override_reason = Some(structure_override_reason(violation));
+
+    if violation.is_warning {
+        CheckResult::Warning {
+            path: violation.path.clone(),
+            stats,
+            raw_stats: None, // Structure violations don't have raw vs effective stats
+            limit: violation.limit,
+            override_reason,
+            suggestions: None,
+            violation_category,
+        }
+    } else {
+        CheckResult::Failed {
+            path: violation.path.clone(),
+            stats,
+            raw_stats: None, // Structure violations don't have raw vs effective stats
+            limit: violation.limit,
+            override_reason,
+            suggestions: None,
+            violation_category,
+        }
+    }
+}
+
+/// Human-readable description of a structure violation, used as its `override_reason`.
+fn structure_override_reason(violation: &StructureViolation) -> String {
+    match &violation.violation_type {
+        ViolationType::FileCount => "structure: files count exceeded".to_string(),
+        ViolationType::DirCount => "structure: subdirs count exceeded".to_string(),
+        ViolationType::MaxDepth => "structure: depth count exceeded".to_string(),
+        ViolationType::TotalFileCount => "structure: total files count exceeded".to_string(),
+        ViolationType::TotalDirCount => "structure: total subdirs count exceeded".to_string(),
+        ViolationType::FileCountByExtension { extension } => {
+            format!("structure: {extension} count exceeded")
+        }
+        ViolationType::PathLength => "structure: path length exceeded".to_string(),
+        ViolationType::FilenameLength => "structure: name length exceeded".to_string(),
+        ViolationType::PathComponents => "structure: path components exceeded".to_string(),
+        ViolationType::FileSize => "structure: file size exceeded".to_string(),
         ViolationType::DisallowedFile => {
             let rule = violation
                 .triggering_rule_pattern
                 .as_deref()
                 .unwrap_or("unknown");
-            Some(format!("structure: disallowed file (rule: {rule})"))
+            format!("structure: disallowed file (rule: {rule})")
         }
         ViolationType::DisallowedDirectory => {
             let rule = violation
                 .triggering_rule_pattern
                 .as_deref()
                 .unwrap_or("unknown");
-            Some(format!("structure: disallowed directory (rule: {rule})"))
+            format!("structure: disallowed directory (rule: {rule})")
         }
         ViolationType::DeniedFile {
             pattern_or_extension,
@@ -91,18 +130,18 @@ pub fn structure_violation_to_check_result(violation: &StructureViolation) -> Ch
                 .triggering_rule_pattern
                 .as_deref()
                 .unwrap_or("global");
-            Some(format!(
-                "structure: denied file (matched: {pattern_or_extension}, rule: {rule})"
-            ))
+            format!("structure: denied file (matched: {pattern_or_extension}, rule: {rule})")
         }
-        ViolationType::NamingConvention { expected_pattern } => {
+        ViolationType::NamingConvention {
+            expected_pattern, ..
+        } => {
             let rule = violation
                 .triggering_rule_pattern
                 .as_deref()
                 .unwrap_or("unknown");
-            Some(format!(
+            format!(
                 "structure: naming convention violation (expected: {expected_pattern}, rule: {rule})"
-            ))
+            )
         }
         ViolationType::MissingSibling {
             expected_sibling_pattern,
@@ -111,9 +150,16 @@ pub fn structure_violation_to_check_result(violation: &StructureViolation) -> Ch
                 .triggering_rule_pattern
                 .as_deref()
                 .unwrap_or("unknown");
-            Some(format!(
+            format!(
                 "structure: missing sibling (expected: {expected_sibling_pattern}, rule: {rule})"
-            ))
+            )
+        }
+        ViolationType::MissingRequiredFile { required_pattern } => {
+            let rule = violation
+                .triggering_rule_pattern
+                .as_deref()
+                .unwrap_or("unknown");
+            format!("structure: missing required file (expected: {required_pattern}, rule: {rule})")
         }
         ViolationType::GroupIncomplete {
             missing_patterns, ..
@@ -123,40 +169,69 @@ pub fn structure_violation_to_check_result(violation: &StructureViolation) -> Ch
                 .as_deref()
                 .unwrap_or("unknown");
             let missing = missing_patterns.join(", ");
-            Some(format!(
-                "structure: group incomplete (missing: {missing}, rule: {rule})"
-            ))
+            format!("structure: group incomplete (missing: {missing}, rule: {rule})")
         }
-        ViolationType::DeniedDirectory { pattern } => {
+        ViolationType::ForbiddenSibling {
+            forbidden_sibling_pattern,
+            conflicting_file,
+        } => {
             let rule = violation
                 .triggering_rule_pattern
                 .as_deref()
-                .unwrap_or("global");
-            Some(format!(
-                "structure: denied directory (matched: {pattern}, rule: {rule})"
-            ))
+                .unwrap_or("unknown");
+            format!(
+                "structure: forbidden sibling {conflicting_file} (forbidden: {forbidden_sibling_pattern}, rule: {rule})"
+            )
         }
-    };
-
-    if violation.is_warning {
-        CheckResult::Warning {
-            path: violation.path.clone(),
-            stats,
-            raw_stats: None, // Structure violations don't have raw vs effective stats
-            limit: violation.limit,
-            override_reason,
-            suggestions: None,
-            violation_category,
+        ViolationType::ExclusiveConflict {
+            conflicting_files, ..
+        } => {
+            let rule = violation
+                .triggering_rule_pattern
+                .as_deref()
+                .unwrap_or("unknown");
+            let conflicting = conflicting_files.join(", ");
+            format!("structure: mutually exclusive files (found: {conflicting}, rule: {rule})")
         }
-    } else {
-        CheckResult::Failed {
-            path: violation.path.clone(),
-            stats,
-            raw_stats: None, // Structure violations don't have raw vs effective stats
-            limit: violation.limit,
-            override_reason,
-            suggestions: None,
-            violation_category,
+        ViolationType::CaseCollision { colliding_paths } => format!(
+            "structure: case collision (paths: {})",
+            colliding_paths.join(", ")
+        ),
+        ViolationType::InvalidWindowsName { reason } => {
+            format!("structure: invalid Windows name ({reason})")
+        }
+        ViolationType::EmptyDirectory => "structure: empty directory".to_string(),
+        ViolationType::Symlink { target, problem } => match problem {
+            SymlinkProblem::Denied => format!("structure: symlink not allowed (target: {target})"),
+            SymlinkProblem::OutsideProject => {
+                format!("structure: symlink outside project (target: {target})")
+            }
+            SymlinkProblem::Loop => format!("structure: symlink loop (ancestor: {target})"),
+        },
+        ViolationType::Layout {
+            layout,
+            entry,
+            problem,
+        } => {
+            let rule = violation
+                .triggering_rule_pattern
+                .as_deref()
+                .unwrap_or("unknown");
+            match problem {
+                LayoutProblem::Missing => {
+                    format!("structure: layout '{layout}' requires {entry} (rule: {rule})")
+                }
+                LayoutProblem::Unexpected => {
+                    format!("structure: {entry} not in layout '{layout}' (rule: {rule})")
+                }
+            }
+        }
+        ViolationType::DeniedDirectory { pattern } => {
+            let rule = violation
+                .triggering_rule_pattern
+                .as_deref()
+                .unwrap_or("global");
+            format!("structure: denied directory (matched: {pattern}, rule: {rule})")
         }
     }
 }
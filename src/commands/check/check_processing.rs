@@ -3,6 +3,7 @@ use std::sync::Mutex;
 
 use crate::cache::Cache;
 use crate::checker::{CheckResult, Checker, ThresholdChecker};
+use crate::config::ContentMetric;
 use crate::counter::LineStats;
 use crate::language::LanguageRegistry;
 use crate::output::FileStatistics;
@@ -54,8 +55,13 @@ pub fn process_file_for_check(
 
     match result {
         FileProcessResult::Success { stats, language } => {
-            let (skip_comments, skip_blank) = checker.get_skip_settings_for_path(file_path);
-            let effective_stats = compute_effective_stats(&stats, skip_comments, skip_blank);
+            let effective_stats = checker.get_metric_for_path(file_path).map_or_else(
+                || {
+                    let (skip_comments, skip_blank) = checker.get_skip_settings_for_path(file_path);
+                    compute_effective_stats(&stats, skip_comments, skip_blank)
+                },
+                |metric| compute_metric_stats(&stats, metric),
+            );
             let check_result = checker.check(file_path, &effective_stats, Some(&stats));
             let file_stats = FileStatistics {
                 path: file_path.to_path_buf(),
@@ -94,3 +100,33 @@ pub fn compute_effective_stats(
 
     effective
 }
+
+/// Fold the lines counted by `metric` into `code` so `sloc()` reflects the metric.
+///
+/// Physical metrics (`non_blank`, `total`) derive from `total`, which includes
+/// lines suppressed by ignore directives.
+#[must_use]
+pub fn compute_metric_stats(stats: &LineStats, metric: ContentMetric) -> LineStats {
+    let mut effective = stats.clone();
+
+    match metric {
+        ContentMetric::Code => {}
+        ContentMetric::CodeAndComments => {
+            effective.code += effective.comment;
+            effective.comment = 0;
+        }
+        ContentMetric::NonBlank => {
+            effective.code = stats.total.saturating_sub(stats.blank);
+            effective.comment = 0;
+            effective.ignored = 0;
+        }
+        ContentMetric::Total => {
+            effective.code = stats.total;
+            effective.comment = 0;
+            effective.blank = 0;
+            effective.ignored = 0;
+        }
+    }
+
+    effective
+}
@@ -3,12 +3,14 @@ use std::sync::Mutex;
 
 use crate::cache::Cache;
 use crate::checker::{CheckResult, ThresholdChecker};
-use crate::config::Config;
+use crate::config::{Config, ContentMetric};
 use crate::counter::LineStats;
 use crate::language::LanguageRegistry;
 use crate::output::FileStatistics;
 
-use super::{CheckFileResult, compute_effective_stats, process_file_for_check};
+use super::{
+    CheckFileResult, compute_effective_stats, compute_metric_stats, process_file_for_check,
+};
 use crate::commands::context::{FileSkipReason, RealFileReader};
 
 /// Asserts that boxing the Success variant data is worthwhile.
@@ -94,6 +96,44 @@ fn compute_effective_stats_include_both() {
     assert_eq!(effective.blank, 0);
 }
 
+fn stats_with_ignored() -> LineStats {
+    LineStats {
+        total: 110,
+        code: 80,
+        comment: 15,
+        blank: 5,
+        ignored: 10,
+    }
+}
+
+#[test]
+fn compute_metric_stats_code_keeps_code_only() {
+    let effective = compute_metric_stats(&stats_with_ignored(), ContentMetric::Code);
+    assert_eq!(effective.sloc(), 80);
+}
+
+#[test]
+fn compute_metric_stats_code_and_comments_honours_ignore_directives() {
+    let effective = compute_metric_stats(&stats_with_ignored(), ContentMetric::CodeAndComments);
+    assert_eq!(effective.sloc(), 95);
+    assert_eq!(effective.comment, 0);
+    assert_eq!(effective.ignored, 10);
+}
+
+#[test]
+fn compute_metric_stats_non_blank_counts_physical_lines() {
+    let effective = compute_metric_stats(&stats_with_ignored(), ContentMetric::NonBlank);
+    assert_eq!(effective.sloc(), 105);
+    assert_eq!(effective.blank, 5);
+}
+
+#[test]
+fn compute_metric_stats_total_counts_every_line() {
+    let effective = compute_metric_stats(&stats_with_ignored(), ContentMetric::Total);
+    assert_eq!(effective.sloc(), 110);
+    assert_eq!(effective.blank, 0);
+}
+
 #[test]
 fn process_file_nonexistent_returns_error() {
     let registry = LanguageRegistry::default();
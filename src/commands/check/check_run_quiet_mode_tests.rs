@@ -45,8 +45,12 @@ fn default_check_args() -> CheckArgs {
         format: OutputFormat::Text,
         output: None,
         warn_only: false,
+        as_of: None,
         diff: None,
         staged: false,
+        changed: false,
+        rev: None,
+        structure_scope: None,
         warnings_as_errors: false,
         fail_fast: false,
         strict: false,
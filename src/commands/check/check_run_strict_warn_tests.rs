@@ -38,8 +38,12 @@ fn default_check_args() -> CheckArgs {
         format: OutputFormat::Text,
         output: None,
         warn_only: false,
+        as_of: None,
         diff: None,
         staged: false,
+        changed: false,
+        rev: None,
+        structure_scope: None,
         warnings_as_errors: false,
         fail_fast: false,
         strict: false,
@@ -64,6 +68,7 @@ fn run_check_impl_with_warn_only() {
     let args = CheckArgs {
         max_lines: Some(1),
         warn_only: true,
+        as_of: None,
         ..default_check_args()
     };
 
@@ -111,6 +116,7 @@ fn run_check_impl_warn_only_overrides_strict() {
     let args = CheckArgs {
         max_lines: Some(1),
         warn_only: true,
+        as_of: None,
         strict: true,
         ..default_check_args()
     };
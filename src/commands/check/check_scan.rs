@@ -3,9 +3,10 @@ use std::path::{Path, PathBuf};
 use crate::checker::CheckResult;
 use crate::cli::{CheckArgs, Cli};
 use crate::commands::context::{CheckContext, FileProcessError, resolve_scan_paths};
+use crate::git::StagedIndex;
 use crate::scanner::ScanResult;
 
-use super::check_git_diff::filter_by_git_diff;
+use super::check_git_diff::{DiffRange, filter_by_git_diff};
 use super::check_processing::CheckFileResult;
 
 /// Scan directories or filter provided files based on mode.
@@ -14,14 +15,35 @@ use super::check_processing::CheckFileResult;
 /// - List of files to process
 /// - Optional scan result with directory stats (None in --files mode)
 /// - Whether to skip structure checks (true in --files mode)
+///
+/// With `staged_index` (--staged), the index tree is scanned instead of the working
+/// directory and only files staged against HEAD are returned. With `diff_range`
+/// (--diff), only files changed in the range are returned, and with --changed only
+/// files that differ from HEAD in the working directory.
 pub fn scan_or_filter_files(
     args: &CheckArgs,
     cli: &Cli,
     paths: &[PathBuf],
     ctx: &CheckContext,
     project_root: &Path,
+    staged_index: Option<&StagedIndex>,
+    diff_range: Option<&DiffRange>,
 ) -> crate::Result<(Vec<PathBuf>, Option<ScanResult>, bool)> {
-    if args.files.is_empty() {
+    if let Some(index) = staged_index {
+        let paths_to_scan = resolve_scan_paths(paths, &args.include);
+        let scan_result = ctx.scanner.scan_tracked_with_structure(
+            &paths_to_scan,
+            index,
+            ctx.structure_scan_config.as_ref(),
+        )?;
+        let files = scan_result
+            .files
+            .iter()
+            .filter(|file| index.is_staged(file))
+            .cloned()
+            .collect();
+        Ok((files, Some(scan_result), false))
+    } else if args.files.is_empty() {
         // Normal mode: scan directories
         // 1. Determine paths to scan
         let paths_to_scan = resolve_scan_paths(paths, &args.include);
@@ -34,8 +56,9 @@ pub fn scan_or_filter_files(
         // 2.1 Filter by git diff if --diff or --staged is specified
         let files = filter_by_git_diff(
             scan_result.files.clone(),
-            args.diff.as_deref(),
+            diff_range,
             args.staged,
+            args.changed,
             project_root,
         )?;
         Ok((files, Some(scan_result), false))
@@ -1,7 +1,9 @@
 mod check_args;
 mod check_baseline_ops;
 mod check_exit;
+mod check_expired;
 mod check_git_diff;
+mod check_git_tree;
 mod check_output;
 mod check_processing;
 mod check_scan;
@@ -12,19 +14,23 @@ pub use runner::run_check;
 
 // Re-export internal items for tests
 #[cfg(test)]
-pub(crate) use check_args::{apply_cli_overrides, validate_and_resolve_paths};
+pub(crate) use check_args::{
+    apply_cli_overrides, resolve_structure_scope, validate_and_resolve_paths,
+};
 #[cfg(test)]
 pub(crate) use check_baseline_ops::{
     apply_baseline_comparison, check_baseline_ratchet, is_structure_violation, load_baseline,
     parse_structure_violation,
 };
 #[cfg(test)]
-pub(crate) use check_git_diff::{DiffRange, parse_diff_range};
+pub(crate) use check_expired::{expired_exemption_results, locate_rule_line};
+#[cfg(test)]
+pub(crate) use check_git_diff::{ChangedDirs, DiffRange, parse_diff_range};
 #[cfg(test)]
 pub(crate) use check_output::{format_output, structure_violation_to_check_result};
 #[cfg(test)]
 pub(crate) use check_processing::{
-    CheckFileResult, compute_effective_stats, process_file_for_check,
+    CheckFileResult, compute_effective_stats, compute_metric_stats, process_file_for_check,
 };
 #[cfg(test)]
 pub(crate) use runner::{CheckOptions, run_check_impl, run_check_with_context};
@@ -34,6 +40,8 @@ mod check_baseline_tests;
 #[cfg(test)]
 mod check_context_structure_tests;
 #[cfg(test)]
+mod check_expired_tests;
+#[cfg(test)]
 mod check_git_diff_tests;
 #[cfg(test)]
 mod check_output_tests;
@@ -1,27 +1,31 @@
 use std::path::{Path, PathBuf};
-use std::sync::Mutex;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
 
 use rayon::prelude::*;
 
-use crate::analyzer::generate_split_suggestions;
+use crate::analyzer::{generate_reorganize_suggestions, generate_split_suggestions};
 use crate::baseline::Baseline;
 use crate::cache::{Cache, compute_config_hash};
-use crate::checker::CheckResult;
-use crate::cli::{CheckArgs, Cli};
-use crate::config::{FetchPolicy, collect_expired_rules};
+use crate::checker::{CheckResult, StructureViolation};
+use crate::cli::{CheckArgs, Cli, StructureScope};
+use crate::config::{ExpiredRule, FetchPolicy, collect_expired_rules_with_date};
+use crate::git::StagedIndex;
 use crate::output::{
     OutputFormat, ProjectStatistics, ScanProgress, StatsFormatter, StatsJsonFormatter,
 };
 use crate::state;
 use crate::{EXIT_CONFIG_ERROR, EXIT_SUCCESS};
 
-use super::check_args::{apply_cli_overrides, validate_and_resolve_paths};
+use super::check_args::{apply_cli_overrides, resolve_structure_scope, validate_and_resolve_paths};
 use super::check_baseline_ops::{
     apply_baseline_comparison, handle_baseline_ratchet, load_baseline, load_baseline_optional,
     update_baseline_from_results,
 };
 use super::check_exit::determine_exit_code;
+use super::check_expired::{expired_exemption_results, report_expired_rules, resolve_as_of};
+use super::check_git_diff::{ChangedDirs, resolve_diff_range};
+use super::check_git_tree::use_revision_tree;
 use super::check_output::{
     format_output, structure_violation_to_check_result, write_additional_formats,
 };
@@ -29,10 +33,11 @@ use super::check_processing::process_file_for_check;
 use super::check_scan::{partition_file_results, scan_or_filter_files};
 use super::check_snapshot::perform_auto_snapshot;
 use crate::commands::context::{
-    CheckContext, color_choice_to_mode, load_cache, load_config, print_preset_info, save_cache,
-    write_output,
+    CheckContext, FileReader, GitTreeReader, color_choice_to_mode, load_cache, load_config,
+    print_preset_info, save_cache, write_output,
 };
 use crate::output::ColorMode;
+use crate::scanner::ScanResult;
 
 /// Options for running a check with injected context.
 ///
@@ -46,6 +51,10 @@ pub struct CheckOptions<'a> {
     pub ctx: &'a CheckContext,
     pub cache: &'a Mutex<Cache>,
     pub baseline: Option<&'a Baseline>,
+    /// Rules past their `expires` date; `on_expire = "fail"` ones become violations.
+    pub expired_rules: &'a [ExpiredRule],
+    /// Config file the configuration was loaded from, which expired rules point at.
+    pub config_path: Option<&'a Path>,
     pub project_root: &'a Path,
 }
 
@@ -149,22 +158,10 @@ pub fn run_check_impl(args: &CheckArgs, cli: &Cli) -> crate::Result<i32> {
     // 2. Apply CLI argument overrides
     apply_cli_overrides(&mut config, args);
 
-    // 2.1 Check for expired rules and emit warnings
-    let expired_rules = collect_expired_rules(&config);
-    for expired in &expired_rules {
-        let reason_suffix = expired
-            .reason
-            .as_ref()
-            .map_or(String::new(), |r| format!(" (reason: {r})"));
-        crate::output::print_warning_full(
-            &format!(
-                "{}.rules[{}] (pattern: '{}') expired on {}{}",
-                expired.rule_type, expired.index, expired.pattern, expired.expires, reason_suffix
-            ),
-            None,
-            Some("Update the expiration date or remove the rule"),
-        );
-    }
+    // 2.1 Check for expired rules (as of --as-of or today) and emit warnings
+    let as_of = resolve_as_of(args)?;
+    let expired_rules = collect_expired_rules_with_date(&config, as_of);
+    report_expired_rules(&expired_rules);
 
     // 3. Load baseline if specified (allow non-existent if update-baseline is specified)
     let baseline = if args.update_baseline.is_some() {
@@ -194,7 +191,18 @@ pub fn run_check_impl(args: &CheckArgs, cli: &Cli) -> crate::Result<i32> {
     let mut exclude_patterns = config.scanner.exclude.clone();
     exclude_patterns.extend(args.exclude.clone());
     let use_gitignore = config.scanner.gitignore && !args.no_gitignore;
-    let ctx = CheckContext::from_config(&config, warn_threshold, exclude_patterns, use_gitignore)?;
+    let mut ctx = CheckContext::from_config_as_of(
+        &config,
+        warn_threshold,
+        exclude_patterns.clone(),
+        use_gitignore,
+        as_of,
+    )?;
+
+    // 4.1 Scan and read the tree of --rev instead of the working directory
+    if let Some(ref rev) = args.rev {
+        use_revision_tree(&mut ctx, rev, &project_root, exclude_patterns)?;
+    }
 
     // 5. Run check with context
     let options = CheckOptions {
@@ -205,11 +213,74 @@ pub fn run_check_impl(args: &CheckArgs, cli: &Cli) -> crate::Result<i32> {
         ctx: &ctx,
         cache: &cache,
         baseline: baseline.as_ref(),
+        expired_rules: &expired_rules,
+        config_path: load_result.config_path.as_deref(),
         project_root: &project_root,
     };
     run_check_with_context(&options)
 }
 
+/// Attach `--suggest` split and reorganisation suggestions to failures and warnings.
+fn attach_suggestions(
+    ctx: &CheckContext,
+    results: &mut [CheckResult],
+    scan_result: Option<&ScanResult>,
+) {
+    generate_split_suggestions(results, &ctx.registry);
+    if let Some(scan_result) = scan_result {
+        generate_reorganize_suggestions(
+            results,
+            scan_result,
+            ctx.structure_scan_config.as_ref(),
+            ctx.structure_checker.as_ref(),
+        );
+    }
+}
+
+/// Read the index for `--staged`, warning about files with unresolved merge conflicts.
+fn open_staged_index(project_root: &Path, quiet: bool) -> crate::Result<StagedIndex> {
+    let index = StagedIndex::open(project_root)?;
+    if !quiet && !index.conflicted().is_empty() {
+        let files: Vec<_> = index
+            .conflicted()
+            .iter()
+            .map(|path| path.display().to_string())
+            .collect();
+        crate::output::print_warning_full(
+            &format!(
+                "{} file(s) with merge conflicts are not checked",
+                files.len()
+            ),
+            Some(&files.join(", ")),
+            Some("Resolve the conflicts and stage the files"),
+        );
+    }
+    Ok(index)
+}
+
+/// Structure violations for a completed scan.
+///
+/// Allowlist/denylist violations are produced by the structure-aware scanner based on:
+/// - global allow/deny settings in [structure]
+/// - per-rule allow/deny/naming settings in [[structure.rules]]
+///
+/// They must be reported even when no directory count limits are configured.
+/// Count limits and missing siblings use the `dir_stats` and files of the same scan.
+fn collect_structure_violations(
+    ctx: &CheckContext,
+    scan_result: &ScanResult,
+) -> Vec<StructureViolation> {
+    let mut violations = scan_result.allowlist_violations.clone();
+    if let Some(ref structure_checker) = ctx.structure_checker
+        && structure_checker.is_enabled()
+    {
+        violations.extend(structure_checker.check(&scan_result.dir_stats));
+        // Check for missing sibling files (co-location enforcement)
+        violations.extend(structure_checker.check_siblings(&scan_result.files));
+    }
+    violations
+}
+
 /// Internal implementation accepting injectable context (for testing).
 ///
 /// This function contains the core check logic and accepts pre-built dependencies,
@@ -225,9 +296,43 @@ pub fn run_check_with_context(opts: &CheckOptions<'_>) -> crate::Result<i32> {
     let baseline = opts.baseline;
     let project_root = opts.project_root;
 
+    let structure_scope = resolve_structure_scope(args)?;
+
+    // --staged checks the index: its tree for structure, its blobs for content
+    let staged_index = if args.staged && args.files.is_empty() {
+        Some(open_staged_index(project_root, cli.quiet)?)
+    } else {
+        None
+    };
+    let staged_reader = staged_index
+        .as_ref()
+        .map(|index| GitTreeReader::new(Arc::clone(index.tree())));
+    let file_reader: &dyn FileReader = match &staged_reader {
+        Some(reader) => reader,
+        None => ctx.file_reader.as_ref(),
+    };
+
+    // --diff: the trees to compare, starting from the merge-base for `base...target`
+    let diff_range = match args.diff.as_deref() {
+        Some(diff_ref) if args.files.is_empty() => Some(resolve_diff_range(
+            diff_ref,
+            config.check.diff_merge_base,
+            project_root,
+            cli.verbose > 0 && !cli.quiet,
+        )?),
+        _ => None,
+    };
+
     // Scan or filter files based on mode
-    let (all_files, scan_result, skip_structure_checks) =
-        scan_or_filter_files(args, cli, paths, ctx, project_root)?;
+    let (all_files, scan_result, skip_structure_checks) = scan_or_filter_files(
+        args,
+        cli,
+        paths,
+        ctx,
+        project_root,
+        staged_index.as_ref(),
+        diff_range.as_ref(),
+    )?;
 
     // Determine fail_fast mode from CLI or config
     let fail_fast = args.fail_fast || config.check.fail_fast;
@@ -250,7 +355,7 @@ pub fn run_check_with_context(opts: &CheckOptions<'_>) -> crate::Result<i32> {
                 &ctx.registry,
                 &ctx.threshold_checker,
                 cache,
-                ctx.file_reader.as_ref(),
+                file_reader,
             );
             progress.inc();
 
@@ -275,49 +380,30 @@ pub fn run_check_with_context(opts: &CheckOptions<'_>) -> crate::Result<i32> {
         }
     }
 
-    // 4. Merge allowlist/denylist violations collected during scan
-    //
-    // These violations are produced by the structure-aware scanner based on:
-    // - global allow/deny settings in [structure]
-    // - per-rule allow/deny/naming settings in [[structure.rules]]
-    //
-    // They must be reported even when no directory count limits are configured.
-    if !skip_structure_checks
-        && let Some(ref scan_result) = scan_result
-        && !scan_result.allowlist_violations.is_empty()
-    {
-        let allowlist_results: Vec<_> = scan_result
-            .allowlist_violations
-            .iter()
-            .map(structure_violation_to_check_result)
-            .collect();
-        results.extend(allowlist_results);
-    }
-
-    // 5. Run structure checks if enabled (using pre-collected dir_stats from unified scan)
+    // 4. Merge structure violations: those collected during the scan and those from
+    // the structure checker, limited to changed directories with --structure-scope changed.
     // Skip in pure incremental mode (--files) since no directory scan was performed
-    if !skip_structure_checks
-        && let Some(ref scan_result) = scan_result
-        && let Some(ref structure_checker) = ctx.structure_checker
-        && structure_checker.is_enabled()
-    {
-        // Use dir_stats collected during unified scan
-        let violations = structure_checker.check(&scan_result.dir_stats);
-        let structure_results: Vec<_> = violations
-            .iter()
-            .map(structure_violation_to_check_result)
-            .collect();
-        results.extend(structure_results);
-
-        // Check for missing sibling files (co-location enforcement)
-        let sibling_violations = structure_checker.check_siblings(&scan_result.files);
-        let sibling_results: Vec<_> = sibling_violations
-            .iter()
-            .map(structure_violation_to_check_result)
-            .collect();
-        results.extend(sibling_results);
+    if !skip_structure_checks && let Some(ref scan_result) = scan_result {
+        let mut violations = collect_structure_violations(ctx, scan_result);
+        if structure_scope == StructureScope::Changed {
+            let changed_dirs = ChangedDirs::from_git(
+                diff_range.as_ref(),
+                args.staged,
+                args.changed,
+                project_root,
+            )?;
+            violations.retain(|violation| changed_dirs.contains(violation));
+        }
+        results.extend(violations.iter().map(structure_violation_to_check_result));
     }
 
+    // 5.1 Report `on_expire = "fail"` rules as violations against the config file
+    results.extend(expired_exemption_results(
+        opts.expired_rules,
+        config,
+        opts.config_path,
+    ));
+
     // 6. Save cache if not disabled (errors are non-critical)
     if !args.no_sloc_cache
         && let Ok(cache_guard) = cache.lock()
@@ -358,9 +444,10 @@ pub fn run_check_with_context(opts: &CheckOptions<'_>) -> crate::Result<i32> {
         )?;
     }
 
-    // 7.1 Generate split suggestions for failed files if --suggest is enabled
+    // 7.1 Generate split suggestions for failed files and reorganisation suggestions for
+    // crowded directories if --suggest is enabled
     if args.suggest {
-        generate_split_suggestions(&mut results, &ctx.registry);
+        attach_suggestions(ctx, &mut results, scan_result.as_ref());
     }
 
     // 7.2 Build project statistics for report-json, HTML charts, or auto-snapshot
@@ -390,8 +477,10 @@ pub fn run_check_with_context(opts: &CheckOptions<'_>) -> crate::Result<i32> {
         determine_exit_code(&results, args.warn_only, warnings_as_errors, ratchet_failed);
 
     // 11. Auto-snapshot on successful check if enabled
+    // (a --rev check is not a snapshot of the current tree)
     if exit_code == EXIT_SUCCESS
         && auto_snapshot_enabled
+        && args.rev.is_none()
         && let Some(ref stats) = project_stats
     {
         perform_auto_snapshot(stats, config, project_root, cli.quiet, cli.verbose);
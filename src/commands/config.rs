@@ -3,7 +3,8 @@ use std::path::Path;
 
 use crate::cli::{Cli, ConfigAction, ConfigOutputFormat};
 use crate::config::{
-    Config, ConfigLoader, FetchPolicy, FileConfigLoader, validate_config_semantics,
+    Config, ConfigLoader, ContentConfig, ContentRule, DenyEmptyDirs, DenySymlinks, FetchPolicy,
+    FileConfigLoader, RuleResolution, StructureConfig, validate_config_semantics,
 };
 use crate::{EXIT_CONFIG_ERROR, EXIT_SUCCESS, Result, SlocGuardError};
 
@@ -128,79 +129,23 @@ pub(crate) fn format_config_text(config: &Config) -> String {
 
     output.push_str("=== Effective Configuration ===\n\n");
 
+    if config.rule_resolution != RuleResolution::default() {
+        let _ = writeln!(output, "rule_resolution = \"{}\"\n", config.rule_resolution);
+    }
+
     // Scanner section
     output.push_str("[scanner]\n");
     let _ = writeln!(output, "  gitignore = {}", config.scanner.gitignore);
     if !config.scanner.exclude.is_empty() {
         let _ = writeln!(output, "  exclude = {:?}", config.scanner.exclude);
     }
-
-    // Content section
-    output.push_str("\n[content]\n");
-    let _ = writeln!(output, "  max_lines = {}", config.content.max_lines);
-    let _ = writeln!(output, "  extensions = {:?}", config.content.extensions);
-    let _ = writeln!(output, "  skip_comments = {}", config.content.skip_comments);
-    let _ = writeln!(output, "  skip_blank = {}", config.content.skip_blank);
-    let _ = writeln!(
-        output,
-        "  warn_threshold = {}",
-        config.content.warn_threshold
-    );
-    if let Some(warn_at) = config.content.warn_at {
-        let _ = writeln!(output, "  warn_at = {warn_at}");
-    }
-    if !config.content.exclude.is_empty() {
-        let _ = writeln!(output, "  exclude = {:?}", config.content.exclude);
+    if config.scanner.follow_symlinks {
+        output.push_str("  follow_symlinks = true\n");
     }
 
-    // Content rules
-    if !config.content.rules.is_empty() {
-        output.push('\n');
-        for (i, rule) in config.content.rules.iter().enumerate() {
-            let _ = writeln!(output, "[[content.rules]]  # rule {i}");
-            let _ = writeln!(output, "  pattern = \"{}\"", rule.pattern);
-            let _ = writeln!(output, "  max_lines = {}", rule.max_lines);
-            if let Some(warn_threshold) = rule.warn_threshold {
-                let _ = writeln!(output, "  warn_threshold = {warn_threshold}");
-            }
-            if let Some(warn_at) = rule.warn_at {
-                let _ = writeln!(output, "  warn_at = {warn_at}");
-            }
-            if let Some(skip_comments) = rule.skip_comments {
-                let _ = writeln!(output, "  skip_comments = {skip_comments}");
-            }
-            if let Some(skip_blank) = rule.skip_blank {
-                let _ = writeln!(output, "  skip_blank = {skip_blank}");
-            }
-            if let Some(reason) = &rule.reason {
-                let _ = writeln!(output, "  reason = \"{reason}\"");
-            }
-            if let Some(expires) = &rule.expires {
-                let _ = writeln!(output, "  expires = \"{expires}\"");
-            }
-        }
-    }
+    write_content_section(&mut output, &config.content);
 
-    // Structure section (if configured)
-    if config.structure.max_files.is_some()
-        || config.structure.max_dirs.is_some()
-        || config.structure.max_depth.is_some()
-        || !config.structure.rules.is_empty()
-    {
-        output.push_str("\n[structure]\n");
-        if let Some(max_files) = config.structure.max_files {
-            let _ = writeln!(output, "  max_files = {max_files}");
-        }
-        if let Some(max_dirs) = config.structure.max_dirs {
-            let _ = writeln!(output, "  max_dirs = {max_dirs}");
-        }
-        if let Some(max_depth) = config.structure.max_depth {
-            let _ = writeln!(output, "  max_depth = {max_depth}");
-        }
-        if let Some(warn_threshold) = config.structure.warn_threshold {
-            let _ = writeln!(output, "  warn_threshold = {warn_threshold}");
-        }
-    }
+    write_structure_section(&mut output, &config.structure);
 
     // Stats section (if configured)
     let report = &config.stats.report;
@@ -225,7 +170,7 @@ pub(crate) fn format_config_text(config: &Config) -> String {
     }
 
     // Check section (if non-default)
-    if config.check.warnings_as_errors || config.check.fail_fast {
+    if config.check.warnings_as_errors || config.check.fail_fast || config.check.diff_merge_base {
         output.push_str("\n[check]\n");
         if config.check.warnings_as_errors {
             let _ = writeln!(output, "  warnings_as_errors = true");
@@ -233,11 +178,183 @@ pub(crate) fn format_config_text(config: &Config) -> String {
         if config.check.fail_fast {
             let _ = writeln!(output, "  fail_fast = true");
         }
+        if config.check.diff_merge_base {
+            let _ = writeln!(output, "  diff_merge_base = true");
+        }
     }
 
     output
 }
 
+/// Write the `[structure]` section, if anything in it is configured.
+fn write_structure_section(output: &mut String, structure: &StructureConfig) {
+    use std::fmt::Write;
+
+    if structure.max_files.is_some()
+        || structure.max_dirs.is_some()
+        || structure.max_depth.is_some()
+        || structure.max_total_files.is_some()
+        || structure.max_total_dirs.is_some()
+        || !structure.max_files_by_ext.is_empty()
+        || structure.max_path_length.is_some()
+        || structure.max_filename_length.is_some()
+        || structure.max_path_components.is_some()
+        || structure.max_file_bytes.is_some()
+        || structure.detect_case_collisions
+        || structure.detect_invalid_windows_names
+        || structure.deny_empty_dirs.is_enabled()
+        || structure.deny_symlinks.is_enabled()
+        || !structure.rules.is_empty()
+        || !structure.layouts.is_empty()
+    {
+        output.push_str("\n[structure]\n");
+        if let Some(max_files) = structure.max_files {
+            let _ = writeln!(output, "  max_files = {max_files}");
+        }
+        if let Some(max_dirs) = structure.max_dirs {
+            let _ = writeln!(output, "  max_dirs = {max_dirs}");
+        }
+        if let Some(max_depth) = structure.max_depth {
+            let _ = writeln!(output, "  max_depth = {max_depth}");
+        }
+        if let Some(max_total_files) = structure.max_total_files {
+            let _ = writeln!(output, "  max_total_files = {max_total_files}");
+        }
+        if let Some(max_total_dirs) = structure.max_total_dirs {
+            let _ = writeln!(output, "  max_total_dirs = {max_total_dirs}");
+        }
+        if !structure.max_files_by_ext.is_empty() {
+            let _ = writeln!(
+                output,
+                "  max_files_by_ext = {:?}",
+                structure.max_files_by_ext
+            );
+        }
+        if let Some(max_path_length) = structure.max_path_length {
+            let _ = writeln!(output, "  max_path_length = {max_path_length}");
+        }
+        if let Some(max_filename_length) = structure.max_filename_length {
+            let _ = writeln!(output, "  max_filename_length = {max_filename_length}");
+        }
+        if let Some(max_path_components) = structure.max_path_components {
+            let _ = writeln!(output, "  max_path_components = {max_path_components}");
+        }
+        if let Some(max_file_bytes) = structure.max_file_bytes {
+            let _ = writeln!(output, "  max_file_bytes = {max_file_bytes}");
+        }
+        if !structure.allow_large_files.is_empty() {
+            let _ = writeln!(
+                output,
+                "  allow_large_files = {:?}",
+                structure.allow_large_files
+            );
+        }
+        if structure.detect_case_collisions {
+            output.push_str("  detect_case_collisions = true\n");
+        }
+        if structure.detect_invalid_windows_names {
+            output.push_str("  detect_invalid_windows_names = true\n");
+        }
+        match structure.deny_empty_dirs {
+            DenyEmptyDirs::Off => {}
+            DenyEmptyDirs::Empty => output.push_str("  deny_empty_dirs = true\n"),
+            DenyEmptyDirs::IgnoreCountExclude => {
+                output.push_str("  deny_empty_dirs = \"ignore_count_exclude\"\n");
+            }
+        }
+        match structure.deny_symlinks {
+            DenySymlinks::Off => {}
+            DenySymlinks::All => output.push_str("  deny_symlinks = true\n"),
+            DenySymlinks::External => output.push_str("  deny_symlinks = \"external\"\n"),
+        }
+        if let Some(warn_threshold) = structure.warn_threshold {
+            let _ = writeln!(output, "  warn_threshold = {warn_threshold}");
+        }
+    }
+}
+
+fn write_content_section(output: &mut String, content: &ContentConfig) {
+    use std::fmt::Write;
+
+    output.push_str("\n[content]\n");
+    let _ = writeln!(output, "  max_lines = {}", content.max_lines);
+    let _ = writeln!(output, "  extensions = {:?}", content.extensions);
+    let _ = writeln!(output, "  skip_comments = {}", content.skip_comments);
+    let _ = writeln!(output, "  skip_blank = {}", content.skip_blank);
+    if let Some(metric) = content.metric {
+        let _ = writeln!(output, "  metric = \"{metric}\"");
+    }
+    let _ = writeln!(output, "  warn_threshold = {}", content.warn_threshold);
+    if let Some(warn_at) = content.warn_at {
+        let _ = writeln!(output, "  warn_at = {warn_at}");
+    }
+    if !content.exclude.is_empty() {
+        let _ = writeln!(output, "  exclude = {:?}", content.exclude);
+    }
+
+    if !content.rules.is_empty() {
+        output.push('\n');
+        for (i, rule) in content.rules.iter().enumerate() {
+            write_content_rule(output, i, rule);
+        }
+    }
+}
+
+fn write_content_rule(output: &mut String, index: usize, rule: &ContentRule) {
+    use std::fmt::Write;
+
+    let _ = writeln!(output, "[[content.rules]]  # rule {index}");
+    if !rule.pattern.is_empty() {
+        let _ = writeln!(output, "  pattern = \"{}\"", rule.pattern);
+    }
+    for (key, values) in [
+        ("patterns", &rule.patterns),
+        ("exclude_patterns", &rule.exclude_patterns),
+        ("languages", &rule.languages),
+    ] {
+        if !values.is_empty() {
+            let _ = writeln!(output, "  {key} = {values:?}");
+        }
+    }
+    let _ = writeln!(output, "  max_lines = {}", rule.max_lines);
+    if let Some(warn_threshold) = rule.warn_threshold {
+        let _ = writeln!(output, "  warn_threshold = {warn_threshold}");
+    }
+    if let Some(warn_at) = rule.warn_at {
+        let _ = writeln!(output, "  warn_at = {warn_at}");
+    }
+    if let Some(skip_comments) = rule.skip_comments {
+        let _ = writeln!(output, "  skip_comments = {skip_comments}");
+    }
+    if let Some(skip_blank) = rule.skip_blank {
+        let _ = writeln!(output, "  skip_blank = {skip_blank}");
+    }
+    if let Some(metric) = rule.metric {
+        let _ = writeln!(output, "  metric = \"{metric}\"");
+    }
+    if let Some(min_comment_ratio) = rule.min_comment_ratio {
+        let _ = writeln!(output, "  min_comment_ratio = {min_comment_ratio}");
+    }
+    if let Some(max_comment_ratio) = rule.max_comment_ratio {
+        let _ = writeln!(output, "  max_comment_ratio = {max_comment_ratio}");
+    }
+    if let Some(min_code) = rule.comment_ratio_min_code {
+        let _ = writeln!(output, "  comment_ratio_min_code = {min_code}");
+    }
+    if let Some(priority) = rule.priority {
+        let _ = writeln!(output, "  priority = {priority}");
+    }
+    if let Some(reason) = &rule.reason {
+        let _ = writeln!(output, "  reason = \"{reason}\"");
+    }
+    if let Some(expires) = &rule.expires {
+        let _ = writeln!(output, "  expires = \"{expires}\"");
+    }
+    if let Some(on_expire) = rule.on_expire {
+        let _ = writeln!(output, "  on_expire = \"{on_expire}\"");
+    }
+}
+
 #[cfg(test)]
 #[path = "config_tests/mod.rs"]
 mod tests;
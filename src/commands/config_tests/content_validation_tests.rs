@@ -112,13 +112,22 @@ fn rule_warn_at_greater_than_rule_max_lines() {
         content: ContentConfig {
             rules: vec![ContentRule {
                 pattern: "**/*.rs".to_string(),
+                patterns: vec![],
+                exclude_patterns: vec![],
+                languages: vec![],
                 max_lines: 300,
                 warn_at: Some(400), // warn_at > max_lines is invalid
                 warn_threshold: None,
                 skip_comments: None,
                 skip_blank: None,
+                metric: None,
+                min_comment_ratio: None,
+                max_comment_ratio: None,
+                comment_ratio_min_code: None,
+                priority: None,
                 reason: None,
                 expires: None,
+                on_expire: None,
             }],
             ..Default::default()
         },
@@ -138,13 +147,22 @@ fn rule_warn_at_less_than_rule_max_lines_is_valid() {
         content: ContentConfig {
             rules: vec![ContentRule {
                 pattern: "**/*.rs".to_string(),
+                patterns: vec![],
+                exclude_patterns: vec![],
+                languages: vec![],
                 max_lines: 300,
                 warn_at: Some(250), // warn_at < max_lines is valid
                 warn_threshold: None,
                 skip_comments: None,
                 skip_blank: None,
+                metric: None,
+                min_comment_ratio: None,
+                max_comment_ratio: None,
+                comment_ratio_min_code: None,
+                priority: None,
                 reason: None,
                 expires: None,
+                on_expire: None,
             }],
             ..Default::default()
         },
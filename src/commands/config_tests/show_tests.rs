@@ -93,13 +93,22 @@ fn format_config_text_includes_all_sections() {
     let mut config = Config::default();
     config.content.rules.push(ContentRule {
         pattern: "**/*.rs".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
         max_lines: 300,
         warn_threshold: Some(0.85),
         warn_at: None,
         skip_comments: Some(true),
         skip_blank: None,
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
         reason: Some("Rust files".to_string()),
         expires: None,
+        on_expire: None,
     });
     config.scanner.exclude = vec!["**/target/**".to_string()];
 
@@ -117,11 +126,13 @@ fn format_config_text_shows_check_section() {
     let mut config = Config::default();
     config.check.warnings_as_errors = true;
     config.check.fail_fast = true;
+    config.check.diff_merge_base = true;
 
     let output = format_config_text(&config);
     assert!(output.contains("[check]"));
     assert!(output.contains("warnings_as_errors = true"));
     assert!(output.contains("fail_fast = true"));
+    assert!(output.contains("diff_merge_base = true"));
 }
 
 #[test]
@@ -3,7 +3,7 @@ use std::fmt;
 use std::fs;
 use std::io;
 use std::path::{Path, PathBuf};
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 
 use sha2::{Digest, Sha256};
 
@@ -11,14 +11,20 @@ use crate::cache::Cache;
 use crate::checker::{StructureChecker, ThresholdChecker};
 use crate::cli::ColorChoice;
 use crate::config::{
-    Config, ConfigLoader, FetchPolicy, FileConfigLoader, LoadResult, validate_config_semantics,
+    Config, ConfigLoader, FetchPolicy, FileConfigLoader, LoadResult, ParsedDate,
+    validate_config_semantics,
 };
 use crate::counter::{CountResult, LineStats, SlocCounter};
+use crate::git::GitTree;
 use crate::language::LanguageRegistry;
 use crate::output::ColorMode;
-use crate::scanner::{AllowlistRuleBuilder, CompositeScanner, FileScanner, StructureScanConfig};
+use crate::scanner::{
+    CompositeScanner, FileScanner, StructureScanConfig, TrackedTree, absolute_path,
+};
 use crate::state;
 
+use super::structure_scan::build_structure_scan_config;
+
 // =============================================================================
 // File Processing Error Types
 // =============================================================================
@@ -134,6 +140,7 @@ pub(crate) fn load_config(
         return Ok(LoadResult {
             config: Config::default(),
             preset_used: None,
+            config_path: None,
         });
     }
 
@@ -275,26 +282,35 @@ pub fn process_file_with_cache(
 
     let path_key = file_path.to_string_lossy().replace('\\', "/");
 
-    // Get file metadata for fast cache validation
-    let (mtime, size) = match reader.metadata(file_path) {
-        Ok(meta) => meta,
-        Err(source) => {
-            return FileProcessResult::Error(FileProcessError::MetadataError {
-                path: file_path.to_path_buf(),
-                source,
-            });
+    // Content-addressed readers are validated by content id, others by metadata
+    let content_id = reader.content_id(file_path);
+    let (mtime, size) = if content_id.is_some() {
+        (0, 0)
+    } else {
+        match reader.metadata(file_path) {
+            Ok(meta) => meta,
+            Err(source) => {
+                return FileProcessResult::Error(FileProcessError::MetadataError {
+                    path: file_path.to_path_buf(),
+                    source,
+                });
+            }
         }
     };
 
-    // Try to get stats from cache using metadata (no file read needed)
+    // Try to get stats from cache (no file read needed)
     let cached_stats = {
         let Ok(cache_guard) = cache.lock() else {
             return FileProcessResult::Error(FileProcessError::CacheLockError {
                 path: file_path.to_path_buf(),
             });
         };
-        cache_guard
-            .get_if_metadata_matches(&path_key, mtime, size)
+        content_id
+            .as_ref()
+            .map_or_else(
+                || cache_guard.get_if_metadata_matches(&path_key, mtime, size),
+                |id| cache_guard.get_if_valid(&path_key, id),
+            )
             .map(|entry| LineStats::from(&entry.stats))
     };
 
@@ -302,7 +318,11 @@ pub fn process_file_with_cache(
         stats
     } else {
         // Cache miss: read file, compute hash, and count lines
-        let (file_hash, content) = match read_file_with_hash_result(reader, file_path) {
+        let read = content_id.map_or_else(
+            || read_file_with_hash_result(reader, file_path),
+            |id| reader.read(file_path).map(|content| (id, content)),
+        );
+        let (file_hash, content) = match read {
             Ok(result) => result,
             Err(source) => {
                 return FileProcessResult::Error(FileProcessError::ReadError {
@@ -352,6 +372,12 @@ pub trait FileReader: Send + Sync {
     /// # Errors
     /// Returns an error if metadata cannot be retrieved.
     fn metadata(&self, path: &Path) -> io::Result<(u64, u64)>;
+
+    /// Identifier of the content `read` returns, when the source is content-addressed
+    /// (such as a git blob id). The cache is then validated by this id instead of metadata.
+    fn content_id(&self, _path: &Path) -> Option<String> {
+        None
+    }
 }
 
 /// Real filesystem implementation of `FileReader`.
@@ -375,6 +401,40 @@ impl FileReader for RealFileReader {
     }
 }
 
+/// Reads file content from a git tree instead of the working directory
+/// (`check --staged` reads the index, `check --rev` and `snapshot --backfill` a commit).
+///
+/// Content is identified by blob id, so cached stats stay valid for as long as the
+/// blob is unchanged.
+pub struct GitTreeReader {
+    tree: Arc<GitTree>,
+}
+
+impl GitTreeReader {
+    #[must_use]
+    pub const fn new(tree: Arc<GitTree>) -> Self {
+        Self { tree }
+    }
+}
+
+impl FileReader for GitTreeReader {
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        self.tree.read(path)
+    }
+
+    fn metadata(&self, path: &Path) -> io::Result<(u64, u64)> {
+        // Blobs have no mtime; the size is the blob's
+        self.tree
+            .file_size(&absolute_path(path))
+            .map(|size| (0, size))
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "file is not in the git tree"))
+    }
+
+    fn content_id(&self, path: &Path) -> Option<String> {
+        self.tree.blob_id(path).map(|id| id.to_string())
+    }
+}
+
 /// Read file contents and compute SHA-256 hash.
 ///
 /// Returns `None` on read error. For explicit error handling, use `read_file_with_hash_result`.
@@ -434,83 +494,54 @@ impl CheckContext {
         warn_threshold: f64,
         exclude_patterns: Vec<String>,
         use_gitignore: bool,
+    ) -> crate::Result<Self> {
+        Self::from_config_as_of(
+            config,
+            warn_threshold,
+            exclude_patterns,
+            use_gitignore,
+            ParsedDate::today(),
+        )
+    }
+
+    /// Create context from config, treating `as_of` as the current date for
+    /// `on_expire = "revert"` rules (`check --as-of`).
+    ///
+    /// # Errors
+    /// Returns error if structure checker or structure scan config initialization fails.
+    pub fn from_config_as_of(
+        config: &Config,
+        warn_threshold: f64,
+        exclude_patterns: Vec<String>,
+        use_gitignore: bool,
+        as_of: ParsedDate,
     ) -> crate::Result<Self> {
         let registry = LanguageRegistry::with_custom_languages(&config.languages);
-        let threshold_checker =
-            ThresholdChecker::new(config.clone())?.with_warning_threshold(warn_threshold);
-        let structure_checker = Some(StructureChecker::new(&config.structure)?);
+        let threshold_checker = ThresholdChecker::new(config.clone())?
+            .with_warning_threshold(warn_threshold)
+            .with_as_of(as_of);
+        let structure_checker = Some(
+            StructureChecker::new(&config.structure)?
+                .with_rule_resolution(config.rule_resolution)
+                .with_as_of(as_of),
+        );
 
         // Build structure scan config for unified traversal
-        let structure_scan_config = Self::build_structure_scan_config(config, &exclude_patterns)?;
+        let structure_scan_config = build_structure_scan_config(config, &exclude_patterns, as_of)?;
 
-        let scanner = Box::new(CompositeScanner::new(exclude_patterns, use_gitignore));
-        let file_reader = Box::new(RealFileReader);
+        let scanner = CompositeScanner::new(exclude_patterns, use_gitignore)
+            .with_follow_symlinks(config.scanner.follow_symlinks);
 
         Ok(Self {
             registry,
             threshold_checker,
             structure_checker,
             structure_scan_config,
-            scanner,
-            file_reader,
+            scanner: Box::new(scanner),
+            file_reader: Box::new(RealFileReader),
         })
     }
 
-    /// Build `StructureScanConfig` from config components.
-    fn build_structure_scan_config(
-        config: &Config,
-        exclude_patterns: &[String],
-    ) -> crate::Result<Option<StructureScanConfig>> {
-        // Only build if structure checking is enabled
-        if !config.structure.is_enabled() {
-            return Ok(None);
-        }
-
-        // Build allowlist rules from structure.rules
-        let mut allowlist_rules = Vec::new();
-        for rule in &config.structure.rules {
-            // Include rules that have allowlists, denylists, or naming patterns
-            if !rule.allow_extensions.is_empty()
-                || !rule.allow_patterns.is_empty()
-                || !rule.allow_files.is_empty()
-                || !rule.allow_dirs.is_empty()
-                || !rule.deny_extensions.is_empty()
-                || !rule.deny_patterns.is_empty()
-                || !rule.deny_files.is_empty()
-                || !rule.deny_dirs.is_empty()
-                || rule.file_naming_pattern.is_some()
-            {
-                let allowlist_rule = AllowlistRuleBuilder::new(rule.scope.clone())
-                    .with_extensions(rule.allow_extensions.clone())
-                    .with_patterns(rule.allow_patterns.clone())
-                    .with_allow_files(rule.allow_files.clone())
-                    .with_allow_dirs(rule.allow_dirs.clone())
-                    .with_deny_extensions(rule.deny_extensions.clone())
-                    .with_deny_patterns(rule.deny_patterns.clone())
-                    .with_deny_files(rule.deny_files.clone())
-                    .with_deny_dirs(rule.deny_dirs.clone())
-                    .with_naming_pattern(rule.file_naming_pattern.clone())
-                    .build()?;
-                allowlist_rules.push(allowlist_rule);
-            }
-        }
-
-        let structure_scan_config = StructureScanConfig::builder()
-            .count_exclude(config.structure.count_exclude.clone())
-            .scanner_exclude(exclude_patterns.to_vec())
-            .allowlist_rules(allowlist_rules)
-            .global_allow_extensions(config.structure.allow_extensions.clone())
-            .global_allow_files(config.structure.allow_files.clone())
-            .global_allow_dirs(config.structure.allow_dirs.clone())
-            .global_deny_extensions(config.structure.deny_extensions.clone())
-            .global_deny_patterns(config.structure.deny_patterns.clone())
-            .global_deny_files(config.structure.deny_files.clone())
-            .global_deny_dirs(config.structure.deny_dirs.clone())
-            .build()?;
-
-        Ok(Some(structure_scan_config))
-    }
-
     /// Create context with custom components (for testing).
     #[must_use]
     pub fn new(
@@ -654,3 +654,57 @@ fn process_file_with_cache_returns_success_for_valid_file() {
         other => panic!("expected Success, got {other:?}"),
     }
 }
+
+/// Content-addressed reader whose content changes without its metadata.
+struct BlobReader {
+    content: &'static str,
+    id: &'static str,
+}
+
+impl FileReader for BlobReader {
+    fn read(&self, _path: &std::path::Path) -> io::Result<Vec<u8>> {
+        Ok(self.content.as_bytes().to_vec())
+    }
+
+    fn metadata(&self, _path: &std::path::Path) -> io::Result<(u64, u64)> {
+        Err(io::Error::other("blobs have no metadata"))
+    }
+
+    fn content_id(&self, _path: &std::path::Path) -> Option<String> {
+        Some(self.id.to_string())
+    }
+}
+
+#[test]
+fn process_file_with_cache_keys_content_addressed_readers_on_content_id() {
+    let registry = LanguageRegistry::default();
+    let cache = Mutex::new(Cache::new(String::new()));
+    let path = std::path::Path::new("src/lib.rs");
+    let code_lines =
+        |reader: &BlobReader| match process_file_with_cache(path, &registry, &cache, reader) {
+            FileProcessResult::Success { stats, .. } => stats.code,
+            other => panic!("expected Success, got {other:?}"),
+        };
+
+    let first = BlobReader {
+        content: "fn a() {}\n",
+        id: "blob-1",
+    };
+    assert_eq!(code_lines(&first), 1);
+    assert_eq!(
+        cache.lock().unwrap().get("src/lib.rs").unwrap().hash,
+        "blob-1"
+    );
+
+    // Same id is served from the cache, a new id is counted again
+    let stale = BlobReader {
+        content: "fn a() {}\nfn b() {}\n",
+        id: "blob-1",
+    };
+    assert_eq!(code_lines(&stale), 1);
+    let updated = BlobReader {
+        content: "fn a() {}\nfn b() {}\n",
+        id: "blob-2",
+    };
+    assert_eq!(code_lines(&updated), 2);
+}
@@ -1,15 +1,17 @@
 use std::fmt::Write;
+use std::path::{Path, PathBuf};
 
 use crate::checker::{
-    ContentExplanation, ContentRuleMatch, MatchStatus, StructureChecker, StructureExplanation,
-    StructureRuleMatch, ThresholdChecker, WarnAtSource,
+    ContentExplanation, StructureChecker, StructureExplanation, SymlinkInfo, ThresholdChecker,
 };
 use crate::cli::{Cli, ExplainArgs, ExplainFormat};
 use crate::config::{ConfigLoader, FetchPolicy, FileConfigLoader, SourcedConfig};
 use crate::error::SlocGuardError;
+use crate::scanner::resolve_symlink;
 use crate::{EXIT_CONFIG_ERROR, EXIT_SUCCESS};
 
-use super::context::{load_config, print_preset_info};
+use super::context::{load_config, print_preset_info, resolve_project_root};
+use super::explain_text::{format_content_text, format_structure_text};
 
 #[must_use]
 pub fn run_explain(args: &ExplainArgs, cli: &Cli) -> i32 {
@@ -51,12 +53,20 @@ pub(crate) fn run_explain_impl(args: &ExplainArgs, cli: &Cli) -> crate::Result<(
 
     if path.is_file() {
         let checker = ThresholdChecker::new(config)?;
-        let explanation = checker.explain(path);
+        let mut explanation = checker.explain(path);
+        explanation.symlink = symlink_info(path);
         println!("{}", format_content_explanation(&explanation, args.format)?);
     } else if path.is_dir() {
-        match StructureChecker::new(&config.structure) {
-            Ok(checker) if checker.is_enabled() => {
-                let explanation = checker.explain(path);
+        let symlink = symlink_info(path);
+        // Scan-level options (deny_symlinks, path limits, ...) enable structure checks
+        // without count limits, and a symlink's target is worth showing either way
+        let structure_configured = config.structure.is_enabled() || symlink.is_some();
+        match StructureChecker::new(&config.structure)
+            .map(|checker| checker.with_rule_resolution(config.rule_resolution))
+        {
+            Ok(checker) if checker.is_enabled() || structure_configured => {
+                let mut explanation = checker.explain(path);
+                explanation.symlink = symlink;
                 println!(
                     "{}",
                     format_structure_explanation(&explanation, args.format)?
@@ -82,6 +92,20 @@ pub(crate) fn run_explain_impl(args: &ExplainArgs, cli: &Cli) -> crate::Result<(
     Ok(())
 }
 
+/// Where `path` points, if it is a symlink itself.
+pub(crate) fn symlink_info(path: &Path) -> Option<SymlinkInfo> {
+    // Drop a trailing separator: `link/` would be read through the link
+    let path: PathBuf = path.components().collect();
+    let target = std::fs::read_link(&path).ok()?;
+    let resolved = resolve_symlink(&path, &target);
+    let outside_project = !resolved.starts_with(resolve_project_root());
+    Some(SymlinkInfo {
+        target,
+        resolved,
+        outside_project,
+    })
+}
+
 /// Run explain --sources: show config inheritance chain and field sources.
 fn run_explain_sources(args: &ExplainArgs, cli: &Cli) -> crate::Result<()> {
     if cli.no_config {
@@ -131,197 +155,6 @@ fn format_structure_explanation(
     }
 }
 
-fn format_content_text(exp: &ContentExplanation) -> String {
-    let mut output = String::new();
-
-    let _ = writeln!(output, "Path: {}", exp.path.display());
-    output.push('\n');
-    output.push_str("Content Rules (SLOC Limits):\n");
-
-    // Show matched rule
-    match &exp.matched_rule {
-        ContentRuleMatch::Excluded { pattern } => {
-            let _ = writeln!(
-                output,
-                "  Status:  EXCLUDED (matches content.exclude pattern \"{pattern}\")"
-            );
-            output.push_str(
-                "  Note:    This file is excluded from SLOC counting but visible for structure checks.\n",
-            );
-            return output;
-        }
-        ContentRuleMatch::Rule {
-            index,
-            pattern,
-            reason,
-        } => {
-            let reason_str = reason
-                .as_ref()
-                .map(|r| format!(" (reason: {r})"))
-                .unwrap_or_default();
-            let _ = writeln!(
-                output,
-                "  Matched: [[content.rules]] index {index} pattern \"{pattern}\"{reason_str}"
-            );
-        }
-        ContentRuleMatch::Default => {
-            output.push_str("  Matched: [content] defaults\n");
-        }
-    }
-
-    let _ = writeln!(output, "  Limit:   {} lines", exp.effective_limit);
-
-    // Show warn_at with context based on source (Rule vs Global, absolute vs percentage)
-    let warn_at_str = match &exp.warn_at_source {
-        WarnAtSource::RuleAbsolute { index } => {
-            format!(
-                "{} lines (from content.rules[{index}], absolute)",
-                exp.effective_warn_at
-            )
-        }
-        WarnAtSource::RulePercentage { index, threshold } => {
-            format!(
-                "{} lines (from content.rules[{index}], {:.0}%)",
-                exp.effective_warn_at,
-                threshold * 100.0
-            )
-        }
-        WarnAtSource::GlobalAbsolute => {
-            format!("{} lines (from [content], absolute)", exp.effective_warn_at)
-        }
-        WarnAtSource::GlobalPercentage { threshold } => {
-            format!(
-                "{} lines (from [content], {:.0}%)",
-                exp.effective_warn_at,
-                threshold * 100.0
-            )
-        }
-    };
-    let _ = writeln!(output, "  Warn at: {warn_at_str}");
-
-    let _ = writeln!(
-        output,
-        "  Skip:    comments={}, blank={}",
-        exp.skip_comments, exp.skip_blank
-    );
-
-    output.push('\n');
-    output.push_str("  Rule Chain (evaluated high->low):\n");
-    for candidate in &exp.rule_chain {
-        let status_char = match candidate.status {
-            MatchStatus::Matched => "+",
-            MatchStatus::Superseded => "-",
-            MatchStatus::NoMatch => " ",
-        };
-        let pattern_str = candidate
-            .pattern
-            .as_ref()
-            .map_or(String::new(), |p| format!(" \"{p}\""));
-        let status_desc = match candidate.status {
-            MatchStatus::Matched => "(MATCHED)",
-            MatchStatus::Superseded => "(superseded)",
-            MatchStatus::NoMatch => "(no match)",
-        };
-        let _ = writeln!(
-            output,
-            "    [{status_char}] {}{} -> {} lines {status_desc}",
-            candidate.source, pattern_str, candidate.limit
-        );
-    }
-
-    output
-}
-
-fn format_structure_text(exp: &StructureExplanation) -> String {
-    let mut output = String::new();
-
-    let _ = writeln!(output, "Path: {}", exp.path.display());
-    output.push('\n');
-    output.push_str("Structure Rules (Directory Limits):\n");
-
-    // Show matched rule
-    match &exp.matched_rule {
-        StructureRuleMatch::Rule {
-            index,
-            pattern,
-            reason,
-        } => {
-            let reason_str = reason
-                .as_ref()
-                .map(|r| format!(" (reason: {r})"))
-                .unwrap_or_default();
-            let _ = writeln!(
-                output,
-                "  Matched: [[structure.rules]] index {index} pattern \"{pattern}\"{reason_str}"
-            );
-        }
-        StructureRuleMatch::Default => {
-            output.push_str("  Matched: [structure] defaults\n");
-        }
-    }
-
-    let max_files_str = format_limit(exp.effective_max_files);
-    let max_dirs_str = format_limit(exp.effective_max_dirs);
-    let max_depth_str = format_limit(exp.effective_max_depth);
-
-    let _ = writeln!(
-        output,
-        "  Limits:  max_files={max_files_str}, max_dirs={max_dirs_str}, max_depth={max_depth_str}"
-    );
-    let _ = writeln!(output, "  Warn at: {:.0}%", exp.warn_threshold * 100.0);
-
-    if let Some(reason) = &exp.override_reason {
-        let _ = writeln!(output, "  Reason:  {reason}");
-    }
-
-    output.push('\n');
-    output.push_str("  Rule Chain (evaluated high->low):\n");
-    for candidate in &exp.rule_chain {
-        let status_char = match candidate.status {
-            MatchStatus::Matched => "+",
-            MatchStatus::Superseded => "-",
-            MatchStatus::NoMatch => " ",
-        };
-        let pattern_str = candidate
-            .pattern
-            .as_ref()
-            .map_or(String::new(), |p| format!(" \"{p}\""));
-        let status_desc = match candidate.status {
-            MatchStatus::Matched => "(MATCHED)",
-            MatchStatus::Superseded => "(superseded)",
-            MatchStatus::NoMatch => "(no match)",
-        };
-        let files_str = candidate
-            .max_files
-            .map_or_else(|| "-".to_string(), |v| v.to_string());
-        let dirs_str = candidate
-            .max_dirs
-            .map_or_else(|| "-".to_string(), |v| v.to_string());
-        let depth_str = candidate
-            .max_depth
-            .map_or_else(|| "-".to_string(), |v| v.to_string());
-        let _ = writeln!(
-            output,
-            "    [{status_char}] {}{} -> files={files_str}, dirs={dirs_str}, depth={depth_str} {status_desc}",
-            candidate.source, pattern_str
-        );
-    }
-
-    output
-}
-
-/// Format an optional limit value for display.
-/// - `None` → "none" (no limit configured)
-/// - `Some(-1)` → "unlimited" (explicitly unlimited)
-/// - `Some(n)` → numeric string
-fn format_limit(value: Option<i64>) -> String {
-    match value {
-        None => "none".to_string(),
-        Some(-1) => "unlimited".to_string(),
-        Some(v) => v.to_string(),
-    }
-}
-
 fn format_json<T: serde::Serialize>(exp: &T) -> crate::Result<String> {
     Ok(serde_json::to_string_pretty(exp)?)
 }
@@ -348,12 +181,15 @@ fn format_json<T: serde::Serialize>(exp: &T) -> crate::Result<String> {
 /// will fail. However, adding new fields to Config won't cause test failures;
 /// update this list manually if new fields warrant inclusion in `--sources`.
 pub(crate) const KEY_FIELDS: &[(&str, &[&str])] = &[
+    // Top-level settings (Config)
+    ("rule_resolution", &["rule_resolution"]),
     // Content settings (ContentConfig)
     ("content.max_lines", &["content", "max_lines"]),
     ("content.extensions", &["content", "extensions"]),
     ("content.warn_threshold", &["content", "warn_threshold"]),
     ("content.skip_comments", &["content", "skip_comments"]),
     ("content.skip_blank", &["content", "skip_blank"]),
+    ("content.metric", &["content", "metric"]),
     // Structure settings (StructureConfig)
     ("structure.max_files", &["structure", "max_files"]),
     ("structure.max_dirs", &["structure", "max_dirs"]),
@@ -362,9 +198,11 @@ pub(crate) const KEY_FIELDS: &[(&str, &[&str])] = &[
     // Scanner settings (ScannerConfig)
     ("scanner.gitignore", &["scanner", "gitignore"]),
     ("scanner.exclude", &["scanner", "exclude"]),
+    ("scanner.follow_symlinks", &["scanner", "follow_symlinks"]),
     // Check settings (CheckConfig)
     ("check.warnings_as_errors", &["check", "warnings_as_errors"]),
     ("check.fail_fast", &["check", "fail_fast"]),
+    ("check.diff_merge_base", &["check", "diff_merge_base"]),
 ];
 
 /// Explanation of configuration inheritance chain.
@@ -4,7 +4,9 @@
 //! field source attribution, and helper functions for TOML value handling.
 
 use crate::cli::ExplainFormat;
-use crate::config::{CheckConfig, Config, ContentConfig, ScannerConfig, StructureConfig};
+use crate::config::{
+    CheckConfig, Config, ContentConfig, ContentMetric, ScannerConfig, StructureConfig,
+};
 
 use super::super::{
     ConfigExplanation, FieldWithSource, KEY_FIELDS, format_config_explanation, format_config_text,
@@ -426,6 +428,7 @@ fn key_fields_match_config_schema() {
             warn_threshold: 0.5,
             skip_comments: false,
             skip_blank: false,
+            metric: Some(ContentMetric::Total),
             ..Default::default()
         },
         structure: StructureConfig {
@@ -438,10 +441,12 @@ fn key_fields_match_config_schema() {
         scanner: ScannerConfig {
             gitignore: false,
             exclude: vec!["test/**".to_string()],
+            follow_symlinks: false,
         },
         check: CheckConfig {
             warnings_as_errors: true,
             fail_fast: true,
+            diff_merge_base: true,
         },
         ..Default::default()
     };
@@ -15,13 +15,22 @@ fn explain_content_rule_matches() {
             max_lines: 500,
             rules: vec![ContentRule {
                 pattern: "**/*.rs".to_string(),
+                patterns: vec![],
+                exclude_patterns: vec![],
+                languages: vec![],
                 max_lines: 300,
                 warn_threshold: None,
                 warn_at: None,
                 skip_comments: None,
                 skip_blank: None,
+                metric: None,
+                min_comment_ratio: None,
+                max_comment_ratio: None,
+                comment_ratio_min_code: None,
+                priority: None,
                 reason: None,
                 expires: None,
+                on_expire: None,
             }],
             ..Default::default()
         },
@@ -45,13 +54,22 @@ fn explain_content_rule_with_reason() {
             max_lines: 500,
             rules: vec![ContentRule {
                 pattern: "src/legacy/**".to_string(),
+                patterns: vec![],
+                exclude_patterns: vec![],
+                languages: vec![],
                 max_lines: 1000,
                 warn_threshold: None,
                 warn_at: None,
                 skip_comments: None,
                 skip_blank: None,
+                metric: None,
+                min_comment_ratio: None,
+                max_comment_ratio: None,
+                comment_ratio_min_code: None,
+                priority: None,
                 reason: Some("Legacy code".to_string()),
                 expires: None,
+                on_expire: None,
             }],
             ..Default::default()
         },
@@ -96,23 +114,41 @@ fn explain_content_last_rule_wins() {
             rules: vec![
                 ContentRule {
                     pattern: "**/*.rs".to_string(),
+                    patterns: vec![],
+                    exclude_patterns: vec![],
+                    languages: vec![],
                     max_lines: 300,
                     warn_threshold: None,
                     warn_at: None,
                     skip_comments: None,
                     skip_blank: None,
+                    metric: None,
+                    min_comment_ratio: None,
+                    max_comment_ratio: None,
+                    comment_ratio_min_code: None,
+                    priority: None,
                     reason: None,
                     expires: None,
+                    on_expire: None,
                 },
                 ContentRule {
                     pattern: "src/generated/**".to_string(),
+                    patterns: vec![],
+                    exclude_patterns: vec![],
+                    languages: vec![],
                     max_lines: 1000,
                     warn_threshold: None,
                     warn_at: None,
                     skip_comments: None,
                     skip_blank: None,
+                    metric: None,
+                    min_comment_ratio: None,
+                    max_comment_ratio: None,
+                    comment_ratio_min_code: None,
+                    priority: None,
                     reason: None,
                     expires: None,
+                    on_expire: None,
                 },
             ],
             ..Default::default()
@@ -150,23 +186,41 @@ fn explain_content_specific_rule_beats_general() {
             rules: vec![
                 ContentRule {
                     pattern: "**/*.rs".to_string(),
+                    patterns: vec![],
+                    exclude_patterns: vec![],
+                    languages: vec![],
                     max_lines: 300,
                     warn_threshold: None,
                     warn_at: None,
                     skip_comments: None,
                     skip_blank: None,
+                    metric: None,
+                    min_comment_ratio: None,
+                    max_comment_ratio: None,
+                    comment_ratio_min_code: None,
+                    priority: None,
                     reason: None,
                     expires: None,
+                    on_expire: None,
                 },
                 ContentRule {
                     pattern: "src/main.rs".to_string(),
+                    patterns: vec![],
+                    exclude_patterns: vec![],
+                    languages: vec![],
                     max_lines: 2000,
                     warn_threshold: None,
                     warn_at: None,
                     skip_comments: None,
                     skip_blank: None,
+                    metric: None,
+                    min_comment_ratio: None,
+                    max_comment_ratio: None,
+                    comment_ratio_min_code: None,
+                    priority: None,
                     reason: Some("Special file".to_string()),
                     expires: None,
+                    on_expire: None,
                 },
             ],
             ..Default::default()
@@ -266,13 +320,22 @@ fn explain_content_warn_at_source_rule_absolute() {
             max_lines: 500,
             rules: vec![ContentRule {
                 pattern: "**/*.rs".to_string(),
+                patterns: vec![],
+                exclude_patterns: vec![],
+                languages: vec![],
                 max_lines: 300,
                 warn_at: Some(250),
                 warn_threshold: None,
                 skip_comments: None,
                 skip_blank: None,
+                metric: None,
+                min_comment_ratio: None,
+                max_comment_ratio: None,
+                comment_ratio_min_code: None,
+                priority: None,
                 reason: None,
                 expires: None,
+                on_expire: None,
             }],
             ..Default::default()
         },
@@ -297,13 +360,22 @@ fn explain_content_warn_at_source_rule_percentage() {
             max_lines: 500,
             rules: vec![ContentRule {
                 pattern: "**/*.rs".to_string(),
+                patterns: vec![],
+                exclude_patterns: vec![],
+                languages: vec![],
                 max_lines: 300,
                 warn_at: None,
                 warn_threshold: Some(0.8),
                 skip_comments: None,
                 skip_blank: None,
+                metric: None,
+                min_comment_ratio: None,
+                max_comment_ratio: None,
+                comment_ratio_min_code: None,
+                priority: None,
                 reason: None,
                 expires: None,
+                on_expire: None,
             }],
             ..Default::default()
         },
@@ -5,10 +5,12 @@
 
 use std::path::PathBuf;
 
+use crate::checker::SymlinkInfo;
 use crate::cli::ExplainFormat;
+use crate::commands::explain_text::format_limit;
 use crate::config::{Config, ContentConfig, ContentRule, StructureConfig, StructureRule};
 
-use super::super::{format_content_explanation, format_limit, format_structure_explanation};
+use super::super::{format_content_explanation, format_structure_explanation};
 
 // ============================================================================
 // Content formatting tests
@@ -37,6 +39,162 @@ fn format_content_text_output_contains_expected_sections() {
     assert!(output.contains("Rule Chain"));
 }
 
+#[test]
+fn format_content_text_shows_metric_instead_of_skip_settings() {
+    let config = Config {
+        content: ContentConfig {
+            max_lines: 500,
+            metric: Some(crate::config::ContentMetric::Total),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let checker = crate::checker::ThresholdChecker::new(config).unwrap();
+    let explanation = checker.explain(&PathBuf::from("src/main.rs"));
+    let output = format_content_explanation(&explanation, ExplainFormat::Text).unwrap();
+
+    assert!(output.contains("Limit:   500 total lines"));
+    assert!(output.contains("Metric:  total"));
+    assert!(!output.contains("Skip:"));
+}
+
+#[test]
+fn format_content_text_shows_comment_ratio_limits() {
+    let config = Config {
+        content: ContentConfig {
+            max_lines: 500,
+            rules: vec![ContentRule {
+                pattern: "src/api/**".to_string(),
+                patterns: vec![],
+                exclude_patterns: vec![],
+                languages: vec![],
+                max_lines: 500,
+                warn_threshold: None,
+                warn_at: None,
+                skip_comments: None,
+                skip_blank: None,
+                metric: None,
+                min_comment_ratio: Some(0.1),
+                max_comment_ratio: Some(0.5),
+                comment_ratio_min_code: None,
+                priority: None,
+                reason: None,
+                expires: None,
+                on_expire: None,
+            }],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let checker = crate::checker::ThresholdChecker::new(config).unwrap();
+    let explanation = checker.explain(&PathBuf::from("src/api/routes.rs"));
+    let output = format_content_explanation(&explanation, ExplainFormat::Text).unwrap();
+
+    assert!(output.contains("Comments: min 10%, max 50% (files with >= 100 code lines)"));
+}
+
+#[test]
+fn format_content_text_explains_winning_rule() {
+    let rule = |pattern: &str, max_lines, priority| ContentRule {
+        pattern: pattern.to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
+        max_lines,
+        warn_threshold: None,
+        warn_at: None,
+        skip_comments: None,
+        skip_blank: None,
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority,
+        reason: None,
+        expires: None,
+        on_expire: None,
+    };
+    let config = Config {
+        content: ContentConfig {
+            rules: vec![
+                rule("src/legacy/**", 1500, Some(10)),
+                rule("src/**", 400, None),
+            ],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let checker = crate::checker::ThresholdChecker::new(config).unwrap();
+    let explanation = checker.explain(&PathBuf::from("src/legacy/old.rs"));
+    let output = format_content_explanation(&explanation, ExplainFormat::Text).unwrap();
+
+    assert!(output.contains("Why:     priority 10 beats content.rules[1] (priority 0)"));
+    assert!(output.contains("content.rules[0] \"src/legacy/**\" [priority=10] -> 1500 lines"));
+}
+
+#[test]
+fn format_content_text_most_specific_shows_specificity() {
+    let config = Config {
+        rule_resolution: crate::config::RuleResolution::MostSpecific,
+        content: ContentConfig {
+            rules: vec![
+                ContentRule {
+                    pattern: "src/api/**".to_string(),
+                    patterns: vec![],
+                    exclude_patterns: vec![],
+                    languages: vec![],
+                    max_lines: 800,
+                    warn_threshold: None,
+                    warn_at: None,
+                    skip_comments: None,
+                    skip_blank: None,
+                    metric: None,
+                    min_comment_ratio: None,
+                    max_comment_ratio: None,
+                    comment_ratio_min_code: None,
+                    priority: None,
+                    reason: None,
+                    expires: None,
+                    on_expire: None,
+                },
+                ContentRule {
+                    pattern: "**/*.rs".to_string(),
+                    patterns: vec![],
+                    exclude_patterns: vec![],
+                    languages: vec![],
+                    max_lines: 300,
+                    warn_threshold: None,
+                    warn_at: None,
+                    skip_comments: None,
+                    skip_blank: None,
+                    metric: None,
+                    min_comment_ratio: None,
+                    max_comment_ratio: None,
+                    comment_ratio_min_code: None,
+                    priority: None,
+                    reason: None,
+                    expires: None,
+                    on_expire: None,
+                },
+            ],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let checker = crate::checker::ThresholdChecker::new(config).unwrap();
+    let explanation = checker.explain(&PathBuf::from("src/api/routes.rs"));
+    let output = format_content_explanation(&explanation, ExplainFormat::Text).unwrap();
+
+    assert!(output.contains(
+        "Why:     more specific than content.rules[1] (literal 8/2 vs 4/0 chars/depth, rule_resolution = most_specific)"
+    ));
+    assert!(output.contains("[specificity=8/2]"));
+}
+
 #[test]
 fn format_content_json_output_is_valid() {
     let config = Config {
@@ -65,13 +223,22 @@ fn format_content_with_rule_shows_pattern() {
             max_lines: 500,
             rules: vec![ContentRule {
                 pattern: "**/*.rs".to_string(),
+                patterns: vec![],
+                exclude_patterns: vec![],
+                languages: vec![],
                 max_lines: 300,
                 warn_threshold: None,
                 warn_at: None,
                 skip_comments: None,
                 skip_blank: None,
+                metric: None,
+                min_comment_ratio: None,
+                max_comment_ratio: None,
+                comment_ratio_min_code: None,
+                priority: None,
                 reason: None,
                 expires: None,
+                on_expire: None,
             }],
             ..Default::default()
         },
@@ -86,6 +253,51 @@ fn format_content_with_rule_shows_pattern() {
     assert!(output.contains("**/*.rs"));
 }
 
+#[test]
+fn format_content_text_shows_deciding_selector() {
+    let selector_rule = |languages: &[&str], exclude: &[&str]| ContentRule {
+        pattern: String::new(),
+        patterns: vec!["src/**".to_string(), "lib/**".to_string()],
+        exclude_patterns: exclude.iter().map(ToString::to_string).collect(),
+        languages: languages.iter().map(ToString::to_string).collect(),
+        max_lines: 300,
+        warn_threshold: None,
+        warn_at: None,
+        skip_comments: None,
+        skip_blank: None,
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
+        reason: None,
+        expires: None,
+        on_expire: None,
+    };
+    let config = Config {
+        content: ContentConfig {
+            rules: vec![
+                selector_rule(&["Rust"], &[]),
+                selector_rule(&[], &["**/main.rs"]),
+                selector_rule(&["Go"], &[]),
+            ],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let checker = crate::checker::ThresholdChecker::new(config).unwrap();
+    let explanation = checker.explain(&PathBuf::from("lib/main.rs"));
+    let output = format_content_explanation(&explanation, ExplainFormat::Text).unwrap();
+
+    assert!(output.contains(
+        "content.rules[0] \"src/**, lib/** [languages: Rust]\" -> 300 lines (MATCHED) \
+         [via pattern \"lib/**\", language Rust]"
+    ));
+    assert!(output.contains("(no match) [excluded by \"**/main.rs\"]"));
+    assert!(output.contains("(no match) [language Rust not listed]"));
+}
+
 #[test]
 fn format_content_with_rule_reason_shows_reason() {
     let config = Config {
@@ -93,13 +305,22 @@ fn format_content_with_rule_reason_shows_reason() {
             max_lines: 500,
             rules: vec![ContentRule {
                 pattern: "legacy/**".to_string(),
+                patterns: vec![],
+                exclude_patterns: vec![],
+                languages: vec![],
                 max_lines: 2000,
                 warn_threshold: None,
                 warn_at: None,
                 skip_comments: None,
                 skip_blank: None,
+                metric: None,
+                min_comment_ratio: None,
+                max_comment_ratio: None,
+                comment_ratio_min_code: None,
+                priority: None,
                 reason: Some("Legacy code".to_string()),
                 expires: None,
+                on_expire: None,
             }],
             ..Default::default()
         },
@@ -208,13 +429,22 @@ fn format_content_text_shows_rule_absolute_source_with_index() {
             max_lines: 500,
             rules: vec![ContentRule {
                 pattern: "**/*.rs".to_string(),
+                patterns: vec![],
+                exclude_patterns: vec![],
+                languages: vec![],
                 max_lines: 300,
                 warn_at: Some(250),
                 warn_threshold: None,
                 skip_comments: None,
                 skip_blank: None,
+                metric: None,
+                min_comment_ratio: None,
+                max_comment_ratio: None,
+                comment_ratio_min_code: None,
+                priority: None,
                 reason: None,
                 expires: None,
+                on_expire: None,
             }],
             ..Default::default()
         },
@@ -237,13 +467,22 @@ fn format_content_text_shows_rule_percentage_source_with_index() {
             max_lines: 500,
             rules: vec![ContentRule {
                 pattern: "**/*.rs".to_string(),
+                patterns: vec![],
+                exclude_patterns: vec![],
+                languages: vec![],
                 max_lines: 300,
                 warn_at: None,
                 warn_threshold: Some(0.8),
                 skip_comments: None,
                 skip_blank: None,
+                metric: None,
+                min_comment_ratio: None,
+                max_comment_ratio: None,
+                comment_ratio_min_code: None,
+                priority: None,
                 reason: None,
                 expires: None,
+                on_expire: None,
             }],
             ..Default::default()
         },
@@ -357,6 +596,43 @@ fn format_structure_with_no_limits_shows_none() {
     assert!(output.contains("max_depth=none"));
 }
 
+#[test]
+fn format_structure_symlinked_directory_shows_target() {
+    let config = StructureConfig {
+        max_files: Some(10),
+        ..Default::default()
+    };
+
+    let checker = crate::checker::StructureChecker::new(&config).unwrap();
+    let mut explanation = checker.explain(&PathBuf::from("vendor/shared"));
+    explanation.symlink = Some(SymlinkInfo {
+        target: PathBuf::from("../../shared"),
+        resolved: PathBuf::from("/work/shared"),
+        outside_project: true,
+    });
+
+    let text = format_structure_explanation(&explanation, ExplainFormat::Text).unwrap();
+    assert!(text.contains(
+        "Path: vendor/shared\nSymlink: -> ../../shared (resolves to /work/shared, outside the project root)\n"
+    ));
+
+    let json = format_structure_explanation(&explanation, ExplainFormat::Json).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed["symlink"]["target"], "../../shared");
+    assert_eq!(parsed["symlink"]["outside_project"], true);
+}
+
+#[test]
+fn format_without_symlink_omits_symlink_line() {
+    let checker = crate::checker::ThresholdChecker::new(Config::default()).unwrap();
+    let explanation = checker.explain(&PathBuf::from("src/main.rs"));
+
+    let text = format_content_explanation(&explanation, ExplainFormat::Text).unwrap();
+    assert!(!text.contains("Symlink:"));
+    let json = format_content_explanation(&explanation, ExplainFormat::Json).unwrap();
+    assert!(!json.contains("symlink"));
+}
+
 // ============================================================================
 // format_limit helper tests
 // ============================================================================
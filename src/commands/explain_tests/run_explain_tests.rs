@@ -435,3 +435,33 @@ max_lines = 400
     let result = super::super::run_explain_impl(&args, &cli);
     assert!(result.is_ok());
 }
+
+// ============================================================================
+// Symlink detection tests
+// ============================================================================
+
+#[test]
+fn symlink_info_is_none_for_regular_paths() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+
+    assert!(super::super::symlink_info(temp_dir.path()).is_none());
+}
+
+#[cfg(unix)]
+#[test]
+fn symlink_info_reports_target_outside_project() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let real = dunce::canonicalize(temp_dir.path()).unwrap().join("real");
+    std::fs::create_dir(&real).unwrap();
+    let link = temp_dir.path().join("alias");
+    std::os::unix::fs::symlink("real", &link).unwrap();
+
+    // A trailing separator still explains the link, not its target
+    let with_slash = PathBuf::from(format!("{}/", link.display()));
+    let info = super::super::symlink_info(&with_slash).unwrap();
+
+    assert_eq!(info.target, PathBuf::from("real"));
+    assert_eq!(info.resolved, real);
+    // The temp directory lies outside the crate's project root
+    assert!(info.outside_project);
+}
@@ -0,0 +1,406 @@
+//! Text rendering for `explain` content and structure explanations.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use crate::checker::{
+    CommentRatioLimits, ContentExplanation, ContentRuleMatch, MatchStatus, RuleRank, RuleWinReason,
+    SelectorOutcome, StructureExplanation, StructureRuleMatch, SymlinkInfo, WarnAtSource,
+};
+use crate::config::{ContentMetric, RuleResolution};
+
+pub(super) fn format_content_text(exp: &ContentExplanation) -> String {
+    let mut output = String::new();
+
+    let _ = writeln!(output, "Path: {}", exp.path.display());
+    write_symlink_line(&mut output, exp.symlink.as_ref());
+    output.push('\n');
+    output.push_str("Content Rules (SLOC Limits):\n");
+
+    // Show matched rule
+    match &exp.matched_rule {
+        ContentRuleMatch::Excluded { pattern } => {
+            let _ = writeln!(
+                output,
+                "  Status:  EXCLUDED (matches content.exclude pattern \"{pattern}\")"
+            );
+            output.push_str(
+                "  Note:    This file is excluded from SLOC counting but visible for structure checks.\n",
+            );
+            return output;
+        }
+        ContentRuleMatch::Rule {
+            index,
+            pattern,
+            reason,
+        } => {
+            let reason_str = reason
+                .as_ref()
+                .map(|r| format!(" (reason: {r})"))
+                .unwrap_or_default();
+            let _ = writeln!(
+                output,
+                "  Matched: [[content.rules]] index {index} pattern \"{pattern}\"{reason_str}"
+            );
+        }
+        ContentRuleMatch::Default => {
+            output.push_str("  Matched: [content] defaults\n");
+        }
+    }
+
+    if let (ContentRuleMatch::Rule { index, .. }, Some(reason)) =
+        (&exp.matched_rule, exp.win_reason)
+    {
+        let rank_at = |i: usize| exp.rule_chain.get(i).and_then(|c| c.rank);
+        let why = format_win_reason(reason, *index, rank_at, "content", exp.rule_resolution);
+        let _ = writeln!(output, "  Why:     {why}");
+    }
+
+    let unit = exp.metric.map_or("lines", ContentMetric::unit);
+    let _ = writeln!(output, "  Limit:   {} {unit}", exp.effective_limit);
+
+    // Show warn_at with context based on source (Rule vs Global, absolute vs percentage)
+    let warn_at_str = match &exp.warn_at_source {
+        WarnAtSource::RuleAbsolute { index } => {
+            format!(
+                "{} lines (from content.rules[{index}], absolute)",
+                exp.effective_warn_at
+            )
+        }
+        WarnAtSource::RulePercentage { index, threshold } => {
+            format!(
+                "{} lines (from content.rules[{index}], {:.0}%)",
+                exp.effective_warn_at,
+                threshold * 100.0
+            )
+        }
+        WarnAtSource::GlobalAbsolute => {
+            format!("{} lines (from [content], absolute)", exp.effective_warn_at)
+        }
+        WarnAtSource::GlobalPercentage { threshold } => {
+            format!(
+                "{} lines (from [content], {:.0}%)",
+                exp.effective_warn_at,
+                threshold * 100.0
+            )
+        }
+    };
+    let _ = writeln!(output, "  Warn at: {warn_at_str}");
+
+    if let Some(metric) = exp.metric {
+        let _ = writeln!(output, "  Metric:  {metric}");
+    } else {
+        let _ = writeln!(
+            output,
+            "  Skip:    comments={}, blank={}",
+            exp.skip_comments, exp.skip_blank
+        );
+    }
+    if let Some(limits) = &exp.comment_ratio {
+        let _ = writeln!(
+            output,
+            "  Comments: {} (files with >= {} code lines)",
+            format_comment_ratio_range(limits),
+            limits.min_code
+        );
+    }
+
+    output.push('\n');
+    output.push_str("  Rule Chain (evaluated high->low):\n");
+    for candidate in &exp.rule_chain {
+        let status_char = match candidate.status {
+            MatchStatus::Matched => "+",
+            MatchStatus::Superseded => "-",
+            MatchStatus::NoMatch => " ",
+            MatchStatus::Expired => "x",
+        };
+        let pattern_str = candidate
+            .pattern
+            .as_ref()
+            .map_or(String::new(), |p| format!(" \"{p}\""));
+        let status_desc = match candidate.status {
+            MatchStatus::Matched => "(MATCHED)",
+            MatchStatus::Superseded => "(superseded)",
+            MatchStatus::NoMatch => "(no match)",
+            MatchStatus::Expired => "(expired, reverted)",
+        };
+        let rank_str = format_rank(candidate.rank, exp.rule_resolution);
+        let selector_str = format_selector(
+            candidate.selector.as_ref(),
+            candidate.pattern.as_ref(),
+            "pattern",
+        );
+        let _ = writeln!(
+            output,
+            "    [{status_char}] {}{}{rank_str} -> {} lines {status_desc}{selector_str}",
+            candidate.source, pattern_str, candidate.limit
+        );
+    }
+
+    output
+}
+
+/// Which selector decided the candidate, when that is not obvious from its label.
+/// `kind` names the selecting glob ("pattern" for content rules, "scope" for structure rules).
+fn format_selector(
+    selector: Option<&SelectorOutcome>,
+    label: Option<&String>,
+    kind: &str,
+) -> String {
+    match selector {
+        Some(SelectorOutcome::Selected { pattern, language }) => {
+            let mut parts = Vec::new();
+            if let Some(pattern) = pattern
+                && label != Some(pattern)
+            {
+                parts.push(format!("{kind} \"{pattern}\""));
+            }
+            if let Some(language) = language {
+                parts.push(format!("language {language}"));
+            }
+            if parts.is_empty() {
+                String::new()
+            } else {
+                format!(" [via {}]", parts.join(", "))
+            }
+        }
+        Some(SelectorOutcome::ExcludedBy { pattern }) => format!(" [excluded by \"{pattern}\"]"),
+        Some(SelectorOutcome::LanguageMismatch { language }) => format!(
+            " [language {} not listed]",
+            language.as_deref().unwrap_or("unknown")
+        ),
+        Some(SelectorOutcome::NoPatternMatch) | None => String::new(),
+    }
+}
+
+pub(super) fn format_structure_text(exp: &StructureExplanation) -> String {
+    let mut output = String::new();
+
+    let _ = writeln!(output, "Path: {}", exp.path.display());
+    write_symlink_line(&mut output, exp.symlink.as_ref());
+    output.push('\n');
+    output.push_str("Structure Rules (Directory Limits):\n");
+
+    // Show matched rule
+    match &exp.matched_rule {
+        StructureRuleMatch::Rule {
+            index,
+            pattern,
+            reason,
+        } => {
+            let reason_str = reason
+                .as_ref()
+                .map(|r| format!(" (reason: {r})"))
+                .unwrap_or_default();
+            let _ = writeln!(
+                output,
+                "  Matched: [[structure.rules]] index {index} pattern \"{pattern}\"{reason_str}"
+            );
+        }
+        StructureRuleMatch::Default => {
+            output.push_str("  Matched: [structure] defaults\n");
+        }
+    }
+
+    if let (StructureRuleMatch::Rule { index, .. }, Some(reason)) =
+        (&exp.matched_rule, exp.win_reason)
+    {
+        let rank_at = |i: usize| exp.rule_chain.get(i).and_then(|c| c.rank);
+        let why = format_win_reason(reason, *index, rank_at, "structure", exp.rule_resolution);
+        let _ = writeln!(output, "  Why:     {why}");
+    }
+
+    let max_files_str = format_limit(exp.effective_max_files);
+    let max_dirs_str = format_limit(exp.effective_max_dirs);
+    let max_depth_str = format_limit(exp.effective_max_depth);
+
+    let _ = writeln!(
+        output,
+        "  Limits:  max_files={max_files_str}, max_dirs={max_dirs_str}, max_depth={max_depth_str}"
+    );
+    if exp.effective_max_total_files.is_some() || exp.effective_max_total_dirs.is_some() {
+        let _ = writeln!(
+            output,
+            "  Totals:  max_total_files={}, max_total_dirs={}",
+            format_limit(exp.effective_max_total_files),
+            format_limit(exp.effective_max_total_dirs)
+        );
+    }
+    if !exp.effective_max_files_by_ext.is_empty() {
+        let _ = writeln!(
+            output,
+            "  By ext:  {}",
+            format_ext_limits(&exp.effective_max_files_by_ext)
+        );
+    }
+    let _ = writeln!(output, "  Warn at: {:.0}%", exp.warn_threshold * 100.0);
+
+    if let Some(reason) = &exp.override_reason {
+        let _ = writeln!(output, "  Reason:  {reason}");
+    }
+
+    output.push('\n');
+    output.push_str("  Rule Chain (evaluated high->low):\n");
+    for candidate in &exp.rule_chain {
+        let status_char = match candidate.status {
+            MatchStatus::Matched => "+",
+            MatchStatus::Superseded => "-",
+            MatchStatus::NoMatch => " ",
+            MatchStatus::Expired => "x",
+        };
+        let pattern_str = candidate
+            .pattern
+            .as_ref()
+            .map_or(String::new(), |p| format!(" \"{p}\""));
+        let status_desc = match candidate.status {
+            MatchStatus::Matched => "(MATCHED)",
+            MatchStatus::Superseded => "(superseded)",
+            MatchStatus::NoMatch => "(no match)",
+            MatchStatus::Expired => "(expired, reverted)",
+        };
+        let files_str = candidate
+            .max_files
+            .map_or_else(|| "-".to_string(), |v| v.to_string());
+        let dirs_str = candidate
+            .max_dirs
+            .map_or_else(|| "-".to_string(), |v| v.to_string());
+        let depth_str = candidate
+            .max_depth
+            .map_or_else(|| "-".to_string(), |v| v.to_string());
+        let rank_str = format_rank(candidate.rank, exp.rule_resolution);
+        let mut totals_str = String::new();
+        if let Some(v) = candidate.max_total_files {
+            let _ = write!(totals_str, ", total_files={v}");
+        }
+        if let Some(v) = candidate.max_total_dirs {
+            let _ = write!(totals_str, ", total_dirs={v}");
+        }
+        if !candidate.max_files_by_ext.is_empty() {
+            let _ = write!(
+                totals_str,
+                ", {}",
+                format_ext_limits(&candidate.max_files_by_ext)
+            );
+        }
+        let selector_str = format_selector(
+            candidate.selector.as_ref(),
+            candidate.pattern.as_ref(),
+            "scope",
+        );
+        let _ = writeln!(
+            output,
+            "    [{status_char}] {}{}{rank_str} -> files={files_str}, dirs={dirs_str}, depth={depth_str}{totals_str} {status_desc}{selector_str}",
+            candidate.source, pattern_str
+        );
+    }
+
+    output
+}
+
+/// Format `max_files_by_ext` limits as `.rs=15, .sql=1`.
+/// `Symlink:` line under `Path:` when the explained path is a symlink.
+fn write_symlink_line(output: &mut String, symlink: Option<&SymlinkInfo>) {
+    let Some(symlink) = symlink else {
+        return;
+    };
+    let location = if symlink.outside_project {
+        ", outside the project root"
+    } else {
+        ""
+    };
+    let _ = writeln!(
+        output,
+        "Symlink: -> {} (resolves to {}{location})",
+        symlink.target.display(),
+        symlink.resolved.display()
+    );
+}
+
+fn format_ext_limits(limits: &BTreeMap<String, i64>) -> String {
+    limits
+        .iter()
+        .map(|(extension, limit)| format!("{extension}={limit}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Format an optional limit value for display.
+/// - `None` → "none" (no limit configured)
+/// - `Some(-1)` → "unlimited" (explicitly unlimited)
+/// - `Some(n)` → numeric string
+pub(super) fn format_limit(value: Option<i64>) -> String {
+    match value {
+        None => "none".to_string(),
+        Some(-1) => "unlimited".to_string(),
+        Some(v) => v.to_string(),
+    }
+}
+
+/// Explain why the matched rule beat the runner-up, e.g.
+/// "priority 10 beats content.rules[0] (priority 0)".
+fn format_win_reason(
+    reason: RuleWinReason,
+    winner: usize,
+    rank_at: impl Fn(usize) -> Option<RuleRank>,
+    section: &str,
+    mode: RuleResolution,
+) -> String {
+    let (runner_up, won, lost) = match reason {
+        RuleWinReason::OnlyMatch => return "only matching rule".to_string(),
+        RuleWinReason::HigherPriority { runner_up }
+        | RuleWinReason::MoreSpecific { runner_up }
+        | RuleWinReason::LaterDeclaration { runner_up } => {
+            (runner_up, rank_at(winner), rank_at(runner_up))
+        }
+    };
+    let other = format!("{section}.rules[{runner_up}]");
+    match (reason, won, lost) {
+        (RuleWinReason::HigherPriority { .. }, Some(won), Some(lost)) => format!(
+            "priority {} beats {other} (priority {})",
+            won.priority, lost.priority
+        ),
+        (RuleWinReason::MoreSpecific { .. }, Some(won), Some(lost)) => format!(
+            "more specific than {other} (literal {}/{} vs {}/{} chars/depth, rule_resolution = {mode})",
+            won.specificity.literal_len,
+            won.specificity.literal_depth,
+            lost.specificity.literal_len,
+            lost.specificity.literal_depth
+        ),
+        _ => format!("declared after {other} (rule_resolution = {mode})"),
+    }
+}
+
+/// Rank annotation for a rule chain entry: non-zero priority, plus specificity in
+/// `most_specific` mode.
+fn format_rank(rank: Option<RuleRank>, mode: RuleResolution) -> String {
+    let Some(rank) = rank else {
+        return String::new();
+    };
+    let mut parts = Vec::new();
+    if rank.priority != 0 {
+        parts.push(format!("priority={}", rank.priority));
+    }
+    if mode == RuleResolution::MostSpecific {
+        parts.push(format!(
+            "specificity={}/{}",
+            rank.specificity.literal_len, rank.specificity.literal_depth
+        ));
+    }
+    if parts.is_empty() {
+        String::new()
+    } else {
+        format!(" [{}]", parts.join(", "))
+    }
+}
+
+/// Format comment ratio bounds as e.g. "min 10%, max 50%".
+fn format_comment_ratio_range(limits: &CommentRatioLimits) -> String {
+    let mut parts = Vec::new();
+    if let Some(min) = limits.min {
+        parts.push(format!("min {:.0}%", min * 100.0));
+    }
+    if let Some(max) = limits.max {
+        parts.push(format!("max {:.0}%", max * 100.0));
+    }
+    parts.join(", ")
+}
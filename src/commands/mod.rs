@@ -3,9 +3,12 @@ pub mod config;
 pub mod context;
 pub mod detect;
 pub mod explain;
+mod explain_text;
 pub mod init;
 pub mod snapshot;
+mod snapshot_backfill;
 pub mod stats;
+mod structure_scan;
 
 pub use check::run_check;
 pub use config::run_config;
@@ -4,7 +4,7 @@
 //! - `stats trend/report` = read history (read-only)
 //! - `snapshot` = write history (this command)
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 
 use rayon::prelude::*;
@@ -13,16 +13,17 @@ use crate::cache::{Cache, compute_config_hash};
 use crate::cli::{Cli, SnapshotArgs};
 use crate::config::FetchPolicy;
 use crate::git::GitContext;
-use crate::output::{ProjectStatistics, ScanProgress};
+use crate::output::{FileStatistics, ProjectStatistics, ScanProgress};
 use crate::scanner::scan_files;
 use crate::state;
 use crate::stats::TrendHistory;
 use crate::{EXIT_CONFIG_ERROR, EXIT_SUCCESS};
 
 use super::context::{
-    RealFileReader, StatsContext, load_cache, load_config, print_preset_info, resolve_scan_paths,
-    save_cache,
+    FileReader, RealFileReader, StatsContext, load_cache, load_config, print_preset_info,
+    resolve_scan_paths, save_cache,
 };
+use super::snapshot_backfill::run_backfill;
 use super::stats::collect_file_stats;
 
 /// Run the snapshot command.
@@ -77,6 +78,11 @@ fn run_snapshot_inner(args: &SnapshotArgs, cli: &Cli) -> crate::Result<i32> {
         config.content.extensions.clone_from(cli_extensions);
     }
 
+    // 1d. Backfill history from past commits instead of recording the working tree
+    if args.backfill {
+        return run_backfill(args, cli, &config, &cache, &project_root);
+    }
+
     // 2. Build stats context
     let ctx = StatsContext::from_config(&config);
 
@@ -89,28 +95,17 @@ fn run_snapshot_inner(args: &SnapshotArgs, cli: &Cli) -> crate::Result<i32> {
 
     // 5. Scan directories
     let use_gitignore = config.scanner.gitignore && !args.common.no_gitignore;
-    let all_files = scan_files(&paths_to_scan, &exclude_patterns, use_gitignore)?;
+    let all_files = scan_files(
+        &paths_to_scan,
+        &exclude_patterns,
+        use_gitignore,
+        config.scanner.follow_symlinks,
+    )?;
 
     // 6. Process files in parallel
-    let reader = RealFileReader;
     let progress = ScanProgress::new(all_files.len() as u64, cli.quiet);
-    let file_stats: Vec<_> = all_files
-        .par_iter()
-        .filter(|file_path| {
-            if ctx.allowed_extensions.is_empty() {
-                return true;
-            }
-            file_path
-                .extension()
-                .and_then(|ext| ext.to_str())
-                .is_some_and(|ext| ctx.allowed_extensions.contains(ext))
-        })
-        .filter_map(|file_path| {
-            let result = collect_file_stats(file_path, &ctx.registry, &cache, &reader);
-            progress.inc();
-            result
-        })
-        .collect();
+    let file_stats =
+        collect_snapshot_stats(&all_files, &ctx, &cache, &RealFileReader, Some(&progress));
     progress.finish();
 
     // 7. Save cache if enabled (errors are non-critical)
@@ -168,6 +163,35 @@ fn run_snapshot_inner(args: &SnapshotArgs, cli: &Cli) -> crate::Result<i32> {
     Ok(EXIT_SUCCESS)
 }
 
+/// Count the files with an allowed extension in parallel, skipping files without stats.
+pub(super) fn collect_snapshot_stats(
+    files: &[PathBuf],
+    ctx: &StatsContext,
+    cache: &Mutex<Cache>,
+    reader: &dyn FileReader,
+    progress: Option<&ScanProgress>,
+) -> Vec<FileStatistics> {
+    files
+        .par_iter()
+        .filter(|file_path| {
+            if ctx.allowed_extensions.is_empty() {
+                return true;
+            }
+            file_path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| ctx.allowed_extensions.contains(ext))
+        })
+        .filter_map(|file_path| {
+            let result = collect_file_stats(file_path, &ctx.registry, cache, reader);
+            if let Some(progress) = progress {
+                progress.inc();
+            }
+            result
+        })
+        .collect()
+}
+
 fn print_dry_run_output(
     stats: &ProjectStatistics,
     git_context: Option<&GitContext>,
@@ -0,0 +1,119 @@
+//! Snapshot backfill: record trend history for past commits (`snapshot --backfill`).
+//!
+//! Each selected commit's tree is scanned and counted straight from the object
+//! database. Blob ids key the SLOC cache, so files unchanged between commits are
+//! only counted once.
+
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use crate::EXIT_SUCCESS;
+use crate::cache::Cache;
+use crate::cli::{Cli, SnapshotArgs};
+use crate::config::Config;
+use crate::git::{CommitHistory, CommitSelection, HistoryCommit};
+use crate::output::{ProjectStatistics, ScanProgress};
+use crate::scanner::{FileScanner, TreeScanner};
+use crate::state;
+use crate::stats::{TrendEntry, TrendHistory, parse_duration};
+
+use super::context::{GitTreeReader, StatsContext, resolve_scan_paths, save_cache};
+use super::snapshot::collect_snapshot_stats;
+use super::stats::format_timestamp;
+
+/// Record a trend entry for each commit selected by `--since`, `--every` and
+/// `--first-parent`, then save the history with retention applied.
+pub(super) fn run_backfill(
+    args: &SnapshotArgs,
+    cli: &Cli,
+    config: &Config,
+    cache: &Mutex<Cache>,
+    project_root: &Path,
+) -> crate::Result<i32> {
+    let now = state::current_unix_timestamp();
+    let since = args.since.as_deref().map(parse_duration).transpose()?;
+    let selection = CommitSelection {
+        since: since.map(|secs| now.saturating_sub(secs)),
+        every: args.every.as_deref().map(parse_duration).transpose()?,
+        first_parent: args.first_parent,
+    };
+
+    let git_history = CommitHistory::discover(project_root)?;
+    let commits = git_history.commits(&selection)?;
+    let branch = git_history.branch().map(String::from);
+
+    let ctx = StatsContext::from_config(config);
+    let mut exclude_patterns = config.scanner.exclude.clone();
+    exclude_patterns.extend(args.common.exclude.clone());
+    let paths_to_scan = resolve_scan_paths(&args.common.paths, &args.common.include);
+
+    let default_path = state::history_path(project_root);
+    let history_path = args.history_file.as_ref().unwrap_or(&default_path);
+    let mut history = TrendHistory::load_or_default(history_path);
+
+    let progress = ScanProgress::new(commits.len() as u64, cli.quiet);
+    let mut added = 0;
+    let mut dry_run_lines = Vec::new();
+    for commit in &commits {
+        let tree = Arc::new(git_history.tree(commit)?);
+        let files =
+            TreeScanner::new(tree.clone(), exclude_patterns.clone()).scan_all(&paths_to_scan)?;
+        let reader = GitTreeReader::new(tree);
+        let stats =
+            ProjectStatistics::new(collect_snapshot_stats(&files, &ctx, cache, &reader, None));
+        progress.inc();
+
+        let entry = TrendEntry::new(&stats)
+            .with_timestamp(commit.timestamp)
+            .with_git_context(Some(commit.short_id()), branch.clone());
+        if args.dry_run {
+            dry_run_lines.push(format_backfill_entry(commit, &entry));
+        } else if history.insert_entry(entry) {
+            added += 1;
+        }
+    }
+    progress.finish();
+
+    // Save cache if enabled (errors are non-critical)
+    if !args.common.no_sloc_cache
+        && let Ok(cache_guard) = cache.lock()
+    {
+        let _ = save_cache(&state::cache_path(project_root), &cache_guard);
+    }
+
+    if args.dry_run {
+        println!("Dry-run: backfill NOT saved\n");
+        println!("Would record {} snapshots:", commits.len());
+        for line in dry_run_lines {
+            println!("{line}");
+        }
+        return Ok(EXIT_SUCCESS);
+    }
+
+    // Retention applies to backfilled entries like any other
+    history.save_with_retention(history_path, &config.trend)?;
+
+    if !cli.quiet {
+        let skipped = commits.len() - added;
+        println!(
+            "Backfilled {added} snapshots from {} commits to {}",
+            commits.len(),
+            history_path.display()
+        );
+        if skipped > 0 {
+            println!("  Skipped {skipped} commits already in history");
+        }
+    }
+
+    Ok(EXIT_SUCCESS)
+}
+
+fn format_backfill_entry(commit: &HistoryCommit, entry: &TrendEntry) -> String {
+    format!(
+        "  {}  {}  {} files, {} code lines",
+        format_timestamp(commit.timestamp),
+        commit.short_id(),
+        entry.total_files,
+        entry.code
+    )
+}
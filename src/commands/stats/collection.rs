@@ -92,7 +92,12 @@ pub fn collect_stats_with_config_and_reader(
 
     // Scan directories
     let use_gitignore = config.scanner.gitignore && !common.no_gitignore;
-    let all_files = scan_files(&paths_to_scan, &exclude_patterns, use_gitignore)?;
+    let all_files = scan_files(
+        &paths_to_scan,
+        &exclude_patterns,
+        use_gitignore,
+        config.scanner.follow_symlinks,
+    )?;
 
     // Process files in parallel
     let progress = ScanProgress::new(all_files.len() as u64, cli.quiet);
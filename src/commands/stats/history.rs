@@ -101,6 +101,7 @@ pub fn format_history_json(entries: &[&TrendEntry]) -> crate::Result<String> {
 ///
 /// Uses manual UTC calculation to avoid adding a datetime dependency (chrono/time).
 /// This is acceptable for simple UTC formatting; complex timezone handling would warrant a crate.
+#[must_use]
 pub fn format_timestamp(timestamp: u64) -> String {
     // Convert to date components (simplified UTC implementation)
     let days_since_epoch = timestamp / 86400;
@@ -3,19 +3,23 @@ mod formatting;
 mod history;
 mod report;
 mod runner;
+mod sizes;
 
 pub use collection::{collect_file_stats, collect_stats_with_config_and_reader};
+pub use history::format_timestamp;
 pub use runner::run_stats;
 
 // Re-export internal items for tests
 #[cfg(test)]
 pub(crate) use formatting::format_stats_output;
 #[cfg(test)]
-pub(crate) use history::{
-    days_to_ymd, format_history_json, format_history_text, format_timestamp, is_leap_year,
-};
+pub(crate) use history::{days_to_ymd, format_history_json, format_history_text, is_leap_year};
 #[cfg(test)]
 pub(crate) use report::{build_exclude_set, parse_breakdown_by};
+#[cfg(test)]
+pub(crate) use sizes::{
+    FileSize, format_sizes_json, format_sizes_markdown, format_sizes_text, sorted_file_sizes,
+};
 
 #[cfg(test)]
 mod stats_collection_tests;
@@ -27,3 +31,5 @@ mod stats_history_tests;
 mod stats_report_tests;
 #[cfg(test)]
 mod stats_runner_tests;
+#[cfg(test)]
+mod stats_sizes_tests;
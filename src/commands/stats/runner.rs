@@ -9,6 +9,7 @@ use super::collection::{collect_stats, save_cache_if_enabled};
 use super::formatting::format_stats_subcommand_output;
 use super::history::run_history;
 use super::report::run_report;
+use super::sizes::run_sizes;
 use crate::commands::context::color_choice_to_mode;
 
 /// Main entry point for the stats command.
@@ -19,6 +20,7 @@ pub fn run_stats(args: &StatsArgs, cli: &Cli) -> i32 {
     let result = match &args.action {
         StatsAction::Summary(summary_args) => run_summary(summary_args, cli),
         StatsAction::Files(files_args) => run_files(files_args, cli),
+        StatsAction::Sizes(sizes_args) => run_sizes(sizes_args, cli),
         StatsAction::Breakdown(breakdown_args) => run_breakdown(breakdown_args, cli),
         StatsAction::Trend(trend_args) => run_trend(trend_args, cli),
         StatsAction::History(history_args) => run_history(history_args, cli),
@@ -0,0 +1,172 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::EXIT_SUCCESS;
+use crate::cli::{Cli, SizesArgs, StatsOutputFormat};
+use crate::commands::context::{load_config, resolve_scan_paths};
+use crate::config::{FetchPolicy, format_byte_size};
+use crate::output::path::display_path;
+use crate::scanner::scan_files;
+use crate::state;
+
+/// A scanned file and its size on disk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileSize {
+    pub path: PathBuf,
+    pub bytes: u64,
+}
+
+/// Run the sizes subcommand: list the largest files by bytes, whatever their extension.
+pub fn run_sizes(args: &SizesArgs, cli: &Cli) -> crate::Result<i32> {
+    let load_result = load_config(
+        args.common.config.as_deref(),
+        cli.no_config,
+        cli.no_extends,
+        FetchPolicy::from_cli(cli.extends_policy),
+    )?;
+    let config = load_result.config;
+    let project_root = state::discover_project_root(Path::new("."));
+
+    // Same file set as the structure scan: scanner excludes and .gitignore apply,
+    // but not `content.extensions`
+    let mut exclude_patterns = config.scanner.exclude.clone();
+    exclude_patterns.extend(args.common.exclude.clone());
+    let paths_to_scan = resolve_scan_paths(&args.common.paths, &args.common.include);
+    let use_gitignore = config.scanner.gitignore && !args.common.no_gitignore;
+    let all_files = scan_files(
+        &paths_to_scan,
+        &exclude_patterns,
+        use_gitignore,
+        config.scanner.follow_symlinks,
+    )?;
+
+    let mut files = sorted_file_sizes(all_files, args.common.ext.as_deref());
+    let total_files = files.len();
+    files.truncate(args.top);
+
+    let root = Some(project_root.as_path());
+    let output = match args.format {
+        StatsOutputFormat::Text => format_sizes_text(&files, total_files, root),
+        StatsOutputFormat::Json => format_sizes_json(&files, total_files, root)?,
+        StatsOutputFormat::Markdown => format_sizes_markdown(&files, total_files, root),
+    };
+    println!("{output}");
+
+    Ok(EXIT_SUCCESS)
+}
+
+/// Sizes of `paths`, largest first (ties by path). Unreadable files are skipped.
+///
+/// `extensions` (e.g., `rs`, `png`) restricts the result when given.
+pub fn sorted_file_sizes(paths: Vec<PathBuf>, extensions: Option<&[String]>) -> Vec<FileSize> {
+    let mut files: Vec<FileSize> = paths
+        .into_iter()
+        .filter(|path| {
+            extensions.is_none_or(|extensions| {
+                path.extension()
+                    .and_then(|ext| ext.to_str())
+                    .is_some_and(|ext| {
+                        extensions
+                            .iter()
+                            .any(|wanted| wanted.trim_start_matches('.') == ext)
+                    })
+            })
+        })
+        .filter_map(|path| {
+            let bytes = std::fs::metadata(&path).ok()?.len();
+            Some(FileSize { path, bytes })
+        })
+        .collect();
+    files.sort_by(|a, b| b.bytes.cmp(&a.bytes).then_with(|| a.path.cmp(&b.path)));
+    files
+}
+
+/// Format the largest files as human-readable text.
+pub fn format_sizes_text(
+    files: &[FileSize],
+    total_files: usize,
+    project_root: Option<&Path>,
+) -> String {
+    if files.is_empty() {
+        return "No files found.".to_string();
+    }
+
+    let mut output = String::new();
+    let _ = writeln!(
+        output,
+        "Largest Files by Size ({} of {total_files} files):\n",
+        files.len()
+    );
+    for (i, file) in files.iter().enumerate() {
+        let _ = writeln!(
+            output,
+            "  {}. {} - {}",
+            i + 1,
+            display_path(&file.path, project_root),
+            format_byte_size(file.bytes)
+        );
+    }
+    output
+}
+
+/// Format the largest files as a Markdown table.
+pub fn format_sizes_markdown(
+    files: &[FileSize],
+    total_files: usize,
+    project_root: Option<&Path>,
+) -> String {
+    let mut output = String::new();
+    let _ = writeln!(
+        output,
+        "## Largest Files by Size ({} of {total_files})\n",
+        files.len()
+    );
+    output.push_str("| File | Size | Bytes |\n");
+    output.push_str("|------|-----:|------:|\n");
+    for file in files {
+        let _ = writeln!(
+            output,
+            "| `{}` | {} | {} |",
+            display_path(&file.path, project_root),
+            format_byte_size(file.bytes),
+            file.bytes
+        );
+    }
+    output
+}
+
+/// Format the largest files as JSON.
+pub fn format_sizes_json(
+    files: &[FileSize],
+    total_files: usize,
+    project_root: Option<&Path>,
+) -> crate::Result<String> {
+    #[derive(Serialize)]
+    struct SizeEntry {
+        path: String,
+        bytes: u64,
+        size: String,
+    }
+
+    #[derive(Serialize)]
+    struct SizesOutput {
+        total_files: usize,
+        files: Vec<SizeEntry>,
+    }
+
+    let output = SizesOutput {
+        total_files,
+        files: files
+            .iter()
+            .map(|file| SizeEntry {
+                path: display_path(&file.path, project_root),
+                bytes: file.bytes,
+                size: format_byte_size(file.bytes),
+            })
+            .collect(),
+    };
+
+    serde_json::to_string_pretty(&output).map_err(crate::SlocGuardError::from)
+}
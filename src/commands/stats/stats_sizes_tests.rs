@@ -0,0 +1,99 @@
+use std::path::{Path, PathBuf};
+
+use tempfile::TempDir;
+
+use super::*;
+
+fn write_file(dir: &TempDir, name: &str, bytes: usize) -> PathBuf {
+    let path = dir.path().join(name);
+    std::fs::write(&path, vec![b'x'; bytes]).unwrap();
+    path
+}
+
+#[test]
+fn sorted_file_sizes_orders_largest_first_then_by_path() {
+    let dir = TempDir::new().unwrap();
+    let small = write_file(&dir, "small.rs", 10);
+    let big = write_file(&dir, "big.png", 5000);
+    let tie_b = write_file(&dir, "b.json", 100);
+    let tie_a = write_file(&dir, "a.json", 100);
+
+    let files = sorted_file_sizes(
+        vec![small.clone(), tie_b.clone(), big.clone(), tie_a.clone()],
+        None,
+    );
+
+    let order: Vec<_> = files.iter().map(|f| (f.path.clone(), f.bytes)).collect();
+    assert_eq!(
+        order,
+        vec![(big, 5000), (tie_a, 100), (tie_b, 100), (small, 10)]
+    );
+}
+
+#[test]
+fn sorted_file_sizes_filters_by_extension() {
+    let dir = TempDir::new().unwrap();
+    let rs = write_file(&dir, "main.rs", 10);
+    let png = write_file(&dir, "logo.png", 20);
+    let none = write_file(&dir, "Makefile", 30);
+
+    let extensions = vec!["rs".to_string(), ".png".to_string()];
+    let files = sorted_file_sizes(vec![rs.clone(), png.clone(), none], Some(&extensions));
+
+    let paths: Vec<_> = files.into_iter().map(|f| f.path).collect();
+    assert_eq!(paths, vec![png, rs]);
+}
+
+#[test]
+fn sorted_file_sizes_skips_missing_files() {
+    let files = sorted_file_sizes(vec![PathBuf::from("does/not/exist.bin")], None);
+    assert!(files.is_empty());
+}
+
+fn sample_files() -> Vec<FileSize> {
+    vec![
+        FileSize {
+            path: PathBuf::from("/project/assets/model.bin"),
+            bytes: 5 * 1024 * 1024 + 300 * 1024,
+        },
+        FileSize {
+            path: PathBuf::from("/project/src/main.rs"),
+            bytes: 512,
+        },
+    ]
+}
+
+#[test]
+fn format_sizes_text_lists_human_readable_sizes() {
+    let output = format_sizes_text(&sample_files(), 7, Some(Path::new("/project")));
+
+    assert!(output.contains("Largest Files by Size (2 of 7 files):"));
+    assert!(output.contains("  1. assets/model.bin - 5.3 MB"));
+    assert!(output.contains("  2. src/main.rs - 512 B"));
+}
+
+#[test]
+fn format_sizes_text_empty() {
+    assert_eq!(format_sizes_text(&[], 0, None), "No files found.");
+}
+
+#[test]
+fn format_sizes_markdown_renders_table() {
+    let output = format_sizes_markdown(&sample_files(), 2, Some(Path::new("/project")));
+
+    assert!(output.contains("## Largest Files by Size (2 of 2)"));
+    assert!(output.contains("| `assets/model.bin` | 5.3 MB | 5550080 |"));
+    assert!(output.contains("| `src/main.rs` | 512 B | 512 |"));
+}
+
+#[test]
+fn format_sizes_json_includes_bytes_and_size() {
+    let output = format_sizes_json(&sample_files(), 3, Some(Path::new("/project"))).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+    assert_eq!(json["total_files"], 3);
+    assert_eq!(json["files"][0]["path"], "assets/model.bin");
+    assert_eq!(json["files"][0]["bytes"], 5_550_080);
+    assert_eq!(json["files"][0]["size"], "5.3 MB");
+    assert_eq!(json["files"][1]["size"], "512 B");
+}
@@ -0,0 +1,145 @@
+//! Scanner-side structure checks compiled from `[structure]`: allowlists,
+//! `require_files`, layouts, path and size limits.
+
+use std::path::{Path, PathBuf};
+
+use crate::checker::ScopeSelector;
+use crate::config::{Config, ParsedDate, SiblingSeverity, revert_after};
+use crate::scanner::{
+    AllowlistRuleBuilder, FileSizeLimits, LayoutRule, PathLimits, PathLimitsConfig, PathLimitsRule,
+    RequiredFilesRule, StructureScanConfig, SymlinkPolicy,
+};
+
+use super::context::resolve_project_root;
+
+/// Build `StructureScanConfig` from config components.
+///
+/// # Errors
+/// Returns an error if a rule scope, layout entry or pattern is not a valid glob.
+pub fn build_structure_scan_config(
+    config: &Config,
+    exclude_patterns: &[String],
+    as_of: ParsedDate,
+) -> crate::Result<Option<StructureScanConfig>> {
+    // Only build if structure checking is enabled
+    if !config.structure.is_enabled() {
+        return Ok(None);
+    }
+
+    // Build allowlist and require_files rules from structure.rules
+    let mut allowlist_rules = Vec::new();
+    let mut required_files_rules = Vec::new();
+    let mut path_limits = PathLimitsConfig {
+        defaults: PathLimits::from_config(&config.structure),
+        rule_resolution: config.rule_resolution,
+        ..Default::default()
+    };
+    let mut file_size_limits = FileSizeLimits::from_config(&config.structure)?
+        .with_rule_resolution(config.rule_resolution);
+    for rule in &config.structure.rules {
+        // Lapsed `on_expire = "revert"` rules no longer allow or deny anything
+        if revert_after(rule.expires.as_deref(), rule.on_expire).is_some_and(|date| date < as_of) {
+            continue;
+        }
+        let selector = ScopeSelector::new(rule)?;
+
+        if !rule.require_files.is_empty() {
+            // Only the directories the scope names, not their subdirectories
+            required_files_rules.push(RequiredFilesRule::from_selector(
+                ScopeSelector::new_exact(rule)?,
+                rule.require_files.clone(),
+                rule.require_files_severity == Some(SiblingSeverity::Warn),
+            )?);
+        }
+
+        let rule_path_limits = PathLimits::from_rule(rule);
+        if rule_path_limits.is_set() {
+            let limits = PathLimitsRule::from_selector(selector.clone(), rule_path_limits)
+                .with_priority(rule.priority);
+            path_limits.rules.push(limits);
+        }
+        file_size_limits.push_rule(rule)?;
+
+        // Include rules that have allowlists, denylists, or naming patterns
+        if !rule.allow_extensions.is_empty()
+            || !rule.allow_patterns.is_empty()
+            || !rule.allow_files.is_empty()
+            || !rule.allow_dirs.is_empty()
+            || !rule.deny_extensions.is_empty()
+            || !rule.deny_patterns.is_empty()
+            || !rule.deny_files.is_empty()
+            || !rule.deny_dirs.is_empty()
+            || rule.file_naming_pattern.is_some()
+            || rule.file_naming.is_some()
+            || rule.dir_naming_pattern.is_some()
+            || rule.dir_naming.is_some()
+        {
+            let allowlist_rule = AllowlistRuleBuilder::new(rule.scope_label())
+                .with_scope_selector(selector)
+                .with_priority(rule.priority)
+                .with_extensions(rule.allow_extensions.clone())
+                .with_patterns(rule.allow_patterns.clone())
+                .with_allow_files(rule.allow_files.clone())
+                .with_allow_dirs(rule.allow_dirs.clone())
+                .with_deny_extensions(rule.deny_extensions.clone())
+                .with_deny_patterns(rule.deny_patterns.clone())
+                .with_deny_files(rule.deny_files.clone())
+                .with_deny_dirs(rule.deny_dirs.clone())
+                .with_naming_pattern(rule.file_naming_pattern.clone())
+                .with_file_naming(rule.file_naming)
+                .with_naming_stem(rule.naming_stem.unwrap_or_default())
+                .with_dir_naming_pattern(rule.dir_naming_pattern.clone())
+                .with_dir_naming(rule.dir_naming)
+                .build()?;
+            allowlist_rules.push(allowlist_rule);
+        }
+    }
+
+    if path_limits.is_enabled() {
+        path_limits.root_prefix = working_dir_in_project();
+    }
+    let layout_rules = config
+        .structure
+        .layouts
+        .iter()
+        .map(LayoutRule::new)
+        .collect::<crate::Result<_>>()?;
+
+    let structure_scan_config = StructureScanConfig::builder()
+        .count_exclude(config.structure.count_exclude.clone())
+        .scanner_exclude(exclude_patterns.to_vec())
+        .allowlist_rules(allowlist_rules)
+        .rule_resolution(config.rule_resolution)
+        .required_files_rules(required_files_rules)
+        .layout_rules(layout_rules)
+        .path_limits(path_limits)
+        .file_size_limits(file_size_limits)
+        .symlink_policy(SymlinkPolicy::new(
+            config.structure.deny_symlinks,
+            resolve_project_root(),
+        ))
+        .detect_case_collisions(config.structure.detect_case_collisions)
+        .detect_invalid_windows_names(config.structure.detect_invalid_windows_names)
+        .global_allow_extensions(config.structure.allow_extensions.clone())
+        .global_allow_files(config.structure.allow_files.clone())
+        .global_allow_dirs(config.structure.allow_dirs.clone())
+        .global_deny_extensions(config.structure.deny_extensions.clone())
+        .global_deny_patterns(config.structure.deny_patterns.clone())
+        .global_deny_files(config.structure.deny_files.clone())
+        .global_deny_dirs(config.structure.deny_dirs.clone())
+        .build()?;
+
+    Ok(Some(structure_scan_config))
+}
+
+/// Working directory relative to the project root (empty if it is the root).
+///
+/// Scan paths are relative to the working directory; path length limits measure
+/// them from the project root.
+fn working_dir_in_project() -> PathBuf {
+    let project_root = resolve_project_root();
+    dunce::canonicalize(".")
+        .ok()
+        .and_then(|cwd| cwd.strip_prefix(&project_root).ok().map(Path::to_path_buf))
+        .unwrap_or_default()
+}
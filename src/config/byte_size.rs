@@ -0,0 +1,111 @@
+//! Byte sizes for `max_file_bytes`, written as a plain byte count or with a
+//! unit (`"5MB"`, `"512 KiB"`, `"1.5G"`). Units are binary: 1 KB = 1024 bytes.
+
+use serde::Deserialize;
+
+use super::UNLIMITED;
+
+const KIB: u64 = 1024;
+
+/// Accepted unit suffixes (lowercase) and their multipliers.
+const UNITS: &[(&str, u64)] = &[
+    ("b", 1),
+    ("k", KIB),
+    ("kb", KIB),
+    ("kib", KIB),
+    ("m", KIB * KIB),
+    ("mb", KIB * KIB),
+    ("mib", KIB * KIB),
+    ("g", KIB * KIB * KIB),
+    ("gb", KIB * KIB * KIB),
+    ("gib", KIB * KIB * KIB),
+];
+
+/// Parse a size such as `"5MB"`, `"512 KiB"`, `"1.5G"` or `"2048"` into bytes.
+///
+/// Returns `None` for negative, malformed or overflowing sizes.
+#[must_use]
+pub fn parse_byte_size(text: &str) -> Option<u64> {
+    let text = text.trim();
+    let split = text
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(text.len());
+    let (number, unit) = text.split_at(split);
+    let unit = unit.trim().to_ascii_lowercase();
+    let multiplier = if unit.is_empty() {
+        1
+    } else {
+        UNITS.iter().find(|(name, _)| *name == unit)?.1
+    };
+
+    if let Ok(whole) = number.parse::<u64>() {
+        return whole.checked_mul(multiplier);
+    }
+    let value: f64 = number.parse().ok()?;
+    // Fractional sizes ("1.5MB") are rounded to whole bytes; the range check
+    // keeps the cast back to u64 lossless.
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    {
+        let bytes = (value * multiplier as f64).round();
+        (bytes.is_finite() && bytes < u64::MAX as f64).then_some(bytes as u64)
+    }
+}
+
+/// Format bytes for display: `"512 B"`, `"4 KB"`, `"5.2 MB"`.
+#[must_use]
+pub fn format_byte_size(bytes: u64) -> String {
+    const UNIT_NAMES: [&str; 4] = ["KB", "MB", "GB", "TB"];
+
+    if bytes < KIB {
+        return format!("{bytes} B");
+    }
+    // Display only: one decimal place is all the precision shown
+    #[allow(clippy::cast_precision_loss)]
+    let mut value = bytes as f64 / KIB as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNIT_NAMES.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    let text = format!("{value:.1}");
+    let text = text.strip_suffix(".0").unwrap_or(&text);
+    format!("{text} {}", UNIT_NAMES[unit])
+}
+
+/// Deserialize an optional byte size from an integer (`-1` = unlimited) or a size string.
+pub(super) fn deserialize_byte_size<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Option<i64>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Helper {
+        Bytes(i64),
+        Text(String),
+    }
+
+    match Helper::deserialize(deserializer)? {
+        Helper::Bytes(bytes) if bytes >= UNLIMITED => Ok(Some(bytes)),
+        Helper::Bytes(bytes) => Err(serde::de::Error::custom(format!(
+            "Invalid max_file_bytes value: {bytes}. Use -1 for unlimited, or a size such as \"5MB\"."
+        ))),
+        Helper::Text(text) => parse_byte_size(&text)
+            .and_then(|bytes| i64::try_from(bytes).ok())
+            .map(Some)
+            .ok_or_else(|| {
+                serde::de::Error::custom(format!(
+                    "Invalid max_file_bytes value '{text}': expected a byte count or a size such as \"5MB\" or \"512KiB\"."
+                ))
+            }),
+    }
+}
+
+#[cfg(test)]
+#[path = "byte_size_tests.rs"]
+mod tests;
@@ -0,0 +1,58 @@
+//! Tests for the `byte_size` module.
+
+use super::*;
+
+#[test]
+fn parse_plain_byte_counts() {
+    assert_eq!(parse_byte_size("0"), Some(0));
+    assert_eq!(parse_byte_size("2048"), Some(2048));
+    assert_eq!(parse_byte_size(" 512B "), Some(512));
+}
+
+#[test]
+fn parse_binary_units_case_insensitively() {
+    assert_eq!(parse_byte_size("5MB"), Some(5 * 1024 * 1024));
+    assert_eq!(parse_byte_size("5 mb"), Some(5 * 1024 * 1024));
+    assert_eq!(parse_byte_size("512KiB"), Some(512 * 1024));
+    assert_eq!(parse_byte_size("64k"), Some(64 * 1024));
+    assert_eq!(parse_byte_size("1G"), Some(1024 * 1024 * 1024));
+}
+
+#[test]
+fn parse_fractional_sizes_rounds_to_bytes() {
+    assert_eq!(parse_byte_size("1.5MB"), Some(1_572_864));
+    assert_eq!(parse_byte_size("0.5KB"), Some(512));
+}
+
+#[test]
+fn parse_rejects_malformed_sizes() {
+    for text in [
+        "",
+        "MB",
+        "-5MB",
+        "5TB",
+        "5 megabytes",
+        "1.2.3KB",
+        "99999999999999999999",
+    ] {
+        assert_eq!(parse_byte_size(text), None, "{text}");
+    }
+}
+
+#[test]
+fn format_uses_largest_whole_unit() {
+    assert_eq!(format_byte_size(0), "0 B");
+    assert_eq!(format_byte_size(1023), "1023 B");
+    assert_eq!(format_byte_size(1024), "1 KB");
+    assert_eq!(format_byte_size(1536), "1.5 KB");
+    assert_eq!(format_byte_size(5 * 1024 * 1024), "5 MB");
+    assert_eq!(format_byte_size(5_550_080), "5.3 MB");
+    assert_eq!(format_byte_size(3 * 1024 * 1024 * 1024), "3 GB");
+}
+
+#[test]
+fn format_round_trips_through_parse() {
+    for bytes in [1024, 5 * 1024 * 1024, 1_572_864] {
+        assert_eq!(parse_byte_size(&format_byte_size(bytes)), Some(bytes));
+    }
+}
@@ -2,7 +2,7 @@
 //!
 //! Provides simple YYYY-MM-DD date parsing and expiration checking.
 
-use super::Config;
+use super::{Config, OnExpire};
 
 /// The type of rule that has expired.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -33,6 +33,8 @@ pub struct ExpiredRule {
     pub expires: String,
     /// Optional reason field from the rule
     pub reason: Option<String>,
+    /// Configured behavior now that the rule has expired
+    pub on_expire: OnExpire,
 }
 
 /// Collect all expired rules from the configuration using today's date.
@@ -60,9 +62,10 @@ pub fn collect_expired_rules_with_date(config: &Config, today: ParsedDate) -> Ve
             expired.push(ExpiredRule {
                 rule_type: ExpiredRuleType::Content,
                 index: i,
-                pattern: rule.pattern.clone(),
+                pattern: rule.selector_label(),
                 expires: expires.clone(),
                 reason: rule.reason.clone(),
+                on_expire: rule.on_expire.unwrap_or_default(),
             });
         }
     }
@@ -75,9 +78,10 @@ pub fn collect_expired_rules_with_date(config: &Config, today: ParsedDate) -> Ve
             expired.push(ExpiredRule {
                 rule_type: ExpiredRuleType::Structure,
                 index: i,
-                pattern: rule.scope.clone(),
+                pattern: rule.scope_label(),
                 expires: expires.clone(),
                 reason: rule.reason.clone(),
+                on_expire: rule.on_expire.unwrap_or_default(),
             });
         }
     }
@@ -85,6 +89,18 @@ pub fn collect_expired_rules_with_date(config: &Config, today: ParsedDate) -> Ve
     expired
 }
 
+/// Date from which a rule with `on_expire = "revert"` no longer applies.
+///
+/// Returns `None` for rules that keep applying after expiry (or have no valid date).
+/// A rule is reverted when this date is strictly before the reference date.
+#[must_use]
+pub fn revert_after(expires: Option<&str>, on_expire: Option<OnExpire>) -> Option<ParsedDate> {
+    if on_expire != Some(OnExpire::Revert) {
+        return None;
+    }
+    expires.and_then(|date| ParsedDate::parse(date).ok())
+}
+
 /// A parsed date with year, month, and day components.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct ParsedDate {
@@ -161,6 +177,12 @@ impl ParsedDate {
     }
 }
 
+impl std::fmt::Display for ParsedDate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
 /// Convert days since Unix epoch (1970-01-01) to (year, month, day).
 const fn days_to_ymd(days: u64) -> (u32, u32, u32) {
     // Algorithm based on Howard Hinnant's date algorithms
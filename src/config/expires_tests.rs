@@ -69,23 +69,41 @@ fn test_collect_expired_rules_with_date() {
     config.content.rules = vec![
         ContentRule {
             pattern: "src/old/**".to_string(),
+            patterns: vec![],
+            exclude_patterns: vec![],
+            languages: vec![],
             max_lines: 500,
             expires: Some("2025-01-01".to_string()),
+            on_expire: None,
             reason: Some("Legacy code".to_string()),
             warn_threshold: None,
             warn_at: None,
             skip_comments: None,
             skip_blank: None,
+            metric: None,
+            min_comment_ratio: None,
+            max_comment_ratio: None,
+            comment_ratio_min_code: None,
+            priority: None,
         },
         ContentRule {
             pattern: "src/new/**".to_string(),
+            patterns: vec![],
+            exclude_patterns: vec![],
+            languages: vec![],
             max_lines: 500,
             expires: Some("2025-12-31".to_string()),
+            on_expire: None,
             reason: None,
             warn_threshold: None,
             warn_at: None,
             skip_comments: None,
             skip_blank: None,
+            metric: None,
+            min_comment_ratio: None,
+            max_comment_ratio: None,
+            comment_ratio_min_code: None,
+            priority: None,
         },
     ];
     config.structure.rules = vec![StructureRule {
@@ -118,43 +136,79 @@ fn test_multiple_content_rules_mixed_expiration() {
     config.content.rules = vec![
         ContentRule {
             pattern: "src/legacy/**".to_string(),
+            patterns: vec![],
+            exclude_patterns: vec![],
+            languages: vec![],
             max_lines: 1000,
             expires: Some("2024-01-01".to_string()), // Expired
+            on_expire: None,
             reason: Some("Legacy exemption".to_string()),
             warn_threshold: None,
             warn_at: None,
             skip_comments: None,
             skip_blank: None,
+            metric: None,
+            min_comment_ratio: None,
+            max_comment_ratio: None,
+            comment_ratio_min_code: None,
+            priority: None,
         },
         ContentRule {
             pattern: "src/generated/**".to_string(),
+            patterns: vec![],
+            exclude_patterns: vec![],
+            languages: vec![],
             max_lines: 2000,
             expires: Some("2026-12-31".to_string()), // Not expired
+            on_expire: None,
             reason: Some("Generated code".to_string()),
             warn_threshold: None,
             warn_at: None,
             skip_comments: None,
             skip_blank: None,
+            metric: None,
+            min_comment_ratio: None,
+            max_comment_ratio: None,
+            comment_ratio_min_code: None,
+            priority: None,
         },
         ContentRule {
             pattern: "src/vendor/**".to_string(),
+            patterns: vec![],
+            exclude_patterns: vec![],
+            languages: vec![],
             max_lines: 1500,
             expires: Some("2025-03-01".to_string()), // Expired
+            on_expire: None,
             reason: Some("Vendor code".to_string()),
             warn_threshold: None,
             warn_at: None,
             skip_comments: None,
             skip_blank: None,
+            metric: None,
+            min_comment_ratio: None,
+            max_comment_ratio: None,
+            comment_ratio_min_code: None,
+            priority: None,
         },
         ContentRule {
             pattern: "tests/**".to_string(),
+            patterns: vec![],
+            exclude_patterns: vec![],
+            languages: vec![],
             max_lines: 800,
             expires: None, // No expiration
+            on_expire: None,
             reason: None,
             warn_threshold: None,
             warn_at: None,
             skip_comments: None,
             skip_blank: None,
+            metric: None,
+            min_comment_ratio: None,
+            max_comment_ratio: None,
+            comment_ratio_min_code: None,
+            priority: None,
         },
     ];
 
@@ -215,23 +269,41 @@ fn test_mixed_content_and_structure_expired_rules() {
     config.content.rules = vec![
         ContentRule {
             pattern: "src/legacy/**".to_string(),
+            patterns: vec![],
+            exclude_patterns: vec![],
+            languages: vec![],
             max_lines: 1000,
             expires: Some("2025-01-01".to_string()), // Expired
+            on_expire: None,
             reason: Some("Legacy content".to_string()),
             warn_threshold: None,
             warn_at: None,
             skip_comments: None,
             skip_blank: None,
+            metric: None,
+            min_comment_ratio: None,
+            max_comment_ratio: None,
+            comment_ratio_min_code: None,
+            priority: None,
         },
         ContentRule {
             pattern: "src/new/**".to_string(),
+            patterns: vec![],
+            exclude_patterns: vec![],
+            languages: vec![],
             max_lines: 500,
             expires: Some("2026-01-01".to_string()), // Not expired
+            on_expire: None,
             reason: None,
             warn_threshold: None,
             warn_at: None,
             skip_comments: None,
             skip_blank: None,
+            metric: None,
+            min_comment_ratio: None,
+            max_comment_ratio: None,
+            comment_ratio_min_code: None,
+            priority: None,
         },
     ];
     config.structure.rules = vec![
@@ -276,33 +348,60 @@ fn test_same_pattern_different_expires() {
     config.content.rules = vec![
         ContentRule {
             pattern: "src/special/**".to_string(),
+            patterns: vec![],
+            exclude_patterns: vec![],
+            languages: vec![],
             max_lines: 800,
             expires: Some("2024-01-01".to_string()), // Expired (first exemption)
+            on_expire: None,
             reason: Some("Phase 1 exemption".to_string()),
             warn_threshold: None,
             warn_at: None,
             skip_comments: None,
             skip_blank: None,
+            metric: None,
+            min_comment_ratio: None,
+            max_comment_ratio: None,
+            comment_ratio_min_code: None,
+            priority: None,
         },
         ContentRule {
             pattern: "src/special/**".to_string(),
+            patterns: vec![],
+            exclude_patterns: vec![],
+            languages: vec![],
             max_lines: 1000,
             expires: Some("2025-01-01".to_string()), // Expired (second exemption)
+            on_expire: None,
             reason: Some("Phase 2 exemption".to_string()),
             warn_threshold: None,
             warn_at: None,
             skip_comments: None,
             skip_blank: None,
+            metric: None,
+            min_comment_ratio: None,
+            max_comment_ratio: None,
+            comment_ratio_min_code: None,
+            priority: None,
         },
         ContentRule {
             pattern: "src/special/**".to_string(),
+            patterns: vec![],
+            exclude_patterns: vec![],
+            languages: vec![],
             max_lines: 1200,
             expires: Some("2026-01-01".to_string()), // Not expired (current exemption)
+            on_expire: None,
             reason: Some("Phase 3 exemption".to_string()),
             warn_threshold: None,
             warn_at: None,
             skip_comments: None,
             skip_blank: None,
+            metric: None,
+            min_comment_ratio: None,
+            max_comment_ratio: None,
+            comment_ratio_min_code: None,
+            priority: None,
         },
     ];
 
@@ -323,23 +422,41 @@ fn test_all_rules_expired() {
     config.content.rules = vec![
         ContentRule {
             pattern: "src/**".to_string(),
+            patterns: vec![],
+            exclude_patterns: vec![],
+            languages: vec![],
             max_lines: 1000,
             expires: Some("2024-01-01".to_string()),
+            on_expire: None,
             reason: None,
             warn_threshold: None,
             warn_at: None,
             skip_comments: None,
             skip_blank: None,
+            metric: None,
+            min_comment_ratio: None,
+            max_comment_ratio: None,
+            comment_ratio_min_code: None,
+            priority: None,
         },
         ContentRule {
             pattern: "tests/**".to_string(),
+            patterns: vec![],
+            exclude_patterns: vec![],
+            languages: vec![],
             max_lines: 800,
             expires: Some("2024-06-01".to_string()),
+            on_expire: None,
             reason: None,
             warn_threshold: None,
             warn_at: None,
             skip_comments: None,
             skip_blank: None,
+            metric: None,
+            min_comment_ratio: None,
+            max_comment_ratio: None,
+            comment_ratio_min_code: None,
+            priority: None,
         },
     ];
     config.structure.rules = vec![StructureRule {
@@ -361,13 +478,22 @@ fn test_no_rules_expired() {
     let mut config = Config::default();
     config.content.rules = vec![ContentRule {
         pattern: "src/**".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
         max_lines: 1000,
         expires: Some("2026-01-01".to_string()), // Future
+        on_expire: None,
         reason: None,
         warn_threshold: None,
         warn_at: None,
         skip_comments: None,
         skip_blank: None,
+        metric: None,
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
     }];
     config.structure.rules = vec![StructureRule {
         scope: "tests/**".to_string(),
@@ -390,33 +516,60 @@ fn test_expires_on_boundary_date() {
     config.content.rules = vec![
         ContentRule {
             pattern: "src/today/**".to_string(),
+            patterns: vec![],
+            exclude_patterns: vec![],
+            languages: vec![],
             max_lines: 1000,
             expires: Some("2025-06-15".to_string()), // Same as today
+            on_expire: None,
             reason: None,
             warn_threshold: None,
             warn_at: None,
             skip_comments: None,
             skip_blank: None,
+            metric: None,
+            min_comment_ratio: None,
+            max_comment_ratio: None,
+            comment_ratio_min_code: None,
+            priority: None,
         },
         ContentRule {
             pattern: "src/yesterday/**".to_string(),
+            patterns: vec![],
+            exclude_patterns: vec![],
+            languages: vec![],
             max_lines: 1000,
             expires: Some("2025-06-14".to_string()), // Yesterday - expired
+            on_expire: None,
             reason: None,
             warn_threshold: None,
             warn_at: None,
             skip_comments: None,
             skip_blank: None,
+            metric: None,
+            min_comment_ratio: None,
+            max_comment_ratio: None,
+            comment_ratio_min_code: None,
+            priority: None,
         },
         ContentRule {
             pattern: "src/tomorrow/**".to_string(),
+            patterns: vec![],
+            exclude_patterns: vec![],
+            languages: vec![],
             max_lines: 1000,
             expires: Some("2025-06-16".to_string()), // Tomorrow - not expired
+            on_expire: None,
             reason: None,
             warn_threshold: None,
             warn_at: None,
             skip_comments: None,
             skip_blank: None,
+            metric: None,
+            min_comment_ratio: None,
+            max_comment_ratio: None,
+            comment_ratio_min_code: None,
+            priority: None,
         },
     ];
 
@@ -443,23 +596,41 @@ fn test_rules_without_expires_never_expire() {
     config.content.rules = vec![
         ContentRule {
             pattern: "src/**".to_string(),
+            patterns: vec![],
+            exclude_patterns: vec![],
+            languages: vec![],
             max_lines: 1000,
             expires: None, // No expiration
+            on_expire: None,
             reason: Some("Permanent exemption".to_string()),
             warn_threshold: None,
             warn_at: None,
             skip_comments: None,
             skip_blank: None,
+            metric: None,
+            min_comment_ratio: None,
+            max_comment_ratio: None,
+            comment_ratio_min_code: None,
+            priority: None,
         },
         ContentRule {
             pattern: "vendor/**".to_string(),
+            patterns: vec![],
+            exclude_patterns: vec![],
+            languages: vec![],
             max_lines: 5000,
             expires: None, // No expiration
+            on_expire: None,
             reason: None,
             warn_threshold: None,
             warn_at: None,
             skip_comments: None,
             skip_blank: None,
+            metric: None,
+            min_comment_ratio: None,
+            max_comment_ratio: None,
+            comment_ratio_min_code: None,
+            priority: None,
         },
     ];
     config.structure.rules = vec![StructureRule {
@@ -475,3 +646,51 @@ fn test_rules_without_expires_never_expire() {
 
     assert!(expired.is_empty());
 }
+
+#[test]
+fn test_expired_rule_carries_on_expire() {
+    let mut config = Config::default();
+    config.structure.rules = vec![
+        StructureRule {
+            scope: "src/legacy".to_string(),
+            expires: Some("2025-01-01".to_string()),
+            on_expire: Some(OnExpire::Revert),
+            ..Default::default()
+        },
+        StructureRule {
+            scope: "src/old".to_string(),
+            expires: Some("2025-01-01".to_string()),
+            ..Default::default()
+        },
+    ];
+
+    let today = ParsedDate::parse("2025-06-15").unwrap();
+    let expired = collect_expired_rules_with_date(&config, today);
+
+    assert_eq!(expired.len(), 2);
+    assert_eq!(expired[0].on_expire, OnExpire::Revert);
+    assert_eq!(expired[1].on_expire, OnExpire::Warn);
+}
+
+#[test]
+fn test_revert_after_only_for_revert_rules() {
+    let date = ParsedDate::parse("2025-06-01").unwrap();
+
+    assert_eq!(
+        revert_after(Some("2025-06-01"), Some(OnExpire::Revert)),
+        Some(date)
+    );
+    assert_eq!(revert_after(Some("2025-06-01"), Some(OnExpire::Fail)), None);
+    assert_eq!(revert_after(Some("2025-06-01"), None), None);
+    assert_eq!(revert_after(None, Some(OnExpire::Revert)), None);
+    assert_eq!(
+        revert_after(Some("not-a-date"), Some(OnExpire::Revert)),
+        None
+    );
+}
+
+#[test]
+fn test_parsed_date_display_is_zero_padded() {
+    let date = ParsedDate::parse("2025-6-1").unwrap();
+    assert_eq!(date.to_string(), "2025-06-01");
+}
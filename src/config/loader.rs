@@ -34,6 +34,8 @@ pub struct LoadResult {
     pub config: Config,
     /// The preset name if a preset was used (e.g., "rust-strict").
     pub preset_used: Option<String>,
+    /// The config file that was loaded (`None` when defaults were used).
+    pub config_path: Option<PathBuf>,
 }
 
 /// Result of loading a configuration with full source tracking.
@@ -271,6 +273,7 @@ impl<F: FileSystem> ConfigLoader for FileConfigLoader<F> {
         Ok(LoadResult {
             config: Config::default(),
             preset_used: None,
+            config_path: None,
         })
     }
 
@@ -301,6 +304,7 @@ impl<F: FileSystem> ConfigLoader for FileConfigLoader<F> {
             Ok(LoadResult {
                 config,
                 preset_used,
+                config_path: Some(path.to_path_buf()),
             })
         } else {
             // Single-file mode: use appropriate path based on reset marker presence
@@ -308,6 +312,7 @@ impl<F: FileSystem> ConfigLoader for FileConfigLoader<F> {
             Ok(LoadResult {
                 config,
                 preset_used: None,
+                config_path: Some(path.to_path_buf()),
             })
         }
     }
@@ -328,6 +333,7 @@ impl<F: FileSystem> ConfigLoader for FileConfigLoader<F> {
         Ok(LoadResult {
             config: Config::default(),
             preset_used: None,
+            config_path: None,
         })
     }
 
@@ -348,6 +354,7 @@ impl<F: FileSystem> ConfigLoader for FileConfigLoader<F> {
         Ok(LoadResult {
             config,
             preset_used: None,
+            config_path: Some(path.to_path_buf()),
         })
     }
 
@@ -1,27 +1,37 @@
+mod byte_size;
 mod expires;
 mod extends;
 mod filesystem;
 mod loader;
 pub(crate) mod merge;
 mod model;
+mod naming;
 pub mod presets;
 mod remote;
+mod template;
 mod validation;
 
-pub use expires::{ExpiredRule, ExpiredRuleType, collect_expired_rules};
+pub use byte_size::{format_byte_size, parse_byte_size};
+pub use expires::{
+    ExpiredRule, ExpiredRuleType, ParsedDate, collect_expired_rules,
+    collect_expired_rules_with_date, revert_after,
+};
 pub use extends::SourcedConfig;
 pub use filesystem::{FileSystem, RealFileSystem};
 pub use loader::{ConfigLoader, FileConfigLoader, LoadResult, LoadResultWithSources};
 pub use merge::RESET_MARKER;
 pub use model::{
-    BaselineConfig, CONFIG_VERSION, CheckConfig, Config, ContentConfig, ContentRule,
-    CustomLanguageConfig, DEFAULT_MAX_LINES, RatchetMode, ScannerConfig, SiblingRequire,
-    SiblingRule, SiblingSeverity, StatsConfig, StatsReportConfig, StructureConfig, StructureRule,
-    TrendConfig, UNLIMITED,
+    BaselineConfig, CONFIG_VERSION, CheckConfig, Config, ContentConfig, ContentMetric, ContentRule,
+    CustomLanguageConfig, DEFAULT_MAX_LINES, DenyEmptyDirs, DenySymlinks, OnExpire, RatchetMode,
+    RuleResolution, ScannerConfig, SiblingRequire, SiblingRule, SiblingSeverity, StatsConfig,
+    StatsReportConfig, StructureConfig, StructureLayout, StructureRule, TrendConfig, UNLIMITED,
 };
+pub(crate) use naming::split_words;
+pub use naming::{NamingStem, NamingStyle};
 pub use remote::{
     FetchPolicy, clear_cache as clear_remote_cache, fetch_remote_config, is_remote_url,
 };
+pub use template::{Placeholder, Template, TemplateValues};
 pub(crate) use validation::validate_config_semantics;
 
 #[cfg(test)]
@@ -1,5 +1,10 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
+use super::byte_size::deserialize_byte_size;
+use super::naming::{NamingStem, NamingStyle};
+
 /// Supported config version. Current version is "2".
 pub const CONFIG_VERSION: &str = "2";
 
@@ -97,6 +102,11 @@ pub struct CheckConfig {
     /// When enabled, short-circuits file processing after detecting a failure.
     #[serde(default)]
     pub fail_fast: bool,
+
+    /// Compare a single `--diff` reference from its merge-base with HEAD,
+    /// as if written `--diff ref...HEAD`. Explicit `base..target` ranges are unaffected.
+    #[serde(default)]
+    pub diff_merge_base: bool,
 }
 
 /// Stats command configuration, specifically for report subcommand defaults.
@@ -155,6 +165,11 @@ pub struct ScannerConfig {
     /// Default: `[".git/**"]` - always exclude .git directory.
     #[serde(default = "default_scanner_exclude")]
     pub exclude: Vec<String>,
+
+    /// Follow symlinks to files and directories (default: false).
+    /// Symlink loops are skipped and reported as structure warnings.
+    #[serde(default)]
+    pub follow_symlinks: bool,
 }
 
 /// Default scanner exclude patterns.
@@ -168,10 +183,134 @@ impl Default for ScannerConfig {
         Self {
             gitignore: true,
             exclude: default_scanner_exclude(),
+            follow_symlinks: false,
+        }
+    }
+}
+
+/// Line metric that content limits are measured against.
+///
+/// Replaces the `skip_comments`/`skip_blank` boolean pair with a single named choice.
+/// `code` and `code_and_comments` honour `sloc-guard:ignore-*` directives; `non_blank`
+/// and `total` measure physical lines, so ignored regions still count towards them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContentMetric {
+    /// Code lines only.
+    Code,
+    /// Code lines plus comment lines.
+    CodeAndComments,
+    /// Physical lines that are not blank.
+    NonBlank,
+    /// All physical lines.
+    Total,
+}
+
+impl ContentMetric {
+    /// Config value name (as written in TOML).
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Code => "code",
+            Self::CodeAndComments => "code_and_comments",
+            Self::NonBlank => "non_blank",
+            Self::Total => "total",
+        }
+    }
+
+    /// The metric a legacy `skip_comments`/`skip_blank` pair counts, if one matches it.
+    ///
+    /// Counting blank lines has no exact metric: `non_blank` and `total` are physical
+    /// counts that include lines suppressed by ignore directives.
+    #[must_use]
+    pub const fn from_skip_settings(skip_comments: bool, skip_blank: bool) -> Option<Self> {
+        match (skip_comments, skip_blank) {
+            (true, true) => Some(Self::Code),
+            (false, true) => Some(Self::CodeAndComments),
+            (_, false) => None,
+        }
+    }
+
+    /// Human-readable unit for labelling counts (e.g. "523 total lines").
+    #[must_use]
+    pub const fn unit(self) -> &'static str {
+        match self {
+            Self::Code => "code lines",
+            Self::CodeAndComments => "code+comment lines",
+            Self::NonBlank => "non-blank lines",
+            Self::Total => "total lines",
+        }
+    }
+}
+
+impl std::fmt::Display for ContentMetric {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// How a winner is chosen among `[[content.rules]]` / `[[structure.rules]]` that match a path.
+///
+/// Explicit `priority` always takes precedence; this only orders rules of equal priority.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleResolution {
+    /// The last declared matching rule wins (default).
+    #[default]
+    LastMatch,
+    /// The rule with the most specific pattern wins (literal length, then literal depth);
+    /// ties fall back to declaration order.
+    MostSpecific,
+}
+
+impl RuleResolution {
+    /// Config value name (as written in TOML).
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::LastMatch => "last_match",
+            Self::MostSpecific => "most_specific",
+        }
+    }
+}
+
+impl std::fmt::Display for RuleResolution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// What happens once a rule's `expires` date has passed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OnExpire {
+    /// Keep applying the rule and emit a warning (default).
+    #[default]
+    Warn,
+    /// Fail `check` with an expired exemption violation pointing at the rule.
+    Fail,
+    /// Stop matching; files fall back to the next matching rule or the defaults.
+    Revert,
+}
+
+impl OnExpire {
+    /// Config value name (as written in TOML).
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Warn => "warn",
+            Self::Fail => "fail",
+            Self::Revert => "revert",
         }
     }
 }
 
+impl std::fmt::Display for OnExpire {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Content configuration for SLOC limits.
 /// Extensions filter is HERE (not in scanner) - only these files get SLOC analysis.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -202,6 +341,11 @@ pub struct ContentConfig {
     #[serde(default = "default_true")]
     pub skip_blank: bool,
 
+    /// Line metric for limits. Takes precedence over `skip_comments`/`skip_blank`
+    /// and must not be combined with either set to `false`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metric: Option<ContentMetric>,
+
     /// Glob patterns for files to exclude from content (SLOC) checks.
     /// These files are still visible for structure checks.
     #[serde(default)]
@@ -221,6 +365,7 @@ impl Default for ContentConfig {
             warn_at: None,
             skip_comments: true,
             skip_blank: true,
+            metric: None,
             exclude: Vec::new(),
             rules: Vec::new(),
         }
@@ -230,9 +375,23 @@ impl Default for ContentConfig {
 /// Content rule for path-based SLOC limits [[content.rules]].
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ContentRule {
-    /// Glob pattern for file matching.
+    /// Glob pattern for file matching. May be omitted when `patterns` or `languages` is set.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub pattern: String,
 
+    /// Additional glob patterns; a file is selected if it matches `pattern` or any of these.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub patterns: Vec<String>,
+
+    /// Glob patterns removing otherwise selected files from this rule (e.g., `"**/*.d.ts"`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude_patterns: Vec<String>,
+
+    /// Language names (case-insensitive, e.g., `"Rust"`); a file must be one of them.
+    /// Combined with the path patterns using AND logic.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub languages: Vec<String>,
+
     /// Maximum lines for matched files.
     pub max_lines: usize,
 
@@ -254,13 +413,69 @@ pub struct ContentRule {
     #[serde(default)]
     pub skip_blank: Option<bool>,
 
+    /// Override the line metric for matched files.
+    /// Mutually exclusive with `skip_comments`/`skip_blank` on the same rule.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metric: Option<ContentMetric>,
+
+    /// Minimum share of comment lines (`comment / (code + comment)`, 0.0-1.0).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_comment_ratio: Option<f64>,
+
+    /// Maximum share of comment lines; catches files dominated by commented-out code.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_comment_ratio: Option<f64>,
+
+    /// Code lines a file needs before comment ratio limits apply (default: 100).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_ratio_min_code: Option<usize>,
+
+    /// Explicit rule priority; higher wins over any `rule_resolution` ordering (default: 0).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i32>,
+
     /// Optional reason for this rule (audit trail, displayed in explain output).
     #[serde(default)]
     pub reason: Option<String>,
 
-    /// Optional expiration date (YYYY-MM-DD). Past dates emit warnings.
+    /// Optional expiration date (YYYY-MM-DD). What happens afterwards follows `on_expire`.
     #[serde(default)]
     pub expires: Option<String>,
+
+    /// Behavior once `expires` has passed (default: warn). Requires `expires`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_expire: Option<OnExpire>,
+}
+
+impl ContentRule {
+    /// Path include globs: `pattern` (if set) followed by `patterns`.
+    pub fn path_patterns(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.pattern.as_str())
+            .filter(|pattern| !pattern.is_empty())
+            .chain(self.patterns.iter().map(String::as_str))
+    }
+
+    /// Short description of the rule's selectors for messages and explain output.
+    ///
+    /// A rule with only `pattern` is labelled by that pattern alone.
+    #[must_use]
+    pub fn selector_label(&self) -> String {
+        use std::fmt::Write;
+
+        let paths: Vec<&str> = self.path_patterns().collect();
+        let mut label = if paths.is_empty() {
+            "**".to_string()
+        } else {
+            paths.join(", ")
+        };
+        if !self.languages.is_empty() {
+            let _ = write!(label, " [languages: {}]", self.languages.join(", "));
+        }
+        if !self.exclude_patterns.is_empty() {
+            let _ = write!(label, " [exclude: {}]", self.exclude_patterns.join(", "));
+        }
+        label
+    }
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
@@ -277,6 +492,10 @@ pub struct Config {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub extends_sha256: Option<String>,
 
+    /// Tie-breaking strategy for overlapping content/structure rules.
+    #[serde(default)]
+    pub rule_resolution: RuleResolution,
+
     /// Scanner configuration (file discovery).
     #[serde(default)]
     pub scanner: ScannerConfig,
@@ -387,12 +606,14 @@ pub enum SiblingSeverity {
 
 /// Sibling rule for file co-location checking.
 ///
-/// Supports two rule types:
+/// Supports four rule types:
 /// - **Directed**: If a file matches `match_pattern`, require specific sibling(s).
 /// - **Group**: If ANY file in the group exists, ALL must exist (atomic group).
+/// - **Forbid**: If a file matches `match_pattern`, specific sibling(s) must NOT exist.
+/// - **Exclusive**: At most one of the listed files may exist in a directory.
 ///
-/// **Note**: Ambiguous configs containing both `match`/`require` AND `group` fields
-/// are rejected during deserialization with a clear error message.
+/// **Note**: Ambiguous configs mixing fields of different rule types (e.g. `require`
+/// with `forbid`, or `match` with `group`) are rejected during deserialization.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub enum SiblingRule {
     /// Directed rule: if file matches, require sibling(s).
@@ -413,6 +634,24 @@ pub enum SiblingRule {
         /// Severity level (default: error).
         severity: SiblingSeverity,
     },
+    /// Forbidden sibling: if file matches, the sibling(s) must not exist.
+    /// Example: `{ match = "*.ts", forbid = "{stem}.js" }`
+    Forbid {
+        /// Glob pattern for files that trigger the rule.
+        match_pattern: String,
+        /// Forbidden sibling pattern(s). Use `{stem}` for the source file's stem.
+        forbid: SiblingRequire,
+        /// Severity level (default: error).
+        severity: SiblingSeverity,
+    },
+    /// Mutually exclusive files: at most one entry may match in a directory.
+    /// Example: `{ exclusive = ["index.js", "index.ts", "index.tsx"] }`
+    Exclusive {
+        /// File name glob patterns, of which at most one may be present.
+        exclusive: Vec<String>,
+        /// Severity level (default: error).
+        severity: SiblingSeverity,
+    },
 }
 
 /// Intermediate struct for deserializing `SiblingRule` with ambiguity detection.
@@ -421,7 +660,9 @@ struct SiblingRuleHelper {
     #[serde(rename = "match")]
     match_pattern: Option<String>,
     require: Option<SiblingRequire>,
+    forbid: Option<SiblingRequire>,
     group: Option<Vec<String>>,
+    exclusive: Option<Vec<String>>,
     #[serde(default)]
     severity: SiblingSeverity,
 }
@@ -432,40 +673,60 @@ impl<'de> serde::Deserialize<'de> for SiblingRule {
         D: serde::Deserializer<'de>,
     {
         let helper = SiblingRuleHelper::deserialize(deserializer)?;
+        let severity = helper.severity;
+
+        let has_directed =
+            helper.match_pattern.is_some() || helper.require.is_some() || helper.forbid.is_some();
+        let kinds = [
+            has_directed,
+            helper.group.is_some(),
+            helper.exclusive.is_some(),
+        ];
+        if kinds.iter().filter(|&&set| set).count() > 1 {
+            return Err(serde::de::Error::custom(
+                "Ambiguous sibling rule: cannot mix 'match'/'require'/'forbid', 'group' \
+                 and 'exclusive'. Use exactly one of Directed ({ match = \"...\", require = \"...\" }), \
+                 Forbid ({ match = \"...\", forbid = \"...\" }), Group ({ group = [...] }) \
+                 or Exclusive ({ exclusive = [...] }).",
+            ));
+        }
 
-        let has_directed = helper.match_pattern.is_some() || helper.require.is_some();
-        let has_group = helper.group.is_some();
+        if let Some(group) = helper.group {
+            return Ok(Self::Group { group, severity });
+        }
+        if let Some(exclusive) = helper.exclusive {
+            return Ok(Self::Exclusive {
+                exclusive,
+                severity,
+            });
+        }
+        if !has_directed {
+            return Err(serde::de::Error::custom(
+                "Invalid sibling rule: must specify either 'match'/'require' (Directed), \
+                 'match'/'forbid' (Forbid), 'group' (Group) or 'exclusive' (Exclusive).",
+            ));
+        }
 
-        match (has_directed, has_group) {
-            (true, true) => Err(serde::de::Error::custom(
-                "Ambiguous sibling rule: cannot mix 'match'/'require' with 'group'. \
-                 Use either Directed ({ match = \"...\", require = \"...\" }) \
-                 OR Group ({ group = [...] }), not both.",
+        let match_pattern = helper.match_pattern.ok_or_else(|| {
+            serde::de::Error::custom("Directed sibling rule requires 'match' field.")
+        })?;
+        match (helper.require, helper.forbid) {
+            (Some(_), Some(_)) => Err(serde::de::Error::custom(
+                "Ambiguous sibling rule: cannot mix 'require' with 'forbid'. \
+                 Use separate sibling rules instead.",
             )),
-            (true, false) => {
-                let match_pattern = helper.match_pattern.ok_or_else(|| {
-                    serde::de::Error::custom("Directed sibling rule requires 'match' field.")
-                })?;
-                let require = helper.require.ok_or_else(|| {
-                    serde::de::Error::custom("Directed sibling rule requires 'require' field.")
-                })?;
-                Ok(Self::Directed {
-                    match_pattern,
-                    require,
-                    severity: helper.severity,
-                })
-            }
-            (false, true) => {
-                // Safe to unwrap: has_group is true
-                let group = helper.group.unwrap();
-                Ok(Self::Group {
-                    group,
-                    severity: helper.severity,
-                })
-            }
-            (false, false) => Err(serde::de::Error::custom(
-                "Invalid sibling rule: must specify either 'match'/'require' (Directed) \
-                 or 'group' (Group).",
+            (Some(require), None) => Ok(Self::Directed {
+                match_pattern,
+                require,
+                severity,
+            }),
+            (None, Some(forbid)) => Ok(Self::Forbid {
+                match_pattern,
+                forbid,
+                severity,
+            }),
+            (None, None) => Err(serde::de::Error::custom(
+                "Directed sibling rule requires 'require' or 'forbid' field.",
             )),
         }
     }
@@ -492,6 +753,123 @@ impl SiblingRequire {
     }
 }
 
+/// Which directories `deny_empty_dirs` reports.
+///
+/// Written as `true`/`false` or `"ignore_count_exclude"` in TOML.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DenyEmptyDirs {
+    /// Empty directories are allowed (default, `false`).
+    #[default]
+    Off,
+    /// Directories with no entries at all are reported (`true`).
+    Empty,
+    /// Directories whose only entries match `count_exclude` (such as a lone
+    /// `.gitkeep`) are reported too (`"ignore_count_exclude"`).
+    IgnoreCountExclude,
+}
+
+impl DenyEmptyDirs {
+    /// Whether empty directory detection is on.
+    #[must_use]
+    pub const fn is_enabled(self) -> bool {
+        !matches!(self, Self::Off)
+    }
+}
+
+impl Serialize for DenyEmptyDirs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Off => serializer.serialize_bool(false),
+            Self::Empty => serializer.serialize_bool(true),
+            Self::IgnoreCountExclude => serializer.serialize_str("ignore_count_exclude"),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for DenyEmptyDirs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Helper {
+            Flag(bool),
+            Mode(String),
+        }
+
+        match Helper::deserialize(deserializer)? {
+            Helper::Flag(false) => Ok(Self::Off),
+            Helper::Flag(true) => Ok(Self::Empty),
+            Helper::Mode(mode) if mode == "ignore_count_exclude" => Ok(Self::IgnoreCountExclude),
+            Helper::Mode(mode) => Err(serde::de::Error::custom(format!(
+                "Invalid deny_empty_dirs value '{mode}': expected true, false or \"ignore_count_exclude\"."
+            ))),
+        }
+    }
+}
+
+/// Which symlinks `deny_symlinks` reports.
+///
+/// Written as `true`/`false` or `"external"` in TOML.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DenySymlinks {
+    /// Symlinks are allowed (default, `false`).
+    #[default]
+    Off,
+    /// Every symlink is reported (`true`).
+    All,
+    /// Only symlinks whose target resolves outside the project root (`"external"`).
+    External,
+}
+
+impl DenySymlinks {
+    /// Whether symlink detection is on.
+    #[must_use]
+    pub const fn is_enabled(self) -> bool {
+        !matches!(self, Self::Off)
+    }
+}
+
+impl Serialize for DenySymlinks {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Off => serializer.serialize_bool(false),
+            Self::All => serializer.serialize_bool(true),
+            Self::External => serializer.serialize_str("external"),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for DenySymlinks {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Helper {
+            Flag(bool),
+            Mode(String),
+        }
+
+        match Helper::deserialize(deserializer)? {
+            Helper::Flag(false) => Ok(Self::Off),
+            Helper::Flag(true) => Ok(Self::All),
+            Helper::Mode(mode) if mode == "external" => Ok(Self::External),
+            Helper::Mode(mode) => Err(serde::de::Error::custom(format!(
+                "Invalid deny_symlinks value '{mode}': expected true, false or \"external\"."
+            ))),
+        }
+    }
+}
+
 /// Configuration for directory structure limits.
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub struct StructureConfig {
@@ -538,6 +916,88 @@ pub struct StructureConfig {
     #[serde(default)]
     pub warn_dirs_threshold: Option<f64>,
 
+    /// Per-extension file limits per directory, keyed by extension with leading dot
+    /// (e.g., `{ ".rs" = 15, ".sql" = 1 }`). Counted after `count_exclude`; warnings
+    /// use `warn_files_threshold`. Use `-1` for unlimited, `0` for prohibited.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub max_files_by_ext: BTreeMap<String, i64>,
+
+    /// Global default limit for files anywhere beneath a directory (recursive, after `count_exclude`).
+    /// Use `-1` for unlimited (no check), `>0` for limit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_total_files: Option<i64>,
+
+    /// Global default limit for directories anywhere beneath a directory (recursive).
+    /// Use `-1` for unlimited (no check), `>0` for limit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_total_dirs: Option<i64>,
+
+    /// Absolute recursive file count at or above which warnings are triggered.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warn_total_files_at: Option<i64>,
+
+    /// Absolute recursive directory count at or above which warnings are triggered.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warn_total_dirs_at: Option<i64>,
+
+    /// Percentage threshold (0.0-1.0) for recursive file count warnings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warn_total_files_threshold: Option<f64>,
+
+    /// Percentage threshold (0.0-1.0) for recursive directory count warnings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warn_total_dirs_threshold: Option<f64>,
+
+    /// Maximum length, in characters, of a file path relative to the project root
+    /// (e.g., `200` to stay clear of Windows' 260-character limit).
+    /// Use `-1` for unlimited (no check), `>0` for limit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_path_length: Option<i64>,
+
+    /// Maximum length, in characters, of a single file or directory name.
+    /// Use `-1` for unlimited (no check), `>0` for limit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_filename_length: Option<i64>,
+
+    /// Maximum number of components in a file path relative to the project root
+    /// (`src/a/b.rs` has 3). Use `-1` for unlimited (no check), `>0` for limit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_path_components: Option<i64>,
+
+    /// Maximum size of any scanned file, whatever its extension, as bytes or with a
+    /// unit (e.g., `"5MB"`, `"512KiB"`; 1 KB = 1024 bytes). Use `-1` for unlimited.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_byte_size",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub max_file_bytes: Option<i64>,
+
+    /// Glob patterns for files exempt from `max_file_bytes` (e.g., "assets/fonts/**").
+    /// Matched against file names and paths, like `count_exclude`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allow_large_files: Vec<String>,
+
+    /// Report sibling files or directories whose names differ only by case
+    /// (`Foo.rs` and `foo.rs`), which collide on case-insensitive file systems.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub detect_case_collisions: bool,
+
+    /// Report names that are invalid on Windows: reserved device names (`CON`, `NUL`,
+    /// `COM1`, ...), trailing dots or spaces, and the characters `< > : " \ | ? *`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub detect_invalid_windows_names: bool,
+
+    /// Report directories without entries. `"ignore_count_exclude"` also reports
+    /// directories whose only entries match `count_exclude` (e.g. a lone `.gitkeep`).
+    #[serde(default)]
+    pub deny_empty_dirs: DenyEmptyDirs,
+
+    /// Report symlinks. `"external"` only reports those whose target resolves
+    /// outside the project root (dangling links are resolved lexically).
+    #[serde(default)]
+    pub deny_symlinks: DenySymlinks,
+
     /// Glob patterns for items not counted in structure limits (e.g., "*.md", ".gitkeep").
     /// These items are still visible but don't count toward file/dir quotas.
     #[serde(default)]
@@ -584,6 +1044,10 @@ pub struct StructureConfig {
     /// Per-directory rules that override global limits.
     #[serde(default)]
     pub rules: Vec<StructureRule>,
+
+    /// Templates describing the expected top-level entries of matching directories.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub layouts: Vec<StructureLayout>,
 }
 
 impl StructureConfig {
@@ -591,11 +1055,23 @@ impl StructureConfig {
     ///
     /// Returns `true` if any limits, rules, or denylists are defined.
     #[must_use]
-    pub const fn is_enabled(&self) -> bool {
+    pub fn is_enabled(&self) -> bool {
         self.max_files.is_some()
             || self.max_dirs.is_some()
             || self.max_depth.is_some()
+            || self.max_total_files.is_some()
+            || self.max_total_dirs.is_some()
+            || !self.max_files_by_ext.is_empty()
+            || self.max_path_length.is_some()
+            || self.max_filename_length.is_some()
+            || self.max_path_components.is_some()
+            || self.max_file_bytes.is_some()
+            || self.detect_case_collisions
+            || self.detect_invalid_windows_names
+            || self.deny_empty_dirs.is_enabled()
+            || self.deny_symlinks.is_enabled()
             || !self.rules.is_empty()
+            || !self.layouts.is_empty()
             // Global allowlist mode should still enable structure scanning so that
             // allowlist violations can be detected and reported even without limits.
             || !self.allow_extensions.is_empty()
@@ -613,8 +1089,19 @@ impl StructureConfig {
 pub struct StructureRule {
     /// Glob pattern defining the directory scope where this rule applies.
     /// Example: `scope = "src/**"` applies to all directories under `src/`.
+    /// May be omitted when `scopes` is set.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub scope: String,
 
+    /// Additional scope globs; a directory is in scope if it matches `scope` or any of these.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub scopes: Vec<String>,
+
+    /// Glob patterns removing otherwise matched directories from the scope
+    /// (e.g., `"src/generated/**"`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude_scopes: Vec<String>,
+
     /// Override limit for files in matched directories.
     /// Use `-1` for unlimited (no check), `0` for prohibited, `>0` for limit.
     #[serde(default)]
@@ -659,6 +1146,61 @@ pub struct StructureRule {
     #[serde(default)]
     pub warn_dirs_threshold: Option<f64>,
 
+    /// Per-extension file limits for matched directories. Entries override the
+    /// `[structure]` table key by key; other extensions keep the global limit.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub max_files_by_ext: BTreeMap<String, i64>,
+
+    /// Override limit for files anywhere beneath matched directories (recursive).
+    /// Use `-1` for unlimited (no check), `>0` for limit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_total_files: Option<i64>,
+
+    /// Override limit for directories anywhere beneath matched directories (recursive).
+    /// Use `-1` for unlimited (no check), `>0` for limit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_total_dirs: Option<i64>,
+
+    /// Absolute recursive file count at or above which warnings are triggered.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warn_total_files_at: Option<i64>,
+
+    /// Absolute recursive directory count at or above which warnings are triggered.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warn_total_dirs_at: Option<i64>,
+
+    /// Percentage threshold (0.0-1.0) for recursive file count warnings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warn_total_files_threshold: Option<f64>,
+
+    /// Percentage threshold (0.0-1.0) for recursive directory count warnings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warn_total_dirs_threshold: Option<f64>,
+
+    /// Override limit for the length of file paths beneath matched directories.
+    /// Use `-1` for unlimited (no check), `>0` for limit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_path_length: Option<i64>,
+
+    /// Override limit for the length of file and directory names in matched directories.
+    /// Use `-1` for unlimited (no check), `>0` for limit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_filename_length: Option<i64>,
+
+    /// Override limit for the number of path components of files in matched directories.
+    /// Use `-1` for unlimited (no check), `>0` for limit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_path_components: Option<i64>,
+
+    /// Override size limit for files in matched directories (bytes or e.g. `"20MB"`).
+    /// Use `-1` for unlimited (no check).
+    #[serde(
+        default,
+        deserialize_with = "deserialize_byte_size",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub max_file_bytes: Option<i64>,
+
     /// Allowlist of allowed file extensions (with leading dot, e.g., ".rs", ".go").
     /// Files NOT matching these extensions are violations.
     /// Combined with `allow_patterns` using OR logic.
@@ -714,6 +1256,25 @@ pub struct StructureRule {
     #[serde(default)]
     pub file_naming_pattern: Option<String>,
 
+    /// Built-in case style for file stems (e.g., `"kebab-case"`, `"PascalCase"`).
+    /// Checked together with `file_naming_pattern` when both are set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_naming: Option<NamingStyle>,
+
+    /// Which part of multi-dot file names (`foo.test.ts`) `file_naming` applies to
+    /// (default: `first_dot`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub naming_stem: Option<NamingStem>,
+
+    /// Regex pattern for directory name validation.
+    /// Subdirectories not matching this pattern trigger a `NamingConvention` violation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dir_naming_pattern: Option<String>,
+
+    /// Built-in case style for directory names.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dir_naming: Option<NamingStyle>,
+
     /// Sibling rules for file co-location checking.
     /// Supports two rule types:
     /// - Directed: `{ match = "*.tsx", require = "{stem}.test.tsx" }`
@@ -721,13 +1282,78 @@ pub struct StructureRule {
     #[serde(default)]
     pub siblings: Vec<SiblingRule>,
 
+    /// File name patterns every directory matching `scope` must contain, e.g. `"README.md"`.
+    /// `{dir}` expands to the directory's own name (`"{dir}.rs"` in `src/parser` → `parser.rs`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub require_files: Vec<String>,
+
+    /// Severity for missing `require_files` (default: error).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub require_files_severity: Option<SiblingSeverity>,
+
+    /// Explicit rule priority; higher wins over any `rule_resolution` ordering (default: 0).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i32>,
+
     /// Optional reason for this rule (audit trail, displayed in explain output).
     #[serde(default)]
     pub reason: Option<String>,
 
-    /// Optional expiration date (YYYY-MM-DD). Past dates emit warnings.
+    /// Optional expiration date (YYYY-MM-DD). What happens afterwards follows `on_expire`.
     #[serde(default)]
     pub expires: Option<String>,
+
+    /// Behavior once `expires` has passed (default: warn). Requires `expires`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_expire: Option<OnExpire>,
+}
+
+impl StructureRule {
+    /// Scope include globs: `scope` (if set) followed by `scopes`.
+    pub fn scope_patterns(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.scope.as_str())
+            .filter(|scope| !scope.is_empty())
+            .chain(self.scopes.iter().map(String::as_str))
+    }
+
+    /// Short description of the rule's scope for messages and explain output.
+    ///
+    /// A rule with only `scope` is labelled by that glob alone.
+    #[must_use]
+    pub fn scope_label(&self) -> String {
+        let mut label = self.scope_patterns().collect::<Vec<_>>().join(", ");
+        if !self.exclude_scopes.is_empty() {
+            label.push_str(" [exclude: ");
+            label.push_str(&self.exclude_scopes.join(", "));
+            label.push(']');
+        }
+        label
+    }
+}
+
+/// Expected shape of the directories matching `scope` (`[[structure.layouts]]`).
+///
+/// Entries are glob patterns matched against entry names; a trailing `/` makes an
+/// entry match directories only (`"cmd/"`), otherwise it matches files only.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct StructureLayout {
+    /// Template name shown in violations (e.g., `"go-service"`).
+    pub name: String,
+
+    /// Glob pattern selecting the directories this template describes (e.g., `"services/*"`).
+    pub scope: String,
+
+    /// Entries every matching directory must contain (e.g., `["cmd/", "internal/", "go.mod"]`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub required: Vec<String>,
+
+    /// Entries a matching directory may contain (e.g., `["docs/"]`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub optional: Vec<String>,
+
+    /// Report top-level entries matching neither `required` nor `optional`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub forbid_other: bool,
 }
 
 #[cfg(test)]
@@ -55,6 +55,7 @@ fn check_config_has_expected_defaults() {
     let config = CheckConfig::default();
     assert!(!config.warnings_as_errors);
     assert!(!config.fail_fast);
+    assert!(!config.diff_merge_base);
 }
 
 #[test]
@@ -65,11 +66,13 @@ fn config_deserialize_check_section() {
         [check]
         warnings_as_errors = true
         fail_fast = true
+        diff_merge_base = true
     "#;
 
     let config: Config = toml::from_str(toml_str).unwrap();
     assert!(config.check.warnings_as_errors);
     assert!(config.check.fail_fast);
+    assert!(config.check.diff_merge_base);
 }
 
 #[test]
@@ -170,3 +173,49 @@ fn config_deserialize_without_version() {
 fn config_version_constant_is_two() {
     assert_eq!(CONFIG_VERSION, "2");
 }
+
+#[test]
+fn config_deserialize_rule_resolution_and_priority() {
+    let toml_str = r#"
+        version = "2"
+        rule_resolution = "most_specific"
+
+        [[content.rules]]
+        pattern = "src/legacy/**"
+        max_lines = 1500
+        priority = 10
+    "#;
+
+    let config: Config = toml::from_str(toml_str).unwrap();
+    assert_eq!(config.rule_resolution, RuleResolution::MostSpecific);
+    assert_eq!(config.content.rules[0].priority, Some(10));
+}
+
+#[test]
+fn config_rule_resolution_defaults_to_last_match() {
+    let config: Config = toml::from_str("version = \"2\"").unwrap();
+    assert_eq!(config.rule_resolution, RuleResolution::LastMatch);
+    assert_eq!(config.rule_resolution.to_string(), "last_match");
+}
+
+#[test]
+fn config_deserialize_content_rule_selectors_without_pattern() {
+    let toml_str = r#"
+        [[content.rules]]
+        patterns = ["packages/*/src/**"]
+        exclude_patterns = ["**/*.d.ts"]
+        languages = ["TypeScript"]
+        max_lines = 500
+    "#;
+
+    let config: Config = toml::from_str(toml_str).unwrap();
+    let rule = &config.content.rules[0];
+    assert!(rule.pattern.is_empty());
+    assert_eq!(rule.patterns, vec!["packages/*/src/**"]);
+    assert_eq!(rule.exclude_patterns, vec!["**/*.d.ts"]);
+    assert_eq!(rule.languages, vec!["TypeScript"]);
+    assert_eq!(
+        rule.selector_label(),
+        "packages/*/src/** [languages: TypeScript] [exclude: **/*.d.ts]"
+    );
+}
@@ -81,3 +81,23 @@ fn config_deserialize_scanner_exclude_with_git() {
     assert!(config.scanner.exclude.contains(&"target/**".to_string()));
     assert!(config.scanner.exclude.contains(&"vendor/**".to_string()));
 }
+
+#[test]
+fn config_deserialize_follow_symlinks() {
+    let config: Config = toml::from_str("version = \"2\"").unwrap();
+    assert!(
+        !config.scanner.follow_symlinks,
+        "symlinks are not followed by default"
+    );
+
+    let toml_str = r#"
+        version = "2"
+
+        [scanner]
+        follow_symlinks = true
+    "#;
+
+    let config: Config = toml::from_str(toml_str).unwrap();
+    assert!(config.scanner.follow_symlinks);
+    assert!(config.scanner.exclude.contains(&".git/**".to_string()));
+}
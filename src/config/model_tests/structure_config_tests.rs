@@ -89,6 +89,42 @@ fn config_deserialize_structure_only_rules() {
     assert_eq!(config.structure.rules[0].scope, "vendor/**");
 }
 
+#[test]
+fn config_deserialize_structure_rule_scopes_and_exclusions() {
+    let toml_str = r#"
+        version = "2"
+
+        [[structure.rules]]
+        scopes = ["src/**", "lib/**"]
+        exclude_scopes = ["src/generated/**"]
+        max_files = 10
+    "#;
+
+    let config: Config = toml::from_str(toml_str).unwrap();
+    let rule = &config.structure.rules[0];
+    assert!(rule.scope.is_empty());
+    assert_eq!(rule.scopes, vec!["src/**", "lib/**"]);
+    assert_eq!(rule.exclude_scopes, vec!["src/generated/**"]);
+    assert_eq!(
+        rule.scope_patterns().collect::<Vec<_>>(),
+        vec!["src/**", "lib/**"]
+    );
+    assert_eq!(
+        rule.scope_label(),
+        "src/**, lib/** [exclude: src/generated/**]"
+    );
+}
+
+#[test]
+fn structure_rule_scope_label_single_scope() {
+    let rule = StructureRule {
+        scope: "src/**".to_string(),
+        ..Default::default()
+    };
+    assert_eq!(rule.scope_patterns().collect::<Vec<_>>(), vec!["src/**"]);
+    assert_eq!(rule.scope_label(), "src/**");
+}
+
 // =============================================================================
 // Warn Threshold Tests
 // =============================================================================
@@ -137,6 +173,110 @@ fn structure_config_warn_threshold_default_none() {
     assert!(config.warn_threshold.is_none());
 }
 
+// =============================================================================
+// max_files_by_ext Tests
+// =============================================================================
+
+#[test]
+fn config_deserialize_max_files_by_ext() {
+    let toml_str = r#"
+        [structure]
+        max_files_by_ext = { ".rs" = 15, ".sql" = 1 }
+
+        [[structure.rules]]
+        scope = "migrations/*"
+        max_files_by_ext = { ".sql" = -1 }
+    "#;
+
+    let config: Config = toml::from_str(toml_str).unwrap();
+    assert_eq!(config.structure.max_files_by_ext.get(".rs"), Some(&15));
+    assert_eq!(config.structure.max_files_by_ext.get(".sql"), Some(&1));
+    assert_eq!(
+        config.structure.rules[0].max_files_by_ext.get(".sql"),
+        Some(&-1)
+    );
+    assert!(config.structure.is_enabled());
+}
+
+// =============================================================================
+// max_file_bytes Tests
+// =============================================================================
+
+#[test]
+fn config_deserialize_max_file_bytes_sizes_and_integers() {
+    let toml_str = r#"
+        [structure]
+        max_file_bytes = "5MB"
+        allow_large_files = ["*.woff2"]
+
+        [[structure.rules]]
+        scope = "fixtures/**"
+        max_file_bytes = 1048576
+
+        [[structure.rules]]
+        scope = "vendor/**"
+        max_file_bytes = -1
+    "#;
+
+    let config: Config = toml::from_str(toml_str).unwrap();
+    assert_eq!(config.structure.max_file_bytes, Some(5 * 1024 * 1024));
+    assert_eq!(config.structure.allow_large_files, vec!["*.woff2"]);
+    assert_eq!(config.structure.rules[0].max_file_bytes, Some(1_048_576));
+    assert_eq!(config.structure.rules[1].max_file_bytes, Some(-1));
+    assert!(config.structure.is_enabled());
+}
+
+#[test]
+fn config_deserialize_max_file_bytes_rejects_invalid_sizes() {
+    for value in ["\"5 parsecs\"", "-2"] {
+        let result: Result<Config, _> =
+            toml::from_str(&format!("[structure]\nmax_file_bytes = {value}"));
+        let message = result.unwrap_err().to_string();
+        assert!(message.contains("max_file_bytes"), "{message}");
+    }
+}
+
+// =============================================================================
+// deny_empty_dirs Tests
+// =============================================================================
+
+#[test]
+fn config_deserialize_deny_empty_dirs_values() {
+    let parse = |value: &str| {
+        let config: Config =
+            toml::from_str(&format!("[structure]\ndeny_empty_dirs = {value}")).unwrap();
+        config.structure.deny_empty_dirs
+    };
+
+    assert_eq!(parse("false"), DenyEmptyDirs::Off);
+    assert_eq!(parse("true"), DenyEmptyDirs::Empty);
+    assert_eq!(
+        parse("\"ignore_count_exclude\""),
+        DenyEmptyDirs::IgnoreCountExclude
+    );
+    assert_eq!(
+        StructureConfig::default().deny_empty_dirs,
+        DenyEmptyDirs::Off
+    );
+}
+
+#[test]
+fn config_deserialize_deny_empty_dirs_rejects_unknown_mode() {
+    let result: Result<Config, _> = toml::from_str("[structure]\ndeny_empty_dirs = \"always\"");
+    let message = result.unwrap_err().to_string();
+    assert!(message.contains("ignore_count_exclude"), "{message}");
+}
+
+#[test]
+fn deny_empty_dirs_enables_structure_config() {
+    let config = StructureConfig {
+        deny_empty_dirs: DenyEmptyDirs::IgnoreCountExclude,
+        ..Default::default()
+    };
+    assert!(config.is_enabled());
+    assert!(!StructureConfig::default().is_enabled());
+}
+
 // =============================================================================
 // deny_files and deny_dirs Tests
 // =============================================================================
@@ -317,7 +457,7 @@ fn sibling_rule_directed_deserialize() {
             assert_eq!(match_pattern, "*.tsx");
             assert_eq!(require.as_patterns(), vec!["{stem}.test.tsx"]);
         }
-        SiblingRule::Group { .. } => panic!("Expected Directed rule"),
+        _ => panic!("Expected Directed rule"),
     }
 }
 
@@ -341,7 +481,7 @@ fn sibling_rule_group_deserialize() {
             assert_eq!(group[0], "{stem}.tsx");
             assert_eq!(group[1], "{stem}.test.tsx");
         }
-        SiblingRule::Directed { .. } => panic!("Expected Group rule"),
+        _ => panic!("Expected Group rule"),
     }
 }
 
@@ -412,3 +552,195 @@ fn sibling_rule_directed_missing_match_rejected() {
     let err_msg = result.unwrap_err().to_string();
     assert!(err_msg.contains("match"));
 }
+
+#[test]
+fn sibling_rule_forbid_deserialize() {
+    let toml_str = r#"
+        [[structure.rules]]
+        scope = "src/**"
+
+        [[structure.rules.siblings]]
+        match = "*.ts"
+        forbid = "{stem}.js"
+        severity = "warn"
+    "#;
+
+    let config: Config = toml::from_str(toml_str).unwrap();
+    assert_eq!(
+        config.structure.rules[0].siblings[0],
+        SiblingRule::Forbid {
+            match_pattern: "*.ts".to_string(),
+            forbid: SiblingRequire::Single("{stem}.js".to_string()),
+            severity: SiblingSeverity::Warn,
+        }
+    );
+}
+
+#[test]
+fn sibling_rule_exclusive_deserialize() {
+    let toml_str = r#"
+        [[structure.rules]]
+        scope = "src/**"
+
+        [[structure.rules.siblings]]
+        exclusive = ["index.js", "index.ts", "index.tsx"]
+    "#;
+
+    let config: Config = toml::from_str(toml_str).unwrap();
+    match &config.structure.rules[0].siblings[0] {
+        SiblingRule::Exclusive {
+            exclusive,
+            severity,
+        } => {
+            assert_eq!(exclusive, &["index.js", "index.ts", "index.tsx"]);
+            assert_eq!(*severity, SiblingSeverity::Error);
+        }
+        _ => panic!("Expected Exclusive rule"),
+    }
+}
+
+#[test]
+fn sibling_rule_require_and_forbid_rejected() {
+    let toml_str = r#"
+        [[structure.rules]]
+        scope = "src/**"
+
+        [[structure.rules.siblings]]
+        match = "*.ts"
+        require = "{stem}.test.ts"
+        forbid = "{stem}.js"
+    "#;
+
+    let err_msg = toml::from_str::<Config>(toml_str).unwrap_err().to_string();
+    assert!(err_msg.contains("cannot mix 'require' with 'forbid'"));
+}
+
+#[test]
+fn sibling_rule_exclusive_with_match_rejected() {
+    let toml_str = r#"
+        [[structure.rules]]
+        scope = "src/**"
+
+        [[structure.rules.siblings]]
+        match = "*.ts"
+        exclusive = ["index.js", "index.ts"]
+    "#;
+
+    let err_msg = toml::from_str::<Config>(toml_str).unwrap_err().to_string();
+    assert!(err_msg.contains("Ambiguous"));
+}
+
+#[test]
+fn structure_rule_naming_styles_deserialize() {
+    use crate::config::{NamingStem, NamingStyle};
+
+    let toml_str = r#"
+        [[structure.rules]]
+        scope = "src/**"
+        file_naming = "kebab-case"
+        naming_stem = "last_dot"
+        dir_naming = "snake_case"
+        dir_naming_pattern = "^[a-z_]+$"
+    "#;
+
+    let config: Config = toml::from_str(toml_str).unwrap();
+    let rule = &config.structure.rules[0];
+    assert_eq!(rule.file_naming, Some(NamingStyle::KebabCase));
+    assert_eq!(rule.naming_stem, Some(NamingStem::LastDot));
+    assert_eq!(rule.dir_naming, Some(NamingStyle::SnakeCase));
+    assert_eq!(rule.dir_naming_pattern.as_deref(), Some("^[a-z_]+$"));
+}
+
+#[test]
+fn structure_rule_unknown_naming_style_rejected() {
+    let toml_str = r#"
+        [[structure.rules]]
+        scope = "src/**"
+        file_naming = "Title Case"
+    "#;
+
+    let result: Result<Config, _> = toml::from_str(toml_str);
+    assert!(result.is_err());
+}
+
+#[test]
+fn structure_rule_require_files_deserialize() {
+    use crate::config::SiblingSeverity;
+
+    let toml_str = r#"
+        [[structure.rules]]
+        scope = "crates/*"
+        require_files = ["Cargo.toml", "{dir}.md"]
+        require_files_severity = "warn"
+    "#;
+
+    let config: Config = toml::from_str(toml_str).unwrap();
+    let rule = &config.structure.rules[0];
+    assert_eq!(rule.require_files, vec!["Cargo.toml", "{dir}.md"]);
+    assert_eq!(rule.require_files_severity, Some(SiblingSeverity::Warn));
+}
+
+// =============================================================================
+// deny_symlinks Tests
+// =============================================================================
+
+#[test]
+fn config_deserialize_deny_symlinks_values() {
+    let parse = |value: &str| {
+        let config: Config =
+            toml::from_str(&format!("[structure]\ndeny_symlinks = {value}")).unwrap();
+        config.structure.deny_symlinks
+    };
+
+    assert_eq!(parse("false"), DenySymlinks::Off);
+    assert_eq!(parse("true"), DenySymlinks::All);
+    assert_eq!(parse("\"external\""), DenySymlinks::External);
+    assert_eq!(StructureConfig::default().deny_symlinks, DenySymlinks::Off);
+}
+
+#[test]
+fn config_deserialize_deny_symlinks_rejects_unknown_mode() {
+    let result: Result<Config, _> = toml::from_str("[structure]\ndeny_symlinks = \"internal\"");
+    let message = result.unwrap_err().to_string();
+    assert!(message.contains("external"), "{message}");
+}
+
+#[test]
+fn deny_symlinks_enables_structure_config() {
+    let config = StructureConfig {
+        deny_symlinks: DenySymlinks::External,
+        ..Default::default()
+    };
+    assert!(config.is_enabled());
+}
+
+// =============================================================================
+// Layout Tests
+// =============================================================================
+
+#[test]
+fn structure_layout_deserialize() {
+    let toml_str = r#"
+        [[structure.layouts]]
+        name = "go-service"
+        scope = "services/*"
+        required = ["cmd/", "go.mod"]
+        optional = ["docs/"]
+        forbid_other = true
+    "#;
+
+    let config: Config = toml::from_str(toml_str).unwrap();
+    let layout = &config.structure.layouts[0];
+    assert_eq!(layout.name, "go-service");
+    assert_eq!(layout.scope, "services/*");
+    assert_eq!(layout.required, vec!["cmd/", "go.mod"]);
+    assert_eq!(layout.optional, vec!["docs/"]);
+    assert!(layout.forbid_other);
+    assert!(config.structure.is_enabled());
+}
+
+#[test]
+fn structure_layout_requires_name() {
+    let result: Result<Config, _> = toml::from_str("[[structure.layouts]]\nscope = \"services/*\"");
+    assert!(result.is_err());
+}
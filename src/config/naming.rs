@@ -0,0 +1,182 @@
+//! Built-in naming styles for `file_naming` / `dir_naming`.
+//!
+//! Styles are checked against a name's stem, so `file_naming = "kebab-case"`
+//! accepts `user-profile.tsx` without a hand-written regex.
+
+use serde::{Deserialize, Serialize};
+
+/// A named case convention.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NamingStyle {
+    /// `user-profile`: lowercase letters and digits joined by single hyphens.
+    #[serde(rename = "kebab-case")]
+    KebabCase,
+    /// `user_profile`: lowercase letters and digits joined by single underscores.
+    #[serde(rename = "snake_case")]
+    SnakeCase,
+    /// `UserProfile`: starts uppercase, letters and digits only.
+    #[serde(rename = "PascalCase")]
+    PascalCase,
+    /// `userProfile`: starts lowercase, letters and digits only.
+    #[serde(rename = "camelCase")]
+    CamelCase,
+    /// `USER_PROFILE`: uppercase letters and digits joined by single underscores.
+    #[serde(rename = "SCREAMING_SNAKE_CASE", alias = "SCREAMING_SNAKE")]
+    ScreamingSnakeCase,
+}
+
+impl NamingStyle {
+    /// Config value name (as written in TOML).
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::KebabCase => "kebab-case",
+            Self::SnakeCase => "snake_case",
+            Self::PascalCase => "PascalCase",
+            Self::CamelCase => "camelCase",
+            Self::ScreamingSnakeCase => "SCREAMING_SNAKE_CASE",
+        }
+    }
+
+    /// Style for a template transform (`{stem:kebab}`); full style names are accepted too.
+    #[must_use]
+    pub fn from_transform(name: &str) -> Option<Self> {
+        match name {
+            "kebab" | "kebab-case" => Some(Self::KebabCase),
+            "snake" | "snake_case" => Some(Self::SnakeCase),
+            "pascal" | "PascalCase" => Some(Self::PascalCase),
+            "camel" | "camelCase" => Some(Self::CamelCase),
+            "screaming_snake" | "SCREAMING_SNAKE_CASE" => Some(Self::ScreamingSnakeCase),
+            _ => None,
+        }
+    }
+
+    /// Convert `name` to this style, splitting words at separators and case changes.
+    ///
+    /// `HTTPServer_config` becomes `http-server-config` in kebab-case.
+    #[must_use]
+    pub fn apply(self, name: &str) -> String {
+        let words = split_words(name);
+        let capitalize = |word: &str| {
+            let mut chars = word.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first.to_ascii_uppercase().to_string() + &chars.as_str().to_ascii_lowercase()
+            })
+        };
+        match self {
+            Self::KebabCase => words.join("-").to_ascii_lowercase(),
+            Self::SnakeCase => words.join("_").to_ascii_lowercase(),
+            Self::ScreamingSnakeCase => words.join("_").to_ascii_uppercase(),
+            Self::PascalCase => words.iter().map(|word| capitalize(word)).collect(),
+            Self::CamelCase => words
+                .iter()
+                .enumerate()
+                .map(|(i, word)| {
+                    if i == 0 {
+                        word.to_ascii_lowercase()
+                    } else {
+                        capitalize(word)
+                    }
+                })
+                .collect(),
+        }
+    }
+
+    /// Whether `word` follows this style. Empty words never match.
+    #[must_use]
+    pub fn matches(self, word: &str) -> bool {
+        match self {
+            Self::KebabCase => {
+                separated(word, '-', |c| c.is_ascii_lowercase() || c.is_ascii_digit())
+            }
+            Self::SnakeCase => {
+                separated(word, '_', |c| c.is_ascii_lowercase() || c.is_ascii_digit())
+            }
+            Self::ScreamingSnakeCase => {
+                separated(word, '_', |c| c.is_ascii_uppercase() || c.is_ascii_digit())
+            }
+            Self::PascalCase => {
+                word.starts_with(|c: char| c.is_ascii_uppercase())
+                    && word.chars().all(|c| c.is_ascii_alphanumeric())
+            }
+            Self::CamelCase => {
+                word.starts_with(|c: char| c.is_ascii_lowercase())
+                    && word.chars().all(|c| c.is_ascii_alphanumeric())
+            }
+        }
+    }
+
+    /// Whether a file name follows this style, ignoring its extension(s) per `stem`.
+    ///
+    /// A leading dot (hidden files like `.eslintrc.js`) is not part of the stem.
+    #[must_use]
+    pub fn matches_file_name(self, file_name: &str, stem: NamingStem) -> bool {
+        let name = file_name.trim_start_matches('.');
+        match stem {
+            NamingStem::FirstDot => self.matches(name.split('.').next().unwrap_or_default()),
+            NamingStem::LastDot => {
+                let stem = name.rsplit_once('.').map_or(name, |(stem, _)| stem);
+                stem.split('.').all(|part| self.matches(part))
+            }
+        }
+    }
+
+    /// Whether a directory name follows this style (a leading dot is ignored).
+    #[must_use]
+    pub fn matches_dir_name(self, dir_name: &str) -> bool {
+        self.matches(dir_name.trim_start_matches('.'))
+    }
+}
+
+impl std::fmt::Display for NamingStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Which part of a multi-dot file name (`foo.test.ts`) a naming style applies to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NamingStem {
+    /// Everything before the first dot: `foo.test.ts` → `foo` (default).
+    #[default]
+    FirstDot,
+    /// Everything before the last dot: `foo.test.ts` → `foo.test`, where every
+    /// dot-separated part (`foo`, `test`) must follow the style.
+    LastDot,
+}
+
+/// Words of `name`: runs of letters and digits, split before an uppercase letter that
+/// follows a lowercase letter or digit, or that starts a word after an acronym (`HTTPServer`).
+pub fn split_words(name: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    for part in name.split(|c: char| !c.is_alphanumeric()) {
+        let chars: Vec<(usize, char)> = part.char_indices().collect();
+        let mut start = 0;
+        for (i, &(index, c)) in chars.iter().enumerate().skip(1) {
+            let previous = chars[i - 1].1;
+            let next_is_lower = chars.get(i + 1).is_some_and(|(_, n)| n.is_lowercase());
+            if c.is_uppercase()
+                && (previous.is_lowercase()
+                    || previous.is_ascii_digit()
+                    || (previous.is_uppercase() && next_is_lower))
+            {
+                words.push(&part[start..index]);
+                start = index;
+            }
+        }
+        words.push(&part[start..]);
+    }
+    words.retain(|word| !word.is_empty());
+    words
+}
+
+/// Non-empty runs of `allowed` characters joined by single `separator`s.
+fn separated(word: &str, separator: char, allowed: impl Fn(char) -> bool) -> bool {
+    word.split(separator)
+        .all(|part| !part.is_empty() && part.chars().all(&allowed))
+}
+
+#[cfg(test)]
+#[path = "naming_tests.rs"]
+mod tests;
@@ -0,0 +1,129 @@
+use super::*;
+
+#[test]
+fn kebab_case_accepts_hyphenated_lowercase() {
+    assert!(NamingStyle::KebabCase.matches("user-profile"));
+    assert!(NamingStyle::KebabCase.matches("v2"));
+    assert!(!NamingStyle::KebabCase.matches("user_profile"));
+    assert!(!NamingStyle::KebabCase.matches("userProfile"));
+    assert!(!NamingStyle::KebabCase.matches("user--profile"));
+    assert!(!NamingStyle::KebabCase.matches("-user"));
+    assert!(!NamingStyle::KebabCase.matches(""));
+}
+
+#[test]
+fn snake_and_screaming_snake_case() {
+    assert!(NamingStyle::SnakeCase.matches("user_profile"));
+    assert!(!NamingStyle::SnakeCase.matches("User_profile"));
+    assert!(NamingStyle::ScreamingSnakeCase.matches("USER_PROFILE_2"));
+    assert!(!NamingStyle::ScreamingSnakeCase.matches("USER__PROFILE"));
+    assert!(!NamingStyle::ScreamingSnakeCase.matches("user_profile"));
+}
+
+#[test]
+fn pascal_and_camel_case() {
+    assert!(NamingStyle::PascalCase.matches("UserProfile"));
+    assert!(!NamingStyle::PascalCase.matches("userProfile"));
+    assert!(!NamingStyle::PascalCase.matches("User_Profile"));
+    assert!(NamingStyle::CamelCase.matches("userProfile"));
+    assert!(!NamingStyle::CamelCase.matches("UserProfile"));
+    assert!(!NamingStyle::CamelCase.matches("user-profile"));
+}
+
+#[test]
+fn first_dot_stem_ignores_every_extension() {
+    let style = NamingStyle::KebabCase;
+    assert!(style.matches_file_name("user-profile.test.ts", NamingStem::FirstDot));
+    assert!(style.matches_file_name("user-profile.TEST.ts", NamingStem::FirstDot));
+    assert!(style.matches_file_name("makefile", NamingStem::FirstDot));
+    assert!(!style.matches_file_name("UserProfile.ts", NamingStem::FirstDot));
+}
+
+#[test]
+fn last_dot_stem_checks_every_part() {
+    let style = NamingStyle::KebabCase;
+    assert!(style.matches_file_name("user-profile.test.ts", NamingStem::LastDot));
+    assert!(!style.matches_file_name("user-profile.Test.ts", NamingStem::LastDot));
+    assert!(style.matches_file_name("user-profile.ts", NamingStem::LastDot));
+}
+
+#[test]
+fn leading_dot_is_not_part_of_the_stem() {
+    assert!(NamingStyle::SnakeCase.matches_file_name(".eslintrc.js", NamingStem::FirstDot));
+    assert!(NamingStyle::KebabCase.matches_dir_name(".github"));
+    assert!(!NamingStyle::KebabCase.matches_dir_name("My Folder"));
+}
+
+#[test]
+fn styles_deserialize_from_config_names() {
+    #[derive(serde::Deserialize)]
+    struct Wrapper {
+        styles: Vec<NamingStyle>,
+        stem: NamingStem,
+    }
+    let parsed: Wrapper = toml::from_str(
+        r#"
+        styles = ["kebab-case", "snake_case", "PascalCase", "camelCase", "SCREAMING_SNAKE_CASE", "SCREAMING_SNAKE"]
+        stem = "last_dot"
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        parsed.styles,
+        vec![
+            NamingStyle::KebabCase,
+            NamingStyle::SnakeCase,
+            NamingStyle::PascalCase,
+            NamingStyle::CamelCase,
+            NamingStyle::ScreamingSnakeCase,
+            NamingStyle::ScreamingSnakeCase,
+        ]
+    );
+    assert_eq!(parsed.stem, NamingStem::LastDot);
+    assert_eq!(
+        NamingStyle::ScreamingSnakeCase.to_string(),
+        "SCREAMING_SNAKE_CASE"
+    );
+}
+
+#[test]
+fn apply_converts_between_styles() {
+    let cases = [
+        ("user_profile", "user-profile", "UserProfile", "userProfile"),
+        ("UserProfile", "user-profile", "UserProfile", "userProfile"),
+        ("HTTPServer", "http-server", "HttpServer", "httpServer"),
+        (
+            "api-v2Client",
+            "api-v2-client",
+            "ApiV2Client",
+            "apiV2Client",
+        ),
+    ];
+    for (name, kebab, pascal, camel) in cases {
+        assert_eq!(NamingStyle::KebabCase.apply(name), kebab, "{name}");
+        assert_eq!(NamingStyle::PascalCase.apply(name), pascal, "{name}");
+        assert_eq!(NamingStyle::CamelCase.apply(name), camel, "{name}");
+    }
+    assert_eq!(NamingStyle::SnakeCase.apply("userProfile"), "user_profile");
+    assert_eq!(
+        NamingStyle::ScreamingSnakeCase.apply("user-profile"),
+        "USER_PROFILE"
+    );
+}
+
+#[test]
+fn from_transform_accepts_short_and_full_names() {
+    assert_eq!(
+        NamingStyle::from_transform("kebab"),
+        Some(NamingStyle::KebabCase)
+    );
+    assert_eq!(
+        NamingStyle::from_transform("PascalCase"),
+        Some(NamingStyle::PascalCase)
+    );
+    assert_eq!(
+        NamingStyle::from_transform("screaming_snake"),
+        Some(NamingStyle::ScreamingSnakeCase)
+    );
+    assert_eq!(NamingStyle::from_transform("title"), None);
+}
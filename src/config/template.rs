@@ -0,0 +1,285 @@
+//! `{placeholder}` templates used by `siblings`, `require_files` and `file_naming_pattern`.
+//!
+//! A placeholder is `{name}` or `{name:transform}`, e.g. `{stem}` or `{dir:pascal}`.
+//! Braces that do not look like a placeholder (glob alternation `{a,b}`, regex
+//! repetition `{2,3}` or classes like `\p{L}`) are kept as literal text.
+
+use std::ffi::OsStr;
+use std::path::Path;
+
+use super::naming::NamingStyle;
+
+/// A value that can be substituted into a template.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Placeholder {
+    /// File name without its last extension (`Button.test` for `Button.test.tsx`).
+    Stem,
+    /// Last extension of the file, without the dot.
+    Ext,
+    /// Name of the directory.
+    Dir,
+    /// Name of the directory's parent.
+    Parent,
+}
+
+impl Placeholder {
+    /// Placeholders available in directed and `forbid` sibling templates.
+    pub const SIBLING: &[Self] = &[Self::Stem, Self::Ext, Self::Dir, Self::Parent];
+    /// Placeholders available in sibling `group` patterns.
+    pub const GROUP: &[Self] = &[Self::Stem, Self::Dir, Self::Parent];
+    /// Placeholders available in `require_files` and `file_naming_pattern`.
+    pub const DIRECTORY: &[Self] = &[Self::Dir, Self::Parent];
+
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Stem => "stem",
+            Self::Ext => "ext",
+            Self::Dir => "dir",
+            Self::Parent => "parent",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        [Self::Stem, Self::Ext, Self::Dir, Self::Parent]
+            .into_iter()
+            .find(|placeholder| placeholder.name() == name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Literal(String),
+    Value {
+        placeholder: Placeholder,
+        transform: Option<NamingStyle>,
+    },
+}
+
+/// Values substituted for the placeholders of a template.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TemplateValues<'a> {
+    pub stem: &'a str,
+    pub ext: &'a str,
+    pub dir: &'a str,
+    pub parent: &'a str,
+}
+
+impl<'a> TemplateValues<'a> {
+    /// Values for a file: its stem and extension, the name of its directory and of that
+    /// directory's parent.
+    #[must_use]
+    pub fn for_file(path: &'a Path) -> Self {
+        let dir = path.parent();
+        Self {
+            stem: to_str(path.file_stem()),
+            ext: to_str(path.extension()),
+            dir: to_str(dir.and_then(Path::file_name)),
+            parent: to_str(dir.and_then(Path::parent).and_then(Path::file_name)),
+        }
+    }
+
+    /// Values for a directory: `{dir}` is its own name and `{parent}` its parent's.
+    #[must_use]
+    pub fn for_dir(dir: &'a Path) -> Self {
+        Self {
+            dir: to_str(dir.file_name()),
+            parent: to_str(dir.parent().and_then(Path::file_name)),
+            ..Self::default()
+        }
+    }
+
+    const fn get(&self, placeholder: Placeholder) -> &'a str {
+        match placeholder {
+            Placeholder::Stem => self.stem,
+            Placeholder::Ext => self.ext,
+            Placeholder::Dir => self.dir,
+            Placeholder::Parent => self.parent,
+        }
+    }
+}
+
+fn to_str(name: Option<&OsStr>) -> &str {
+    name.and_then(OsStr::to_str).unwrap_or_default()
+}
+
+/// A parsed template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template {
+    source: String,
+    segments: Vec<Segment>,
+}
+
+impl Template {
+    /// Parse `source`, accepting only the `allowed` placeholders.
+    ///
+    /// # Errors
+    /// Returns a description of the first unknown placeholder, unknown transform, or
+    /// placeholder that is not in `allowed`.
+    pub fn parse(source: &str, allowed: &[Placeholder]) -> Result<Self, String> {
+        let mut segments = Vec::new();
+        let mut literal = String::new();
+        let mut rest = source;
+        while let Some(open) = rest.find('{') {
+            let Some(close) = rest[open..].find('}').map(|close| open + close) else {
+                break;
+            };
+            let (before, inner) = (&rest[..open], &rest[open + 1..close]);
+            literal.push_str(before);
+            if before.ends_with("\\p") || before.ends_with("\\P") || !is_placeholder(inner) {
+                literal.push_str(&rest[open..=close]);
+            } else {
+                if !literal.is_empty() {
+                    segments.push(Segment::Literal(std::mem::take(&mut literal)));
+                }
+                segments.push(parse_placeholder(inner, allowed)?);
+            }
+            rest = &rest[close + 1..];
+        }
+        literal.push_str(rest);
+        if !literal.is_empty() {
+            segments.push(Segment::Literal(literal));
+        }
+        Ok(Self {
+            source: source.to_string(),
+            segments,
+        })
+    }
+
+    /// The template as written in the config.
+    #[must_use]
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Whether the template contains any placeholder.
+    #[must_use]
+    pub fn has_placeholders(&self) -> bool {
+        self.segments
+            .iter()
+            .any(|segment| matches!(segment, Segment::Value { .. }))
+    }
+
+    /// Number of `{stem}` placeholders, and whether any of them has a transform.
+    #[must_use]
+    pub fn stem_usage(&self) -> (usize, bool) {
+        self.segments
+            .iter()
+            .fold((0, false), |(count, transformed), segment| match segment {
+                Segment::Value {
+                    placeholder: Placeholder::Stem,
+                    transform,
+                } => (count + 1, transformed || transform.is_some()),
+                _ => (count, transformed),
+            })
+    }
+
+    /// Substitute `values` into the template.
+    #[must_use]
+    pub fn expand(&self, values: &TemplateValues) -> String {
+        self.expand_with(values, str::to_string)
+    }
+
+    /// Substitute `values` into the template, passing each value through `escape`
+    /// (e.g. `globset::escape` or `regex::escape`); literal text is kept as is.
+    #[must_use]
+    pub fn expand_with(&self, values: &TemplateValues, escape: impl Fn(&str) -> String) -> String {
+        self.segments
+            .iter()
+            .map(|segment| match segment {
+                Segment::Literal(text) => text.clone(),
+                Segment::Value {
+                    placeholder,
+                    transform,
+                } => {
+                    let value = values.get(*placeholder);
+                    escape(&transform.map_or_else(|| value.to_string(), |style| style.apply(value)))
+                }
+            })
+            .collect()
+    }
+
+    /// The expanded text before and after the template's single `{stem}`, or `None`
+    /// unless it has exactly one `{stem}` without a transform.
+    #[must_use]
+    pub fn stem_affixes(&self, values: &TemplateValues) -> Option<(String, String)> {
+        if self.stem_usage() != (1, false) {
+            return None;
+        }
+        let position = self.segments.iter().position(|segment| {
+            matches!(
+                segment,
+                Segment::Value {
+                    placeholder: Placeholder::Stem,
+                    ..
+                }
+            )
+        })?;
+        let expand = |segments: &[Segment]| {
+            Self {
+                source: String::new(),
+                segments: segments.to_vec(),
+            }
+            .expand(values)
+        };
+        Some((
+            expand(&self.segments[..position]),
+            expand(&self.segments[position + 1..]),
+        ))
+    }
+}
+
+/// `name` or `name:transform`, each starting with a letter.
+fn is_placeholder(inner: &str) -> bool {
+    let is_word = |word: &str| {
+        word.starts_with(|c: char| c.is_ascii_alphabetic())
+            && word
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    };
+    match inner.split_once(':') {
+        Some((name, transform)) => is_word(name) && is_word(transform),
+        None => is_word(inner),
+    }
+}
+
+fn parse_placeholder(inner: &str, allowed: &[Placeholder]) -> Result<Segment, String> {
+    let (name, transform) = inner
+        .split_once(':')
+        .map_or((inner, None), |(name, transform)| (name, Some(transform)));
+    let expected = || {
+        allowed
+            .iter()
+            .map(|placeholder| format!("{{{}}}", placeholder.name()))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let placeholder = Placeholder::from_name(name).ok_or_else(|| {
+        format!(
+            "unknown placeholder '{{{inner}}}' (expected {})",
+            expected()
+        )
+    })?;
+    if !allowed.contains(&placeholder) {
+        return Err(format!(
+            "placeholder '{{{name}}}' is not available here (expected {})",
+            expected()
+        ));
+    }
+    let transform = transform
+        .map(|transform| {
+            NamingStyle::from_transform(transform).ok_or_else(|| {
+                format!(
+                    "unknown transform '{transform}' in '{{{inner}}}' (expected kebab, snake, pascal, camel or screaming_snake)"
+                )
+            })
+        })
+        .transpose()?;
+    Ok(Segment::Value {
+        placeholder,
+        transform,
+    })
+}
+
+#[cfg(test)]
+#[path = "template_tests.rs"]
+mod tests;
@@ -0,0 +1,106 @@
+use std::path::Path;
+
+use super::*;
+
+fn parse(source: &str) -> Template {
+    Template::parse(source, Placeholder::SIBLING).unwrap()
+}
+
+#[test]
+fn file_values_come_from_path() {
+    let values = TemplateValues::for_file(Path::new("src/components/Button/Button.test.tsx"));
+
+    assert_eq!(values.stem, "Button.test");
+    assert_eq!(values.ext, "tsx");
+    assert_eq!(values.dir, "Button");
+    assert_eq!(values.parent, "components");
+}
+
+#[test]
+fn dir_values_come_from_path() {
+    let values = TemplateValues::for_dir(Path::new("crates/core"));
+
+    assert_eq!(values.dir, "core");
+    assert_eq!(values.parent, "crates");
+    assert_eq!(values.stem, "");
+}
+
+#[test]
+fn expands_placeholders_and_transforms() {
+    let values = TemplateValues::for_file(Path::new("src/user_profile/UserCard.tsx"));
+
+    assert_eq!(
+        parse("{stem}_test.{ext}").expand(&values),
+        "UserCard_test.tsx"
+    );
+    assert_eq!(parse("{dir:pascal}.tsx").expand(&values), "UserProfile.tsx");
+    assert_eq!(parse("{stem:kebab}.css").expand(&values), "user-card.css");
+    assert_eq!(parse("{parent}/{stem}").expand(&values), "src/UserCard");
+    assert_eq!(parse("{stem}/mod.rs").expand(&values), "UserCard/mod.rs");
+}
+
+#[test]
+fn non_placeholder_braces_are_literal() {
+    let values = TemplateValues::for_dir(Path::new("src/api"));
+
+    for source in [
+        "*.{ts,tsx}",
+        "^[a-z]{2,8}$",
+        r"^\p{Lu}.*$",
+        "README{,.md}",
+        "{",
+    ] {
+        let template = Template::parse(source, Placeholder::DIRECTORY).unwrap();
+        assert!(!template.has_placeholders(), "{source}");
+        assert_eq!(template.expand(&values), source);
+    }
+}
+
+#[test]
+fn expand_with_escapes_values_only() {
+    let template = Template::parse("{dir}.*", Placeholder::DIRECTORY).unwrap();
+
+    assert_eq!(
+        template.expand_with(&TemplateValues::for_dir(Path::new("[a]")), globset::escape),
+        "[[]a[]].*"
+    );
+}
+
+#[test]
+fn unknown_placeholder_is_rejected() {
+    let err = Template::parse("{stem}.{kind}", Placeholder::SIBLING).unwrap_err();
+
+    assert!(err.contains("unknown placeholder '{kind}'"), "{err}");
+    assert!(err.contains("{stem}, {ext}, {dir}, {parent}"), "{err}");
+}
+
+#[test]
+fn unknown_transform_is_rejected() {
+    let err = Template::parse("{stem:title}", Placeholder::SIBLING).unwrap_err();
+
+    assert!(err.contains("unknown transform 'title'"), "{err}");
+}
+
+#[test]
+fn unavailable_placeholder_is_rejected() {
+    let err = Template::parse("{stem}.md", Placeholder::DIRECTORY).unwrap_err();
+
+    assert!(err.contains("'{stem}' is not available here"), "{err}");
+    assert!(err.contains("{dir}, {parent}"), "{err}");
+}
+
+#[test]
+fn stem_affixes_expand_other_placeholders() {
+    let values = TemplateValues::for_file(Path::new("src/button/x.ts"));
+    let template = Template::parse("{dir}.{stem}.css", Placeholder::GROUP).unwrap();
+
+    assert_eq!(
+        template.stem_affixes(&values),
+        Some(("button.".to_string(), ".css".to_string()))
+    );
+    assert_eq!(template.stem_usage(), (1, false));
+
+    let transformed = Template::parse("{stem:kebab}.css", Placeholder::GROUP).unwrap();
+    assert_eq!(transformed.stem_usage(), (1, true));
+    assert_eq!(transformed.stem_affixes(&values), None);
+}
@@ -3,7 +3,9 @@
 //! Validates that configuration values are semantically correct after parsing.
 //! This includes range checks, pattern validation, and cross-field constraints.
 
-use crate::config::Config;
+use crate::config::expires::ParsedDate;
+use crate::config::{Config, OnExpire, Placeholder, SiblingRule, Template};
+use crate::language::LanguageRegistry;
 use crate::stats::parse_duration;
 use crate::{Result, SlocGuardError};
 
@@ -23,7 +25,7 @@ pub fn validate_config_semantics(config: &Config) -> Result<()> {
     validate_glob_patterns(config)?;
     validate_stats_section(config)?;
     validate_structure_section(config)?;
-    Ok(())
+    validate_rule_expiry(config)
 }
 
 fn validate_content_section(config: &Config) -> Result<()> {
@@ -56,6 +58,126 @@ fn validate_content_section(config: &Config) -> Result<()> {
             )));
         }
     }
+    validate_content_metric(config)?;
+    validate_content_selectors(config)?;
+    validate_comment_ratios(config)
+}
+
+/// Every rule needs a selector, and `languages` must name known languages.
+fn validate_content_selectors(config: &Config) -> Result<()> {
+    let mut registry = None;
+    for (i, rule) in config.content.rules.iter().enumerate() {
+        if rule.path_patterns().next().is_none() && rule.languages.is_empty() {
+            return Err(SlocGuardError::Config(format!(
+                "content.rules[{i}] needs at least one of pattern, patterns or languages"
+            )));
+        }
+        for language in &rule.languages {
+            let registry = registry
+                .get_or_insert_with(|| LanguageRegistry::with_custom_languages(&config.languages));
+            if !registry
+                .all()
+                .iter()
+                .any(|known| known.name.eq_ignore_ascii_case(language))
+            {
+                return Err(SlocGuardError::Config(format!(
+                    "content.rules[{i}].languages contains unknown language '{language}'"
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn validate_comment_ratios(config: &Config) -> Result<()> {
+    for (i, rule) in config.content.rules.iter().enumerate() {
+        for (key, value) in [
+            ("min_comment_ratio", rule.min_comment_ratio),
+            ("max_comment_ratio", rule.max_comment_ratio),
+        ] {
+            if let Some(ratio) = value
+                && !(0.0..=1.0).contains(&ratio)
+            {
+                return Err(SlocGuardError::Config(format!(
+                    "content.rules[{i}].{key} must be between 0.0 and 1.0, got {ratio}"
+                )));
+            }
+        }
+
+        if let (Some(min), Some(max)) = (rule.min_comment_ratio, rule.max_comment_ratio)
+            && min > max
+        {
+            return Err(SlocGuardError::Config(format!(
+                "content.rules[{i}].min_comment_ratio ({min}) must not exceed max_comment_ratio ({max})"
+            )));
+        }
+
+        if rule.comment_ratio_min_code.is_some()
+            && rule.min_comment_ratio.is_none()
+            && rule.max_comment_ratio.is_none()
+        {
+            return Err(SlocGuardError::Config(format!(
+                "content.rules[{i}].comment_ratio_min_code requires min_comment_ratio or max_comment_ratio"
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// `on_expire` only makes sense with a date that can actually pass.
+fn validate_rule_expiry(config: &Config) -> Result<()> {
+    for (i, rule) in config.content.rules.iter().enumerate() {
+        validate_on_expire("content", i, rule.on_expire, rule.expires.as_deref())?;
+    }
+    for (i, rule) in config.structure.rules.iter().enumerate() {
+        validate_on_expire("structure", i, rule.on_expire, rule.expires.as_deref())?;
+    }
+    Ok(())
+}
+
+fn validate_on_expire(
+    section: &str,
+    i: usize,
+    on_expire: Option<OnExpire>,
+    expires: Option<&str>,
+) -> Result<()> {
+    let Some(on_expire) = on_expire else {
+        return Ok(());
+    };
+    let Some(expires) = expires else {
+        return Err(SlocGuardError::Config(format!(
+            "{section}.rules[{i}].on_expire (\"{on_expire}\") requires expires"
+        )));
+    };
+    ParsedDate::parse(expires)
+        .map(|_| ())
+        .map_err(|e| SlocGuardError::Config(format!("{section}.rules[{i}].expires: {e}")))
+}
+
+/// `metric` replaces the skip booleans, so a level that sets both is ambiguous.
+///
+/// Global booleans default to `true` and cannot be told apart from unset values,
+/// so only a `false` alongside `content.metric` counts as a conflict.
+fn validate_content_metric(config: &Config) -> Result<()> {
+    if let Some(metric) = config.content.metric
+        && (!config.content.skip_comments || !config.content.skip_blank)
+    {
+        return Err(SlocGuardError::Config(format!(
+            "content.metric (\"{metric}\") conflicts with content.skip_comments/skip_blank = false; \
+             use metric alone"
+        )));
+    }
+
+    for (i, rule) in config.content.rules.iter().enumerate() {
+        if let Some(metric) = rule.metric
+            && (rule.skip_comments.is_some() || rule.skip_blank.is_some())
+        {
+            return Err(SlocGuardError::Config(format!(
+                "content.rules[{i}] sets both metric (\"{metric}\") and skip_comments/skip_blank; \
+                 use metric alone"
+            )));
+        }
+    }
     Ok(())
 }
 
@@ -72,6 +194,28 @@ fn validate_glob_patterns(config: &Config) -> Result<()> {
             source: e,
         })?;
     }
+    for rule in &config.content.rules {
+        for pattern in rule
+            .path_patterns()
+            .chain(rule.exclude_patterns.iter().map(String::as_str))
+        {
+            globset::Glob::new(pattern).map_err(|e| SlocGuardError::InvalidPattern {
+                pattern: pattern.to_string(),
+                source: e,
+            })?;
+        }
+    }
+    for rule in &config.structure.rules {
+        for pattern in rule
+            .scope_patterns()
+            .chain(rule.exclude_scopes.iter().map(String::as_str))
+        {
+            globset::Glob::new(pattern).map_err(|e| SlocGuardError::InvalidPattern {
+                pattern: pattern.to_string(),
+                source: e,
+            })?;
+        }
+    }
     Ok(())
 }
 
@@ -108,6 +252,81 @@ fn validate_stats_section(config: &Config) -> Result<()> {
 fn validate_structure_section(config: &Config) -> Result<()> {
     validate_structure_global(config)?;
     validate_structure_rules(config)?;
+    validate_structure_layouts(config)?;
+    validate_structure_templates(config)?;
+    Ok(())
+}
+
+/// Sibling, `require_files` and `file_naming_pattern` templates may only use known placeholders.
+fn validate_structure_templates(config: &Config) -> Result<()> {
+    for (i, rule) in config.structure.rules.iter().enumerate() {
+        let mut templates: Vec<(&str, &str, &[Placeholder])> = Vec::new();
+        for sibling in &rule.siblings {
+            match sibling {
+                SiblingRule::Directed {
+                    require: patterns, ..
+                }
+                | SiblingRule::Forbid {
+                    forbid: patterns, ..
+                } => templates.extend(
+                    patterns
+                        .as_patterns()
+                        .into_iter()
+                        .map(|pattern| ("siblings", pattern, Placeholder::SIBLING)),
+                ),
+                SiblingRule::Group { group, .. } => templates.extend(
+                    group
+                        .iter()
+                        .map(|pattern| ("siblings", pattern.as_str(), Placeholder::GROUP)),
+                ),
+                SiblingRule::Exclusive { .. } => {}
+            }
+        }
+        templates.extend(
+            rule.require_files
+                .iter()
+                .map(|pattern| ("require_files", pattern.as_str(), Placeholder::DIRECTORY)),
+        );
+        templates.extend(
+            rule.file_naming_pattern
+                .as_deref()
+                .map(|pattern| ("file_naming_pattern", pattern, Placeholder::DIRECTORY)),
+        );
+        for (field, pattern, allowed) in templates {
+            Template::parse(pattern, allowed).map_err(|e| {
+                SlocGuardError::Config(format!("structure.rules[{i}].{field} '{pattern}': {e}"))
+            })?;
+        }
+    }
+    Ok(())
+}
+
+/// Layouts need a name and scope; every entry must be a valid glob.
+fn validate_structure_layouts(config: &Config) -> Result<()> {
+    for (i, layout) in config.structure.layouts.iter().enumerate() {
+        if layout.name.trim().is_empty() {
+            return Err(SlocGuardError::Config(format!(
+                "structure.layouts[{i}].name must not be empty"
+            )));
+        }
+        if layout.scope.is_empty() {
+            return Err(SlocGuardError::Config(format!(
+                "structure.layouts[{i}] ('{}') needs a scope",
+                layout.name
+            )));
+        }
+        for pattern in std::iter::once(&layout.scope)
+            .chain(&layout.required)
+            .chain(&layout.optional)
+        {
+            globset::Glob::new(pattern.trim_end_matches('/')).map_err(|e| {
+                SlocGuardError::InvalidPattern {
+                    pattern: pattern.clone(),
+                    source: e,
+                }
+            })?;
+        }
+    }
     Ok(())
 }
 
@@ -170,11 +389,63 @@ fn validate_structure_global(config: &Config) -> Result<()> {
             "structure.warn_dirs_at ({warn_dirs_at}) must be less than structure.max_dirs ({max_dirs})"
         )));
     }
+    let s = &config.structure;
+    validate_total_warn(
+        "structure",
+        "files",
+        s.max_total_files,
+        s.warn_total_files_at,
+        s.warn_total_files_threshold,
+    )?;
+    validate_total_warn(
+        "structure",
+        "dirs",
+        s.max_total_dirs,
+        s.warn_total_dirs_at,
+        s.warn_total_dirs_threshold,
+    )
+}
+
+/// Validate `warn_total_{kind}_at`/`warn_total_{kind}_threshold` against `max_total_{kind}`.
+fn validate_total_warn(
+    prefix: &str,
+    kind: &str,
+    max: Option<i64>,
+    warn_at: Option<i64>,
+    warn_threshold: Option<f64>,
+) -> Result<()> {
+    if let Some(threshold) = warn_threshold
+        && !(0.0..=1.0).contains(&threshold)
+    {
+        return Err(SlocGuardError::Config(format!(
+            "{prefix}.warn_total_{kind}_threshold must be between 0.0 and 1.0, got {threshold}"
+        )));
+    }
+    if let Some(at) = warn_at
+        && at < 0
+    {
+        return Err(SlocGuardError::Config(format!(
+            "{prefix}.warn_total_{kind}_at must be non-negative, got {at}"
+        )));
+    }
+    if let (Some(at), Some(max)) = (warn_at, max)
+        && max >= 0
+        && at >= max
+    {
+        return Err(SlocGuardError::Config(format!(
+            "{prefix}.warn_total_{kind}_at ({at}) must be less than {prefix}.max_total_{kind} ({max})"
+        )));
+    }
     Ok(())
 }
 
 fn validate_structure_rules(config: &Config) -> Result<()> {
     for (i, rule) in config.structure.rules.iter().enumerate() {
+        if rule.scope_patterns().next().is_none() {
+            return Err(SlocGuardError::Config(format!(
+                "structure.rules[{i}] needs at least one of scope or scopes"
+            )));
+        }
         if let Some(warn_threshold) = rule.warn_threshold
             && !(0.0..=1.0).contains(&warn_threshold)
         {
@@ -226,36 +497,25 @@ fn validate_structure_rules(config: &Config) -> Result<()> {
                 "structure.rules[{i}].warn_dirs_at ({warn_dirs_at}) must be less than structure.rules[{i}].max_dirs ({max_dirs})"
             )));
         }
+        let prefix = format!("structure.rules[{i}]");
+        validate_total_warn(
+            &prefix,
+            "files",
+            rule.max_total_files,
+            rule.warn_total_files_at,
+            rule.warn_total_files_threshold,
+        )?;
+        validate_total_warn(
+            &prefix,
+            "dirs",
+            rule.max_total_dirs,
+            rule.warn_total_dirs_at,
+            rule.warn_total_dirs_threshold,
+        )?;
     }
     Ok(())
 }
 
 #[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_valid_config_passes_validation() {
-        let config = Config::default();
-        assert!(validate_config_semantics(&config).is_ok());
-    }
-
-    #[test]
-    fn test_invalid_warn_threshold_rejected() {
-        let mut config = Config::default();
-        config.content.warn_threshold = 1.5;
-        let result = validate_config_semantics(&config);
-        assert!(result.is_err());
-        assert!(result.unwrap_err().to_string().contains("warn_threshold"));
-    }
-
-    #[test]
-    fn test_warn_at_greater_than_max_lines_rejected() {
-        let mut config = Config::default();
-        config.content.max_lines = 100;
-        config.content.warn_at = Some(150);
-        let result = validate_config_semantics(&config);
-        assert!(result.is_err());
-        assert!(result.unwrap_err().to_string().contains("warn_at"));
-    }
-}
+#[path = "validation_tests.rs"]
+mod tests;
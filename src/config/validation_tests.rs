@@ -0,0 +1,363 @@
+use super::*;
+
+#[test]
+fn test_valid_config_passes_validation() {
+    let config = Config::default();
+    assert!(validate_config_semantics(&config).is_ok());
+}
+
+#[test]
+fn test_invalid_warn_threshold_rejected() {
+    let mut config = Config::default();
+    config.content.warn_threshold = 1.5;
+    let result = validate_config_semantics(&config);
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("warn_threshold"));
+}
+
+#[test]
+fn test_warn_at_greater_than_max_lines_rejected() {
+    let mut config = Config::default();
+    config.content.max_lines = 100;
+    config.content.warn_at = Some(150);
+    let result = validate_config_semantics(&config);
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("warn_at"));
+}
+
+#[test]
+fn test_global_metric_with_skip_false_rejected() {
+    let mut config = Config::default();
+    config.content.metric = Some(crate::config::ContentMetric::Total);
+    config.content.skip_blank = false;
+    let result = validate_config_semantics(&config);
+    assert!(result.unwrap_err().to_string().contains("content.metric"));
+}
+
+#[test]
+fn test_global_metric_with_default_skip_passes() {
+    let mut config = Config::default();
+    config.content.metric = Some(crate::config::ContentMetric::NonBlank);
+    assert!(validate_config_semantics(&config).is_ok());
+}
+
+#[test]
+fn test_rule_metric_with_skip_override_rejected() {
+    let mut config = Config::default();
+    config.content.rules.push(crate::config::ContentRule {
+        pattern: "**/*.rs".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
+        max_lines: 500,
+        warn_threshold: None,
+        warn_at: None,
+        skip_comments: Some(false),
+        skip_blank: None,
+        metric: Some(crate::config::ContentMetric::Code),
+        min_comment_ratio: None,
+        max_comment_ratio: None,
+        comment_ratio_min_code: None,
+        priority: None,
+        reason: None,
+        expires: None,
+        on_expire: None,
+    });
+    let result = validate_config_semantics(&config);
+    assert!(result.unwrap_err().to_string().contains("content.rules[0]"));
+}
+
+fn ratio_rule(min: Option<f64>, max: Option<f64>) -> crate::config::ContentRule {
+    crate::config::ContentRule {
+        pattern: "src/api/**".to_string(),
+        patterns: vec![],
+        exclude_patterns: vec![],
+        languages: vec![],
+        max_lines: 500,
+        warn_threshold: None,
+        warn_at: None,
+        skip_comments: None,
+        skip_blank: None,
+        metric: None,
+        min_comment_ratio: min,
+        max_comment_ratio: max,
+        comment_ratio_min_code: None,
+        priority: None,
+        reason: None,
+        expires: None,
+        on_expire: None,
+    }
+}
+
+#[test]
+fn test_comment_ratio_out_of_range_rejected() {
+    let mut config = Config::default();
+    config.content.rules.push(ratio_rule(Some(1.5), None));
+    let result = validate_config_semantics(&config);
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("min_comment_ratio")
+    );
+}
+
+#[test]
+fn test_comment_ratio_min_above_max_rejected() {
+    let mut config = Config::default();
+    config.content.rules.push(ratio_rule(Some(0.6), Some(0.4)));
+    let result = validate_config_semantics(&config);
+    assert!(result.unwrap_err().to_string().contains("must not exceed"));
+}
+
+#[test]
+fn test_comment_ratio_min_code_without_ratio_rejected() {
+    let mut config = Config::default();
+    let mut rule = ratio_rule(None, None);
+    rule.comment_ratio_min_code = Some(50);
+    config.content.rules.push(rule);
+    let result = validate_config_semantics(&config);
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("comment_ratio_min_code")
+    );
+}
+
+#[test]
+fn test_valid_comment_ratio_passes() {
+    let mut config = Config::default();
+    config.content.rules.push(ratio_rule(Some(0.1), Some(0.6)));
+    assert!(validate_config_semantics(&config).is_ok());
+}
+
+#[test]
+fn test_on_expire_without_expires_rejected() {
+    let mut config = Config::default();
+    let mut rule = ratio_rule(None, None);
+    rule.on_expire = Some(OnExpire::Revert);
+    config.content.rules.push(rule);
+    let result = validate_config_semantics(&config);
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("content.rules[0].on_expire (\"revert\") requires expires")
+    );
+}
+
+#[test]
+fn test_on_expire_with_invalid_date_rejected() {
+    let mut config = Config::default();
+    config.structure.rules.push(crate::config::StructureRule {
+        scope: "src/legacy".to_string(),
+        expires: Some("2025/06/01".to_string()),
+        on_expire: Some(OnExpire::Fail),
+        ..Default::default()
+    });
+    let result = validate_config_semantics(&config);
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("structure.rules[0].expires")
+    );
+}
+
+#[test]
+fn test_on_expire_with_expires_passes() {
+    let mut config = Config::default();
+    let mut rule = ratio_rule(None, None);
+    rule.expires = Some("2025-06-01".to_string());
+    rule.on_expire = Some(OnExpire::Fail);
+    config.content.rules.push(rule);
+    assert!(validate_config_semantics(&config).is_ok());
+}
+
+#[test]
+fn test_rule_without_selector_rejected() {
+    let mut config = Config::default();
+    let mut rule = ratio_rule(None, None);
+    rule.pattern = String::new();
+    config.content.rules.push(rule);
+    let result = validate_config_semantics(&config);
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("needs at least one of pattern, patterns or languages")
+    );
+}
+
+#[test]
+fn test_rule_unknown_language_rejected() {
+    let mut config = Config::default();
+    let mut rule = ratio_rule(None, None);
+    rule.languages = vec!["rust".to_string(), "Rustic".to_string()];
+    config.content.rules.push(rule);
+    let result = validate_config_semantics(&config);
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("unknown language 'Rustic'")
+    );
+}
+
+#[test]
+fn test_rule_invalid_exclude_pattern_rejected() {
+    let mut config = Config::default();
+    let mut rule = ratio_rule(None, None);
+    rule.exclude_patterns = vec!["[invalid".to_string()];
+    config.content.rules.push(rule);
+    assert!(matches!(
+        validate_config_semantics(&config),
+        Err(SlocGuardError::InvalidPattern { .. })
+    ));
+}
+
+#[test]
+fn test_structure_total_warn_at_not_below_max_rejected() {
+    let mut config = Config::default();
+    config.structure.max_total_files = Some(100);
+    config.structure.warn_total_files_at = Some(100);
+    let result = validate_config_semantics(&config);
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("structure.warn_total_files_at (100) must be less than")
+    );
+}
+
+#[test]
+fn test_structure_rule_total_threshold_out_of_range_rejected() {
+    let mut config = Config::default();
+    config.structure.rules.push(crate::config::StructureRule {
+        scope: "src/**".to_string(),
+        max_total_dirs: Some(10),
+        warn_total_dirs_threshold: Some(1.5),
+        ..Default::default()
+    });
+    let result = validate_config_semantics(&config);
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("structure.rules[0].warn_total_dirs_threshold must be between")
+    );
+}
+
+#[test]
+fn test_structure_rule_without_scope_rejected() {
+    let mut config = Config::default();
+    config.structure.rules.push(crate::config::StructureRule {
+        max_files: Some(10),
+        exclude_scopes: vec!["src/generated/**".to_string()],
+        ..Default::default()
+    });
+    let result = validate_config_semantics(&config);
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("structure.rules[0] needs at least one of scope or scopes")
+    );
+}
+
+#[test]
+fn test_structure_rule_invalid_exclude_scope_rejected() {
+    let mut config = Config::default();
+    config.structure.rules.push(crate::config::StructureRule {
+        scopes: vec!["src/**".to_string(), "lib/**".to_string()],
+        exclude_scopes: vec!["[bad".to_string()],
+        ..Default::default()
+    });
+    let result = validate_config_semantics(&config);
+    assert!(matches!(
+        result,
+        Err(SlocGuardError::InvalidPattern { pattern, .. }) if pattern == "[bad"
+    ));
+}
+
+#[test]
+fn test_structure_layout_without_name_rejected() {
+    let mut config = Config::default();
+    config
+        .structure
+        .layouts
+        .push(crate::config::StructureLayout {
+            scope: "services/*".to_string(),
+            required: vec!["go.mod".to_string()],
+            ..Default::default()
+        });
+    let result = validate_config_semantics(&config);
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("structure.layouts[0].name must not be empty")
+    );
+}
+
+#[test]
+fn test_structure_layout_invalid_entry_rejected() {
+    let mut config = Config::default();
+    config
+        .structure
+        .layouts
+        .push(crate::config::StructureLayout {
+            name: "go-service".to_string(),
+            scope: "services/*".to_string(),
+            optional: vec!["[bad/".to_string()],
+            ..Default::default()
+        });
+    let result = validate_config_semantics(&config);
+    assert!(matches!(
+        result,
+        Err(SlocGuardError::InvalidPattern { pattern, .. }) if pattern == "[bad/"
+    ));
+}
+
+#[test]
+fn test_structure_template_unknown_placeholder_rejected() {
+    use crate::config::{SiblingRequire, SiblingRule, SiblingSeverity, StructureRule};
+
+    let mut config = Config::default();
+    config.structure.rules.push(StructureRule {
+        scope: "src/**".to_string(),
+        siblings: vec![SiblingRule::Directed {
+            match_pattern: "*.ts".to_string(),
+            require: SiblingRequire::Single("{stem}.{kind}.ts".to_string()),
+            severity: SiblingSeverity::Error,
+        }],
+        ..Default::default()
+    });
+    let message = validate_config_semantics(&config).unwrap_err().to_string();
+    assert!(
+        message.contains("structure.rules[0].siblings '{stem}.{kind}.ts'"),
+        "{message}"
+    );
+    assert!(
+        message.contains("unknown placeholder '{kind}'"),
+        "{message}"
+    );
+}
+
+#[test]
+fn test_structure_template_placeholder_outside_its_context_rejected() {
+    let mut config = Config::default();
+    config.structure.rules.push(crate::config::StructureRule {
+        scope: "src/**".to_string(),
+        require_files: vec!["{dir:pascal}.tsx".to_string()],
+        file_naming_pattern: Some("^{stem}\\.rs$".to_string()),
+        ..Default::default()
+    });
+    let message = validate_config_semantics(&config).unwrap_err().to_string();
+    assert!(
+        message.contains("structure.rules[0].file_naming_pattern"),
+        "{message}"
+    );
+    assert!(message.contains("'{stem}' is not available"), "{message}");
+}
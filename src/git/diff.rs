@@ -1,6 +1,7 @@
 use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 
+use gix::index::entry::Stage;
 use gix::object::tree::EntryKind;
 
 use crate::{Result, SlocGuardError};
@@ -44,7 +45,7 @@ impl GitDiff {
         &self.workdir
     }
 
-    fn open_repo(&self) -> Result<gix::Repository> {
+    pub(super) fn open_repo(&self) -> Result<gix::Repository> {
         gix::open(&self.repo_path)
             .map_err(|e| SlocGuardError::Git(format!("Failed to open git repository: {e}")))
     }
@@ -87,7 +88,7 @@ impl GitDiff {
     }
 
     /// Build a map of HEAD tree entries for efficient O(1) lookups.
-    fn build_head_path_map(
+    pub(super) fn build_head_path_map(
         tree: &gix::Tree<'_>,
         prefix: &Path,
     ) -> Result<HashMap<PathBuf, gix::ObjectId>> {
@@ -108,29 +109,23 @@ impl GitDiff {
                 .map_err(|e| SlocGuardError::Git(format!("Invalid filename encoding: {e}")))?;
             let path = prefix.join(name);
 
-            match entry.mode().kind() {
-                EntryKind::Blob | EntryKind::BlobExecutable => {
-                    map.insert(path, entry.oid().into());
-                }
-                EntryKind::Tree => {
-                    let subtree = entry.object().map_err(|e| {
-                        SlocGuardError::Git(format!("Failed to get subtree object: {e}"))
-                    })?;
-                    Self::build_head_path_map_recursive(&subtree.into_tree(), &path, map)?;
-                }
-                // Submodules (Commit) and symbolic links (Link) are intentionally skipped.
-                // We only track regular file changes, not submodule pointer updates.
-                EntryKind::Commit | EntryKind::Link => {}
+            // Symlinks and submodules are kept too: the index lists them like files
+            if entry.mode().kind() == EntryKind::Tree {
+                let subtree = entry.object().map_err(|e| {
+                    SlocGuardError::Git(format!("Failed to get subtree object: {e}"))
+                })?;
+                Self::build_head_path_map_recursive(&subtree.into_tree(), &path, map)?;
+            } else {
+                map.insert(path, entry.oid().into());
             }
         }
         Ok(())
     }
 
-    /// Get files staged for commit (index differs from HEAD).
+    /// Index entries and the HEAD tree entries they are compared against.
     ///
-    /// # Errors
-    /// Returns an error if the repository cannot be accessed.
-    pub fn get_staged_files(&self) -> Result<HashSet<PathBuf>> {
+    /// New repos have no commits yet, so the HEAD map is empty.
+    pub(super) fn staged_state(&self) -> Result<StagedState> {
         let repo = self.open_repo()?;
 
         // Get index (staging area)
@@ -138,7 +133,6 @@ impl GitDiff {
             .open_index()
             .map_err(|e| SlocGuardError::Git(format!("Failed to open git index: {e}")))?;
 
-        // Get HEAD tree (if exists) - new repos have no commits yet
         // Use HashMap for O(1) lookup instead of HashSet with O(n) search
         let head_paths: HashMap<PathBuf, gix::ObjectId> = match repo.head_commit() {
             Ok(commit) => {
@@ -150,15 +144,41 @@ impl GitDiff {
             Err(_) => HashMap::new(),
         };
 
-        let mut staged_files = HashSet::new();
+        // Conflicted paths have one entry per merge side (stages 1-3) and no staged blob
+        let mut index_entries = Vec::new();
+        let mut conflicted = HashSet::new();
         for entry in index.entries() {
-            let path_str = String::from_utf8_lossy(entry.path(&index)).to_string();
-            let path = PathBuf::from(&path_str);
+            let path = PathBuf::from(String::from_utf8_lossy(entry.path(&index)).as_ref());
+            if entry.stage() == Stage::Unconflicted {
+                index_entries.push((path, entry.id));
+            } else {
+                conflicted.insert(path);
+            }
+        }
+
+        Ok(StagedState {
+            index_entries,
+            conflicted,
+            head_paths,
+        })
+    }
+
+    /// Get files staged for commit (index differs from HEAD).
+    ///
+    /// Files with unresolved merge conflicts have nothing staged yet and are left out.
+    ///
+    /// # Errors
+    /// Returns an error if the repository cannot be accessed.
+    pub fn get_staged_files(&self) -> Result<HashSet<PathBuf>> {
+        let state = self.staged_state()?;
 
+        let mut staged_files = HashSet::new();
+        for (path, id) in state.index_entries {
             // O(1) lookup instead of O(n) search
-            let is_staged = head_paths
+            let is_staged = state
+                .head_paths
                 .get(&path)
-                .is_none_or(|head_oid| *head_oid != entry.id);
+                .is_none_or(|head_oid| *head_oid != id);
 
             if is_staged {
                 staged_files.insert(self.workdir.join(path));
@@ -167,6 +187,51 @@ impl GitDiff {
 
         Ok(staged_files)
     }
+
+    /// Get entries the index adds or removes compared to HEAD.
+    ///
+    /// Directories count as entries: staging the first file of a new directory adds
+    /// that directory too. A rename shows up as a removal plus an addition.
+    ///
+    /// # Errors
+    /// Returns an error if the repository cannot be accessed.
+    pub fn get_staged_added_removed(&self) -> Result<HashSet<PathBuf>> {
+        let state = self.staged_state()?;
+        let index_paths = state.index_entries.into_iter().map(|(path, _)| path);
+        let index_entries = with_ancestors(index_paths.chain(state.conflicted));
+        let head_entries = with_ancestors(state.head_paths.into_keys());
+
+        Ok(index_entries
+            .symmetric_difference(&head_entries)
+            .map(|path| self.workdir.join(path))
+            .collect())
+    }
+}
+
+/// Index and HEAD entries, relative to the repository root.
+pub(super) struct StagedState {
+    /// Unconflicted (stage 0) entries.
+    pub(super) index_entries: Vec<(PathBuf, gix::ObjectId)>,
+    /// Paths with unresolved merge conflicts.
+    pub(super) conflicted: HashSet<PathBuf>,
+    pub(super) head_paths: HashMap<PathBuf, gix::ObjectId>,
+}
+
+/// The given file paths plus every directory containing them.
+pub(super) fn with_ancestors(paths: impl Iterator<Item = PathBuf>) -> HashSet<PathBuf> {
+    let mut entries = HashSet::new();
+    for path in paths {
+        let mut dir = path.parent().map(Path::to_path_buf);
+        // Stop once an ancestor is known: its own ancestors were added with it
+        while let Some(current) = dir.filter(|d| !d.as_os_str().is_empty()) {
+            if !entries.insert(current.clone()) {
+                break;
+            }
+            dir = current.parent().map(Path::to_path_buf);
+        }
+        entries.insert(path);
+    }
+    entries
 }
 
 impl GitDiff {
@@ -183,66 +248,17 @@ impl GitDiff {
         base_ref: &str,
         target_ref: &str,
     ) -> Result<HashSet<PathBuf>> {
-        let repo = self.open_repo()?;
-
-        // Parse the base reference
-        let base_commit = repo
-            .rev_parse_single(base_ref)
-            .map_err(|e| {
-                SlocGuardError::Git(format!("Failed to parse reference '{base_ref}': {e}"))
-            })?
-            .object()
-            .map_err(|e| {
-                SlocGuardError::Git(format!("Failed to get object for '{base_ref}': {e}"))
-            })?
-            .peel_to_commit()
-            .map_err(|e| {
-                SlocGuardError::Git(format!("Failed to peel to commit '{base_ref}': {e}"))
-            })?;
-
-        // Parse the target reference
-        let target_commit = repo
-            .rev_parse_single(target_ref)
-            .map_err(|e| {
-                SlocGuardError::Git(format!("Failed to parse reference '{target_ref}': {e}"))
-            })?
-            .object()
-            .map_err(|e| {
-                SlocGuardError::Git(format!("Failed to get object for '{target_ref}': {e}"))
-            })?
-            .peel_to_commit()
-            .map_err(|e| {
-                SlocGuardError::Git(format!("Failed to peel to commit '{target_ref}': {e}"))
-            })?;
-
-        // Get trees
-        let base_tree = base_commit.tree().map_err(|e| {
-            SlocGuardError::Git(format!("Failed to get tree for '{base_ref}': {e}"))
-        })?;
-        let target_tree = target_commit.tree().map_err(|e| {
-            SlocGuardError::Git(format!("Failed to get tree for '{target_ref}': {e}"))
-        })?;
-
-        // Use optimized comparison that skips identical subtrees
-        let mut changed_files = HashSet::new();
-        let mut deleted_paths = Vec::new();
-        Self::compare_trees_recursive(
-            &base_tree,
-            &target_tree,
-            Path::new(""),
-            &mut changed_files,
-            &mut deleted_paths,
-        )?;
+        let changes = self.compare_refs(base_ref, target_ref)?;
 
         // Prepend workdir to all changed paths
-        let changed_files: HashSet<PathBuf> = changed_files
+        let mut result: HashSet<PathBuf> = changes
+            .changed
             .into_iter()
             .map(|p| self.workdir.join(p))
             .collect();
 
         // For deleted files, only include them if they still exist locally
-        let mut result = changed_files;
-        for path in deleted_paths {
+        for path in changes.deleted_candidates {
             let full_path = self.workdir.join(&path);
             if full_path.exists() {
                 result.insert(full_path);
@@ -252,21 +268,86 @@ impl GitDiff {
         Ok(result)
     }
 
+    /// Get entries added or removed between two git references.
+    ///
+    /// Added and removed directories are reported along with everything beneath
+    /// them. A rename shows up as a removal plus an addition.
+    ///
+    /// # Errors
+    /// Returns an error if either reference cannot be parsed or the repository cannot be accessed.
+    pub fn get_added_removed_range(
+        &self,
+        base_ref: &str,
+        target_ref: &str,
+    ) -> Result<HashSet<PathBuf>> {
+        let changes = self.compare_refs(base_ref, target_ref)?;
+        Ok(changes
+            .added_or_removed
+            .into_iter()
+            .map(|p| self.workdir.join(p))
+            .collect())
+    }
+
+    /// Compare the trees of two git references.
+    fn compare_refs(&self, base_ref: &str, target_ref: &str) -> Result<TreeChanges> {
+        let repo = self.open_repo()?;
+        let base_tree = Self::resolve_tree(&repo, base_ref)?;
+        let target_tree = Self::resolve_tree(&repo, target_ref)?;
+
+        // Use optimized comparison that skips identical subtrees
+        let mut changes = TreeChanges::default();
+        Self::compare_trees_recursive(&base_tree, &target_tree, Path::new(""), &mut changes)?;
+        Ok(changes)
+    }
+
+    /// Best common ancestor of two git references, for `base...target` diffs.
+    ///
+    /// # Errors
+    /// Returns an error if either reference cannot be parsed or the two commits
+    /// share no history.
+    pub fn merge_base(&self, base_ref: &str, target_ref: &str) -> Result<gix::ObjectId> {
+        let repo = self.open_repo()?;
+        let base = Self::resolve_commit(&repo, base_ref)?.id;
+        let target = Self::resolve_commit(&repo, target_ref)?.id;
+        repo.merge_base(base, target)
+            .map(gix::Id::detach)
+            .map_err(|e| {
+                SlocGuardError::Git(format!(
+                    "Failed to find merge-base of '{base_ref}' and '{target_ref}': {e}"
+                ))
+            })
+    }
+
+    /// Resolve a git reference to the commit it points to.
+    fn resolve_commit<'repo>(
+        repo: &'repo gix::Repository,
+        rev: &str,
+    ) -> Result<gix::Commit<'repo>> {
+        repo.rev_parse_single(rev)
+            .map_err(|e| SlocGuardError::Git(format!("Failed to parse reference '{rev}': {e}")))?
+            .object()
+            .map_err(|e| SlocGuardError::Git(format!("Failed to get object for '{rev}': {e}")))?
+            .peel_to_commit()
+            .map_err(|e| SlocGuardError::Git(format!("Failed to peel to commit '{rev}': {e}")))
+    }
+
+    /// Resolve a git reference to the tree of the commit it points to.
+    fn resolve_tree<'repo>(repo: &'repo gix::Repository, rev: &str) -> Result<gix::Tree<'repo>> {
+        Self::resolve_commit(repo, rev)?
+            .tree()
+            .map_err(|e| SlocGuardError::Git(format!("Failed to get tree for '{rev}': {e}")))
+    }
+
     /// Compare two trees recursively, short-circuiting when subtree OIDs match.
     ///
     /// When a subtree has the same OID in both trees, we skip traversing it entirely
     /// since identical OIDs guarantee identical contents. This dramatically reduces
     /// the number of git objects we need to load for large repositories.
-    ///
-    /// # Parameters
-    /// - `deleted_candidates`: Paths that were deleted in git but may still exist locally
-    ///   (e.g., uncommitted changes). These require existence checks after traversal.
     fn compare_trees_recursive(
         base_tree: &gix::Tree<'_>,
         target_tree: &gix::Tree<'_>,
         prefix: &Path,
-        changed: &mut HashSet<PathBuf>,
-        deleted_candidates: &mut Vec<PathBuf>,
+        changes: &mut TreeChanges,
     ) -> Result<()> {
         // Build entry maps for efficient lookup by filename
         let base_entries = Self::build_entry_map(base_tree)?;
@@ -279,18 +360,12 @@ impl GitDiff {
             if let Some(base_entry) = base_entries.get(name) {
                 // Entry exists in both trees - check if OIDs differ
                 if base_entry.oid != target_entry.oid {
-                    Self::process_changed_entry(
-                        base_entry,
-                        target_entry,
-                        &path,
-                        changed,
-                        deleted_candidates,
-                    )?;
+                    Self::process_changed_entry(base_entry, target_entry, &path, changes)?;
                 }
                 // If OIDs are equal, entire subtree is identical - skip it
             } else {
                 // Entry only in target - it's new, add all its blobs
-                Self::process_added_entry(target_entry, &path, changed)?;
+                Self::process_added_entry(target_entry, &path, changes)?;
             }
         }
 
@@ -298,7 +373,7 @@ impl GitDiff {
         for (name, base_entry) in &base_entries {
             if !target_entries.contains_key(name) {
                 let path = prefix.join(name);
-                Self::process_deleted_entry(base_entry, &path, deleted_candidates)?;
+                Self::process_deleted_entry(base_entry, &path, changes)?;
             }
         }
 
@@ -310,8 +385,7 @@ impl GitDiff {
         base_entry: &TreeEntry<'_, '_>,
         target_entry: &TreeEntry<'_, '_>,
         path: &Path,
-        changed: &mut HashSet<PathBuf>,
-        deleted_candidates: &mut Vec<PathBuf>,
+        changes: &mut TreeChanges,
     ) -> Result<()> {
         match (base_entry.kind, target_entry.kind) {
             // Both are blobs - file content changed
@@ -319,7 +393,7 @@ impl GitDiff {
                 EntryKind::Blob | EntryKind::BlobExecutable,
                 EntryKind::Blob | EntryKind::BlobExecutable,
             ) => {
-                changed.insert(path.to_path_buf());
+                changes.changed.insert(path.to_path_buf());
             }
             // Both are trees - recurse to find specific changes
             (EntryKind::Tree, EntryKind::Tree) => {
@@ -334,23 +408,22 @@ impl GitDiff {
                     &base_subtree.into_tree(),
                     &target_subtree.into_tree(),
                     path,
-                    changed,
-                    deleted_candidates,
+                    changes,
                 )?;
             }
             // Type changed: tree -> blob (directory became file)
             (EntryKind::Tree, EntryKind::Blob | EntryKind::BlobExecutable) => {
                 // All files in the old directory are "deleted"
-                Self::process_deleted_entry(base_entry, path, deleted_candidates)?;
+                Self::process_deleted_entry(base_entry, path, changes)?;
                 // The new file is added
-                changed.insert(path.to_path_buf());
+                changes.changed.insert(path.to_path_buf());
             }
             // Type changed: blob -> tree (file became directory)
             (EntryKind::Blob | EntryKind::BlobExecutable, EntryKind::Tree) => {
                 // Old file is "deleted"
-                deleted_candidates.push(path.to_path_buf());
+                changes.deleted_candidates.push(path.to_path_buf());
                 // All files in the new directory are added
-                Self::process_added_entry(target_entry, path, changed)?;
+                Self::process_added_entry(target_entry, path, changes)?;
             }
             // Submodules (Commit) and symbolic links (Link) are intentionally skipped.
             // We only track regular file changes, not submodule pointer updates.
@@ -364,18 +437,20 @@ impl GitDiff {
     fn process_added_entry(
         entry: &TreeEntry<'_, '_>,
         path: &Path,
-        changed: &mut HashSet<PathBuf>,
+        changes: &mut TreeChanges,
     ) -> Result<()> {
+        changes.added_or_removed.insert(path.to_path_buf());
         match entry.kind {
             EntryKind::Blob | EntryKind::BlobExecutable => {
-                changed.insert(path.to_path_buf());
+                changes.changed.insert(path.to_path_buf());
             }
             EntryKind::Tree => {
                 let subtree = entry.object().map_err(|e| {
                     SlocGuardError::Git(format!("Failed to get subtree object: {e}"))
                 })?;
                 let paths = Self::collect_all_blob_paths(&subtree.into_tree(), path)?;
-                changed.extend(paths);
+                changes.added_or_removed.extend(paths.iter().cloned());
+                changes.changed.extend(paths);
             }
             // Submodules (Commit) and symbolic links (Link) are intentionally skipped.
             // We only track regular file changes, not submodule pointer updates.
@@ -388,18 +463,20 @@ impl GitDiff {
     fn process_deleted_entry(
         entry: &TreeEntry<'_, '_>,
         path: &Path,
-        deleted_candidates: &mut Vec<PathBuf>,
+        changes: &mut TreeChanges,
     ) -> Result<()> {
+        changes.added_or_removed.insert(path.to_path_buf());
         match entry.kind {
             EntryKind::Blob | EntryKind::BlobExecutable => {
-                deleted_candidates.push(path.to_path_buf());
+                changes.deleted_candidates.push(path.to_path_buf());
             }
             EntryKind::Tree => {
                 let subtree = entry.object().map_err(|e| {
                     SlocGuardError::Git(format!("Failed to get subtree object: {e}"))
                 })?;
                 let paths = Self::collect_all_blob_paths(&subtree.into_tree(), path)?;
-                deleted_candidates.extend(paths);
+                changes.added_or_removed.extend(paths.iter().cloned());
+                changes.deleted_candidates.extend(paths);
             }
             // Submodules (Commit) and symbolic links (Link) are intentionally skipped.
             // We only track regular file changes, not submodule pointer updates.
@@ -427,6 +504,18 @@ impl GitDiff {
     }
 }
 
+/// Paths collected while comparing two trees, relative to the repository root.
+#[derive(Default)]
+struct TreeChanges {
+    /// Added or modified files.
+    changed: HashSet<PathBuf>,
+    /// Paths that were deleted in git but may still exist locally
+    /// (e.g., uncommitted changes). These require existence checks.
+    deleted_candidates: Vec<PathBuf>,
+    /// Added or removed entries of any kind, including directories.
+    added_or_removed: HashSet<PathBuf>,
+}
+
 /// Helper struct to hold tree entry data for efficient comparison.
 ///
 /// Caches the entry's kind and OID to avoid repeated lookups during
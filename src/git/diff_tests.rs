@@ -1,9 +1,12 @@
-use std::path::Path;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
 use tempfile::TempDir;
 
 use super::*;
+use crate::git::StagedIndex;
+use crate::scanner::TrackedTree;
 
 fn create_git_repo() -> TempDir {
     let dir = TempDir::new().unwrap();
@@ -677,3 +680,233 @@ fn changed_files_skips_unchanged_subtrees() {
         .any(|p| p.to_string_lossy().contains("unchanged"));
     assert!(!has_unchanged, "Unchanged subtree should be skipped");
 }
+
+// ============================================================================
+// Added/Removed Entry Tests
+// ============================================================================
+
+fn relative_entries(git_diff: &GitDiff, entries: &HashSet<PathBuf>) -> Vec<String> {
+    let mut relative: Vec<_> = entries
+        .iter()
+        .map(|p| {
+            p.strip_prefix(git_diff.workdir())
+                .unwrap()
+                .to_string_lossy()
+                .replace('\\', "/")
+        })
+        .collect();
+    relative.sort();
+    relative
+}
+
+#[test]
+fn added_removed_range_reports_renames_and_new_directories() {
+    let dir = create_git_repo();
+    std::fs::create_dir(dir.path().join("src")).unwrap();
+    create_file(dir.path(), "main.rs", "fn main() {}");
+    create_file(dir.path(), "src/keep.rs", "fn keep() {}");
+    create_file(dir.path(), "src/old.rs", "fn old() {}");
+    git_add_all(dir.path());
+    git_commit(dir.path(), "Initial commit");
+
+    // Modify one file and move another into a new directory
+    create_file(dir.path(), "main.rs", "fn main() { println!(\"hi\"); }");
+    std::fs::create_dir(dir.path().join("lib")).unwrap();
+    std::fs::rename(dir.path().join("src/old.rs"), dir.path().join("lib/new.rs")).unwrap();
+    Command::new("git")
+        .args(["add", "-A"])
+        .current_dir(dir.path())
+        .output()
+        .expect("Failed to git add");
+    git_commit(dir.path(), "Rename file");
+
+    let git_diff = GitDiff::discover(dir.path()).unwrap();
+    let entries = git_diff.get_added_removed_range("HEAD~1", "HEAD").unwrap();
+
+    assert_eq!(
+        relative_entries(&git_diff, &entries),
+        vec!["lib", "lib/new.rs", "src/old.rs"]
+    );
+}
+
+#[test]
+fn staged_added_removed_reports_index_changes() {
+    let dir = create_git_repo();
+    std::fs::create_dir(dir.path().join("old")).unwrap();
+    create_file(dir.path(), "main.rs", "fn main() {}");
+    create_file(dir.path(), "old/x.rs", "fn x() {}");
+    git_add_all(dir.path());
+    git_commit(dir.path(), "Initial commit");
+
+    // Stage a modification and a move into a new directory
+    create_file(dir.path(), "main.rs", "fn main() { println!(\"hi\"); }");
+    std::fs::create_dir(dir.path().join("new")).unwrap();
+    std::fs::rename(dir.path().join("old/x.rs"), dir.path().join("new/x.rs")).unwrap();
+    Command::new("git")
+        .args(["add", "-A"])
+        .current_dir(dir.path())
+        .output()
+        .expect("Failed to git add");
+
+    let git_diff = GitDiff::discover(dir.path()).unwrap();
+    let entries = git_diff.get_staged_added_removed().unwrap();
+
+    assert_eq!(
+        relative_entries(&git_diff, &entries),
+        vec!["new", "new/x.rs", "old", "old/x.rs"]
+    );
+}
+
+// ============================================================================
+// Working Directory Tests (for --changed)
+// ============================================================================
+
+#[test]
+fn worktree_changed_files_includes_staged_unstaged_and_untracked() {
+    let dir = create_git_repo();
+    create_file(dir.path(), ".gitignore", "*.log\n");
+    create_file(dir.path(), "staged.rs", "fn staged() {}");
+    create_file(dir.path(), "unstaged.rs", "fn unstaged() {}");
+    create_file(dir.path(), "clean.rs", "fn clean() {}");
+    git_add_all(dir.path());
+    git_commit(dir.path(), "Initial commit");
+
+    create_file(dir.path(), "staged.rs", "fn staged() { todo!() }");
+    git_add_all(dir.path());
+    create_file(dir.path(), "unstaged.rs", "fn unstaged() { todo!() }");
+    create_file(dir.path(), "untracked.rs", "fn untracked() {}");
+    create_file(dir.path(), "debug.log", "ignored");
+    // Rewritten with the same content: the stat data changes, the blob does not
+    create_file(dir.path(), "clean.rs", "fn clean() {}");
+
+    let git_diff = GitDiff::discover(dir.path()).unwrap();
+    let changed = git_diff.get_worktree_changed_files().unwrap();
+
+    assert_eq!(
+        relative_entries(&git_diff, &changed),
+        vec!["staged.rs", "unstaged.rs", "untracked.rs"]
+    );
+}
+
+#[test]
+fn worktree_changed_files_empty_for_clean_checkout() {
+    let dir = create_git_repo();
+    create_file(dir.path(), "main.rs", "fn main() {}");
+    git_add_all(dir.path());
+    git_commit(dir.path(), "Initial commit");
+
+    let git_diff = GitDiff::discover(dir.path()).unwrap();
+    assert!(git_diff.get_worktree_changed_files().unwrap().is_empty());
+}
+
+#[test]
+fn worktree_changed_files_apply_line_ending_conversion() {
+    let dir = create_git_repo();
+    git(dir.path(), &["config", "core.autocrlf", "true"]);
+    create_file(dir.path(), "same.rs", "fn a() {}\nfn b() {}\n");
+    create_file(dir.path(), "edited.rs", "fn a() {}\n");
+    git_commit_all(dir.path(), "Initial commit");
+
+    // Only line endings change in same.rs; autocrlf normalizes them back to LF
+    create_file(dir.path(), "same.rs", "fn a() {}\r\nfn b() {}\r\n");
+    create_file(dir.path(), "edited.rs", "fn a() {}\r\nfn c() {}\r\n");
+
+    let git_diff = GitDiff::discover(dir.path()).unwrap();
+    let changed = git_diff.get_worktree_changed_files().unwrap();
+
+    assert_eq!(relative_entries(&git_diff, &changed), vec!["edited.rs"]);
+}
+
+#[test]
+fn worktree_added_removed_reports_deleted_and_untracked_entries() {
+    let dir = create_git_repo();
+    std::fs::create_dir(dir.path().join("old")).unwrap();
+    create_file(dir.path(), "main.rs", "fn main() {}");
+    create_file(dir.path(), "old/x.rs", "fn x() {}");
+    git_add_all(dir.path());
+    git_commit(dir.path(), "Initial commit");
+
+    // Neither change is staged
+    std::fs::remove_file(dir.path().join("old/x.rs")).unwrap();
+    std::fs::create_dir(dir.path().join("new")).unwrap();
+    create_file(dir.path(), "new/y.rs", "fn y() {}");
+    create_file(dir.path(), "main.rs", "fn main() { println!(\"hi\"); }");
+
+    let git_diff = GitDiff::discover(dir.path()).unwrap();
+    let entries = git_diff.get_worktree_added_removed().unwrap();
+
+    assert_eq!(
+        relative_entries(&git_diff, &entries),
+        vec!["new", "new/y.rs", "old", "old/x.rs"]
+    );
+}
+
+fn git(dir: &Path, args: &[&str]) {
+    Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .expect("Failed to run git");
+}
+
+fn git_commit_all(dir: &Path, message: &str) {
+    git_add_all(dir);
+    git_commit(dir, message);
+}
+
+/// A repo mid-merge with `conflict.rs` unresolved and `clean.rs` staged.
+fn create_conflicted_repo() -> TempDir {
+    let dir = create_git_repo();
+    create_file(dir.path(), "conflict.rs", "fn base() {}\n");
+    git_add_all(dir.path());
+    git_commit(dir.path(), "Initial commit");
+
+    git(dir.path(), &["checkout", "-b", "side"]);
+    create_file(dir.path(), "conflict.rs", "fn theirs() {}\n");
+    git_commit_all(dir.path(), "Theirs");
+    git(dir.path(), &["checkout", "-"]);
+    create_file(dir.path(), "conflict.rs", "fn ours() {}\n");
+    git_commit_all(dir.path(), "Ours");
+    git(dir.path(), &["merge", "side"]);
+
+    create_file(dir.path(), "clean.rs", "fn clean() {}\n");
+    git(dir.path(), &["add", "clean.rs"]);
+    dir
+}
+
+#[test]
+fn staged_files_skip_merge_conflicts() {
+    let dir = create_conflicted_repo();
+    let git_diff = GitDiff::discover(dir.path()).unwrap();
+
+    let staged = git_diff.get_staged_files().unwrap();
+    assert_eq!(relative_entries(&git_diff, &staged), vec!["clean.rs"]);
+
+    // The conflicted file is still tracked, so it is neither added nor removed
+    let entries = git_diff.get_staged_added_removed().unwrap();
+    assert_eq!(relative_entries(&git_diff, &entries), vec!["clean.rs"]);
+}
+
+#[test]
+fn worktree_changed_files_include_merge_conflicts() {
+    let dir = create_conflicted_repo();
+    let git_diff = GitDiff::discover(dir.path()).unwrap();
+
+    let changed = git_diff.get_worktree_changed_files().unwrap();
+    assert_eq!(
+        relative_entries(&git_diff, &changed),
+        vec!["clean.rs", "conflict.rs"]
+    );
+}
+
+#[test]
+fn staged_index_leaves_out_merge_conflicts() {
+    let dir = create_conflicted_repo();
+    let index = StagedIndex::open(dir.path()).unwrap();
+    let workdir = dunce::canonicalize(dir.path()).unwrap();
+
+    assert_eq!(index.conflicted(), [workdir.join("conflict.rs")]);
+    assert_eq!(index.files(), vec![workdir.join("clean.rs")]);
+    assert!(index.is_staged(&workdir.join("clean.rs")));
+    assert!(!index.is_staged(&workdir.join("conflict.rs")));
+}
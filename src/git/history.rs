@@ -0,0 +1,169 @@
+//! Commit history of the current branch, for backfilling trend snapshots.
+
+use std::path::{Path, PathBuf};
+
+use gix::revision::walk::Sorting;
+use gix::traverse::commit::simple::CommitTimeOrder;
+
+use super::tree::GitTree;
+use crate::scanner::absolute_path;
+use crate::{Result, SlocGuardError};
+
+/// Short hash length, matching `GitContext`.
+const SHORT_HASH_LEN: usize = 7;
+
+/// A commit selected for a snapshot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HistoryCommit {
+    pub id: gix::ObjectId,
+    /// Commit time (seconds since epoch)
+    pub timestamp: u64,
+}
+
+impl HistoryCommit {
+    /// Short commit hash (e.g., "a1b2c3d"), as recorded in trend entries.
+    #[must_use]
+    pub fn short_id(&self) -> String {
+        let mut id = self.id.to_string();
+        id.truncate(SHORT_HASH_LEN);
+        id
+    }
+}
+
+/// Which commits of the current branch to select.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CommitSelection {
+    /// Skip commits older than this timestamp.
+    pub since: Option<u64>,
+    /// Minimum seconds between selected commits.
+    pub every: Option<u64>,
+    /// Follow only the first parent of merge commits.
+    pub first_parent: bool,
+}
+
+/// Walks the commits reachable from HEAD and reads their trees.
+pub struct CommitHistory {
+    repo: gix::ThreadSafeRepository,
+    mount: PathBuf,
+    branch: Option<String>,
+}
+
+impl CommitHistory {
+    /// Open the repository containing `path`.
+    ///
+    /// Trees are mounted at the working directory, or at `path` in a bare repository.
+    ///
+    /// # Errors
+    /// Returns an error if no git repository is found.
+    pub fn discover(path: &Path) -> Result<Self> {
+        let repo = gix::discover(path)
+            .map_err(|e| SlocGuardError::Git(format!("Failed to discover git repository: {e}")))?;
+        let mount = absolute_path(repo.workdir().unwrap_or(path));
+        let branch = repo
+            .head_name()
+            .ok()
+            .flatten()
+            .map(|name| name.shorten().to_string());
+        Ok(Self {
+            repo: repo.into_sync(),
+            mount,
+            branch,
+        })
+    }
+
+    /// Current branch name (None for detached HEAD).
+    #[must_use]
+    pub fn branch(&self) -> Option<&str> {
+        self.branch.as_deref()
+    }
+
+    /// Commits reachable from HEAD that match `selection`, oldest first.
+    ///
+    /// HEAD is always selected; `every` then keeps each commit that is at least that
+    /// much older than the last one kept.
+    ///
+    /// # Errors
+    /// Returns an error if HEAD cannot be resolved or the history cannot be walked.
+    pub fn commits(&self, selection: &CommitSelection) -> Result<Vec<HistoryCommit>> {
+        let repo = self.repo.to_thread_local();
+        let head = repo
+            .head_id()
+            .map_err(|e| SlocGuardError::Git(format!("Failed to resolve HEAD: {e}")))?;
+
+        let order = CommitTimeOrder::NewestFirst;
+        let sorting = selection
+            .since
+            .map_or(Sorting::ByCommitTime(order), |since| {
+                Sorting::ByCommitTimeCutoff {
+                    order,
+                    seconds: i64::try_from(since).unwrap_or(i64::MAX),
+                }
+            });
+        let mut walk = repo.rev_walk([head]).sorting(sorting);
+        if selection.first_parent {
+            walk = walk.first_parent_only();
+        }
+
+        let mut commits = Vec::new();
+        for info in walk
+            .all()
+            .map_err(|e| SlocGuardError::Git(format!("Failed to walk history: {e}")))?
+        {
+            let info =
+                info.map_err(|e| SlocGuardError::Git(format!("Failed to read commit: {e}")))?;
+            // The walk only fills in commit times when its sorting needs them
+            let commit_time = match info.commit_time {
+                Some(time) => time,
+                None => commit_time(&info)?,
+            };
+            let timestamp = u64::try_from(commit_time).unwrap_or_default();
+            commits.push(HistoryCommit {
+                id: info.id,
+                timestamp,
+            });
+        }
+
+        let mut selected = sample_commits(commits, selection.every);
+        selected.reverse();
+        Ok(selected)
+    }
+
+    /// Files and blobs of `commit`'s tree.
+    ///
+    /// # Errors
+    /// Returns an error if the commit's tree cannot be read.
+    pub fn tree(&self, commit: &HistoryCommit) -> Result<GitTree> {
+        GitTree::at_object(&self.repo.to_thread_local(), commit.id, &self.mount)
+    }
+}
+
+/// Commit time of a walked commit, read from the commit object.
+fn commit_time(info: &gix::revision::walk::Info<'_>) -> Result<i64> {
+    let error = |e: &dyn std::fmt::Display| {
+        SlocGuardError::Git(format!("Failed to read commit time of {}: {e}", info.id))
+    };
+    let commit = info.object().map_err(|e| error(&e))?;
+    Ok(commit.time().map_err(|e| error(&e))?.seconds)
+}
+
+/// Thin out newest-first `commits` so kept commits are at least `every` seconds apart.
+#[must_use]
+pub fn sample_commits(commits: Vec<HistoryCommit>, every: Option<u64>) -> Vec<HistoryCommit> {
+    let Some(every) = every else {
+        return commits;
+    };
+    let mut kept: Vec<HistoryCommit> = Vec::new();
+    for commit in commits {
+        if kept
+            .last()
+            .is_none_or(|last| last.timestamp.saturating_sub(commit.timestamp) >= every)
+        {
+            kept.push(commit);
+        }
+    }
+    kept
+}
+
+#[cfg(test)]
+#[path = "history_tests.rs"]
+mod tests;
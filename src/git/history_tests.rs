@@ -0,0 +1,187 @@
+use std::path::Path;
+use std::process::Command;
+
+use tempfile::TempDir;
+
+use super::*;
+use crate::scanner::TrackedTree;
+
+/// Commit times of the fixture history, one day apart.
+const DAY: u64 = 86_400;
+const FIRST: u64 = 1_700_000_000;
+
+fn git(dir: &Path, args: &[&str], timestamp: Option<u64>) {
+    let mut command = Command::new("git");
+    command.args(args).current_dir(dir);
+    if let Some(timestamp) = timestamp {
+        let date = format!("@{timestamp} +0000");
+        command
+            .env("GIT_AUTHOR_DATE", &date)
+            .env("GIT_COMMITTER_DATE", &date);
+    }
+    let output = command.output().expect("Failed to run git");
+    assert!(output.status.success(), "git {args:?} failed: {output:?}");
+}
+
+/// Commit `name` with `content` at `timestamp`.
+fn commit_file(dir: &Path, name: &str, content: &str, timestamp: u64) {
+    std::fs::write(dir.join(name), content).unwrap();
+    git(dir, &["add", "."], None);
+    git(dir, &["commit", "-m", name], Some(timestamp));
+}
+
+/// Repository on `main` with commits `a.rs`..`d.rs` at days 0, 1, 2 and 10.
+fn create_history() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path();
+    git(root, &["init", "-b", "main"], None);
+    git(root, &["config", "user.email", "test@test.com"], None);
+    git(root, &["config", "user.name", "Test User"], None);
+    for (name, day) in [("a.rs", 0), ("b.rs", 1), ("c.rs", 2), ("d.rs", 10)] {
+        commit_file(root, name, "fn main() {}\n", FIRST + day * DAY);
+    }
+    dir
+}
+
+fn commit(timestamp: u64) -> HistoryCommit {
+    HistoryCommit {
+        id: gix::ObjectId::null(gix::hash::Kind::Sha1),
+        timestamp,
+    }
+}
+
+fn timestamps(commits: &[HistoryCommit]) -> Vec<u64> {
+    commits.iter().map(|c| c.timestamp).collect()
+}
+
+fn days(commits: &[HistoryCommit]) -> Vec<u64> {
+    commits
+        .iter()
+        .map(|c| (c.timestamp - FIRST) / DAY)
+        .collect()
+}
+
+// =============================================================================
+// sample_commits Tests
+// =============================================================================
+
+#[test]
+fn sample_commits_without_interval_keeps_all() {
+    let commits = vec![commit(300), commit(200), commit(100)];
+    let sampled = sample_commits(commits, None);
+    assert_eq!(timestamps(&sampled), vec![300, 200, 100]);
+}
+
+#[test]
+fn sample_commits_keeps_head_and_spaced_commits() {
+    let commits = vec![
+        commit(1000),
+        commit(950),
+        commit(900),
+        commit(850),
+        commit(700),
+    ];
+    let sampled = sample_commits(commits, Some(100));
+    assert_eq!(timestamps(&sampled), vec![1000, 900, 700]);
+}
+
+#[test]
+fn sample_commits_empty_history() {
+    assert!(sample_commits(Vec::new(), Some(100)).is_empty());
+}
+
+#[test]
+fn short_id_is_seven_chars() {
+    assert_eq!(commit(0).short_id(), "0000000");
+}
+
+// =============================================================================
+// CommitHistory Tests
+// =============================================================================
+
+#[test]
+fn commits_are_oldest_first_with_commit_times() {
+    let dir = create_history();
+    let history = CommitHistory::discover(dir.path()).unwrap();
+
+    let commits = history.commits(&CommitSelection::default()).unwrap();
+
+    assert_eq!(days(&commits), vec![0, 1, 2, 10]);
+    assert_eq!(history.branch(), Some("main"));
+}
+
+#[test]
+fn commits_since_skips_older_commits() {
+    let dir = create_history();
+    let history = CommitHistory::discover(dir.path()).unwrap();
+
+    let commits = history
+        .commits(&CommitSelection {
+            since: Some(FIRST + DAY),
+            ..CommitSelection::default()
+        })
+        .unwrap();
+
+    assert_eq!(days(&commits), vec![1, 2, 10]);
+}
+
+#[test]
+fn commits_every_samples_back_from_head() {
+    let dir = create_history();
+    let history = CommitHistory::discover(dir.path()).unwrap();
+
+    let commits = history
+        .commits(&CommitSelection {
+            every: Some(2 * DAY),
+            ..CommitSelection::default()
+        })
+        .unwrap();
+
+    // HEAD (day 10) is kept, then day 2, then day 0 (day 1 is too close to day 2)
+    assert_eq!(days(&commits), vec![0, 2, 10]);
+}
+
+#[test]
+fn commits_first_parent_skips_merged_branch() {
+    let dir = create_history();
+    let root = dir.path();
+    git(root, &["checkout", "-b", "feature"], None);
+    commit_file(root, "feature.rs", "", FIRST + 11 * DAY);
+    git(root, &["checkout", "main"], None);
+    commit_file(root, "e.rs", "", FIRST + 12 * DAY);
+    git(
+        root,
+        &["merge", "--no-ff", "-m", "merge", "feature"],
+        Some(FIRST + 13 * DAY),
+    );
+    let history = CommitHistory::discover(root).unwrap();
+
+    let all = history.commits(&CommitSelection::default()).unwrap();
+    let first_parent = history
+        .commits(&CommitSelection {
+            first_parent: true,
+            ..CommitSelection::default()
+        })
+        .unwrap();
+
+    assert_eq!(days(&all), vec![0, 1, 2, 10, 11, 12, 13]);
+    assert_eq!(days(&first_parent), vec![0, 1, 2, 10, 12, 13]);
+}
+
+#[test]
+fn tree_holds_files_of_the_commit() {
+    let dir = create_history();
+    let history = CommitHistory::discover(dir.path()).unwrap();
+    let commits = history.commits(&CommitSelection::default()).unwrap();
+
+    let tree = history.tree(&commits[1]).unwrap();
+    let mut names: Vec<String> = tree
+        .files()
+        .iter()
+        .filter_map(|path| Some(path.file_name()?.to_string_lossy().into_owned()))
+        .collect();
+    names.sort();
+
+    assert_eq!(names, vec!["a.rs", "b.rs"]);
+    assert_eq!(commits[1].short_id().len(), 7);
+}
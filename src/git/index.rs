@@ -0,0 +1,109 @@
+//! The git index as a file tree, for checking what `check --staged` would commit.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use gix::index::entry::{Mode, Stage};
+
+use super::diff::GitDiff;
+use super::tree::GitTree;
+use crate::scanner::{TrackedTree, absolute_path};
+use crate::{Result, SlocGuardError};
+
+/// Regular files of the git index, and which of them are staged against HEAD.
+///
+/// The tree is mounted at the canonical working directory.
+pub struct StagedIndex {
+    tree: Arc<GitTree>,
+    /// Files whose blob differs from HEAD (or that are new).
+    staged: HashSet<PathBuf>,
+    /// Files with unresolved merge conflicts, left out of the tree.
+    conflicted: Vec<PathBuf>,
+}
+
+impl StagedIndex {
+    /// Read the index of the repository containing `path`.
+    ///
+    /// # Errors
+    /// Returns an error if no git repository is found or its index or HEAD cannot be read.
+    pub fn open(path: &Path) -> Result<Self> {
+        let repo = gix::discover(path)
+            .map_err(|e| SlocGuardError::Git(format!("Failed to discover git repository: {e}")))?;
+        let workdir = repo
+            .workdir()
+            .map(absolute_path)
+            .ok_or_else(|| SlocGuardError::Git("Repository has no working directory".into()))?;
+        let index = repo
+            .open_index()
+            .map_err(|e| SlocGuardError::Git(format!("Failed to open git index: {e}")))?;
+
+        // New repos have no commits yet, so every index entry is staged
+        let head_paths = match repo.head_commit() {
+            Ok(commit) => {
+                let head_tree = commit
+                    .tree()
+                    .map_err(|e| SlocGuardError::Git(format!("Failed to get HEAD tree: {e}")))?;
+                GitDiff::build_head_path_map(&head_tree, Path::new(""))?
+            }
+            Err(_) => HashMap::new(),
+        };
+
+        // Symlinks and submodules are not files to a directory walk either
+        let mut files = HashMap::new();
+        let mut staged = HashSet::new();
+        let mut conflicted = Vec::new();
+        for entry in index.entries() {
+            if !matches!(entry.mode, Mode::FILE | Mode::FILE_EXECUTABLE) {
+                continue;
+            }
+            let path = PathBuf::from(String::from_utf8_lossy(entry.path(&index)).as_ref());
+            let abs_path = workdir.join(&path);
+            // Stages 1-3 hold the merge sides of a conflict; nothing is staged until it is resolved
+            if entry.stage() != Stage::Unconflicted {
+                if conflicted.last() != Some(&abs_path) {
+                    conflicted.push(abs_path);
+                }
+                continue;
+            }
+            if head_paths.get(&path).is_none_or(|id| *id != entry.id) {
+                staged.insert(abs_path.clone());
+            }
+            files.insert(abs_path, entry.id);
+        }
+
+        Ok(Self {
+            tree: Arc::new(GitTree::new(repo.into_sync(), files)),
+            staged,
+            conflicted,
+        })
+    }
+
+    /// Files and blobs of the index.
+    #[must_use]
+    pub const fn tree(&self) -> &Arc<GitTree> {
+        &self.tree
+    }
+
+    /// Whether `path` is in the index with content that differs from HEAD.
+    #[must_use]
+    pub fn is_staged(&self, path: &Path) -> bool {
+        self.staged.contains(&absolute_path(path))
+    }
+
+    /// Files with unresolved merge conflicts, which the tree leaves out.
+    #[must_use]
+    pub fn conflicted(&self) -> &[PathBuf] {
+        &self.conflicted
+    }
+}
+
+impl TrackedTree for StagedIndex {
+    fn files(&self) -> Vec<PathBuf> {
+        self.tree.files()
+    }
+
+    fn file_size(&self, path: &Path) -> Option<u64> {
+        self.tree.file_size(path)
+    }
+}
@@ -1,8 +1,15 @@
 mod context;
 mod diff;
+mod history;
+mod index;
+mod tree;
+mod worktree;
 
 pub use context::GitContext;
 pub use diff::{ChangedFiles, GitDiff};
+pub use history::{CommitHistory, CommitSelection, HistoryCommit, sample_commits};
+pub use index::StagedIndex;
+pub use tree::GitTree;
 
 #[cfg(test)]
 #[path = "diff_tests.rs"]
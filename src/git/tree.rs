@@ -0,0 +1,125 @@
+//! Git trees as file trees, for checking content that is not checked out.
+
+use std::collections::HashMap;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use gix::object::tree::EntryKind;
+
+use crate::scanner::{TrackedTree, absolute_path};
+use crate::{Result, SlocGuardError};
+
+/// Regular files of a git tree (a revision or the index) and their blobs.
+///
+/// Paths are absolute under the directory the tree is mounted at, so paths relative
+/// to the current directory can be looked up after `absolute_path`.
+pub struct GitTree {
+    repo: gix::ThreadSafeRepository,
+    files: HashMap<PathBuf, gix::ObjectId>,
+}
+
+impl GitTree {
+    pub(super) const fn new(
+        repo: gix::ThreadSafeRepository,
+        files: HashMap<PathBuf, gix::ObjectId>,
+    ) -> Self {
+        Self { repo, files }
+    }
+
+    /// Read the tree of `rev` (a commit, tag or tree) in the repository containing `path`.
+    ///
+    /// The tree is mounted at the working directory, or at `path` in a bare repository.
+    ///
+    /// # Errors
+    /// Returns an error if no git repository is found or `rev` does not resolve to a tree.
+    pub fn at_revision(path: &Path, rev: &str) -> Result<Self> {
+        let repo = gix::discover(path)
+            .map_err(|e| SlocGuardError::Git(format!("Failed to discover git repository: {e}")))?;
+        let mount = absolute_path(repo.workdir().unwrap_or(path));
+        let id = repo
+            .rev_parse_single(rev)
+            .map_err(|e| SlocGuardError::Git(format!("Failed to resolve '{rev}': {e}")))?
+            .detach();
+        Self::at_object(&repo, id, &mount)
+    }
+
+    /// Read the tree of the commit, tag or tree `id`, mounted at `mount`.
+    ///
+    /// # Errors
+    /// Returns an error if `id` cannot be read or does not point to a tree.
+    pub(super) fn at_object(
+        repo: &gix::Repository,
+        id: gix::ObjectId,
+        mount: &Path,
+    ) -> Result<Self> {
+        let tree = repo
+            .find_object(id)
+            .map_err(|e| SlocGuardError::Git(format!("Failed to read '{id}': {e}")))?
+            .peel_to_tree()
+            .map_err(|e| SlocGuardError::Git(format!("'{id}' does not point to a tree: {e}")))?;
+        let mut files = HashMap::new();
+        collect_blobs(&tree, mount, &mut files)?;
+        Ok(Self::new(repo.clone().into_sync(), files))
+    }
+
+    /// Object id of the blob for `path`.
+    #[must_use]
+    pub fn blob_id(&self, path: &Path) -> Option<gix::ObjectId> {
+        self.files.get(&absolute_path(path)).copied()
+    }
+
+    /// Content of the blob for `path`.
+    ///
+    /// # Errors
+    /// Returns `NotFound` if `path` is not a file in the tree, or the object
+    /// database error if the blob cannot be read.
+    pub fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        let id = self.blob_id(path).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::NotFound, "file is not in the git tree")
+        })?;
+        let repo = self.repo.to_thread_local();
+        let mut blob = repo.find_blob(id).map_err(io::Error::other)?;
+        Ok(blob.take_data())
+    }
+}
+
+impl TrackedTree for GitTree {
+    fn files(&self) -> Vec<PathBuf> {
+        self.files.keys().cloned().collect()
+    }
+
+    fn file_size(&self, path: &Path) -> Option<u64> {
+        let id = self.files.get(path)?;
+        let header = self.repo.to_thread_local().find_header(*id).ok()?;
+        Some(header.size())
+    }
+}
+
+fn collect_blobs(
+    tree: &gix::Tree<'_>,
+    prefix: &Path,
+    files: &mut HashMap<PathBuf, gix::ObjectId>,
+) -> Result<()> {
+    for entry in tree.iter() {
+        let entry =
+            entry.map_err(|e| SlocGuardError::Git(format!("Failed to read tree entry: {e}")))?;
+        let name = std::str::from_utf8(entry.filename())
+            .map_err(|e| SlocGuardError::Git(format!("Invalid filename encoding: {e}")))?;
+        let path = prefix.join(name);
+
+        match entry.mode().kind() {
+            EntryKind::Blob | EntryKind::BlobExecutable => {
+                files.insert(path, entry.oid().into());
+            }
+            EntryKind::Tree => {
+                let subtree = entry.object().map_err(|e| {
+                    SlocGuardError::Git(format!("Failed to get subtree object: {e}"))
+                })?;
+                collect_blobs(&subtree.into_tree(), &path, files)?;
+            }
+            // Symlinks and submodules are not files to a directory walk either
+            EntryKind::Commit | EntryKind::Link => {}
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,166 @@
+//! Working directory changes against HEAD, for checking uncommitted work (`--changed`).
+
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicBool;
+
+use gix::dir::entry::{Kind, Status};
+use gix::dir::walk::EmissionMode;
+use gix::index::entry::{Mode, Stage, Stat, stat};
+
+use super::diff::{GitDiff, with_ancestors};
+use crate::{Result, SlocGuardError};
+
+impl GitDiff {
+    /// Get files that differ between HEAD and the working directory.
+    ///
+    /// Covers staged files, tracked files modified since they were staged, and
+    /// untracked files that are not ignored.
+    ///
+    /// # Errors
+    /// Returns an error if the repository cannot be accessed.
+    pub fn get_worktree_changed_files(&self) -> Result<HashSet<PathBuf>> {
+        let mut changed = self.get_staged_files()?;
+        let worktree = self.worktree_state()?;
+        changed.extend(
+            worktree
+                .modified
+                .into_iter()
+                .chain(worktree.untracked)
+                .map(|path| self.workdir().join(path)),
+        );
+        Ok(changed)
+    }
+
+    /// Get entries the working directory adds or removes compared to HEAD.
+    ///
+    /// Like [`Self::get_staged_added_removed`], but tracked files deleted from disk
+    /// count as removed and untracked files as added.
+    ///
+    /// # Errors
+    /// Returns an error if the repository cannot be accessed.
+    pub fn get_worktree_added_removed(&self) -> Result<HashSet<PathBuf>> {
+        let state = self.staged_state()?;
+        let worktree = self.worktree_state()?;
+        let on_disk = state
+            .index_entries
+            .into_iter()
+            .map(|(path, _)| path)
+            .chain(state.conflicted)
+            .filter(|path| self.workdir().join(path).symlink_metadata().is_ok())
+            .chain(worktree.untracked);
+        let worktree_entries = with_ancestors(on_disk);
+        let head_entries = with_ancestors(state.head_paths.into_keys());
+
+        Ok(worktree_entries
+            .symmetric_difference(&head_entries)
+            .map(|path| self.workdir().join(path))
+            .collect())
+    }
+
+    /// Tracked files whose content differs from the index, and untracked files.
+    fn worktree_state(&self) -> Result<WorktreeState> {
+        let repo = self.open_repo()?;
+        let index = repo
+            .open_index()
+            .map_err(|e| SlocGuardError::Git(format!("Failed to open git index: {e}")))?;
+
+        let (mut pipeline, _) = repo
+            .filter_pipeline(None)
+            .map_err(|e| SlocGuardError::Git(format!("Failed to set up git filters: {e}")))?;
+
+        let mut modified = Vec::new();
+        for entry in index.entries() {
+            // Symlinks and submodules have no line counts to check
+            if !matches!(entry.mode, Mode::FILE | Mode::FILE_EXECUTABLE) {
+                continue;
+            }
+            let path = PathBuf::from(String::from_utf8_lossy(entry.path(&index)).to_string());
+            // A conflicted path has an entry per merge side; its worktree file is the change
+            if entry.stage() != Stage::Unconflicted {
+                if modified.last() != Some(&path) {
+                    modified.push(path);
+                }
+                continue;
+            }
+            if differs_from_index(&mut pipeline, &index, entry, self.workdir(), &path) {
+                modified.push(path);
+            }
+        }
+
+        let options = repo
+            .dirwalk_options()
+            .map_err(|e| SlocGuardError::Git(format!("Failed to read git config: {e}")))?
+            .emit_untracked(EmissionMode::Matching);
+        let mut collect = gix::dir::walk::delegate::Collect::default();
+        repo.dirwalk(
+            &index,
+            None::<&str>,
+            &AtomicBool::new(false),
+            options,
+            &mut collect,
+        )
+        .map_err(|e| SlocGuardError::Git(format!("Failed to walk working directory: {e}")))?;
+        let untracked = collect
+            .unorded_entries
+            .into_iter()
+            .filter(|(entry, _)| {
+                entry.status == Status::Untracked && entry.disk_kind == Some(Kind::File)
+            })
+            .map(|(entry, _)| PathBuf::from(entry.rela_path.to_string()))
+            .collect();
+
+        Ok(WorktreeState {
+            modified,
+            untracked,
+        })
+    }
+}
+
+/// Working directory state, relative to the repository root.
+struct WorktreeState {
+    /// Tracked files whose content no longer matches the index.
+    modified: Vec<PathBuf>,
+    /// Files that are neither tracked nor ignored.
+    untracked: Vec<PathBuf>,
+}
+
+/// Whether the file at `rela_path` no longer holds the content the index `entry` records.
+///
+/// Files whose stat data still matches the index are trusted unchanged, as git does.
+/// Otherwise the file is run through the repository's filters (`core.autocrlf`,
+/// `eol`/`text` attributes, clean filters) before hashing, so only content changes count.
+/// Deleted or unreadable files are not reported: there is nothing left to check.
+fn differs_from_index(
+    pipeline: &mut gix::filter::Pipeline<'_>,
+    index: &gix::index::State,
+    entry: &gix::index::Entry,
+    workdir: &Path,
+    rela_path: &Path,
+) -> bool {
+    let path = workdir.join(rela_path);
+    let Ok(metadata) = gix::index::fs::Metadata::from_path_no_follow(&path) else {
+        return false;
+    };
+    let options = stat::Options::default();
+    if let Ok(current) = Stat::from_fs(&metadata)
+        && entry.stat.matches(&current, options)
+        && !entry.stat.is_racy(index.timestamp(), options)
+    {
+        return false;
+    }
+
+    let object_hash = pipeline.repo.object_hash();
+    let Ok(file) = File::open(&path) else {
+        return false;
+    };
+    let mut data = Vec::new();
+    let converted = pipeline
+        .convert_to_git(file, rela_path, index)
+        .is_ok_and(|mut stream| stream.read_to_end(&mut data).is_ok());
+    converted
+        && gix::objs::compute_hash(object_hash, gix::object::Kind::Blob, &data)
+            .is_ok_and(|id| id != entry.id)
+}
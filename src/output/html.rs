@@ -4,6 +4,7 @@ use std::path::{Path, PathBuf};
 use crate::checker::CheckResult;
 use crate::error::Result;
 
+use super::html_suggestions::{write_reorganization, write_split_suggestions};
 // Re-export for use by stats HTML formatter
 pub use super::html_template::{HTML_FOOTER, HTML_HEADER};
 use super::path::display_path;
@@ -388,6 +389,16 @@ impl HtmlFormatter {
         )
         .ok();
 
+        // Optional comment ratio breach or expired exemption
+        if let Some(message) = result.category_message() {
+            let escaped_message = html_escape(&message);
+            writeln!(
+                output,
+                r#"                        <div class="reason">{escaped_message}</div>"#
+            )
+            .ok();
+        }
+
         // Optional reason
         if let Some(reason) = result.override_reason() {
             let escaped_reason = html_escape(reason);
@@ -398,31 +409,14 @@ impl HtmlFormatter {
             .ok();
         }
 
-        // Optional split suggestions
-        if self.show_suggestions
-            && let Some(suggestion) = result.suggestions()
-            && suggestion.has_suggestions()
-        {
-            output.push_str("                        <div class=\"suggestions\">\n");
-            output.push_str("                            <h4>Split suggestions:</h4>\n");
-            output.push_str("                            <ul>\n");
-            for chunk in &suggestion.chunks {
-                let funcs = if chunk.functions.is_empty() {
-                    String::new()
-                } else {
-                    format!(" ({})", chunk.functions.join(", "))
-                };
-                writeln!(
-                    output,
-                    r"                                <li>{}.* (~{} lines){}</li>",
-                    html_escape(&chunk.suggested_name),
-                    chunk.line_count,
-                    html_escape(&funcs)
-                )
-                .ok();
+        // Optional split and reorganization suggestions
+        if self.show_suggestions {
+            if let Some(suggestion) = result.suggestions() {
+                write_split_suggestions(output, suggestion);
+            }
+            if let Some(suggestion) = result.reorganization() {
+                write_reorganization(output, suggestion);
             }
-            output.push_str("                            </ul>\n");
-            output.push_str("                        </div>\n");
         }
 
         output.push_str("                    </td>\n");
@@ -438,11 +432,14 @@ impl HtmlFormatter {
         )
         .ok();
 
-        // Lines: effective SLOC after skip_comments/skip_blank rules
+        // Lines: effective SLOC after skip_comments/skip_blank rules (or the configured metric)
         let sloc = result.stats().sloc();
+        let sloc_label = result
+            .configured_metric()
+            .map_or_else(|| sloc.to_string(), |m| format!("{sloc} {}", m.unit()));
         writeln!(
             output,
-            r#"                    <td class="number" data-value="{sloc}">{sloc}</td>"#
+            r#"                    <td class="number" data-value="{sloc}">{sloc_label}</td>"#
         )
         .ok();
         let limit = result.limit();
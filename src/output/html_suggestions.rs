@@ -0,0 +1,65 @@
+//! `--suggest` blocks shown under a file in the HTML report.
+
+use std::fmt::Write;
+
+use super::html::html_escape;
+use crate::analyzer::{ReorganizeSuggestion, SplitSuggestion};
+
+pub(super) fn write_split_suggestions(output: &mut String, suggestion: &SplitSuggestion) {
+    if !suggestion.has_suggestions() {
+        return;
+    }
+    output.push_str("                        <div class=\"suggestions\">\n");
+    output.push_str("                            <h4>Split suggestions:</h4>\n");
+    output.push_str("                            <ul>\n");
+    for chunk in &suggestion.chunks {
+        let funcs = if chunk.functions.is_empty() {
+            String::new()
+        } else {
+            format!(" ({})", chunk.functions.join(", "))
+        };
+        writeln!(
+            output,
+            r"                                <li>{}.* (~{} lines){}</li>",
+            html_escape(&chunk.suggested_name),
+            chunk.line_count,
+            html_escape(&funcs)
+        )
+        .ok();
+    }
+    output.push_str("                            </ul>\n");
+    output.push_str("                        </div>\n");
+}
+
+pub(super) fn write_reorganization(output: &mut String, suggestion: &ReorganizeSuggestion) {
+    if !suggestion.has_suggestions() {
+        return;
+    }
+    output.push_str("                        <div class=\"suggestions\">\n");
+    output.push_str("                            <h4>Reorganization suggestions:</h4>\n");
+    output.push_str("                            <ul>\n");
+    for bucket in &suggestion.buckets {
+        writeln!(
+            output,
+            r"                                <li>{}/ ({}): {}</li>",
+            html_escape(&bucket.directory),
+            html_escape(&bucket.pattern),
+            html_escape(&bucket.files.join(", "))
+        )
+        .ok();
+    }
+    writeln!(
+        output,
+        r"                                <li>Leaves {} files (limit: {})</li>",
+        suggestion.remaining_files(),
+        suggestion.limit
+    )
+    .ok();
+    if suggestion.dir_limit_reached {
+        output.push_str(
+            "                                <li>Stopped early: more subdirectories would exceed max_dirs</li>\n",
+        );
+    }
+    output.push_str("                            </ul>\n");
+    output.push_str("                        </div>\n");
+}
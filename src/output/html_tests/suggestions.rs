@@ -82,3 +82,26 @@ fn split_suggestions_with_empty_functions() {
     // Empty functions should not produce parentheses
     assert!(!output.contains("big_file_part1.* (~300 lines) ("));
 }
+
+#[test]
+fn shows_reorganization_suggestions_when_enabled() {
+    use crate::analyzer::{BucketKind, ReorganizeBucket, ReorganizeSuggestion};
+
+    let suggestion =
+        ReorganizeSuggestion::new(PathBuf::from("src/utils"), 5, 3).with_buckets(vec![
+            ReorganizeBucket {
+                directory: "user".to_string(),
+                kind: BucketKind::Prefix,
+                pattern: "user*".to_string(),
+                files: vec!["user_create.rs".to_string(), "user_delete.rs".to_string()],
+            },
+        ]);
+    let result = make_failed_result("src/utils", 5, 3).with_reorganization(suggestion);
+
+    let formatter = HtmlFormatter::new().with_suggestions(true);
+    let output = formatter.format(&[result]).unwrap();
+
+    assert!(output.contains("Reorganization suggestions:"));
+    assert!(output.contains("<li>user/ (user*): user_create.rs, user_delete.rs</li>"));
+    assert!(output.contains("<li>Leaves 3 files (limit: 3)</li>"));
+}
@@ -2,8 +2,9 @@ use std::path::{Path, PathBuf};
 
 use serde::Serialize;
 
-use crate::analyzer::SplitSuggestion;
+use crate::analyzer::{ReorganizeSuggestion, SplitSuggestion};
 use crate::checker::{CheckResult, ViolationCategory};
+use crate::config::ContentMetric;
 use crate::error::Result;
 
 use super::OutputFormatter;
@@ -67,6 +68,9 @@ struct FileResult {
     status: String,
     sloc: usize,
     limit: usize,
+    /// Line metric `sloc` is counted with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    metric: Option<ContentMetric>,
     stats: FileStats,
     #[serde(skip_serializing_if = "Option::is_none")]
     override_reason: Option<String>,
@@ -74,6 +78,8 @@ struct FileResult {
     violation_category: Option<ViolationCategory>,
     #[serde(skip_serializing_if = "Option::is_none")]
     suggestions: Option<SplitSuggestion>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reorganization: Option<ReorganizeSuggestion>,
 }
 
 #[derive(Serialize)]
@@ -113,10 +119,13 @@ impl OutputFormatter for JsonFormatter {
 
 impl JsonFormatter {
     fn convert_result(&self, result: &CheckResult) -> FileResult {
-        let suggestions = if self.show_suggestions {
-            result.suggestions().cloned()
+        let (suggestions, reorganization) = if self.show_suggestions {
+            (
+                result.suggestions().cloned(),
+                result.reorganization().cloned(),
+            )
         } else {
-            None
+            (None, None)
         };
 
         // Use raw_stats for display (before skip_comments/skip_blank adjustments)
@@ -131,6 +140,7 @@ impl JsonFormatter {
             },
             sloc: result.stats().sloc(),
             limit: result.limit(),
+            metric: result.metric(),
             stats: FileStats {
                 total: raw.total,
                 code: raw.code,
@@ -138,8 +148,13 @@ impl JsonFormatter {
                 blank: raw.blank,
             },
             override_reason: result.override_reason().map(String::from),
-            violation_category: result.violation_category().cloned(),
+            // Line counts are identified by `metric`; only other categories are listed
+            violation_category: result
+                .violation_category()
+                .filter(|category| !matches!(category, ViolationCategory::Content { .. }))
+                .cloned(),
             suggestions,
+            reorganization,
         }
     }
 }
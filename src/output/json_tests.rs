@@ -124,6 +124,41 @@ fn json_result_fields() {
     assert_eq!(stats.get("blank").unwrap(), 5);
 }
 
+#[test]
+fn json_content_result_reports_metric_without_category() {
+    let formatter = JsonFormatter::new();
+    let mut result = make_failed_result("test.rs", 600, 500);
+    if let CheckResult::Failed {
+        violation_category, ..
+    } = &mut result
+    {
+        *violation_category = Some(ViolationCategory::Content {
+            metric: ContentMetric::Code,
+            configured: false,
+        });
+    }
+
+    let output = formatter.format(&[result]).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+    let file_result = &parsed["results"][0];
+    assert_eq!(file_result["metric"], "code");
+    assert!(file_result.get("violation_category").is_none());
+}
+
+#[test]
+fn content_category_serializes_without_metric() {
+    let category = ViolationCategory::Content {
+        metric: ContentMetric::Total,
+        configured: true,
+    };
+
+    assert_eq!(
+        serde_json::to_value(&category).unwrap(),
+        serde_json::json!({ "category": "content" })
+    );
+}
+
 #[test]
 fn json_status_values() {
     let formatter = JsonFormatter::new();
@@ -268,3 +303,32 @@ fn json_default_formatter() {
     let output = formatter.format(&results).unwrap();
     assert!(output.contains("summary"));
 }
+
+#[test]
+fn json_with_reorganization_suggestions() {
+    use crate::analyzer::{BucketKind, ReorganizeBucket, ReorganizeSuggestion};
+
+    let suggestion =
+        ReorganizeSuggestion::new(PathBuf::from("src/utils"), 5, 3).with_buckets(vec![
+            ReorganizeBucket {
+                directory: "user".to_string(),
+                kind: BucketKind::Prefix,
+                pattern: "user*".to_string(),
+                files: vec!["user_create.rs".to_string(), "user_delete.rs".to_string()],
+            },
+        ]);
+    let result = make_failed_result("src/utils", 5, 3).with_reorganization(suggestion);
+
+    let output = JsonFormatter::new()
+        .with_suggestions(true)
+        .format(&[result])
+        .unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+    let file_result = &parsed["results"][0];
+
+    assert!(file_result.get("suggestions").is_none());
+    let bucket = &file_result["reorganization"]["buckets"][0];
+    assert_eq!(bucket["directory"], "user");
+    assert_eq!(bucket["kind"], "prefix");
+    assert_eq!(bucket["files"][1], "user_delete.rs");
+}
@@ -54,6 +54,49 @@ impl MarkdownFormatter {
             CheckResult::Grandfathered { .. } => "Grandfathered",
         }
     }
+
+    fn write_reorganizations(&self, results: &[&CheckResult], output: &mut String) {
+        let suggestions: Vec<_> = results
+            .iter()
+            .filter_map(|r| r.reorganization().map(|s| (r.path(), s)))
+            .filter(|(_, s)| s.has_suggestions())
+            .collect();
+        if suggestions.is_empty() {
+            return;
+        }
+
+        writeln!(output).ok();
+        writeln!(output, "### Reorganization Suggestions\n").ok();
+        for (path, suggestion) in suggestions {
+            writeln!(output, "#### `{}`\n", self.display_path(path)).ok();
+            writeln!(output, "| Suggested Directory | Grouped By | Files |").ok();
+            writeln!(output, "|---------------------|------------|-------|").ok();
+            for bucket in &suggestion.buckets {
+                writeln!(
+                    output,
+                    "| `{}/` | `{}` | {} |",
+                    bucket.directory,
+                    bucket.pattern,
+                    bucket.files.join(", ")
+                )
+                .ok();
+            }
+            writeln!(
+                output,
+                "\nLeaves {} files (limit: {}).\n",
+                suggestion.remaining_files(),
+                suggestion.limit
+            )
+            .ok();
+            if suggestion.dir_limit_reached {
+                writeln!(
+                    output,
+                    "Stopped early: more subdirectories would exceed `max_dirs`.\n"
+                )
+                .ok();
+            }
+        }
+    }
 }
 
 impl Default for MarkdownFormatter {
@@ -113,12 +156,19 @@ impl OutputFormatter for MarkdownFormatter {
                 // Use raw_stats for display (before skip_comments/skip_blank adjustments)
                 let raw = result.raw_stats();
                 let total = raw.total;
-                let sloc = result.stats().sloc();
+                let sloc = result.configured_metric().map_or_else(
+                    || result.stats().sloc().to_string(),
+                    |m| format!("{} {}", result.stats().sloc(), m.unit()),
+                );
                 let limit = result.limit();
                 let code = raw.code;
                 let comment = raw.comment;
                 let blank = raw.blank;
-                let reason = result.override_reason().unwrap_or("-");
+                let reason = match (result.category_message(), result.override_reason()) {
+                    (Some(ratio), Some(r)) => format!("{ratio}; {r}"),
+                    (Some(ratio), None) => ratio,
+                    (None, r) => r.unwrap_or("-").to_string(),
+                };
 
                 writeln!(
                     output,
@@ -164,6 +214,8 @@ impl OutputFormatter for MarkdownFormatter {
                         }
                     }
                 }
+
+                self.write_reorganizations(&non_passed, &mut output);
             }
         }
 
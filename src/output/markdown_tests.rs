@@ -293,3 +293,31 @@ fn no_grandfathered_row_when_count_is_zero() {
 
     assert!(!output.contains("Grandfathered"));
 }
+
+#[test]
+fn with_suggestions_shows_reorganization_section() {
+    use crate::analyzer::{BucketKind, ReorganizeBucket, ReorganizeSuggestion};
+
+    let suggestion =
+        ReorganizeSuggestion::new(PathBuf::from("src/utils"), 5, 3).with_buckets(vec![
+            ReorganizeBucket {
+                directory: "user".to_string(),
+                kind: BucketKind::Prefix,
+                pattern: "user*".to_string(),
+                files: vec!["user_create.rs".to_string(), "user_delete.rs".to_string()],
+            },
+        ]);
+    let result = make_failed_result("src/utils", 5, 3).with_reorganization(suggestion);
+
+    let output = MarkdownFormatter::new()
+        .with_suggestions(true)
+        .format(std::slice::from_ref(&result))
+        .unwrap();
+
+    assert!(output.contains("### Reorganization Suggestions"));
+    assert!(output.contains("| `user/` | `user*` | user_create.rs, user_delete.rs |"));
+    assert!(output.contains("Leaves 3 files (limit: 3)."));
+
+    let hidden = MarkdownFormatter::new().format(&[result]).unwrap();
+    assert!(!hidden.contains("Reorganization Suggestions"));
+}
@@ -1,12 +1,14 @@
 mod ansi;
 mod error_output;
 mod html;
+mod html_suggestions;
 mod html_template;
 mod json;
 mod markdown;
 pub mod path;
 mod progress;
 mod sarif;
+mod sarif_rules;
 mod stats;
 pub mod svg;
 mod text;
@@ -3,11 +3,18 @@ use std::path::{Path, PathBuf};
 use serde::Serialize;
 
 use crate::analyzer::SplitSuggestion;
-use crate::checker::{CheckResult, ViolationCategory, ViolationType};
+use crate::checker::{
+    CheckResult, LayoutProblem, SymlinkProblem, ViolationCategory, ViolationType,
+};
+use crate::config::{ContentMetric, format_byte_size};
 use crate::error::Result;
 
 use super::OutputFormatter;
 use super::path::display_path;
+use super::sarif_rules::{
+    RULE_COMMENT_RATIO, RULE_EXPIRED_EXEMPTION, RULE_LINE_LIMIT_EXCEEDED, RULE_LINE_LIMIT_WARNING,
+    RULES, rule_index, structure_rule_id,
+};
 
 /// SARIF 2.1.0 output formatter for GitHub Code Scanning and other CI/CD tools.
 pub struct SarifFormatter {
@@ -53,20 +60,6 @@ const TOOL_NAME: &str = "sloc-guard";
 const TOOL_VERSION: &str = env!("CARGO_PKG_VERSION");
 const TOOL_INFO_URI: &str = crate::REPO_URL;
 
-// Content (SLOC) rule IDs
-const RULE_LINE_LIMIT_EXCEEDED: &str = "sloc-guard/line-limit-exceeded";
-const RULE_LINE_LIMIT_WARNING: &str = "sloc-guard/line-limit-warning";
-
-// Structure rule IDs
-const RULE_STRUCTURE_FILE_COUNT: &str = "sloc-guard/structure-file-count";
-const RULE_STRUCTURE_DIR_COUNT: &str = "sloc-guard/structure-dir-count";
-const RULE_STRUCTURE_MAX_DEPTH: &str = "sloc-guard/structure-max-depth";
-const RULE_STRUCTURE_DISALLOWED_FILE: &str = "sloc-guard/structure-disallowed-file";
-const RULE_STRUCTURE_DISALLOWED_DIR: &str = "sloc-guard/structure-disallowed-dir";
-const RULE_STRUCTURE_DENIED: &str = "sloc-guard/structure-denied";
-const RULE_STRUCTURE_NAMING: &str = "sloc-guard/structure-naming";
-const RULE_STRUCTURE_SIBLING: &str = "sloc-guard/structure-sibling";
-
 #[derive(Serialize)]
 struct SarifLog {
     #[serde(rename = "$schema")]
@@ -146,6 +139,14 @@ struct Location {
 struct PhysicalLocation {
     #[serde(rename = "artifactLocation")]
     artifact_location: ArtifactLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<Region>,
+}
+
+#[derive(Serialize)]
+struct Region {
+    #[serde(rename = "startLine")]
+    start_line: usize,
 }
 
 #[derive(Serialize)]
@@ -184,162 +185,45 @@ struct StatsProperties {
 
 impl SarifFormatter {
     fn build_rules() -> Vec<ReportingDescriptor> {
-        vec![
-            // Content (SLOC) rules - indices 0-1
-            ReportingDescriptor {
-                id: RULE_LINE_LIMIT_EXCEEDED,
-                name: "LineLimitExceeded",
-                short_description: MultiformatMessageString {
-                    text: "File exceeds SLOC limit",
-                },
-                full_description: MultiformatMessageString {
-                    text: "The source lines of code (SLOC) in this file exceeds the configured maximum limit.",
-                },
-                default_configuration: ReportingConfiguration { level: "error" },
-            },
-            ReportingDescriptor {
-                id: RULE_LINE_LIMIT_WARNING,
-                name: "LineLimitWarning",
-                short_description: MultiformatMessageString {
-                    text: "File approaching SLOC limit",
-                },
-                full_description: MultiformatMessageString {
-                    text: "The source lines of code (SLOC) in this file is approaching the configured maximum limit.",
-                },
-                default_configuration: ReportingConfiguration { level: "warning" },
-            },
-            // Structure rules - indices 2-9
-            ReportingDescriptor {
-                id: RULE_STRUCTURE_FILE_COUNT,
-                name: "StructureFileCount",
-                short_description: MultiformatMessageString {
-                    text: "Directory exceeds file count limit",
-                },
-                full_description: MultiformatMessageString {
-                    text: "The number of files in this directory exceeds the configured maximum limit.",
-                },
-                default_configuration: ReportingConfiguration { level: "error" },
-            },
-            ReportingDescriptor {
-                id: RULE_STRUCTURE_DIR_COUNT,
-                name: "StructureDirCount",
-                short_description: MultiformatMessageString {
-                    text: "Directory exceeds subdirectory count limit",
-                },
-                full_description: MultiformatMessageString {
-                    text: "The number of subdirectories in this directory exceeds the configured maximum limit.",
-                },
-                default_configuration: ReportingConfiguration { level: "error" },
-            },
-            ReportingDescriptor {
-                id: RULE_STRUCTURE_MAX_DEPTH,
-                name: "StructureMaxDepth",
-                short_description: MultiformatMessageString {
-                    text: "Directory exceeds maximum depth",
-                },
-                full_description: MultiformatMessageString {
-                    text: "The directory nesting depth exceeds the configured maximum limit.",
-                },
-                default_configuration: ReportingConfiguration { level: "error" },
-            },
-            ReportingDescriptor {
-                id: RULE_STRUCTURE_DISALLOWED_FILE,
-                name: "StructureDisallowedFile",
-                short_description: MultiformatMessageString {
-                    text: "File type not allowed",
-                },
-                full_description: MultiformatMessageString {
-                    text: "This file type is not in the allowlist for this directory.",
-                },
-                default_configuration: ReportingConfiguration { level: "error" },
-            },
-            ReportingDescriptor {
-                id: RULE_STRUCTURE_DISALLOWED_DIR,
-                name: "StructureDisallowedDir",
-                short_description: MultiformatMessageString {
-                    text: "Directory not allowed",
-                },
-                full_description: MultiformatMessageString {
-                    text: "This directory is not in the allowlist.",
-                },
-                default_configuration: ReportingConfiguration { level: "error" },
-            },
-            ReportingDescriptor {
-                id: RULE_STRUCTURE_DENIED,
-                name: "StructureDenied",
-                short_description: MultiformatMessageString {
-                    text: "File or directory denied",
-                },
-                full_description: MultiformatMessageString {
-                    text: "This file or directory matches a deny pattern and is not allowed.",
-                },
-                default_configuration: ReportingConfiguration { level: "error" },
-            },
-            ReportingDescriptor {
-                id: RULE_STRUCTURE_NAMING,
-                name: "StructureNaming",
-                short_description: MultiformatMessageString {
-                    text: "File naming convention violated",
-                },
-                full_description: MultiformatMessageString {
-                    text: "The file name does not match the required naming pattern for this directory.",
-                },
-                default_configuration: ReportingConfiguration { level: "error" },
-            },
-            ReportingDescriptor {
-                id: RULE_STRUCTURE_SIBLING,
-                name: "StructureSibling",
+        RULES
+            .iter()
+            .map(|rule| ReportingDescriptor {
+                id: rule.id,
+                name: rule.name,
                 short_description: MultiformatMessageString {
-                    text: "Required sibling file missing",
+                    text: rule.short_description,
                 },
                 full_description: MultiformatMessageString {
-                    text: "A required sibling file is missing for this file.",
+                    text: rule.full_description,
                 },
-                default_configuration: ReportingConfiguration { level: "error" },
-            },
-        ]
+                default_configuration: ReportingConfiguration { level: rule.level },
+            })
+            .collect()
     }
 
     /// Get rule ID and index based on violation category and result type.
     fn get_rule_info(result: &CheckResult) -> (&'static str, usize, &'static str) {
-        let is_warning = result.is_warning();
-        let is_grandfathered = result.is_grandfathered();
-
-        match result.violation_category() {
+        let rule_id = match result.violation_category() {
             Some(ViolationCategory::Structure { violation_type, .. }) => {
-                let (rule_id, rule_index) = match violation_type {
-                    ViolationType::FileCount => (RULE_STRUCTURE_FILE_COUNT, 2),
-                    ViolationType::DirCount => (RULE_STRUCTURE_DIR_COUNT, 3),
-                    ViolationType::MaxDepth => (RULE_STRUCTURE_MAX_DEPTH, 4),
-                    ViolationType::DisallowedFile => (RULE_STRUCTURE_DISALLOWED_FILE, 5),
-                    ViolationType::DisallowedDirectory => (RULE_STRUCTURE_DISALLOWED_DIR, 6),
-                    ViolationType::DeniedFile { .. } | ViolationType::DeniedDirectory { .. } => {
-                        (RULE_STRUCTURE_DENIED, 7)
-                    }
-                    ViolationType::NamingConvention { .. } => (RULE_STRUCTURE_NAMING, 8),
-                    ViolationType::MissingSibling { .. }
-                    | ViolationType::GroupIncomplete { .. } => (RULE_STRUCTURE_SIBLING, 9),
-                };
-                let level = if is_grandfathered {
-                    "note"
-                } else if is_warning {
-                    "warning"
-                } else {
-                    "error"
-                };
-                (rule_id, rule_index, level)
-            }
-            Some(ViolationCategory::Content) | None => {
-                // Content (SLOC) violation
-                if is_grandfathered {
-                    (RULE_LINE_LIMIT_EXCEEDED, 0, "note")
-                } else if is_warning {
-                    (RULE_LINE_LIMIT_WARNING, 1, "warning")
-                } else {
-                    (RULE_LINE_LIMIT_EXCEEDED, 0, "error")
-                }
+                structure_rule_id(violation_type)
             }
-        }
+            Some(ViolationCategory::CommentRatio { .. }) => RULE_COMMENT_RATIO,
+            Some(ViolationCategory::ExpiredExemption { .. }) => RULE_EXPIRED_EXEMPTION,
+            // Content (SLOC) violation: warnings have their own rule
+            Some(ViolationCategory::Content { .. }) | None if result.is_warning() => {
+                RULE_LINE_LIMIT_WARNING
+            }
+            Some(ViolationCategory::Content { .. }) | None => RULE_LINE_LIMIT_EXCEEDED,
+        };
+
+        let level = if result.is_grandfathered() {
+            "note"
+        } else if result.is_warning() {
+            "warning"
+        } else {
+            "error"
+        };
+        (rule_id, rule_index(rule_id), level)
     }
 
     /// Generate message text based on violation category.
@@ -348,24 +232,43 @@ impl SarifFormatter {
             Some(ViolationCategory::Structure { violation_type, .. }) => {
                 Self::format_structure_message(result, violation_type)
             }
-            Some(ViolationCategory::Content) | None => Self::format_content_message(result),
+            Some(ViolationCategory::CommentRatio {
+                bound,
+                ratio,
+                threshold,
+            }) => {
+                let suffix = if result.is_grandfathered() {
+                    " (grandfathered)"
+                } else {
+                    ""
+                };
+                format!("File {}{suffix}", bound.describe(*ratio, *threshold))
+            }
+            Some(ViolationCategory::ExpiredExemption { .. }) => {
+                let description = result.category_message().unwrap_or_default();
+                format!("Exemption {description}")
+            }
+            Some(ViolationCategory::Content { .. }) | None => Self::format_content_message(result),
         }
     }
 
     fn format_content_message(result: &CheckResult) -> String {
         let sloc = result.stats().sloc();
         let limit = result.limit();
+        let unit = result
+            .configured_metric()
+            .map_or("SLOC", ContentMetric::unit);
 
         if result.is_grandfathered() {
-            format!("File has {sloc} SLOC, exceeding limit of {limit} (grandfathered)")
+            format!("File has {sloc} {unit}, exceeding limit of {limit} (grandfathered)")
         } else if result.is_warning() {
             format!(
-                "File has {sloc} SLOC ({:.1}% of {limit} limit)",
+                "File has {sloc} {unit} ({:.1}% of {limit} limit)",
                 result.usage_percent()
             )
         } else {
             format!(
-                "File has {sloc} SLOC, exceeding limit of {limit} by {} lines",
+                "File has {sloc} {unit}, exceeding limit of {limit} by {} lines",
                 sloc.saturating_sub(limit)
             )
         }
@@ -396,6 +299,43 @@ impl SarifFormatter {
                     "Directory depth is {actual}, exceeding limit of {limit}{grandfathered_suffix}"
                 )
             }
+            ViolationType::TotalFileCount => {
+                format!(
+                    "Directory subtree has {actual} files, exceeding total limit of {limit}{grandfathered_suffix}"
+                )
+            }
+            ViolationType::FileCountByExtension { extension } => {
+                format!(
+                    "Directory has {actual} '{extension}' files, exceeding limit of {limit}{grandfathered_suffix}"
+                )
+            }
+            ViolationType::TotalDirCount => {
+                format!(
+                    "Directory subtree has {actual} subdirectories, exceeding total limit of {limit}{grandfathered_suffix}"
+                )
+            }
+            ViolationType::PathLength => {
+                format!(
+                    "Path is {actual} characters long, exceeding limit of {limit}{grandfathered_suffix}"
+                )
+            }
+            ViolationType::FilenameLength => {
+                format!(
+                    "Name is {actual} characters long, exceeding limit of {limit}{grandfathered_suffix}"
+                )
+            }
+            ViolationType::PathComponents => {
+                format!(
+                    "Path has {actual} components, exceeding limit of {limit}{grandfathered_suffix}"
+                )
+            }
+            ViolationType::FileSize => {
+                let (size, max) = (
+                    format_byte_size(actual as u64),
+                    format_byte_size(limit as u64),
+                );
+                format!("File is {size}, exceeding size limit of {max}{grandfathered_suffix}")
+            }
             ViolationType::DisallowedFile => {
                 format!("File type not allowed in this directory{grandfathered_suffix}")
             }
@@ -410,9 +350,13 @@ impl SarifFormatter {
             ViolationType::DeniedDirectory { pattern } => {
                 format!("Directory matches deny pattern '{pattern}'{grandfathered_suffix}")
             }
-            ViolationType::NamingConvention { expected_pattern } => {
+            ViolationType::NamingConvention {
+                expected_pattern,
+                is_directory,
+            } => {
+                let kind = if *is_directory { "Directory" } else { "File" };
                 format!(
-                    "File name does not match required pattern '{expected_pattern}'{grandfathered_suffix}"
+                    "{kind} name does not match required pattern '{expected_pattern}'{grandfathered_suffix}"
                 )
             }
             ViolationType::MissingSibling {
@@ -422,12 +366,64 @@ impl SarifFormatter {
                     "Missing required sibling file matching '{expected_sibling_pattern}'{grandfathered_suffix}"
                 )
             }
+            ViolationType::MissingRequiredFile { required_pattern } => {
+                format!(
+                    "Directory is missing required file matching '{required_pattern}'{grandfathered_suffix}"
+                )
+            }
             ViolationType::GroupIncomplete {
                 missing_patterns, ..
             } => {
                 let missing = missing_patterns.join(", ");
                 format!("Incomplete file group, missing: {missing}{grandfathered_suffix}")
             }
+            ViolationType::ForbiddenSibling {
+                forbidden_sibling_pattern,
+                conflicting_file,
+            } => {
+                format!(
+                    "Forbidden sibling file '{conflicting_file}' exists (matches '{forbidden_sibling_pattern}'){grandfathered_suffix}"
+                )
+            }
+            ViolationType::ExclusiveConflict {
+                conflicting_files, ..
+            } => {
+                let conflicting = conflicting_files.join(", ");
+                format!("Mutually exclusive files present: {conflicting}{grandfathered_suffix}")
+            }
+            ViolationType::CaseCollision { colliding_paths } => {
+                let colliding = colliding_paths.join(", ");
+                format!("Names differ only by case: {colliding}{grandfathered_suffix}")
+            }
+            ViolationType::InvalidWindowsName { reason } => {
+                format!("Name is invalid on Windows: {reason}{grandfathered_suffix}")
+            }
+            ViolationType::EmptyDirectory if actual == 0 => {
+                format!("Directory is empty{grandfathered_suffix}")
+            }
+            ViolationType::EmptyDirectory => {
+                format!("Directory only has {actual} count_exclude entries{grandfathered_suffix}")
+            }
+            ViolationType::Symlink { target, problem } => {
+                let issue = match problem {
+                    SymlinkProblem::Denied => "is not allowed",
+                    SymlinkProblem::OutsideProject => "resolves outside the project root",
+                    SymlinkProblem::Loop => "loops back to an ancestor",
+                };
+                format!("Symlink to '{target}' {issue}{grandfathered_suffix}")
+            }
+            ViolationType::Layout {
+                layout,
+                entry,
+                problem: LayoutProblem::Missing,
+            } => {
+                format!(
+                    "Directory is missing '{entry}' required by layout '{layout}'{grandfathered_suffix}"
+                )
+            }
+            ViolationType::Layout { layout, entry, .. } => {
+                format!("Entry '{entry}' is not part of layout '{layout}'{grandfathered_suffix}")
+            }
         }
     }
 
@@ -451,6 +447,14 @@ impl SarifFormatter {
         // Convert path to URI format (already uses forward slashes from display_path)
         let uri = self.display_path(result.path());
 
+        // Expired exemptions point at the rule inside the config file
+        let region = match result.violation_category() {
+            Some(ViolationCategory::ExpiredExemption {
+                line: Some(line), ..
+            }) => Some(Region { start_line: *line }),
+            _ => None,
+        };
+
         let suggestions = if self.show_suggestions {
             result.suggestions().cloned()
         } else {
@@ -468,6 +472,7 @@ impl SarifFormatter {
                         uri,
                         uri_base_id: "%SRCROOT%",
                     },
+                    region,
                 },
             }],
             suppressions,
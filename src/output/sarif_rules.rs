@@ -0,0 +1,265 @@
+//! SARIF rule metadata published in `tool.driver.rules`.
+//!
+//! A rule's position in [`RULES`] is its `ruleIndex`; append new rules at the end
+//! so indices stay stable for consumers that cache them.
+
+use crate::checker::ViolationType;
+
+// Content (SLOC) rule IDs
+pub(super) const RULE_LINE_LIMIT_EXCEEDED: &str = "sloc-guard/line-limit-exceeded";
+pub(super) const RULE_LINE_LIMIT_WARNING: &str = "sloc-guard/line-limit-warning";
+
+// Structure rule IDs
+pub(super) const RULE_STRUCTURE_FILE_COUNT: &str = "sloc-guard/structure-file-count";
+pub(super) const RULE_STRUCTURE_DIR_COUNT: &str = "sloc-guard/structure-dir-count";
+pub(super) const RULE_STRUCTURE_MAX_DEPTH: &str = "sloc-guard/structure-max-depth";
+pub(super) const RULE_STRUCTURE_DISALLOWED_FILE: &str = "sloc-guard/structure-disallowed-file";
+pub(super) const RULE_STRUCTURE_DISALLOWED_DIR: &str = "sloc-guard/structure-disallowed-dir";
+pub(super) const RULE_STRUCTURE_DENIED: &str = "sloc-guard/structure-denied";
+pub(super) const RULE_STRUCTURE_NAMING: &str = "sloc-guard/structure-naming";
+pub(super) const RULE_STRUCTURE_SIBLING: &str = "sloc-guard/structure-sibling";
+pub(super) const RULE_STRUCTURE_TOTAL_FILE_COUNT: &str = "sloc-guard/structure-total-file-count";
+pub(super) const RULE_STRUCTURE_TOTAL_DIR_COUNT: &str = "sloc-guard/structure-total-dir-count";
+pub(super) const RULE_STRUCTURE_REQUIRED_FILE: &str = "sloc-guard/structure-required-file";
+pub(super) const RULE_STRUCTURE_SIBLING_CONFLICT: &str = "sloc-guard/structure-sibling-conflict";
+pub(super) const RULE_STRUCTURE_PATH_LENGTH: &str = "sloc-guard/structure-path-length";
+pub(super) const RULE_STRUCTURE_CASE_COLLISION: &str = "sloc-guard/structure-case-collision";
+pub(super) const RULE_STRUCTURE_INVALID_NAME: &str = "sloc-guard/structure-invalid-name";
+pub(super) const RULE_STRUCTURE_EMPTY_DIR: &str = "sloc-guard/structure-empty-dir";
+pub(super) const RULE_STRUCTURE_FILE_COUNT_BY_EXT: &str = "sloc-guard/structure-file-count-by-ext";
+pub(super) const RULE_STRUCTURE_FILE_SIZE: &str = "sloc-guard/structure-file-size";
+pub(super) const RULE_STRUCTURE_SYMLINK: &str = "sloc-guard/structure-symlink";
+pub(super) const RULE_STRUCTURE_LAYOUT: &str = "sloc-guard/structure-layout";
+
+// Content quality rule IDs
+pub(super) const RULE_COMMENT_RATIO: &str = "sloc-guard/comment-ratio";
+
+// Configuration rule IDs
+pub(super) const RULE_EXPIRED_EXEMPTION: &str = "sloc-guard/expired-exemption";
+
+pub(super) struct RuleSpec {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub short_description: &'static str,
+    pub full_description: &'static str,
+    pub level: &'static str,
+}
+
+pub(super) const RULES: &[RuleSpec] = &[
+    // Content (SLOC) rules
+    RuleSpec {
+        id: RULE_LINE_LIMIT_EXCEEDED,
+        name: "LineLimitExceeded",
+        short_description: "File exceeds SLOC limit",
+        full_description: "The source lines of code (SLOC) in this file exceeds the configured maximum limit.",
+        level: "error",
+    },
+    RuleSpec {
+        id: RULE_LINE_LIMIT_WARNING,
+        name: "LineLimitWarning",
+        short_description: "File approaching SLOC limit",
+        full_description: "The source lines of code (SLOC) in this file is approaching the configured maximum limit.",
+        level: "warning",
+    },
+    // Structure rules
+    RuleSpec {
+        id: RULE_STRUCTURE_FILE_COUNT,
+        name: "StructureFileCount",
+        short_description: "Directory exceeds file count limit",
+        full_description: "The number of files in this directory exceeds the configured maximum limit.",
+        level: "error",
+    },
+    RuleSpec {
+        id: RULE_STRUCTURE_DIR_COUNT,
+        name: "StructureDirCount",
+        short_description: "Directory exceeds subdirectory count limit",
+        full_description: "The number of subdirectories in this directory exceeds the configured maximum limit.",
+        level: "error",
+    },
+    RuleSpec {
+        id: RULE_STRUCTURE_MAX_DEPTH,
+        name: "StructureMaxDepth",
+        short_description: "Directory exceeds maximum depth",
+        full_description: "The directory nesting depth exceeds the configured maximum limit.",
+        level: "error",
+    },
+    RuleSpec {
+        id: RULE_STRUCTURE_DISALLOWED_FILE,
+        name: "StructureDisallowedFile",
+        short_description: "File type not allowed",
+        full_description: "This file type is not in the allowlist for this directory.",
+        level: "error",
+    },
+    RuleSpec {
+        id: RULE_STRUCTURE_DISALLOWED_DIR,
+        name: "StructureDisallowedDir",
+        short_description: "Directory not allowed",
+        full_description: "This directory is not in the allowlist.",
+        level: "error",
+    },
+    RuleSpec {
+        id: RULE_STRUCTURE_DENIED,
+        name: "StructureDenied",
+        short_description: "File or directory denied",
+        full_description: "This file or directory matches a deny pattern and is not allowed.",
+        level: "error",
+    },
+    RuleSpec {
+        id: RULE_STRUCTURE_NAMING,
+        name: "StructureNaming",
+        short_description: "File naming convention violated",
+        full_description: "The file name does not match the required naming pattern for this directory.",
+        level: "error",
+    },
+    RuleSpec {
+        id: RULE_STRUCTURE_SIBLING,
+        name: "StructureSibling",
+        short_description: "Required sibling file missing",
+        full_description: "A required sibling file is missing for this file.",
+        level: "error",
+    },
+    // Content quality rules
+    RuleSpec {
+        id: RULE_COMMENT_RATIO,
+        name: "CommentRatio",
+        short_description: "Comment density out of range",
+        full_description: "The share of comment lines in this file is below min_comment_ratio or above max_comment_ratio.",
+        level: "error",
+    },
+    // Configuration rules
+    RuleSpec {
+        id: RULE_EXPIRED_EXEMPTION,
+        name: "ExpiredExemption",
+        short_description: "Rule exemption has expired",
+        full_description: "A rule with on_expire = \"fail\" is past its expires date. Renew or remove the exemption.",
+        level: "error",
+    },
+    // Recursive structure rules
+    RuleSpec {
+        id: RULE_STRUCTURE_TOTAL_FILE_COUNT,
+        name: "StructureTotalFileCount",
+        short_description: "Directory subtree exceeds total file count limit",
+        full_description: "The number of files anywhere beneath this directory exceeds the configured max_total_files limit.",
+        level: "error",
+    },
+    RuleSpec {
+        id: RULE_STRUCTURE_TOTAL_DIR_COUNT,
+        name: "StructureTotalDirCount",
+        short_description: "Directory subtree exceeds total directory count limit",
+        full_description: "The number of directories anywhere beneath this directory exceeds the configured max_total_dirs limit.",
+        level: "error",
+    },
+    RuleSpec {
+        id: RULE_STRUCTURE_REQUIRED_FILE,
+        name: "StructureRequiredFile",
+        short_description: "Directory is missing a required file",
+        full_description: "A directory matching a rule's scope does not contain a file matching one of its require_files patterns.",
+        level: "error",
+    },
+    RuleSpec {
+        id: RULE_STRUCTURE_SIBLING_CONFLICT,
+        name: "StructureSiblingConflict",
+        short_description: "Conflicting sibling files present",
+        full_description: "A file has a sibling forbidden by a 'forbid' rule, or more than one of a set of mutually 'exclusive' files exists in the directory.",
+        level: "error",
+    },
+    RuleSpec {
+        id: RULE_STRUCTURE_PATH_LENGTH,
+        name: "StructurePathLength",
+        short_description: "Path or name too long",
+        full_description: "A path relative to the project root exceeds max_path_length or max_path_components, or a file or directory name exceeds max_filename_length.",
+        level: "error",
+    },
+    RuleSpec {
+        id: RULE_STRUCTURE_CASE_COLLISION,
+        name: "StructureCaseCollision",
+        short_description: "Names differ only by case",
+        full_description: "Two or more entries in the same directory have names that differ only by letter case and collide on case-insensitive file systems.",
+        level: "error",
+    },
+    RuleSpec {
+        id: RULE_STRUCTURE_INVALID_NAME,
+        name: "StructureInvalidName",
+        short_description: "Name is invalid on Windows",
+        full_description: "A file or directory name uses a reserved device name, a character Windows forbids, or ends with a dot or space.",
+        level: "error",
+    },
+    RuleSpec {
+        id: RULE_STRUCTURE_EMPTY_DIR,
+        name: "StructureEmptyDir",
+        short_description: "Directory is empty",
+        full_description: "A directory has no entries, or (with deny_empty_dirs = \"ignore_count_exclude\") only entries matching count_exclude such as .gitkeep.",
+        level: "error",
+    },
+    RuleSpec {
+        id: RULE_STRUCTURE_FILE_COUNT_BY_EXT,
+        name: "StructureFileCountByExt",
+        short_description: "Too many files with one extension in directory",
+        full_description: "The number of files with a given extension in a directory exceeds its max_files_by_ext limit.",
+        level: "error",
+    },
+    RuleSpec {
+        id: RULE_STRUCTURE_FILE_SIZE,
+        name: "StructureFileSize",
+        short_description: "File exceeds size limit",
+        full_description: "A file of any type is larger than max_file_bytes and does not match allow_large_files.",
+        level: "error",
+    },
+    RuleSpec {
+        id: RULE_STRUCTURE_SYMLINK,
+        name: "StructureSymlink",
+        short_description: "Symlink not allowed",
+        full_description: "A symlink is present although deny_symlinks = true, resolves outside the project root (deny_symlinks = \"external\"), or loops back to an ancestor while following symlinks.",
+        level: "error",
+    },
+    RuleSpec {
+        id: RULE_STRUCTURE_LAYOUT,
+        name: "StructureLayout",
+        short_description: "Directory does not match its layout template",
+        full_description: "A directory matching a [[structure.layouts]] scope lacks one of the template's required entries, or (with forbid_other) contains an entry listed as neither required nor optional.",
+        level: "error",
+    },
+];
+
+/// Position of `id` in [`RULES`], used as the SARIF `ruleIndex`.
+pub(super) fn rule_index(id: &str) -> usize {
+    // INVARIANT: every rule ID constant above has a matching RULES entry
+    RULES
+        .iter()
+        .position(|rule| rule.id == id)
+        .expect("SARIF rule ID must be registered in RULES")
+}
+
+/// SARIF rule ID reported for a structure violation.
+pub(super) const fn structure_rule_id(violation_type: &ViolationType) -> &'static str {
+    match violation_type {
+        ViolationType::FileCount => RULE_STRUCTURE_FILE_COUNT,
+        ViolationType::DirCount => RULE_STRUCTURE_DIR_COUNT,
+        ViolationType::MaxDepth => RULE_STRUCTURE_MAX_DEPTH,
+        ViolationType::TotalFileCount => RULE_STRUCTURE_TOTAL_FILE_COUNT,
+        ViolationType::FileCountByExtension { .. } => RULE_STRUCTURE_FILE_COUNT_BY_EXT,
+        ViolationType::TotalDirCount => RULE_STRUCTURE_TOTAL_DIR_COUNT,
+        ViolationType::PathLength
+        | ViolationType::FilenameLength
+        | ViolationType::PathComponents => RULE_STRUCTURE_PATH_LENGTH,
+        ViolationType::FileSize => RULE_STRUCTURE_FILE_SIZE,
+        ViolationType::MissingRequiredFile { .. } => RULE_STRUCTURE_REQUIRED_FILE,
+        ViolationType::DisallowedFile => RULE_STRUCTURE_DISALLOWED_FILE,
+        ViolationType::DisallowedDirectory => RULE_STRUCTURE_DISALLOWED_DIR,
+        ViolationType::DeniedFile { .. } | ViolationType::DeniedDirectory { .. } => {
+            RULE_STRUCTURE_DENIED
+        }
+        ViolationType::NamingConvention { .. } => RULE_STRUCTURE_NAMING,
+        ViolationType::MissingSibling { .. } | ViolationType::GroupIncomplete { .. } => {
+            RULE_STRUCTURE_SIBLING
+        }
+        ViolationType::ForbiddenSibling { .. } | ViolationType::ExclusiveConflict { .. } => {
+            RULE_STRUCTURE_SIBLING_CONFLICT
+        }
+        ViolationType::CaseCollision { .. } => RULE_STRUCTURE_CASE_COLLISION,
+        ViolationType::InvalidWindowsName { .. } => RULE_STRUCTURE_INVALID_NAME,
+        ViolationType::EmptyDirectory => RULE_STRUCTURE_EMPTY_DIR,
+        ViolationType::Symlink { .. } => RULE_STRUCTURE_SYMLINK,
+        ViolationType::Layout { .. } => RULE_STRUCTURE_LAYOUT,
+    }
+}
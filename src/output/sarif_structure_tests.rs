@@ -0,0 +1,532 @@
+//! SARIF output for structure violations.
+
+use std::path::PathBuf;
+
+use super::*;
+use crate::checker::{ViolationCategory, ViolationType};
+use crate::counter::LineStats;
+
+fn make_structure_failed(
+    path: &str,
+    violation_type: ViolationType,
+    actual: usize,
+    limit: usize,
+) -> CheckResult {
+    CheckResult::Failed {
+        path: PathBuf::from(path),
+        stats: LineStats {
+            total: 0,
+            code: actual,
+            comment: 0,
+            blank: 0,
+            ignored: 0,
+        },
+        raw_stats: None,
+        limit,
+        override_reason: None,
+        suggestions: None,
+        violation_category: Some(ViolationCategory::Structure {
+            violation_type,
+            triggering_rule: None,
+        }),
+    }
+}
+
+fn make_structure_warning(
+    path: &str,
+    violation_type: ViolationType,
+    actual: usize,
+    limit: usize,
+) -> CheckResult {
+    CheckResult::Warning {
+        path: PathBuf::from(path),
+        stats: LineStats {
+            total: 0,
+            code: actual,
+            comment: 0,
+            blank: 0,
+            ignored: 0,
+        },
+        raw_stats: None,
+        limit,
+        override_reason: None,
+        suggestions: None,
+        violation_category: Some(ViolationCategory::Structure {
+            violation_type,
+            triggering_rule: None,
+        }),
+    }
+}
+
+fn make_structure_grandfathered(
+    path: &str,
+    violation_type: ViolationType,
+    actual: usize,
+    limit: usize,
+) -> CheckResult {
+    CheckResult::Grandfathered {
+        path: PathBuf::from(path),
+        stats: LineStats {
+            total: 0,
+            code: actual,
+            comment: 0,
+            blank: 0,
+            ignored: 0,
+        },
+        raw_stats: None,
+        limit,
+        override_reason: None,
+        violation_category: Some(ViolationCategory::Structure {
+            violation_type,
+            triggering_rule: None,
+        }),
+    }
+}
+
+#[test]
+fn sarif_rules_include_structure_rules() {
+    let formatter = SarifFormatter::new();
+    let results: Vec<CheckResult> = vec![];
+
+    let output = formatter.format(&results).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+    let rules = parsed["runs"][0]["tool"]["driver"]["rules"]
+        .as_array()
+        .unwrap();
+    // 2 content rules + 20 structure rules + comment ratio + expired exemption = 24 total
+    assert_eq!(rules.len(), 24);
+
+    // Verify structure rule IDs exist
+    let rule_ids: Vec<&str> = rules.iter().map(|r| r["id"].as_str().unwrap()).collect();
+    assert!(rule_ids.contains(&"sloc-guard/structure-file-count"));
+    assert!(rule_ids.contains(&"sloc-guard/structure-dir-count"));
+    assert!(rule_ids.contains(&"sloc-guard/structure-max-depth"));
+    assert!(rule_ids.contains(&"sloc-guard/structure-disallowed-file"));
+    assert!(rule_ids.contains(&"sloc-guard/structure-disallowed-dir"));
+    assert!(rule_ids.contains(&"sloc-guard/structure-denied"));
+    assert!(rule_ids.contains(&"sloc-guard/structure-naming"));
+    assert!(rule_ids.contains(&"sloc-guard/structure-sibling"));
+}
+
+#[test]
+fn sarif_file_count_violation() {
+    let formatter = SarifFormatter::new();
+    let results = vec![make_structure_failed(
+        "src/components",
+        ViolationType::FileCount,
+        25,
+        20,
+    )];
+
+    let output = formatter.format(&results).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+    let result = &parsed["runs"][0]["results"][0];
+    assert_eq!(result["ruleId"], "sloc-guard/structure-file-count");
+    assert_eq!(result["level"], "error");
+    assert_eq!(result["ruleIndex"], 2);
+
+    let message = result["message"]["text"].as_str().unwrap();
+    assert!(message.contains("25 files"));
+    assert!(message.contains("limit of 20"));
+}
+
+#[test]
+fn sarif_dir_count_violation() {
+    let formatter = SarifFormatter::new();
+    let results = vec![make_structure_failed(
+        "src/modules",
+        ViolationType::DirCount,
+        15,
+        10,
+    )];
+
+    let output = formatter.format(&results).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+    let result = &parsed["runs"][0]["results"][0];
+    assert_eq!(result["ruleId"], "sloc-guard/structure-dir-count");
+    assert_eq!(result["ruleIndex"], 3);
+
+    let message = result["message"]["text"].as_str().unwrap();
+    assert!(message.contains("15 subdirectories"));
+    assert!(message.contains("limit of 10"));
+}
+
+#[test]
+fn sarif_max_depth_violation() {
+    let formatter = SarifFormatter::new();
+    let results = vec![make_structure_failed(
+        "src/a/b/c/d/e/f",
+        ViolationType::MaxDepth,
+        6,
+        5,
+    )];
+
+    let output = formatter.format(&results).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+    let result = &parsed["runs"][0]["results"][0];
+    assert_eq!(result["ruleId"], "sloc-guard/structure-max-depth");
+    assert_eq!(result["ruleIndex"], 4);
+
+    let message = result["message"]["text"].as_str().unwrap();
+    assert!(message.contains("depth is 6"));
+    assert!(message.contains("limit of 5"));
+}
+
+#[test]
+fn sarif_file_size_violation_shows_human_readable_sizes() {
+    let formatter = SarifFormatter::new();
+    let results = vec![make_structure_failed(
+        "assets/video.mp4",
+        ViolationType::FileSize,
+        12 * 1024 * 1024,
+        5 * 1024 * 1024,
+    )];
+
+    let output = formatter.format(&results).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+    let result = &parsed["runs"][0]["results"][0];
+    assert_eq!(result["ruleId"], "sloc-guard/structure-file-size");
+
+    let message = result["message"]["text"].as_str().unwrap();
+    assert_eq!(message, "File is 12 MB, exceeding size limit of 5 MB");
+}
+
+#[test]
+fn sarif_symlink_violation_names_target() {
+    let formatter = SarifFormatter::new();
+    let results = vec![make_structure_failed(
+        "vendor/shared",
+        ViolationType::Symlink {
+            target: "../../shared".to_string(),
+            problem: SymlinkProblem::OutsideProject,
+        },
+        1,
+        0,
+    )];
+
+    let output = formatter.format(&results).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+    let result = &parsed["runs"][0]["results"][0];
+    assert_eq!(result["ruleId"], "sloc-guard/structure-symlink");
+
+    let message = result["message"]["text"].as_str().unwrap();
+    assert_eq!(
+        message,
+        "Symlink to '../../shared' resolves outside the project root"
+    );
+}
+
+#[test]
+fn sarif_layout_violations_name_template_and_entry() {
+    let layout = |entry: &str, problem| ViolationType::Layout {
+        layout: "go-service".to_string(),
+        entry: entry.to_string(),
+        problem,
+    };
+    let formatter = SarifFormatter::new();
+    let results = vec![
+        make_structure_failed(
+            "services/api",
+            layout("go.mod", LayoutProblem::Missing),
+            0,
+            1,
+        ),
+        make_structure_failed(
+            "services/api/scripts",
+            layout("scripts/", LayoutProblem::Unexpected),
+            1,
+            0,
+        ),
+    ];
+
+    let output = formatter.format(&results).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+    let results = &parsed["runs"][0]["results"];
+    assert_eq!(results[0]["ruleId"], "sloc-guard/structure-layout");
+    assert_eq!(
+        results[0]["message"]["text"],
+        "Directory is missing 'go.mod' required by layout 'go-service'"
+    );
+    assert_eq!(
+        results[1]["message"]["text"],
+        "Entry 'scripts/' is not part of layout 'go-service'"
+    );
+}
+
+#[test]
+fn sarif_disallowed_file_violation() {
+    let formatter = SarifFormatter::new();
+    let results = vec![make_structure_failed(
+        "src/components/test.txt",
+        ViolationType::DisallowedFile,
+        1,
+        0,
+    )];
+
+    let output = formatter.format(&results).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+    let result = &parsed["runs"][0]["results"][0];
+    assert_eq!(result["ruleId"], "sloc-guard/structure-disallowed-file");
+    assert_eq!(result["ruleIndex"], 5);
+
+    let message = result["message"]["text"].as_str().unwrap();
+    assert!(message.contains("not allowed"));
+}
+
+#[test]
+fn sarif_disallowed_directory_violation() {
+    let formatter = SarifFormatter::new();
+    let results = vec![make_structure_failed(
+        "src/temp",
+        ViolationType::DisallowedDirectory,
+        1,
+        0,
+    )];
+
+    let output = formatter.format(&results).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+    let result = &parsed["runs"][0]["results"][0];
+    assert_eq!(result["ruleId"], "sloc-guard/structure-disallowed-dir");
+    assert_eq!(result["ruleIndex"], 6);
+}
+
+#[test]
+fn sarif_denied_file_violation() {
+    let formatter = SarifFormatter::new();
+    let results = vec![make_structure_failed(
+        "build/output.exe",
+        ViolationType::DeniedFile {
+            pattern_or_extension: ".exe".to_string(),
+        },
+        1,
+        0,
+    )];
+
+    let output = formatter.format(&results).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+    let result = &parsed["runs"][0]["results"][0];
+    assert_eq!(result["ruleId"], "sloc-guard/structure-denied");
+    assert_eq!(result["ruleIndex"], 7);
+
+    let message = result["message"]["text"].as_str().unwrap();
+    assert!(message.contains("deny pattern '.exe'"));
+}
+
+#[test]
+fn sarif_denied_directory_violation() {
+    let formatter = SarifFormatter::new();
+    let results = vec![make_structure_failed(
+        "vendor/node_modules",
+        ViolationType::DeniedDirectory {
+            pattern: "**/node_modules/".to_string(),
+        },
+        1,
+        0,
+    )];
+
+    let output = formatter.format(&results).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+    let result = &parsed["runs"][0]["results"][0];
+    assert_eq!(result["ruleId"], "sloc-guard/structure-denied");
+    assert_eq!(result["ruleIndex"], 7);
+
+    let message = result["message"]["text"].as_str().unwrap();
+    assert!(message.contains("deny pattern"));
+    assert!(message.contains("node_modules"));
+}
+
+#[test]
+fn sarif_naming_convention_violation() {
+    let formatter = SarifFormatter::new();
+    let results = vec![make_structure_failed(
+        "src/components/MyComponent.tsx",
+        ViolationType::NamingConvention {
+            expected_pattern: "^[a-z][a-z0-9_]*\\.tsx$".to_string(),
+            is_directory: false,
+        },
+        1,
+        0,
+    )];
+
+    let output = formatter.format(&results).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+    let result = &parsed["runs"][0]["results"][0];
+    assert_eq!(result["ruleId"], "sloc-guard/structure-naming");
+    assert_eq!(result["ruleIndex"], 8);
+
+    let message = result["message"]["text"].as_str().unwrap();
+    assert!(message.contains("does not match required pattern"));
+}
+
+#[test]
+fn sarif_missing_sibling_violation() {
+    let formatter = SarifFormatter::new();
+    let results = vec![make_structure_failed(
+        "src/components/Button.tsx",
+        ViolationType::MissingSibling {
+            expected_sibling_pattern: "{stem}.test.tsx".to_string(),
+        },
+        1,
+        1,
+    )];
+
+    let output = formatter.format(&results).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+    let result = &parsed["runs"][0]["results"][0];
+    assert_eq!(result["ruleId"], "sloc-guard/structure-sibling");
+    assert_eq!(result["ruleIndex"], 9);
+
+    let message = result["message"]["text"].as_str().unwrap();
+    assert!(message.contains("Missing required sibling"));
+    assert!(message.contains("{stem}.test.tsx"));
+}
+
+#[test]
+fn sarif_group_incomplete_violation() {
+    let formatter = SarifFormatter::new();
+    let results = vec![make_structure_failed(
+        "src/models/user.rs",
+        ViolationType::GroupIncomplete {
+            group_patterns: vec![
+                "{stem}.rs".to_string(),
+                "{stem}_test.rs".to_string(),
+                "{stem}_mock.rs".to_string(),
+            ],
+            missing_patterns: vec!["{stem}_mock.rs".to_string()],
+        },
+        1,
+        1,
+    )];
+
+    let output = formatter.format(&results).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+    let result = &parsed["runs"][0]["results"][0];
+    assert_eq!(result["ruleId"], "sloc-guard/structure-sibling");
+    assert_eq!(result["ruleIndex"], 9);
+
+    let message = result["message"]["text"].as_str().unwrap();
+    assert!(message.contains("Incomplete file group"));
+    assert!(message.contains("{stem}_mock.rs"));
+}
+
+#[test]
+fn sarif_sibling_conflict_violations() {
+    let formatter = SarifFormatter::new();
+    let results = vec![
+        make_structure_failed(
+            "src/lib/foo.ts",
+            ViolationType::ForbiddenSibling {
+                forbidden_sibling_pattern: "{stem}.js".to_string(),
+                conflicting_file: "foo.js".to_string(),
+            },
+            1,
+            0,
+        ),
+        make_structure_failed(
+            "src/app",
+            ViolationType::ExclusiveConflict {
+                exclusive_patterns: vec!["index.js".to_string(), "index.ts".to_string()],
+                conflicting_files: vec!["index.js".to_string(), "index.ts".to_string()],
+            },
+            2,
+            1,
+        ),
+    ];
+
+    let output = formatter.format(&results).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+    let forbidden = &parsed["runs"][0]["results"][0];
+    assert_eq!(forbidden["ruleId"], "sloc-guard/structure-sibling-conflict");
+    assert_eq!(forbidden["ruleIndex"], 15);
+    let message = forbidden["message"]["text"].as_str().unwrap();
+    assert!(message.contains("Forbidden sibling file 'foo.js'"));
+
+    let exclusive = &parsed["runs"][0]["results"][1];
+    assert_eq!(exclusive["ruleId"], "sloc-guard/structure-sibling-conflict");
+    let message = exclusive["message"]["text"].as_str().unwrap();
+    assert!(message.contains("index.js, index.ts"));
+}
+
+#[test]
+fn sarif_structure_warning_level() {
+    let formatter = SarifFormatter::new();
+    let results = vec![make_structure_warning(
+        "src/large_dir",
+        ViolationType::FileCount,
+        18,
+        20,
+    )];
+
+    let output = formatter.format(&results).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+    let result = &parsed["runs"][0]["results"][0];
+    assert_eq!(result["ruleId"], "sloc-guard/structure-file-count");
+    assert_eq!(result["level"], "warning");
+}
+
+#[test]
+fn sarif_structure_grandfathered() {
+    let formatter = SarifFormatter::new();
+    let results = vec![make_structure_grandfathered(
+        "legacy/big_dir",
+        ViolationType::FileCount,
+        30,
+        20,
+    )];
+
+    let output = formatter.format(&results).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+    let result = &parsed["runs"][0]["results"][0];
+    assert_eq!(result["ruleId"], "sloc-guard/structure-file-count");
+    assert_eq!(result["level"], "note");
+    assert!(result.get("suppressions").is_some());
+
+    let message = result["message"]["text"].as_str().unwrap();
+    assert!(message.contains("(grandfathered)"));
+}
+
+#[test]
+fn sarif_mixed_content_and_structure_violations() {
+    let formatter = SarifFormatter::new();
+    let results = vec![
+        make_failed_result("src/big_file.rs", 600, 500),
+        make_structure_failed("src/components", ViolationType::FileCount, 25, 20),
+        make_warning_result("src/medium_file.rs", 460, 500),
+        make_structure_warning("src/modules", ViolationType::DirCount, 9, 10),
+    ];
+
+    let output = formatter.format(&results).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+    let sarif_results = parsed["runs"][0]["results"].as_array().unwrap();
+    assert_eq!(sarif_results.len(), 4);
+
+    // Content violation
+    assert_eq!(sarif_results[0]["ruleId"], "sloc-guard/line-limit-exceeded");
+    // Structure violation
+    assert_eq!(
+        sarif_results[1]["ruleId"],
+        "sloc-guard/structure-file-count"
+    );
+    // Content warning
+    assert_eq!(sarif_results[2]["ruleId"], "sloc-guard/line-limit-warning");
+    // Structure warning
+    assert_eq!(sarif_results[3]["ruleId"], "sloc-guard/structure-dir-count");
+}
@@ -125,8 +125,8 @@ fn sarif_rules_defined() {
     let rules = parsed["runs"][0]["tool"]["driver"]["rules"]
         .as_array()
         .unwrap();
-    // 2 content rules + 8 structure rules = 10 total
-    assert_eq!(rules.len(), 10);
+    // 2 content rules + 20 structure rules + comment ratio + expired exemption = 24 total
+    assert_eq!(rules.len(), 24);
     // Content rules at indices 0-1
     assert_eq!(rules[0]["id"], "sloc-guard/line-limit-exceeded");
     assert_eq!(rules[1]["id"], "sloc-guard/line-limit-warning");
@@ -340,412 +340,108 @@ fn sarif_override_reason_included() {
     assert_eq!(props["overrideReason"], "Legacy migration code");
 }
 
-// ============================================================================
-// Structure violation tests
-// ============================================================================
-
-mod structure_violations {
-    use super::*;
-    use crate::checker::{ViolationCategory, ViolationType};
-
-    fn make_structure_failed(
-        path: &str,
-        violation_type: ViolationType,
-        actual: usize,
-        limit: usize,
-    ) -> CheckResult {
-        CheckResult::Failed {
-            path: PathBuf::from(path),
-            stats: LineStats {
-                total: 0,
-                code: actual,
-                comment: 0,
-                blank: 0,
-                ignored: 0,
-            },
-            raw_stats: None,
-            limit,
-            override_reason: None,
-            suggestions: None,
-            violation_category: Some(ViolationCategory::Structure {
-                violation_type,
-                triggering_rule: None,
-            }),
-        }
-    }
-
-    fn make_structure_warning(
-        path: &str,
-        violation_type: ViolationType,
-        actual: usize,
-        limit: usize,
-    ) -> CheckResult {
-        CheckResult::Warning {
-            path: PathBuf::from(path),
-            stats: LineStats {
-                total: 0,
-                code: actual,
-                comment: 0,
-                blank: 0,
-                ignored: 0,
-            },
-            raw_stats: None,
-            limit,
-            override_reason: None,
-            suggestions: None,
-            violation_category: Some(ViolationCategory::Structure {
-                violation_type,
-                triggering_rule: None,
-            }),
-        }
-    }
-
-    fn make_structure_grandfathered(
-        path: &str,
-        violation_type: ViolationType,
-        actual: usize,
-        limit: usize,
-    ) -> CheckResult {
-        CheckResult::Grandfathered {
-            path: PathBuf::from(path),
-            stats: LineStats {
-                total: 0,
-                code: actual,
-                comment: 0,
-                blank: 0,
-                ignored: 0,
-            },
-            raw_stats: None,
-            limit,
-            override_reason: None,
-            violation_category: Some(ViolationCategory::Structure {
-                violation_type,
-                triggering_rule: None,
-            }),
-        }
-    }
-
-    #[test]
-    fn sarif_rules_include_structure_rules() {
-        let formatter = SarifFormatter::new();
-        let results: Vec<CheckResult> = vec![];
-
-        let output = formatter.format(&results).unwrap();
-        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
-
-        let rules = parsed["runs"][0]["tool"]["driver"]["rules"]
-            .as_array()
-            .unwrap();
-        // 2 content rules + 8 structure rules = 10 total
-        assert_eq!(rules.len(), 10);
-
-        // Verify structure rule IDs exist
-        let rule_ids: Vec<&str> = rules.iter().map(|r| r["id"].as_str().unwrap()).collect();
-        assert!(rule_ids.contains(&"sloc-guard/structure-file-count"));
-        assert!(rule_ids.contains(&"sloc-guard/structure-dir-count"));
-        assert!(rule_ids.contains(&"sloc-guard/structure-max-depth"));
-        assert!(rule_ids.contains(&"sloc-guard/structure-disallowed-file"));
-        assert!(rule_ids.contains(&"sloc-guard/structure-disallowed-dir"));
-        assert!(rule_ids.contains(&"sloc-guard/structure-denied"));
-        assert!(rule_ids.contains(&"sloc-guard/structure-naming"));
-        assert!(rule_ids.contains(&"sloc-guard/structure-sibling"));
-    }
-
-    #[test]
-    fn sarif_file_count_violation() {
-        let formatter = SarifFormatter::new();
-        let results = vec![make_structure_failed(
-            "src/components",
-            ViolationType::FileCount,
-            25,
-            20,
-        )];
-
-        let output = formatter.format(&results).unwrap();
-        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
-
-        let result = &parsed["runs"][0]["results"][0];
-        assert_eq!(result["ruleId"], "sloc-guard/structure-file-count");
-        assert_eq!(result["level"], "error");
-        assert_eq!(result["ruleIndex"], 2);
-
-        let message = result["message"]["text"].as_str().unwrap();
-        assert!(message.contains("25 files"));
-        assert!(message.contains("limit of 20"));
-    }
-
-    #[test]
-    fn sarif_dir_count_violation() {
-        let formatter = SarifFormatter::new();
-        let results = vec![make_structure_failed(
-            "src/modules",
-            ViolationType::DirCount,
-            15,
-            10,
-        )];
-
-        let output = formatter.format(&results).unwrap();
-        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
-
-        let result = &parsed["runs"][0]["results"][0];
-        assert_eq!(result["ruleId"], "sloc-guard/structure-dir-count");
-        assert_eq!(result["ruleIndex"], 3);
-
-        let message = result["message"]["text"].as_str().unwrap();
-        assert!(message.contains("15 subdirectories"));
-        assert!(message.contains("limit of 10"));
-    }
-
-    #[test]
-    fn sarif_max_depth_violation() {
-        let formatter = SarifFormatter::new();
-        let results = vec![make_structure_failed(
-            "src/a/b/c/d/e/f",
-            ViolationType::MaxDepth,
-            6,
-            5,
-        )];
-
-        let output = formatter.format(&results).unwrap();
-        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
-
-        let result = &parsed["runs"][0]["results"][0];
-        assert_eq!(result["ruleId"], "sloc-guard/structure-max-depth");
-        assert_eq!(result["ruleIndex"], 4);
-
-        let message = result["message"]["text"].as_str().unwrap();
-        assert!(message.contains("depth is 6"));
-        assert!(message.contains("limit of 5"));
-    }
-
-    #[test]
-    fn sarif_disallowed_file_violation() {
-        let formatter = SarifFormatter::new();
-        let results = vec![make_structure_failed(
-            "src/components/test.txt",
-            ViolationType::DisallowedFile,
-            1,
-            0,
-        )];
-
-        let output = formatter.format(&results).unwrap();
-        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
-
-        let result = &parsed["runs"][0]["results"][0];
-        assert_eq!(result["ruleId"], "sloc-guard/structure-disallowed-file");
-        assert_eq!(result["ruleIndex"], 5);
-
-        let message = result["message"]["text"].as_str().unwrap();
-        assert!(message.contains("not allowed"));
-    }
-
-    #[test]
-    fn sarif_disallowed_directory_violation() {
-        let formatter = SarifFormatter::new();
-        let results = vec![make_structure_failed(
-            "src/temp",
-            ViolationType::DisallowedDirectory,
-            1,
-            0,
-        )];
-
-        let output = formatter.format(&results).unwrap();
-        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
-
-        let result = &parsed["runs"][0]["results"][0];
-        assert_eq!(result["ruleId"], "sloc-guard/structure-disallowed-dir");
-        assert_eq!(result["ruleIndex"], 6);
-    }
-
-    #[test]
-    fn sarif_denied_file_violation() {
-        let formatter = SarifFormatter::new();
-        let results = vec![make_structure_failed(
-            "build/output.exe",
-            ViolationType::DeniedFile {
-                pattern_or_extension: ".exe".to_string(),
-            },
-            1,
-            0,
-        )];
-
-        let output = formatter.format(&results).unwrap();
-        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
-
-        let result = &parsed["runs"][0]["results"][0];
-        assert_eq!(result["ruleId"], "sloc-guard/structure-denied");
-        assert_eq!(result["ruleIndex"], 7);
-
-        let message = result["message"]["text"].as_str().unwrap();
-        assert!(message.contains("deny pattern '.exe'"));
-    }
-
-    #[test]
-    fn sarif_denied_directory_violation() {
-        let formatter = SarifFormatter::new();
-        let results = vec![make_structure_failed(
-            "vendor/node_modules",
-            ViolationType::DeniedDirectory {
-                pattern: "**/node_modules/".to_string(),
-            },
-            1,
-            0,
-        )];
-
-        let output = formatter.format(&results).unwrap();
-        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
-
-        let result = &parsed["runs"][0]["results"][0];
-        assert_eq!(result["ruleId"], "sloc-guard/structure-denied");
-        assert_eq!(result["ruleIndex"], 7);
-
-        let message = result["message"]["text"].as_str().unwrap();
-        assert!(message.contains("deny pattern"));
-        assert!(message.contains("node_modules"));
-    }
-
-    #[test]
-    fn sarif_naming_convention_violation() {
-        let formatter = SarifFormatter::new();
-        let results = vec![make_structure_failed(
-            "src/components/MyComponent.tsx",
-            ViolationType::NamingConvention {
-                expected_pattern: "^[a-z][a-z0-9_]*\\.tsx$".to_string(),
-            },
-            1,
-            0,
-        )];
-
-        let output = formatter.format(&results).unwrap();
-        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
-
-        let result = &parsed["runs"][0]["results"][0];
-        assert_eq!(result["ruleId"], "sloc-guard/structure-naming");
-        assert_eq!(result["ruleIndex"], 8);
-
-        let message = result["message"]["text"].as_str().unwrap();
-        assert!(message.contains("does not match required pattern"));
-    }
-
-    #[test]
-    fn sarif_missing_sibling_violation() {
-        let formatter = SarifFormatter::new();
-        let results = vec![make_structure_failed(
-            "src/components/Button.tsx",
-            ViolationType::MissingSibling {
-                expected_sibling_pattern: "{stem}.test.tsx".to_string(),
-            },
-            1,
-            1,
-        )];
+#[test]
+fn sarif_content_message_uses_metric_unit() {
+    let results = vec![CheckResult::Failed {
+        path: PathBuf::from("README.md"),
+        stats: LineStats {
+            total: 523,
+            code: 523,
+            comment: 0,
+            blank: 0,
+            ignored: 0,
+        },
+        raw_stats: None,
+        limit: 500,
+        override_reason: None,
+        suggestions: None,
+        violation_category: Some(crate::checker::ViolationCategory::Content {
+            metric: crate::config::ContentMetric::Total,
+            configured: true,
+        }),
+    }];
 
-        let output = formatter.format(&results).unwrap();
-        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+    let output = SarifFormatter::new().format(&results).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
 
-        let result = &parsed["runs"][0]["results"][0];
-        assert_eq!(result["ruleId"], "sloc-guard/structure-sibling");
-        assert_eq!(result["ruleIndex"], 9);
+    let result = &parsed["runs"][0]["results"][0];
+    assert_eq!(result["ruleId"], "sloc-guard/line-limit-exceeded");
+    assert_eq!(
+        result["message"]["text"],
+        "File has 523 total lines, exceeding limit of 500 by 23 lines"
+    );
+}
 
-        let message = result["message"]["text"].as_str().unwrap();
-        assert!(message.contains("Missing required sibling"));
-        assert!(message.contains("{stem}.test.tsx"));
-    }
+#[test]
+fn sarif_comment_ratio_violation() {
+    let results = vec![CheckResult::Failed {
+        path: PathBuf::from("src/parser.rs"),
+        stats: LineStats {
+            total: 210,
+            code: 200,
+            comment: 5,
+            blank: 5,
+            ignored: 0,
+        },
+        raw_stats: None,
+        limit: 500,
+        override_reason: None,
+        suggestions: None,
+        violation_category: Some(crate::checker::ViolationCategory::CommentRatio {
+            bound: crate::checker::CommentRatioBound::Min,
+            ratio: 5.0 / 205.0,
+            threshold: 0.1,
+        }),
+    }];
 
-    #[test]
-    fn sarif_group_incomplete_violation() {
-        let formatter = SarifFormatter::new();
-        let results = vec![make_structure_failed(
-            "src/models/user.rs",
-            ViolationType::GroupIncomplete {
-                group_patterns: vec![
-                    "{stem}.rs".to_string(),
-                    "{stem}_test.rs".to_string(),
-                    "{stem}_mock.rs".to_string(),
-                ],
-                missing_patterns: vec!["{stem}_mock.rs".to_string()],
-            },
-            1,
-            1,
-        )];
+    let output = SarifFormatter::new().format(&results).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
 
-        let output = formatter.format(&results).unwrap();
-        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+    let result = &parsed["runs"][0]["results"][0];
+    assert_eq!(result["ruleId"], "sloc-guard/comment-ratio");
+    assert_eq!(result["ruleIndex"], 10);
+    assert_eq!(result["level"], "error");
+    assert_eq!(
+        result["message"]["text"],
+        "File comment ratio 2.4% is below minimum 10.0%"
+    );
+}
 
-        let result = &parsed["runs"][0]["results"][0];
-        assert_eq!(result["ruleId"], "sloc-guard/structure-sibling");
-        assert_eq!(result["ruleIndex"], 9);
+#[test]
+fn sarif_expired_exemption_points_at_config_line() {
+    let results = vec![CheckResult::Failed {
+        path: PathBuf::from(".sloc-guard.toml"),
+        stats: LineStats::default(),
+        raw_stats: None,
+        limit: 0,
+        override_reason: None,
+        suggestions: None,
+        violation_category: Some(crate::checker::ViolationCategory::ExpiredExemption {
+            rule: "content.rules[0]".to_string(),
+            pattern: "src/legacy/**".to_string(),
+            expires: "2025-06-01".to_string(),
+            line: Some(7),
+        }),
+    }];
 
-        let message = result["message"]["text"].as_str().unwrap();
-        assert!(message.contains("Incomplete file group"));
-        assert!(message.contains("{stem}_mock.rs"));
-    }
+    let output = SarifFormatter::new().format(&results).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
 
-    #[test]
-    fn sarif_structure_warning_level() {
-        let formatter = SarifFormatter::new();
-        let results = vec![make_structure_warning(
-            "src/large_dir",
-            ViolationType::FileCount,
-            18,
-            20,
-        )];
-
-        let output = formatter.format(&results).unwrap();
-        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
-
-        let result = &parsed["runs"][0]["results"][0];
-        assert_eq!(result["ruleId"], "sloc-guard/structure-file-count");
-        assert_eq!(result["level"], "warning");
-    }
+    let result = &parsed["runs"][0]["results"][0];
+    assert_eq!(result["ruleId"], "sloc-guard/expired-exemption");
+    assert_eq!(result["ruleIndex"], 11);
+    assert_eq!(
+        result["message"]["text"],
+        "Exemption content.rules[0] (pattern 'src/legacy/**') expired on 2025-06-01"
+    );
+    let location = &result["locations"][0]["physicalLocation"];
+    assert_eq!(location["artifactLocation"]["uri"], ".sloc-guard.toml");
+    assert_eq!(location["region"]["startLine"], 7);
+}
 
-    #[test]
-    fn sarif_structure_grandfathered() {
-        let formatter = SarifFormatter::new();
-        let results = vec![make_structure_grandfathered(
-            "legacy/big_dir",
-            ViolationType::FileCount,
-            30,
-            20,
-        )];
-
-        let output = formatter.format(&results).unwrap();
-        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
-
-        let result = &parsed["runs"][0]["results"][0];
-        assert_eq!(result["ruleId"], "sloc-guard/structure-file-count");
-        assert_eq!(result["level"], "note");
-        assert!(result.get("suppressions").is_some());
-
-        let message = result["message"]["text"].as_str().unwrap();
-        assert!(message.contains("(grandfathered)"));
-    }
+// ============================================================================
+// Structure violation tests
+// ============================================================================
 
-    #[test]
-    fn sarif_mixed_content_and_structure_violations() {
-        let formatter = SarifFormatter::new();
-        let results = vec![
-            make_failed_result("src/big_file.rs", 600, 500),
-            make_structure_failed("src/components", ViolationType::FileCount, 25, 20),
-            make_warning_result("src/medium_file.rs", 460, 500),
-            make_structure_warning("src/modules", ViolationType::DirCount, 9, 10),
-        ];
-
-        let output = formatter.format(&results).unwrap();
-        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
-
-        let sarif_results = parsed["runs"][0]["results"].as_array().unwrap();
-        assert_eq!(sarif_results.len(), 4);
-
-        // Content violation
-        assert_eq!(sarif_results[0]["ruleId"], "sloc-guard/line-limit-exceeded");
-        // Structure violation
-        assert_eq!(
-            sarif_results[1]["ruleId"],
-            "sloc-guard/structure-file-count"
-        );
-        // Content warning
-        assert_eq!(sarif_results[2]["ruleId"], "sloc-guard/line-limit-warning");
-        // Structure warning
-        assert_eq!(sarif_results[3]["ruleId"], "sloc-guard/structure-dir-count");
-    }
-}
+#[path = "sarif_structure_tests.rs"]
+mod structure_violations;
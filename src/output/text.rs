@@ -3,6 +3,7 @@ use std::io::Write as IoWrite;
 use std::path::{Path, PathBuf};
 
 use crate::checker::{CheckResult, ViolationCategory, ViolationType};
+use crate::config::format_byte_size;
 use crate::error::Result;
 
 use super::OutputFormatter;
@@ -143,7 +144,26 @@ impl TextFormatter {
             Some(ViolationCategory::Structure { violation_type, .. }) => {
                 Self::format_structure_violation(result, violation_type, output);
             }
-            Some(ViolationCategory::Content) | None => {
+            Some(ViolationCategory::CommentRatio {
+                bound,
+                ratio,
+                threshold,
+            }) => {
+                Self::format_comment_ratio_violation(
+                    result,
+                    &bound.describe(*ratio, *threshold),
+                    output,
+                );
+            }
+            Some(ViolationCategory::ExpiredExemption { line, .. }) => {
+                if let Some(description) = result.category_message() {
+                    writeln!(output, "   Expired exemption: {description}").ok();
+                }
+                if let Some(line) = line {
+                    writeln!(output, "   Location: {path_display}:{line}").ok();
+                }
+            }
+            Some(ViolationCategory::Content { .. }) | None => {
                 // Content violation: show line count and breakdown
                 Self::format_content_violation(result, output);
             }
@@ -161,6 +181,12 @@ impl TextFormatter {
         {
             Self::format_suggestions(suggestion, output);
         }
+        if self.show_suggestions
+            && let Some(suggestion) = result.reorganization()
+            && suggestion.has_suggestions()
+        {
+            Self::format_reorganization(suggestion, output);
+        }
     }
 
     fn format_structure_violation(
@@ -196,6 +222,69 @@ impl TextFormatter {
                 )
                 .ok();
             }
+            ViolationType::TotalFileCount => {
+                writeln!(
+                    output,
+                    "   Total files: {} (limit: {})",
+                    result.stats().sloc(),
+                    result.limit()
+                )
+                .ok();
+            }
+            ViolationType::FileCountByExtension { extension } => {
+                writeln!(
+                    output,
+                    "   {extension} files: {} (limit: {})",
+                    result.stats().sloc(),
+                    result.limit()
+                )
+                .ok();
+            }
+            ViolationType::TotalDirCount => {
+                writeln!(
+                    output,
+                    "   Total directories: {} (limit: {})",
+                    result.stats().sloc(),
+                    result.limit()
+                )
+                .ok();
+            }
+            ViolationType::PathLength => {
+                writeln!(
+                    output,
+                    "   Path length: {} (limit: {})",
+                    result.stats().sloc(),
+                    result.limit()
+                )
+                .ok();
+            }
+            ViolationType::FilenameLength => {
+                writeln!(
+                    output,
+                    "   Name length: {} (limit: {})",
+                    result.stats().sloc(),
+                    result.limit()
+                )
+                .ok();
+            }
+            ViolationType::PathComponents => {
+                writeln!(
+                    output,
+                    "   Path components: {} (limit: {})",
+                    result.stats().sloc(),
+                    result.limit()
+                )
+                .ok();
+            }
+            ViolationType::FileSize => {
+                writeln!(
+                    output,
+                    "   Size: {} (limit: {})",
+                    format_byte_size(result.stats().sloc() as u64),
+                    format_byte_size(result.limit() as u64)
+                )
+                .ok();
+            }
             // File-level structure violations: the reason message is self-explanatory
             ViolationType::DisallowedFile
             | ViolationType::DisallowedDirectory
@@ -203,7 +292,15 @@ impl TextFormatter {
             | ViolationType::DeniedDirectory { .. }
             | ViolationType::NamingConvention { .. }
             | ViolationType::MissingSibling { .. }
-            | ViolationType::GroupIncomplete { .. } => {
+            | ViolationType::MissingRequiredFile { .. }
+            | ViolationType::GroupIncomplete { .. }
+            | ViolationType::ForbiddenSibling { .. }
+            | ViolationType::ExclusiveConflict { .. }
+            | ViolationType::CaseCollision { .. }
+            | ViolationType::InvalidWindowsName { .. }
+            | ViolationType::EmptyDirectory
+            | ViolationType::Symlink { .. }
+            | ViolationType::Layout { .. } => {
                 // No additional metrics needed for file-level violations
             }
         }
@@ -213,14 +310,38 @@ impl TextFormatter {
         // Show raw stats first (before skip_comments/skip_blank adjustments)
         let raw = result.raw_stats();
         writeln!(output, "   Total: {}", raw.total).ok();
+        match result.configured_metric() {
+            Some(metric) => writeln!(
+                output,
+                "   Lines: {} {} (limit: {})",
+                result.stats().sloc(),
+                metric.unit(),
+                result.limit()
+            ),
+            None => writeln!(
+                output,
+                "   Lines: {} (limit: {})",
+                result.stats().sloc(),
+                result.limit()
+            ),
+        }
+        .ok();
+
         writeln!(
             output,
-            "   Lines: {} (limit: {})",
-            result.stats().sloc(),
-            result.limit()
+            "   Breakdown: code={}, comment={}, blank={}",
+            raw.code, raw.comment, raw.blank
         )
         .ok();
+    }
 
+    fn format_comment_ratio_violation(
+        result: &CheckResult,
+        description: &str,
+        output: &mut Vec<u8>,
+    ) {
+        let raw = result.raw_stats();
+        writeln!(output, "   Comments: {description}").ok();
         writeln!(
             output,
             "   Breakdown: code={}, comment={}, blank={}",
@@ -245,6 +366,38 @@ impl TextFormatter {
         }
     }
 
+    fn format_reorganization(
+        suggestion: &crate::analyzer::ReorganizeSuggestion,
+        output: &mut Vec<u8>,
+    ) {
+        writeln!(output, "   Reorganization suggestions:").ok();
+        for bucket in &suggestion.buckets {
+            writeln!(
+                output,
+                "     → {}/ ({}, {} files)",
+                bucket.directory,
+                bucket.pattern,
+                bucket.files.len()
+            )
+            .ok();
+            writeln!(output, "       Files: {}", bucket.files.join(", ")).ok();
+        }
+        writeln!(
+            output,
+            "     Leaves {} files (limit: {})",
+            suggestion.remaining_files(),
+            suggestion.limit
+        )
+        .ok();
+        if suggestion.dir_limit_reached {
+            writeln!(
+                output,
+                "     Stopped early: more subdirectories would exceed max_dirs"
+            )
+            .ok();
+        }
+    }
+
     fn format_summary(
         &self,
         total: usize,
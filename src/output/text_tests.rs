@@ -453,6 +453,35 @@ fn format_structure_max_depth_violation() {
     assert!(!output.contains("Lines:"));
 }
 
+#[test]
+fn format_structure_file_size_violation() {
+    use crate::checker::{ViolationCategory, ViolationType};
+    let formatter = TextFormatter::new(ColorMode::Never);
+    let results = vec![CheckResult::Failed {
+        path: PathBuf::from("fixtures/dump.json"),
+        stats: LineStats {
+            total: 1_572_864,
+            code: 1_572_864,
+            comment: 0,
+            blank: 0,
+            ignored: 0,
+        },
+        raw_stats: None,
+        limit: 1024 * 1024,
+        override_reason: Some("structure: file size exceeded".to_string()),
+        suggestions: None,
+        violation_category: Some(ViolationCategory::Structure {
+            violation_type: ViolationType::FileSize,
+            triggering_rule: None,
+        }),
+    }];
+
+    let output = formatter.format(&results).unwrap();
+
+    assert!(output.contains("Size: 1.5 MB (limit: 1 MB)"));
+    assert!(!output.contains("Lines:"));
+}
+
 #[test]
 fn format_structure_disallowed_file_violation() {
     use crate::checker::{ViolationCategory, ViolationType};
@@ -518,6 +547,7 @@ fn format_structure_naming_convention_violation() {
         violation_category: Some(ViolationCategory::Structure {
             violation_type: ViolationType::NamingConvention {
                 expected_pattern: "^[a-z_]+\\.rs$".to_string(),
+                is_directory: false,
             },
             triggering_rule: Some("src/**".to_string()),
         }),
@@ -546,16 +576,79 @@ fn format_content_violation_category() {
         limit: 500,
         override_reason: None,
         suggestions: None,
-        violation_category: Some(ViolationCategory::Content),
+        violation_category: Some(ViolationCategory::Content {
+            metric: crate::config::ContentMetric::Total,
+            configured: true,
+        }),
     }];
 
     let output = formatter.format(&results).unwrap();
 
     assert!(output.contains("Total: 610"));
-    assert!(output.contains("Lines: 600 (limit: 500)"));
+    assert!(output.contains("Lines: 600 total lines (limit: 500)"));
     assert!(output.contains("Breakdown: code=600, comment=5, blank=5"));
 }
 
+#[test]
+fn format_comment_ratio_violation_category() {
+    use crate::checker::{CommentRatioBound, ViolationCategory};
+    let formatter = TextFormatter::new(ColorMode::Never);
+    let results = vec![CheckResult::Failed {
+        path: PathBuf::from("src/parser.rs"),
+        stats: LineStats {
+            total: 260,
+            code: 100,
+            comment: 150,
+            blank: 10,
+            ignored: 0,
+        },
+        raw_stats: None,
+        limit: 500,
+        override_reason: None,
+        suggestions: None,
+        violation_category: Some(ViolationCategory::CommentRatio {
+            bound: CommentRatioBound::Max,
+            ratio: 0.6,
+            threshold: 0.5,
+        }),
+    }];
+
+    let output = formatter.format(&results).unwrap();
+
+    assert!(output.contains("Comments: comment ratio 60.0% is above maximum 50.0%"));
+    assert!(output.contains("Breakdown: code=100, comment=150, blank=10"));
+    assert!(!output.contains("Lines:"));
+}
+
+#[test]
+fn format_expired_exemption_category() {
+    use crate::checker::ViolationCategory;
+    let formatter = TextFormatter::new(ColorMode::Never);
+    let results = vec![CheckResult::Failed {
+        path: PathBuf::from(".sloc-guard.toml"),
+        stats: LineStats::default(),
+        raw_stats: None,
+        limit: 0,
+        override_reason: Some("Legacy code".to_string()),
+        suggestions: None,
+        violation_category: Some(ViolationCategory::ExpiredExemption {
+            rule: "structure.rules[1]".to_string(),
+            pattern: "src/legacy".to_string(),
+            expires: "2025-06-01".to_string(),
+            line: Some(12),
+        }),
+    }];
+
+    let output = formatter.format(&results).unwrap();
+
+    assert!(output.contains(
+        "Expired exemption: structure.rules[1] (pattern 'src/legacy') expired on 2025-06-01"
+    ));
+    assert!(output.contains("Location: .sloc-guard.toml:12"));
+    assert!(output.contains("Reason: Legacy code"));
+    assert!(!output.contains("Lines:"));
+}
+
 #[test]
 fn grandfathered_with_reason_shown() {
     let formatter = TextFormatter::with_verbose(ColorMode::Never, 1);
@@ -579,3 +672,27 @@ fn grandfathered_with_reason_shown() {
     assert!(output.contains("GRANDFATHERED"));
     assert!(output.contains("Reason: legacy code from initial import"));
 }
+
+#[test]
+fn with_suggestions_shows_reorganization_suggestions() {
+    use crate::analyzer::{BucketKind, ReorganizeBucket, ReorganizeSuggestion};
+
+    let suggestion =
+        ReorganizeSuggestion::new(PathBuf::from("src/utils"), 5, 3).with_buckets(vec![
+            ReorganizeBucket {
+                directory: "user".to_string(),
+                kind: BucketKind::Prefix,
+                pattern: "user*".to_string(),
+                files: vec!["user_create.rs".to_string(), "user_delete.rs".to_string()],
+            },
+        ]);
+    let result = make_failed_result("src/utils", 5, 3).with_reorganization(suggestion);
+
+    let formatter = TextFormatter::new(ColorMode::Never).with_suggestions(true);
+    let output = formatter.format(&[result]).unwrap();
+
+    assert!(output.contains("Reorganization suggestions:"));
+    assert!(output.contains("→ user/ (user*, 2 files)"));
+    assert!(output.contains("Files: user_create.rs, user_delete.rs"));
+    assert!(output.contains("Leaves 3 files (limit: 3)"));
+}
@@ -1,18 +1,23 @@
+use std::borrow::Cow;
 use std::path::Path;
 
 use globset::{Glob, GlobSet, GlobSetBuilder};
 use regex::Regex;
 
 use crate::SlocGuardError;
+use crate::checker::{RuleRank, ScopeSelector};
+use crate::config::{NamingStem, NamingStyle, Placeholder, Template, TemplateValues};
 use crate::error::Result;
 use crate::output::path::normalize_for_matching;
 
 /// A compiled allowlist rule for checking allowed file types in a directory.
 #[derive(Debug, Clone)]
 pub struct AllowlistRule {
-    /// Glob pattern defining the directory scope where this rule applies.
+    /// Scope label of the rule (its glob, for single-scope rules).
     pub scope: String,
-    matcher: globset::GlobMatcher,
+    selector: ScopeSelector,
+    /// Explicit `priority`, ranked together with the matching scope's specificity.
+    pub priority: Option<i32>,
     /// Validated extensions (with leading dot, e.g., ".rs").
     pub allow_extensions: Vec<String>,
     /// Compiled patterns for allowlist matching.
@@ -41,6 +46,18 @@ pub struct AllowlistRule {
     naming_pattern: Option<Regex>,
     /// Original regex string for error messages.
     pub naming_pattern_str: Option<String>,
+    /// Naming regex with `{dir}`/`{parent}` placeholders, compiled per directory.
+    naming_template: Option<Template>,
+    /// Built-in case style for file stems (optional).
+    pub file_naming_style: Option<NamingStyle>,
+    /// Which part of multi-dot file names the file style applies to.
+    pub naming_stem: NamingStem,
+    /// Compiled regex for directory name validation (optional).
+    dir_naming_pattern: Option<Regex>,
+    /// Original directory regex string for error messages.
+    pub dir_naming_pattern_str: Option<String>,
+    /// Built-in case style for directory names (optional).
+    pub dir_naming_style: Option<NamingStyle>,
 }
 
 impl AllowlistRule {
@@ -140,29 +157,79 @@ impl AllowlistRule {
     #[must_use]
     pub fn matches_directory(&self, dir: &Path) -> bool {
         let normalized = normalize_for_matching(dir);
-        self.matcher.is_match(normalized)
+        self.selector.is_match(normalized)
     }
 
-    /// Check if a filename matches the naming convention pattern.
-    /// Returns `true` if no pattern is set or if the filename matches.
+    /// Priority and specificity of the scope matching `dir`, to pick among overlapping rules.
+    pub(crate) fn rank(&self, dir: &Path) -> RuleRank {
+        let normalized = normalize_for_matching(dir);
+        RuleRank::with_specificity(self.selector.specificity(&normalized), self.priority)
+    }
+
+    /// Check if a filename matches the naming convention (pattern and style).
+    /// Returns `true` if neither is set or if the filename matches.
     #[must_use]
     pub fn filename_matches_naming_pattern(&self, file_path: &Path) -> bool {
-        let Some(ref regex) = self.naming_pattern else {
-            return true; // No naming pattern = always valid
-        };
+        self.file_naming_mismatch(file_path).is_none()
+    }
 
+    /// The file naming convention a file violates (regex or style name), if any.
+    #[must_use]
+    pub fn file_naming_mismatch(&self, file_path: &Path) -> Option<String> {
         let file_name = file_path
             .file_name()
             .map(|n| n.to_string_lossy())
             .unwrap_or_default();
 
-        regex.is_match(&file_name)
+        if let Some((regex, pattern)) = self.file_naming_regex(file_path)
+            && !regex.is_match(&file_name)
+        {
+            return Some(pattern);
+        }
+        self.file_naming_style
+            .filter(|style| !style.matches_file_name(&file_name, self.naming_stem))
+            .map(|style| style.to_string())
+    }
+
+    /// The file naming regex for `file_path` and the pattern to report, with
+    /// placeholders expanded for the file's directory.
+    fn file_naming_regex(&self, file_path: &Path) -> Option<(Cow<'_, Regex>, String)> {
+        if let Some(template) = &self.naming_template {
+            let values = TemplateValues::for_file(file_path);
+            let pattern = template.expand_with(&values, regex::escape);
+            return Regex::new(&pattern)
+                .ok()
+                .map(|regex| (Cow::Owned(regex), pattern));
+        }
+        let regex = self.naming_pattern.as_ref()?;
+        Some((Cow::Borrowed(regex), self.naming_pattern_str.clone()?))
+    }
+
+    /// The directory naming convention a directory violates (regex or style name), if any.
+    #[must_use]
+    pub fn dir_naming_mismatch(&self, dir_path: &Path) -> Option<String> {
+        let dir_name = dir_path
+            .file_name()
+            .map(|n| n.to_string_lossy())
+            .unwrap_or_default();
+
+        if let (Some(regex), Some(pattern)) =
+            (&self.dir_naming_pattern, &self.dir_naming_pattern_str)
+            && !regex.is_match(&dir_name)
+        {
+            return Some(pattern.clone());
+        }
+        self.dir_naming_style
+            .filter(|style| !style.matches_dir_name(&dir_name))
+            .map(|style| style.to_string())
     }
 }
 
 /// Builder for creating `AllowlistRule` instances.
 pub struct AllowlistRuleBuilder {
     scope: String,
+    selector: Option<ScopeSelector>,
+    priority: Option<i32>,
     allow_extensions: Vec<String>,
     allow_patterns: Vec<String>,
     allow_files: Vec<String>,
@@ -172,6 +239,10 @@ pub struct AllowlistRuleBuilder {
     deny_files: Vec<String>,
     deny_dirs: Vec<String>,
     naming_pattern: Option<String>,
+    file_naming_style: Option<NamingStyle>,
+    naming_stem: NamingStem,
+    dir_naming_pattern: Option<String>,
+    dir_naming_style: Option<NamingStyle>,
 }
 
 impl AllowlistRuleBuilder {
@@ -179,6 +250,8 @@ impl AllowlistRuleBuilder {
     pub const fn new(scope: String) -> Self {
         Self {
             scope,
+            selector: None,
+            priority: None,
             allow_extensions: Vec::new(),
             allow_patterns: Vec::new(),
             allow_files: Vec::new(),
@@ -188,9 +261,27 @@ impl AllowlistRuleBuilder {
             deny_files: Vec::new(),
             deny_dirs: Vec::new(),
             naming_pattern: None,
+            file_naming_style: None,
+            naming_stem: NamingStem::FirstDot,
+            dir_naming_pattern: None,
+            dir_naming_style: None,
         }
     }
 
+    /// Use a compiled multi-scope selector instead of compiling `scope` as one glob.
+    #[must_use]
+    pub fn with_scope_selector(mut self, selector: ScopeSelector) -> Self {
+        self.selector = Some(selector);
+        self
+    }
+
+    /// Set the explicit `priority` used when several rules match a directory.
+    #[must_use]
+    pub const fn with_priority(mut self, priority: Option<i32>) -> Self {
+        self.priority = priority;
+        self
+    }
+
     #[must_use]
     pub fn with_extensions(mut self, extensions: Vec<String>) -> Self {
         self.allow_extensions = extensions;
@@ -245,15 +336,39 @@ impl AllowlistRuleBuilder {
         self
     }
 
+    #[must_use]
+    pub const fn with_file_naming(mut self, style: Option<NamingStyle>) -> Self {
+        self.file_naming_style = style;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_naming_stem(mut self, stem: NamingStem) -> Self {
+        self.naming_stem = stem;
+        self
+    }
+
+    #[must_use]
+    pub fn with_dir_naming_pattern(mut self, pattern: Option<String>) -> Self {
+        self.dir_naming_pattern = pattern;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_dir_naming(mut self, style: Option<NamingStyle>) -> Self {
+        self.dir_naming_style = style;
+        self
+    }
+
     /// Build the `AllowlistRule`.
     ///
     /// # Errors
     /// Returns an error if any pattern is invalid.
     pub fn build(self) -> Result<AllowlistRule> {
-        let glob = Glob::new(&self.scope).map_err(|e| SlocGuardError::InvalidPattern {
-            pattern: self.scope.clone(),
-            source: e,
-        })?;
+        let selector = match self.selector {
+            Some(selector) => selector,
+            None => ScopeSelector::from_glob(&self.scope)?,
+        };
 
         let mut pattern_builder = GlobSetBuilder::new();
         for p in &self.allow_patterns {
@@ -353,22 +468,20 @@ impl AllowlistRuleBuilder {
                 source: e,
             })?;
 
-        // Compile naming pattern regex if provided
-        let (naming_pattern, naming_pattern_str) = match self.naming_pattern {
-            Some(pattern_str) => {
-                let regex = Regex::new(&pattern_str).map_err(|e| {
-                    SlocGuardError::Config(format!(
-                        "Invalid naming pattern regex '{pattern_str}': {e}"
-                    ))
-                })?;
-                (Some(regex), Some(pattern_str))
-            }
-            None => (None, None),
+        // Compile naming pattern regexes if provided
+        let naming_template = compile_naming_template(self.naming_pattern.as_deref())?;
+        let (naming_pattern, naming_pattern_str) = if naming_template.is_some() {
+            (None, self.naming_pattern)
+        } else {
+            compile_naming_regex(self.naming_pattern)?
         };
+        let (dir_naming_pattern, dir_naming_pattern_str) =
+            compile_naming_regex(self.dir_naming_pattern)?;
 
         Ok(AllowlistRule {
             scope: self.scope,
-            matcher: glob.compile_matcher(),
+            selector,
+            priority: self.priority,
             allow_extensions: self.allow_extensions,
             allow_patterns,
             allow_files,
@@ -383,6 +496,47 @@ impl AllowlistRuleBuilder {
             deny_dir_strs: self.deny_dirs,
             naming_pattern,
             naming_pattern_str,
+            naming_template,
+            file_naming_style: self.file_naming_style,
+            naming_stem: self.naming_stem,
+            dir_naming_pattern,
+            dir_naming_pattern_str,
+            dir_naming_style: self.dir_naming_style,
         })
     }
 }
+
+/// Compile an optional naming regex, keeping the source string for error messages.
+fn compile_naming_regex(pattern: Option<String>) -> Result<(Option<Regex>, Option<String>)> {
+    match pattern {
+        Some(pattern_str) => {
+            let regex = Regex::new(&pattern_str).map_err(|e| {
+                SlocGuardError::Config(format!("Invalid naming pattern regex '{pattern_str}': {e}"))
+            })?;
+            Ok((Some(regex), Some(pattern_str)))
+        }
+        None => Ok((None, None)),
+    }
+}
+
+/// Parse a file naming regex with `{dir}`/`{parent}` placeholders; `None` without placeholders.
+fn compile_naming_template(pattern: Option<&str>) -> Result<Option<Template>> {
+    let Some(pattern) = pattern else {
+        return Ok(None);
+    };
+    let template = Template::parse(pattern, Placeholder::DIRECTORY)
+        .map_err(|e| SlocGuardError::Config(format!("Invalid naming pattern '{pattern}': {e}")))?;
+    if !template.has_placeholders() {
+        return Ok(None);
+    }
+    // Validate the rest of the regex with neutral names
+    let values = TemplateValues {
+        dir: "dir",
+        parent: "parent",
+        ..TemplateValues::default()
+    };
+    Regex::new(&template.expand(&values)).map_err(|e| {
+        SlocGuardError::Config(format!("Invalid naming pattern regex '{pattern}': {e}"))
+    })?;
+    Ok(Some(template))
+}
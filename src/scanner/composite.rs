@@ -3,6 +3,7 @@ use std::path::{Path, PathBuf};
 use super::FileScanner;
 use super::directory::DirectoryScanner;
 use super::filter::GlobFilter;
+use super::tracked::{TrackedTree, scan_tracked_tree};
 use super::{ScanResult, StructureScanConfig};
 use crate::error::Result;
 
@@ -12,9 +13,11 @@ use crate::error::Result;
 /// - Uses `DirectoryScanner::with_gitignore` when `use_gitignore` is enabled
 /// - Uses regular `DirectoryScanner` otherwise
 /// - Applies exclude patterns via `GlobFilter`
+/// - Follows symlinks when `follow_symlinks` is enabled
 pub struct CompositeScanner {
     exclude_patterns: Vec<String>,
     pub(crate) use_gitignore: bool,
+    follow_symlinks: bool,
 }
 
 impl CompositeScanner {
@@ -24,29 +27,47 @@ impl CompositeScanner {
         Self {
             exclude_patterns,
             use_gitignore,
+            follow_symlinks: false,
         }
     }
+
+    /// Follow symlinks while scanning (`scanner.follow_symlinks`).
+    #[must_use]
+    pub const fn with_follow_symlinks(mut self, follow_symlinks: bool) -> Self {
+        self.follow_symlinks = follow_symlinks;
+        self
+    }
+
+    fn directory_scanner(&self) -> Result<DirectoryScanner<GlobFilter>> {
+        let filter = GlobFilter::new(Vec::new(), &self.exclude_patterns)?;
+        Ok(directory_scanner(
+            filter,
+            self.use_gitignore,
+            self.follow_symlinks,
+        ))
+    }
+}
+
+const fn directory_scanner(
+    filter: GlobFilter,
+    use_gitignore: bool,
+    follow_symlinks: bool,
+) -> DirectoryScanner<GlobFilter> {
+    let scanner = if use_gitignore {
+        DirectoryScanner::with_gitignore(filter, true)
+    } else {
+        DirectoryScanner::new(filter)
+    };
+    scanner.with_follow_symlinks(follow_symlinks)
 }
 
 impl FileScanner for CompositeScanner {
     fn scan(&self, root: &Path) -> Result<Vec<PathBuf>> {
-        let filter = GlobFilter::new(Vec::new(), &self.exclude_patterns)?;
-        if self.use_gitignore {
-            let scanner = DirectoryScanner::with_gitignore(filter, true);
-            scanner.scan(root)
-        } else {
-            let scanner = DirectoryScanner::new(filter);
-            scanner.scan(root)
-        }
+        self.directory_scanner()?.scan(root)
     }
 
     fn scan_all(&self, paths: &[PathBuf]) -> Result<Vec<PathBuf>> {
-        let filter = GlobFilter::new(Vec::new(), &self.exclude_patterns)?;
-        let scanner = if self.use_gitignore {
-            DirectoryScanner::with_gitignore(filter, true)
-        } else {
-            DirectoryScanner::new(filter)
-        };
+        let scanner = self.directory_scanner()?;
 
         let mut all_files = Vec::new();
         for path in paths {
@@ -60,36 +81,35 @@ impl FileScanner for CompositeScanner {
         root: &Path,
         structure_config: Option<&StructureScanConfig>,
     ) -> Result<ScanResult> {
-        let filter = GlobFilter::new(Vec::new(), &self.exclude_patterns)?;
-        if self.use_gitignore {
-            let scanner = DirectoryScanner::with_gitignore(filter, true);
-            scanner.scan_with_structure(root, structure_config)
-        } else {
-            let scanner = DirectoryScanner::new(filter);
-            scanner.scan_with_structure(root, structure_config)
-        }
+        self.directory_scanner()?
+            .scan_with_structure(root, structure_config)
     }
 
     fn scan_all_with_structure(
         &self,
         paths: &[PathBuf],
         structure_config: Option<&StructureScanConfig>,
+    ) -> Result<ScanResult> {
+        let scanner = self.directory_scanner()?;
+
+        let mut combined = ScanResult::default();
+        for path in paths {
+            combined.merge(scanner.scan_with_structure(path, structure_config)?);
+        }
+        Ok(combined)
+    }
+
+    fn scan_tracked_with_structure(
+        &self,
+        paths: &[PathBuf],
+        tree: &dyn TrackedTree,
+        structure_config: Option<&StructureScanConfig>,
     ) -> Result<ScanResult> {
         let filter = GlobFilter::new(Vec::new(), &self.exclude_patterns)?;
-        let scanner = if self.use_gitignore {
-            DirectoryScanner::with_gitignore(filter, true)
-        } else {
-            DirectoryScanner::new(filter)
-        };
 
         let mut combined = ScanResult::default();
         for path in paths {
-            let result = scanner.scan_with_structure(path, structure_config)?;
-            combined.files.extend(result.files);
-            combined.dir_stats.extend(result.dir_stats);
-            combined
-                .allowlist_violations
-                .extend(result.allowlist_violations);
+            combined.merge(scan_tracked_tree(path, tree, &filter, structure_config));
         }
         Ok(combined)
     }
@@ -101,7 +121,8 @@ impl FileScanner for CompositeScanner {
 /// Extension filtering should be done by the caller (e.g., `ThresholdChecker`).
 ///
 /// Uses `DirectoryScanner::with_gitignore` when `use_gitignore` is true to
-/// respect .gitignore patterns (works even outside git repositories).
+/// respect .gitignore patterns (works even outside git repositories), and follows
+/// symlinks when `follow_symlinks` is true.
 ///
 /// # Errors
 /// Returns an error if the directory cannot be read or if glob patterns are invalid.
@@ -109,13 +130,10 @@ pub fn scan_files(
     paths: &[PathBuf],
     exclude_patterns: &[String],
     use_gitignore: bool,
+    follow_symlinks: bool,
 ) -> Result<Vec<PathBuf>> {
     let filter = GlobFilter::new(Vec::new(), exclude_patterns)?;
-    let scanner = if use_gitignore {
-        DirectoryScanner::with_gitignore(filter, true)
-    } else {
-        DirectoryScanner::new(filter)
-    };
+    let scanner = directory_scanner(filter, use_gitignore, follow_symlinks);
 
     let mut all_files = Vec::new();
     for path in paths {
@@ -253,7 +253,7 @@ fn scan_files_without_gitignore() {
     std::fs::write(temp_dir.path().join("test.rs"), "").unwrap();
     std::fs::write(temp_dir.path().join("lib.rs"), "").unwrap();
 
-    let files = scan_files(&[temp_dir.path().to_path_buf()], &[], false).unwrap();
+    let files = scan_files(&[temp_dir.path().to_path_buf()], &[], false, false).unwrap();
     assert_eq!(files.len(), 2);
 }
 
@@ -268,6 +268,7 @@ fn scan_files_with_exclude() {
         &[temp_dir.path().to_path_buf()],
         &["**/vendor/**".to_string()],
         false,
+        false,
     )
     .unwrap();
     assert_eq!(files.len(), 1);
@@ -278,7 +279,7 @@ fn scan_files_with_gitignore_true_fallback() {
     let temp_dir = TempDir::new().unwrap();
     std::fs::write(temp_dir.path().join("test.rs"), "").unwrap();
 
-    let files = scan_files(&[temp_dir.path().to_path_buf()], &[], true);
+    let files = scan_files(&[temp_dir.path().to_path_buf()], &[], true, false);
     assert!(files.is_ok());
 }
 
@@ -288,7 +289,7 @@ fn scan_files_function_basic() {
     std::fs::write(temp_dir.path().join("test.rs"), "").unwrap();
     std::fs::write(temp_dir.path().join("lib.rs"), "").unwrap();
 
-    let files = scan_files(&[temp_dir.path().to_path_buf()], &[], false).unwrap();
+    let files = scan_files(&[temp_dir.path().to_path_buf()], &[], false, false).unwrap();
     assert_eq!(files.len(), 2);
 }
 
@@ -304,6 +305,7 @@ fn scan_files_function_with_exclude() {
         &[temp_dir.path().to_path_buf()],
         &["**/node_modules/**".to_string()],
         false,
+        false,
     )
     .unwrap();
     assert_eq!(files.len(), 1);
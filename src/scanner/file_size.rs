@@ -0,0 +1,185 @@
+//! File size limits (`max_file_bytes`), checked during the structure scan from
+//! each file's metadata, so they cover assets and data files as well as code.
+
+use std::path::Path;
+
+use globset::{Glob, GlobSet, GlobSetBuilder};
+
+use crate::SlocGuardError;
+use crate::checker::{RuleRank, ScopeSelector, StructureViolation, select_winner};
+use crate::config::{RuleResolution, StructureConfig, StructureRule, UNLIMITED};
+use crate::error::Result;
+use crate::output::path::normalize_for_matching;
+
+/// Per-rule size limit applying to files in directories matching `scope`.
+#[derive(Debug, Clone)]
+pub struct FileSizeRule {
+    /// Scope label of the rule (its glob, for single-scope rules).
+    pub scope: String,
+    selector: ScopeSelector,
+    /// Explicit `priority`, ranked together with the matching scope's specificity.
+    pub priority: Option<i32>,
+    /// Limit in bytes, or `-1` for unlimited.
+    pub max_file_bytes: i64,
+}
+
+impl FileSizeRule {
+    /// Compile a rule.
+    ///
+    /// # Errors
+    /// Returns `SlocGuardError::InvalidPattern` if the scope is not a valid glob.
+    pub fn new(scope: String, max_file_bytes: i64) -> Result<Self> {
+        Ok(Self {
+            selector: ScopeSelector::from_glob(&scope)?,
+            scope,
+            priority: None,
+            max_file_bytes,
+        })
+    }
+
+    /// Create a rule for an already compiled scope.
+    #[must_use]
+    pub fn from_selector(selector: ScopeSelector, max_file_bytes: i64) -> Self {
+        Self {
+            scope: selector.label().to_string(),
+            selector,
+            priority: None,
+            max_file_bytes,
+        }
+    }
+
+    /// Set the explicit `priority` used when several rules match a directory.
+    #[must_use]
+    pub const fn with_priority(mut self, priority: Option<i32>) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Check if a directory path matches this rule's scope.
+    #[must_use]
+    pub fn matches_directory(&self, dir: &Path) -> bool {
+        self.selector.is_match(normalize_for_matching(dir))
+    }
+
+    /// Priority and specificity of the scope matching `dir`, to pick among overlapping rules.
+    pub(crate) fn rank(&self, dir: &Path) -> RuleRank {
+        let normalized = normalize_for_matching(dir);
+        RuleRank::with_specificity(self.selector.specificity(&normalized), self.priority)
+    }
+}
+
+/// Global size limit, per-rule overrides, and files exempt from both.
+#[derive(Debug, Clone, Default)]
+pub struct FileSizeLimits {
+    /// Global limit in bytes from `[structure]`.
+    pub max_file_bytes: Option<i64>,
+    /// Rules in declaration order; among those matching a file's parent directory, the
+    /// winner is picked like any structure rule (`priority`, then `rule_resolution`).
+    pub rules: Vec<FileSizeRule>,
+    /// How overlapping rules of equal priority are resolved.
+    pub rule_resolution: RuleResolution,
+    /// Compiled `allow_large_files` patterns.
+    allow: GlobSet,
+}
+
+impl FileSizeLimits {
+    /// Create limits with a global default and `allow_large_files` patterns.
+    ///
+    /// # Errors
+    /// Returns `SlocGuardError::InvalidPattern` if an allow pattern is not a valid glob.
+    pub fn new(max_file_bytes: Option<i64>, allow_patterns: &[String]) -> Result<Self> {
+        let mut builder = GlobSetBuilder::new();
+        for pattern in allow_patterns {
+            builder.add(
+                Glob::new(pattern).map_err(|source| SlocGuardError::InvalidPattern {
+                    pattern: pattern.clone(),
+                    source,
+                })?,
+            );
+        }
+        let allow = builder
+            .build()
+            .map_err(|source| SlocGuardError::InvalidPattern {
+                pattern: "allow_large_files".to_string(),
+                source,
+            })?;
+        Ok(Self {
+            max_file_bytes,
+            rules: Vec::new(),
+            rule_resolution: RuleResolution::default(),
+            allow,
+        })
+    }
+
+    /// Limits from `[structure]` `max_file_bytes` and `allow_large_files`.
+    ///
+    /// # Errors
+    /// Returns `SlocGuardError::InvalidPattern` if an allow pattern is not a valid glob.
+    pub fn from_config(config: &StructureConfig) -> Result<Self> {
+        Self::new(config.max_file_bytes, &config.allow_large_files)
+    }
+
+    /// Set how overlapping rules of equal priority are resolved.
+    #[must_use]
+    pub const fn with_rule_resolution(mut self, rule_resolution: RuleResolution) -> Self {
+        self.rule_resolution = rule_resolution;
+        self
+    }
+
+    /// Add the `max_file_bytes` override of a `[[structure.rules]]` entry, if it has one.
+    ///
+    /// # Errors
+    /// Returns `SlocGuardError::InvalidPattern` if a rule scope is not a valid glob.
+    pub fn push_rule(&mut self, rule: &StructureRule) -> Result<()> {
+        if let Some(max_file_bytes) = rule.max_file_bytes {
+            self.rules.push(
+                FileSizeRule::from_selector(ScopeSelector::new(rule)?, max_file_bytes)
+                    .with_priority(rule.priority),
+            );
+        }
+        Ok(())
+    }
+
+    /// Whether a global or per-rule limit is configured.
+    #[must_use]
+    pub const fn is_enabled(&self) -> bool {
+        self.max_file_bytes.is_some() || !self.rules.is_empty()
+    }
+
+    /// The rule whose limit applies to files directly in `dir`, if any matches.
+    fn winning_rule(&self, dir: &Path) -> Option<&FileSizeRule> {
+        let matching = self
+            .rules
+            .iter()
+            .enumerate()
+            .filter(|(_, rule)| rule.matches_directory(dir))
+            .map(|(index, _)| index);
+        select_winner(
+            matching,
+            |index| self.rules[index].rank(dir),
+            self.rule_resolution,
+        )
+        .map(|index| &self.rules[index])
+    }
+
+    /// Violation for a file of `size` bytes, if it exceeds its limit.
+    pub(crate) fn violation(&self, path: &Path, size: u64) -> Option<StructureViolation> {
+        let rule = path.parent().and_then(|parent| self.winning_rule(parent));
+        let limit = rule.map_or(self.max_file_bytes, |r| Some(r.max_file_bytes));
+        let limit = u64::try_from(limit.filter(|&l| l != UNLIMITED)?).ok()?;
+        if size <= limit {
+            return None;
+        }
+
+        let name = path.file_name().unwrap_or_default();
+        if self.allow.is_match(name) || self.allow.is_match(normalize_for_matching(path)) {
+            return None;
+        }
+        Some(StructureViolation::file_size(
+            path.to_path_buf(),
+            usize::try_from(size).unwrap_or(usize::MAX),
+            usize::try_from(limit).unwrap_or(usize::MAX),
+            rule.map(|r| r.scope.clone()),
+        ))
+    }
+}
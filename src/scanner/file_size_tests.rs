@@ -0,0 +1,215 @@
+use std::path::{Path, PathBuf};
+
+use tempfile::TempDir;
+
+use super::*;
+use crate::checker::{StructureViolation, ViolationType};
+use crate::config::RuleResolution;
+use crate::scanner::TestConfigParams;
+
+struct AcceptAllFilter;
+
+impl FileFilter for AcceptAllFilter {
+    fn should_include(&self, _path: &Path) -> bool {
+        true
+    }
+}
+
+fn summary(violation: &StructureViolation) -> (ViolationType, usize, usize, Option<String>) {
+    (
+        violation.violation_type.clone(),
+        violation.actual,
+        violation.limit,
+        violation.triggering_rule_pattern.clone(),
+    )
+}
+
+// =============================================================================
+// FileSizeLimits Tests
+// =============================================================================
+
+#[test]
+fn violation_when_file_exceeds_global_limit() {
+    let limits = FileSizeLimits::new(Some(100), &[]).unwrap();
+
+    assert!(limits.violation(Path::new("data/a.bin"), 100).is_none());
+    let violation = limits.violation(Path::new("data/a.bin"), 101).unwrap();
+    assert_eq!(
+        summary(&violation),
+        (ViolationType::FileSize, 101, 100, None)
+    );
+    assert_eq!(violation.path, PathBuf::from("data/a.bin"));
+}
+
+#[test]
+fn no_violation_without_limit() {
+    let limits = FileSizeLimits::new(None, &[]).unwrap();
+
+    assert!(!limits.is_enabled());
+    assert!(limits.violation(Path::new("a.bin"), u64::MAX).is_none());
+}
+
+#[test]
+fn last_matching_rule_overrides_global_limit() {
+    let mut limits = FileSizeLimits::new(Some(100), &[]).unwrap();
+    limits
+        .rules
+        .push(FileSizeRule::new("fixtures".to_string(), 10).unwrap());
+    limits
+        .rules
+        .push(FileSizeRule::new("fixtures".to_string(), 1000).unwrap());
+
+    assert!(
+        limits
+            .violation(Path::new("fixtures/big.json"), 500)
+            .is_none()
+    );
+    let violation = limits
+        .violation(Path::new("fixtures/big.json"), 5000)
+        .unwrap();
+    assert_eq!(
+        summary(&violation),
+        (
+            ViolationType::FileSize,
+            5000,
+            1000,
+            Some("fixtures".to_string())
+        )
+    );
+    // Outside the rule scope the global limit applies
+    assert!(limits.violation(Path::new("src/big.json"), 500).is_some());
+}
+
+#[test]
+fn most_specific_rule_wins_regardless_of_order() {
+    let mut limits = FileSizeLimits::new(None, &[])
+        .unwrap()
+        .with_rule_resolution(RuleResolution::MostSpecific);
+    limits
+        .rules
+        .push(FileSizeRule::new("assets/fonts".to_string(), 10_240).unwrap());
+    limits
+        .rules
+        .push(FileSizeRule::new("assets/**".to_string(), 2048).unwrap());
+
+    assert!(
+        limits
+            .violation(Path::new("assets/fonts/a.woff2"), 5000)
+            .is_none()
+    );
+    let violation = limits
+        .violation(Path::new("assets/img/logo.png"), 5000)
+        .unwrap();
+    assert_eq!(
+        violation.triggering_rule_pattern.as_deref(),
+        Some("assets/**")
+    );
+}
+
+#[test]
+fn rule_priority_beats_declaration_order() {
+    let mut limits = FileSizeLimits::new(None, &[]).unwrap();
+    limits.rules.push(
+        FileSizeRule::new("assets/fonts".to_string(), 10_240)
+            .unwrap()
+            .with_priority(Some(10)),
+    );
+    limits
+        .rules
+        .push(FileSizeRule::new("assets/**".to_string(), 2048).unwrap());
+
+    assert!(
+        limits
+            .violation(Path::new("assets/fonts/a.woff2"), 5000)
+            .is_none()
+    );
+}
+
+#[test]
+fn unlimited_rule_disables_global_limit() {
+    let mut limits = FileSizeLimits::new(Some(100), &[]).unwrap();
+    limits
+        .rules
+        .push(FileSizeRule::new("vendor/**".to_string(), -1).unwrap());
+
+    assert!(
+        limits
+            .violation(Path::new("vendor/lib/huge.js"), 1_000_000)
+            .is_none()
+    );
+}
+
+#[test]
+fn allow_large_files_matches_names_and_paths() {
+    let limits = FileSizeLimits::new(
+        Some(100),
+        &["*.woff2".to_string(), "assets/models/**".to_string()],
+    )
+    .unwrap();
+
+    assert!(limits.violation(Path::new("web/font.woff2"), 500).is_none());
+    assert!(
+        limits
+            .violation(Path::new("./assets/models/weights.bin"), 500)
+            .is_none()
+    );
+    assert!(
+        limits
+            .violation(Path::new("assets/logo.png"), 500)
+            .is_some()
+    );
+}
+
+#[test]
+fn invalid_allow_pattern_is_rejected() {
+    assert!(FileSizeLimits::new(Some(100), &["[".to_string()]).is_err());
+}
+
+// =============================================================================
+// Scan Integration Tests
+// =============================================================================
+
+#[test]
+fn scan_reports_large_files_of_any_type() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::create_dir(temp_dir.path().join("assets")).unwrap();
+    std::fs::write(temp_dir.path().join("assets/logo.png"), vec![0u8; 2048]).unwrap();
+    std::fs::write(temp_dir.path().join("small.txt"), "tiny").unwrap();
+
+    let config = StructureScanConfig::new(TestConfigParams {
+        file_size_limits: FileSizeLimits::new(Some(1024), &[]).unwrap(),
+        ..Default::default()
+    })
+    .unwrap();
+    let violations = DirectoryScanner::new(AcceptAllFilter)
+        .scan_with_structure(temp_dir.path(), Some(&config))
+        .unwrap()
+        .allowlist_violations;
+
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].path, temp_dir.path().join("assets/logo.png"));
+    assert_eq!(
+        summary(&violations[0]),
+        (ViolationType::FileSize, 2048, 1024, None)
+    );
+}
+
+#[test]
+fn scan_checks_count_excluded_files() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(temp_dir.path().join("dump.sql"), vec![0u8; 2048]).unwrap();
+
+    let config = StructureScanConfig::new(TestConfigParams {
+        count_exclude_patterns: vec!["*.sql".to_string()],
+        file_size_limits: FileSizeLimits::new(Some(1024), &[]).unwrap(),
+        ..Default::default()
+    })
+    .unwrap();
+    let violations = DirectoryScanner::new(AcceptAllFilter)
+        .scan_with_structure(temp_dir.path(), Some(&config))
+        .unwrap()
+        .allowlist_violations;
+
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].violation_type, ViolationType::FileSize);
+}
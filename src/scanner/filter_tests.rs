@@ -50,6 +50,15 @@ fn filter_exclude_specific_files() {
     assert!(!filter.should_include(Path::new("src/code.generated.rs")));
 }
 
+#[test]
+fn filter_exclude_matches_dot_slash_prefixed_paths() {
+    let filter = GlobFilter::new(vec![], &[".git/**".to_string()]).unwrap();
+
+    assert!(!filter.should_include(Path::new("./.git/config")));
+    assert!(!filter.should_include(Path::new(".git/config")));
+    assert!(filter.should_include(Path::new("./src/.gitignore")));
+}
+
 #[test]
 fn filter_invalid_pattern_returns_error() {
     let result = GlobFilter::new(vec![], &["[invalid".to_string()]);
@@ -0,0 +1,140 @@
+use std::path::Path;
+
+use super::{AllowlistRule, AllowlistRuleBuilder, RequiredFilesRule};
+use crate::SlocGuardError;
+use crate::checker::{LayoutProblem, ScopeSelector, StructureViolation};
+use crate::config::{StructureLayout, Template};
+use crate::error::Result;
+use crate::output::path::normalize_for_matching;
+
+/// File and subdirectory names of a scanned directory.
+#[derive(Debug, Default)]
+pub struct EntryNames {
+    pub files: Vec<String>,
+    pub dirs: Vec<String>,
+}
+
+/// A compiled `[[structure.layouts]]` template.
+///
+/// Required entries are checked like `require_files` (directories against
+/// subdirectory names); `forbid_other` checks entries against an allowlist of
+/// every listed entry, like `allow_files`/`allow_dirs`.
+#[derive(Debug, Clone)]
+pub struct LayoutRule {
+    /// Template name.
+    pub name: String,
+    /// Glob pattern selecting the directories the template describes.
+    pub scope: String,
+    selector: ScopeSelector,
+    required_files: RequiredFilesRule,
+    /// Required subdirectory names, without the trailing `/`.
+    required_dirs: RequiredFilesRule,
+    /// `required` and `optional` entries as `allow_files`/`allow_dirs`.
+    allowlist: AllowlistRule,
+    /// Report entries matching neither `required` nor `optional`.
+    pub forbid_other: bool,
+}
+
+impl LayoutRule {
+    /// Compile a template.
+    ///
+    /// # Errors
+    /// Returns `SlocGuardError::InvalidPattern` if the scope or any entry is not a valid
+    /// glob, or `SlocGuardError::Config` if an entry uses a placeholder.
+    pub fn new(layout: &StructureLayout) -> Result<Self> {
+        // `services/*` describes each service, not the directories inside it
+        let selector = ScopeSelector::from_glob_exact(&layout.scope)?;
+        let (required_dirs, required_files) = split_entries(&layout.required)?;
+        let (optional_dirs, optional_files) = split_entries(&layout.optional)?;
+        let allowlist = AllowlistRuleBuilder::new(layout.scope.clone())
+            .with_scope_selector(selector.clone())
+            .with_allow_files([required_files.as_slice(), &optional_files].concat())
+            .with_allow_dirs([required_dirs.as_slice(), &optional_dirs].concat())
+            .build()?;
+        Ok(Self {
+            name: layout.name.clone(),
+            scope: layout.scope.clone(),
+            required_files: RequiredFilesRule::from_selector(
+                selector.clone(),
+                required_files,
+                false,
+            )?,
+            required_dirs: RequiredFilesRule::from_selector(
+                selector.clone(),
+                required_dirs,
+                false,
+            )?,
+            selector,
+            allowlist,
+            forbid_other: layout.forbid_other,
+        })
+    }
+
+    /// Check if a directory path matches this template's scope.
+    #[must_use]
+    pub fn matches_directory(&self, dir: &Path) -> bool {
+        self.selector.is_match(normalize_for_matching(dir))
+    }
+
+    /// Missing required directories and files of `dir`, then (with `forbid_other`)
+    /// its unexpected entries.
+    #[must_use]
+    pub fn violations(&self, dir: &Path, entries: &EntryNames) -> Vec<StructureViolation> {
+        let missing_dirs = self
+            .required_dirs
+            .missing(dir, &entries.dirs)
+            .into_iter()
+            .map(|name| format!("{name}/"));
+        let missing_files = self.required_files.missing(dir, &entries.files);
+        let missing = missing_dirs
+            .chain(missing_files)
+            .map(|entry| (dir.to_path_buf(), entry, LayoutProblem::Missing));
+
+        let mut unexpected = Vec::new();
+        if self.forbid_other {
+            for name in &entries.files {
+                let path = dir.join(name);
+                if !self.allowlist.file_matches(&path) {
+                    unexpected.push((path, name.clone(), LayoutProblem::Unexpected));
+                }
+            }
+            for name in &entries.dirs {
+                let path = dir.join(name);
+                if !self.allowlist.dir_matches(&path) {
+                    unexpected.push((path, format!("{name}/"), LayoutProblem::Unexpected));
+                }
+            }
+            unexpected.sort_by(|a, b| a.0.cmp(&b.0));
+        }
+
+        missing
+            .chain(unexpected)
+            .map(|(path, entry, problem)| {
+                StructureViolation::layout(
+                    path,
+                    self.scope.clone(),
+                    self.name.clone(),
+                    entry,
+                    problem,
+                )
+            })
+            .collect()
+    }
+}
+
+/// Directory entries (names ending with `/`, stripped) and file entries of a template.
+///
+/// Entries are plain globs; `{dir}`/`{parent}` placeholders are rejected.
+fn split_entries(entries: &[String]) -> Result<(Vec<String>, Vec<String>)> {
+    let mut dirs = Vec::new();
+    let mut files = Vec::new();
+    for entry in entries {
+        Template::parse(entry, &[])
+            .map_err(|e| SlocGuardError::Config(format!("Invalid layout entry '{entry}': {e}")))?;
+        match entry.strip_suffix('/') {
+            Some(name) => dirs.push(name.to_string()),
+            None => files.push(entry.clone()),
+        }
+    }
+    Ok((dirs, files))
+}
@@ -0,0 +1,230 @@
+use std::path::{Path, PathBuf};
+
+use tempfile::TempDir;
+
+use super::layout::EntryNames;
+use super::*;
+use crate::checker::{LayoutProblem, StructureViolation, ViolationType};
+use crate::config::StructureLayout;
+use crate::scanner::TestConfigParams;
+
+struct AcceptAllFilter;
+
+impl FileFilter for AcceptAllFilter {
+    fn should_include(&self, _path: &Path) -> bool {
+        true
+    }
+}
+
+fn strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(ToString::to_string).collect()
+}
+
+fn go_service(forbid_other: bool) -> LayoutRule {
+    LayoutRule::new(&StructureLayout {
+        name: "go-service".to_string(),
+        scope: "**/services/*".to_string(),
+        required: strings(&["cmd/", "internal/", "go.mod"]),
+        optional: strings(&["docs/", "*.md"]),
+        forbid_other,
+    })
+    .unwrap()
+}
+
+fn layout_problem(violation: &StructureViolation) -> (String, LayoutProblem) {
+    match &violation.violation_type {
+        ViolationType::Layout {
+            layout,
+            entry,
+            problem,
+        } => {
+            assert_eq!(layout, "go-service");
+            (entry.clone(), *problem)
+        }
+        other => panic!("expected a layout violation, got {other:?}"),
+    }
+}
+
+fn scan(root: &Path, rules: Vec<LayoutRule>) -> Vec<StructureViolation> {
+    let config = StructureScanConfig::new(TestConfigParams {
+        layout_rules: rules,
+        ..Default::default()
+    })
+    .unwrap();
+    DirectoryScanner::new(AcceptAllFilter)
+        .scan_with_structure(root, Some(&config))
+        .unwrap()
+        .allowlist_violations
+}
+
+// =============================================================================
+// LayoutRule Tests
+// =============================================================================
+
+#[test]
+fn complete_directory_has_no_violations() {
+    let entries = EntryNames {
+        files: strings(&["go.mod", "README.md"]),
+        dirs: strings(&["cmd", "internal", "docs"]),
+    };
+
+    assert!(
+        go_service(true)
+            .violations(Path::new("services/api"), &entries)
+            .is_empty()
+    );
+}
+
+#[test]
+fn missing_required_entries_are_reported_on_the_directory() {
+    let entries = EntryNames {
+        // A file named like a required directory does not count
+        files: strings(&["cmd"]),
+        dirs: strings(&["internal"]),
+    };
+    let violations = go_service(false).violations(Path::new("services/api"), &entries);
+
+    let found: Vec<_> = violations.iter().map(layout_problem).collect();
+    assert_eq!(
+        found,
+        vec![
+            ("cmd/".to_string(), LayoutProblem::Missing),
+            ("go.mod".to_string(), LayoutProblem::Missing),
+        ]
+    );
+    assert!(
+        violations
+            .iter()
+            .all(|v| v.path == Path::new("services/api"))
+    );
+    assert_eq!(
+        violations[0].triggering_rule_pattern.as_deref(),
+        Some("**/services/*")
+    );
+}
+
+#[test]
+fn forbid_other_reports_unlisted_entries() {
+    let entries = EntryNames {
+        files: strings(&["go.mod", "Makefile"]),
+        dirs: strings(&["cmd", "internal", "scripts"]),
+    };
+
+    let violations = go_service(true).violations(Path::new("services/api"), &entries);
+    let found: Vec<_> = violations
+        .iter()
+        .map(|v| (v.path.clone(), layout_problem(v)))
+        .collect();
+    assert_eq!(
+        found,
+        vec![
+            (
+                PathBuf::from("services/api/Makefile"),
+                ("Makefile".to_string(), LayoutProblem::Unexpected)
+            ),
+            (
+                PathBuf::from("services/api/scripts"),
+                ("scripts/".to_string(), LayoutProblem::Unexpected)
+            ),
+        ]
+    );
+
+    // Without forbid_other, extra entries are fine
+    assert!(
+        go_service(false)
+            .violations(Path::new("services/api"), &entries)
+            .is_empty()
+    );
+}
+
+#[test]
+fn invalid_entry_is_rejected() {
+    let layout = StructureLayout {
+        name: "bad".to_string(),
+        scope: "services/*".to_string(),
+        required: strings(&["[bad/"]),
+        ..Default::default()
+    };
+    assert!(LayoutRule::new(&layout).is_err());
+}
+
+// =============================================================================
+// Scan Integration Tests
+// =============================================================================
+
+#[test]
+fn scan_checks_each_matching_directory() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    for dir in [
+        "services/api/cmd",
+        "services/api/internal",
+        "services/web/cmd",
+    ] {
+        std::fs::create_dir_all(root.join(dir)).unwrap();
+    }
+    std::fs::write(root.join("services/api/go.mod"), "").unwrap();
+    std::fs::write(root.join("services/web/go.mod"), "").unwrap();
+    std::fs::write(root.join("services/web/notes.txt"), "").unwrap();
+    // Nested entries are not part of the top-level layout
+    std::fs::write(root.join("services/api/cmd/main.go"), "").unwrap();
+
+    let violations = scan(root, vec![go_service(true)]);
+    let mut found: Vec<_> = violations
+        .iter()
+        .map(|v| (v.path.clone(), layout_problem(v)))
+        .collect();
+    found.sort_by(|a, b| a.0.cmp(&b.0));
+
+    assert_eq!(
+        found,
+        vec![
+            (
+                root.join("services/web"),
+                ("internal/".to_string(), LayoutProblem::Missing)
+            ),
+            (
+                root.join("services/web/notes.txt"),
+                ("notes.txt".to_string(), LayoutProblem::Unexpected)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn scope_wildcard_does_not_select_nested_directories() {
+    let rule = LayoutRule::new(&StructureLayout {
+        name: "go-service".to_string(),
+        scope: "services/*".to_string(),
+        required: strings(&["cmd/", "internal/", "go.mod"]),
+        optional: strings(&["docs/"]),
+        forbid_other: true,
+    })
+    .unwrap();
+
+    assert!(rule.matches_directory(Path::new("services/api")));
+    assert!(rule.matches_directory(Path::new("./services/api")));
+    assert!(!rule.matches_directory(Path::new("services/api/cmd")));
+    assert!(!rule.matches_directory(Path::new("services/api/internal/db")));
+}
+
+#[test]
+fn scan_reports_empty_matching_directory() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::create_dir_all(temp_dir.path().join("services/empty")).unwrap();
+
+    let violations = scan(temp_dir.path(), vec![go_service(false)]);
+
+    assert_eq!(violations.len(), 3);
+}
+
+#[test]
+fn placeholder_entry_is_rejected() {
+    let layout = StructureLayout {
+        name: "bad".to_string(),
+        scope: "services/*".to_string(),
+        required: strings(&["{dir}.go"]),
+        ..Default::default()
+    };
+    assert!(LayoutRule::new(&layout).is_err());
+}
@@ -1,14 +1,28 @@
 mod allowlist;
 mod composite;
 mod directory;
+mod file_size;
 mod filter;
+mod layout;
+mod path_limits;
+mod portability;
+mod required_files;
+mod scan_state;
 mod structure_config;
+mod symlinks;
+mod tracked;
 
 pub use allowlist::{AllowlistRule, AllowlistRuleBuilder};
 pub use composite::{CompositeScanner, scan_files};
 pub use directory::DirectoryScanner;
+pub use file_size::{FileSizeLimits, FileSizeRule};
 pub use filter::{FileFilter, GlobFilter};
+pub use layout::LayoutRule;
+pub use path_limits::{PathLimits, PathLimitsConfig, PathLimitsRule};
+pub use required_files::RequiredFilesRule;
 pub use structure_config::StructureScanConfig;
+pub use symlinks::{SymlinkPolicy, resolve_symlink};
+pub use tracked::{TrackedTree, TreeScanner, absolute_path, scan_tracked_tree};
 
 #[cfg(test)]
 pub use structure_config::TestConfigParams;
@@ -30,6 +44,15 @@ pub struct ScanResult {
     pub allowlist_violations: Vec<StructureViolation>,
 }
 
+impl ScanResult {
+    /// Add the files, directory stats and violations of another scan.
+    pub fn merge(&mut self, other: Self) {
+        self.files.extend(other.files);
+        self.dir_stats.extend(other.dir_stats);
+        self.allowlist_violations.extend(other.allowlist_violations);
+    }
+}
+
 /// Trait for scanning directories and finding files.
 ///
 /// Implementations must be thread-safe (`Send + Sync`) for parallel processing.
@@ -77,15 +100,22 @@ pub trait FileScanner: Send + Sync {
     ) -> Result<ScanResult> {
         let mut combined = ScanResult::default();
         for path in paths {
-            let result = self.scan_with_structure(path, structure_config)?;
-            combined.files.extend(result.files);
-            combined.dir_stats.extend(result.dir_stats);
-            combined
-                .allowlist_violations
-                .extend(result.allowlist_violations);
+            combined.merge(self.scan_with_structure(path, structure_config)?);
         }
         Ok(combined)
     }
+
+    /// Scan the files of `tree` under `paths` instead of the filesystem, with the same
+    /// filtering and structure statistics as `scan_all_with_structure`.
+    ///
+    /// # Errors
+    /// Returns an error if the scanner's filter cannot be built.
+    fn scan_tracked_with_structure(
+        &self,
+        paths: &[PathBuf],
+        tree: &dyn TrackedTree,
+        structure_config: Option<&StructureScanConfig>,
+    ) -> Result<ScanResult>;
 }
 
 #[cfg(test)]
@@ -95,12 +125,26 @@ mod composite_tests;
 #[cfg(test)]
 mod directory_tests;
 #[cfg(test)]
+mod file_size_tests;
+#[cfg(test)]
 mod global_allow_tests;
 #[cfg(test)]
 mod global_deny_tests;
 #[cfg(test)]
+mod layout_tests;
+#[cfg(test)]
 mod naming_pattern_tests;
 #[cfg(test)]
+mod path_limits_tests;
+#[cfg(test)]
+mod portability_tests;
+#[cfg(test)]
+mod required_files_tests;
+#[cfg(test)]
 mod structure_config_tests;
 #[cfg(test)]
 mod structure_scan_tests;
+#[cfg(test)]
+mod symlinks_tests;
+#[cfg(test)]
+mod tracked_tests;
@@ -2,6 +2,7 @@ use std::path::Path;
 
 use super::*;
 use crate::checker::ViolationType;
+use crate::config::{NamingStem, NamingStyle};
 use crate::scanner::TestConfigParams;
 use tempfile::TempDir;
 
@@ -162,8 +163,12 @@ fn scan_with_structure_naming_violation_includes_pattern() {
 
     assert_eq!(result.allowlist_violations.len(), 1);
     match &result.allowlist_violations[0].violation_type {
-        ViolationType::NamingConvention { expected_pattern } => {
+        ViolationType::NamingConvention {
+            expected_pattern,
+            is_directory,
+        } => {
             assert_eq!(expected_pattern, &pattern);
+            assert!(!is_directory);
         }
         _ => panic!("Expected NamingConvention violation"),
     }
@@ -236,3 +241,189 @@ fn disallowed_file_does_not_trigger_naming_violation() {
         ViolationType::DisallowedFile
     ));
 }
+
+// =============================================================================
+// Naming Styles and Directory Naming Tests
+// =============================================================================
+
+fn scan_with_rule(
+    temp_dir: &TempDir,
+    rule: AllowlistRule,
+) -> Vec<crate::checker::StructureViolation> {
+    let config = StructureScanConfig::new(TestConfigParams {
+        allowlist_rules: vec![rule],
+        ..Default::default()
+    })
+    .unwrap();
+    let scanner = DirectoryScanner::new(AcceptAllFilter);
+    scanner
+        .scan_with_structure(temp_dir.path(), Some(&config))
+        .unwrap()
+        .allowlist_violations
+}
+
+#[test]
+fn allowlist_rule_file_naming_style_uses_stem() {
+    let rule = AllowlistRuleBuilder::new("src/**".to_string())
+        .with_file_naming(Some(NamingStyle::KebabCase))
+        .build()
+        .unwrap();
+
+    assert!(rule.filename_matches_naming_pattern(Path::new("user-card.test.tsx")));
+    assert_eq!(
+        rule.file_naming_mismatch(Path::new("UserCard.tsx")),
+        Some("kebab-case".to_string())
+    );
+}
+
+#[test]
+fn allowlist_rule_last_dot_stem_checks_inner_segments() {
+    let rule = AllowlistRuleBuilder::new("src/**".to_string())
+        .with_file_naming(Some(NamingStyle::SnakeCase))
+        .with_naming_stem(NamingStem::LastDot)
+        .build()
+        .unwrap();
+
+    assert!(rule.filename_matches_naming_pattern(Path::new("user_card.spec.py")));
+    assert!(!rule.filename_matches_naming_pattern(Path::new("user_card.Spec.py")));
+}
+
+#[test]
+fn allowlist_rule_pattern_is_reported_before_style() {
+    let rule = AllowlistRuleBuilder::new("src/**".to_string())
+        .with_naming_pattern(Some("^[a-z-]+\\.ts$".to_string()))
+        .with_file_naming(Some(NamingStyle::KebabCase))
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        rule.file_naming_mismatch(Path::new("Button.tsx")),
+        Some("^[a-z-]+\\.ts$".to_string())
+    );
+}
+
+#[test]
+fn allowlist_rule_dir_naming_pattern_and_style() {
+    let rule = AllowlistRuleBuilder::new("src/**".to_string())
+        .with_dir_naming_pattern(Some("^[a-z]+$".to_string()))
+        .with_dir_naming(Some(NamingStyle::SnakeCase))
+        .build()
+        .unwrap();
+
+    assert!(rule.dir_naming_mismatch(Path::new("src/utils")).is_none());
+    assert_eq!(
+        rule.dir_naming_mismatch(Path::new("src/my_utils")),
+        Some("^[a-z]+$".to_string())
+    );
+}
+
+#[test]
+fn allowlist_rule_invalid_dir_naming_pattern_fails_build() {
+    let result = AllowlistRuleBuilder::new("src/**".to_string())
+        .with_dir_naming_pattern(Some("[invalid".to_string()))
+        .build();
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn allowlist_rule_naming_pattern_expands_directory_placeholders() {
+    let rule = AllowlistRuleBuilder::new("src/components/*".to_string())
+        .with_naming_pattern(Some("^({dir}|index)\\.tsx?$".to_string()))
+        .build()
+        .unwrap();
+
+    assert!(rule.filename_matches_naming_pattern(Path::new("src/components/Button/Button.tsx")));
+    assert!(rule.filename_matches_naming_pattern(Path::new("src/components/Button/index.ts")));
+    assert_eq!(
+        rule.file_naming_mismatch(Path::new("src/components/Button/Card.tsx")),
+        Some("^(Button|index)\\.tsx?$".to_string())
+    );
+    // Directory names are escaped before being substituted into the regex
+    assert!(!rule.filename_matches_naming_pattern(Path::new("src/a.b/aXb.tsx")));
+}
+
+#[test]
+#[allow(clippy::literal_string_with_formatting_args)] // {dir:snake} is template syntax
+fn allowlist_rule_naming_pattern_applies_transforms() {
+    let rule = AllowlistRuleBuilder::new("src/**".to_string())
+        .with_naming_pattern(Some("^{parent:snake}_{dir:snake}_.*\\.py$".to_string()))
+        .build()
+        .unwrap();
+
+    assert!(
+        rule.filename_matches_naming_pattern(Path::new("src/UserApi/v2/user_api_v2_models.py"))
+    );
+    assert!(!rule.filename_matches_naming_pattern(Path::new("src/UserApi/v2/models.py")));
+}
+
+#[test]
+fn allowlist_rule_naming_pattern_rejects_unknown_placeholder() {
+    let result = AllowlistRuleBuilder::new("src/**".to_string())
+        .with_naming_pattern(Some("^{stem}\\.rs$".to_string()))
+        .build();
+
+    assert!(result.unwrap_err().to_string().contains("not available"));
+}
+
+#[test]
+fn scan_with_structure_detects_dir_naming_violation() {
+    let temp_dir = TempDir::new().unwrap();
+    let src_dir = temp_dir.path().join("src");
+    std::fs::create_dir_all(src_dir.join("user-profile")).unwrap();
+    std::fs::create_dir_all(src_dir.join("UserSettings")).unwrap();
+
+    let rule = AllowlistRuleBuilder::new("**/src".to_string())
+        .with_dir_naming(Some(NamingStyle::KebabCase))
+        .build()
+        .unwrap();
+    let violations = scan_with_rule(&temp_dir, rule);
+
+    assert_eq!(violations.len(), 1);
+    assert!(violations[0].path.ends_with("UserSettings"));
+    assert_eq!(
+        violations[0].violation_type,
+        ViolationType::NamingConvention {
+            expected_pattern: "kebab-case".to_string(),
+            is_directory: true,
+        }
+    );
+}
+
+#[test]
+fn disallowed_dir_does_not_trigger_naming_violation() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::create_dir_all(temp_dir.path().join("src/Vendor")).unwrap();
+
+    let rule = AllowlistRuleBuilder::new("**/src".to_string())
+        .with_allow_dirs(vec!["lib".to_string()])
+        .with_dir_naming(Some(NamingStyle::KebabCase))
+        .build()
+        .unwrap();
+    let violations = scan_with_rule(&temp_dir, rule);
+
+    assert_eq!(violations.len(), 1);
+    assert_eq!(
+        violations[0].violation_type,
+        ViolationType::DisallowedDirectory
+    );
+}
+
+#[test]
+fn scan_with_structure_detects_file_naming_style_violation() {
+    let temp_dir = TempDir::new().unwrap();
+    let src_dir = temp_dir.path().join("src");
+    std::fs::create_dir(&src_dir).unwrap();
+    std::fs::write(src_dir.join("UserCard.tsx"), "").unwrap();
+    std::fs::write(src_dir.join("UserCard.test.tsx"), "").unwrap();
+    std::fs::write(src_dir.join("userCard.tsx"), "").unwrap();
+
+    let rule = AllowlistRuleBuilder::new("**/src".to_string())
+        .with_file_naming(Some(NamingStyle::PascalCase))
+        .build()
+        .unwrap();
+    let violations = scan_with_rule(&temp_dir, rule);
+
+    assert_eq!(violations.len(), 1);
+    assert!(violations[0].path.ends_with("userCard.tsx"));
+}
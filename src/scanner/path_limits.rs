@@ -0,0 +1,212 @@
+//! Path and name length limits (`max_path_length`, `max_filename_length`,
+//! `max_path_components`), checked during the structure scan.
+//!
+//! Paths are measured relative to the project root with `/` separators, so the
+//! result does not depend on the platform or on where the scan was started.
+
+use std::path::{Component, Path, PathBuf};
+
+use crate::checker::{RuleRank, ScopeSelector, StructureViolation, ViolationType, select_winner};
+use crate::config::{RuleResolution, StructureConfig, StructureRule, UNLIMITED};
+use crate::error::Result;
+use crate::output::path::normalize_for_matching;
+
+/// Path length limits from `[structure]` or one `[[structure.rules]]` entry.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PathLimits {
+    pub max_path_length: Option<i64>,
+    pub max_filename_length: Option<i64>,
+    pub max_path_components: Option<i64>,
+}
+
+impl PathLimits {
+    #[must_use]
+    pub const fn from_config(config: &StructureConfig) -> Self {
+        Self {
+            max_path_length: config.max_path_length,
+            max_filename_length: config.max_filename_length,
+            max_path_components: config.max_path_components,
+        }
+    }
+
+    #[must_use]
+    pub const fn from_rule(rule: &StructureRule) -> Self {
+        Self {
+            max_path_length: rule.max_path_length,
+            max_filename_length: rule.max_filename_length,
+            max_path_components: rule.max_path_components,
+        }
+    }
+
+    /// Field-wise override: values set here win, unset ones come from `defaults`.
+    #[must_use]
+    pub fn or(self, defaults: Self) -> Self {
+        Self {
+            max_path_length: self.max_path_length.or(defaults.max_path_length),
+            max_filename_length: self.max_filename_length.or(defaults.max_filename_length),
+            max_path_components: self.max_path_components.or(defaults.max_path_components),
+        }
+    }
+
+    /// Whether any limit is configured (including `-1` overrides).
+    #[must_use]
+    pub const fn is_set(&self) -> bool {
+        self.max_path_length.is_some()
+            || self.max_filename_length.is_some()
+            || self.max_path_components.is_some()
+    }
+}
+
+/// Per-rule path limits applying to entries of directories matching `scope`.
+#[derive(Debug, Clone)]
+pub struct PathLimitsRule {
+    /// Scope label of the rule (its glob, for single-scope rules).
+    pub scope: String,
+    selector: ScopeSelector,
+    /// Explicit `priority`, ranked together with the matching scope's specificity.
+    pub priority: Option<i32>,
+    pub limits: PathLimits,
+}
+
+impl PathLimitsRule {
+    /// Compile a rule.
+    ///
+    /// # Errors
+    /// Returns `SlocGuardError::InvalidPattern` if the scope is not a valid glob.
+    pub fn new(scope: String, limits: PathLimits) -> Result<Self> {
+        Ok(Self {
+            selector: ScopeSelector::from_glob(&scope)?,
+            scope,
+            priority: None,
+            limits,
+        })
+    }
+
+    /// Create a rule for an already compiled scope.
+    #[must_use]
+    pub fn from_selector(selector: ScopeSelector, limits: PathLimits) -> Self {
+        Self {
+            scope: selector.label().to_string(),
+            selector,
+            priority: None,
+            limits,
+        }
+    }
+
+    /// Set the explicit `priority` used when several rules match a directory.
+    #[must_use]
+    pub const fn with_priority(mut self, priority: Option<i32>) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Check if a directory path matches this rule's scope.
+    #[must_use]
+    pub fn matches_directory(&self, dir: &Path) -> bool {
+        self.selector.is_match(normalize_for_matching(dir))
+    }
+
+    /// Priority and specificity of the scope matching `dir`, to pick among overlapping rules.
+    pub(crate) fn rank(&self, dir: &Path) -> RuleRank {
+        let normalized = normalize_for_matching(dir);
+        RuleRank::with_specificity(self.selector.specificity(&normalized), self.priority)
+    }
+}
+
+/// Global path limits, per-rule overrides, and where relative scan paths start.
+#[derive(Debug, Clone, Default)]
+pub struct PathLimitsConfig {
+    pub defaults: PathLimits,
+    /// Rules in declaration order; among those matching an entry's parent directory, the
+    /// winner is picked like any structure rule (`priority`, then `rule_resolution`).
+    pub rules: Vec<PathLimitsRule>,
+    /// How overlapping rules of equal priority are resolved.
+    pub rule_resolution: RuleResolution,
+    /// Working directory relative to the project root (empty when they coincide).
+    /// Prepended to relative scan paths before measuring.
+    pub root_prefix: PathBuf,
+}
+
+impl PathLimitsConfig {
+    /// Whether any global or per-rule limit is configured.
+    #[must_use]
+    pub const fn is_enabled(&self) -> bool {
+        self.defaults.is_set() || !self.rules.is_empty()
+    }
+
+    /// The rule whose limits apply to entries directly in `dir`, if any matches.
+    fn winning_rule(&self, dir: &Path) -> Option<&PathLimitsRule> {
+        let matching = self
+            .rules
+            .iter()
+            .enumerate()
+            .filter(|(_, rule)| rule.matches_directory(dir))
+            .map(|(index, _)| index);
+        select_winner(
+            matching,
+            |index| self.rules[index].rank(dir),
+            self.rule_resolution,
+        )
+        .map(|index| &self.rules[index])
+    }
+
+    /// Violations for a scanned entry.
+    ///
+    /// Files are checked against all three limits; directories only against
+    /// `max_filename_length`, since any file beneath a too-long directory path is
+    /// reported on its own.
+    pub(crate) fn violations(&self, path: &Path, is_dir: bool) -> Vec<StructureViolation> {
+        if !self.is_enabled() {
+            return Vec::new();
+        }
+        let rule = path.parent().and_then(|parent| self.winning_rule(parent));
+        let limits = rule.map_or(self.defaults, |r| r.limits.or(self.defaults));
+        let rule_pattern = rule.map(|r| r.scope.clone());
+
+        let relative = self.root_prefix.join(normalize_for_matching(path));
+        let names: Vec<String> = relative
+            .components()
+            .filter_map(|component| match component {
+                Component::Normal(name) => Some(name.to_string_lossy().into_owned()),
+                _ => None,
+            })
+            .collect();
+        let name_length = names.last().map_or(0, |name| name.chars().count());
+        let path_length = names.iter().map(|name| name.chars().count()).sum::<usize>()
+            + names.len().saturating_sub(1);
+
+        let mut measured = vec![(
+            ViolationType::FilenameLength,
+            name_length,
+            limits.max_filename_length,
+        )];
+        if !is_dir {
+            measured.push((
+                ViolationType::PathLength,
+                path_length,
+                limits.max_path_length,
+            ));
+            measured.push((
+                ViolationType::PathComponents,
+                names.len(),
+                limits.max_path_components,
+            ));
+        }
+
+        measured
+            .into_iter()
+            .filter_map(|(violation_type, actual, limit)| {
+                let limit = usize::try_from(limit.filter(|&l| l != UNLIMITED)?).ok()?;
+                (actual > limit).then(|| {
+                    StructureViolation::path_limit(
+                        path.to_path_buf(),
+                        violation_type,
+                        actual,
+                        limit,
+                        rule_pattern.clone(),
+                    )
+                })
+            })
+            .collect()
+    }
+}
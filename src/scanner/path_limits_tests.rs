@@ -0,0 +1,213 @@
+use std::path::{Path, PathBuf};
+
+use tempfile::TempDir;
+
+use super::*;
+use crate::checker::{StructureViolation, ViolationType};
+use crate::config::RuleResolution;
+use crate::scanner::TestConfigParams;
+
+struct AcceptAllFilter;
+
+impl FileFilter for AcceptAllFilter {
+    fn should_include(&self, _path: &Path) -> bool {
+        true
+    }
+}
+
+fn limits(path: Option<i64>, name: Option<i64>, components: Option<i64>) -> PathLimits {
+    PathLimits {
+        max_path_length: path,
+        max_filename_length: name,
+        max_path_components: components,
+    }
+}
+
+fn kinds(violations: &[StructureViolation]) -> Vec<(ViolationType, usize, usize)> {
+    violations
+        .iter()
+        .map(|v| (v.violation_type.clone(), v.actual, v.limit))
+        .collect()
+}
+
+// =============================================================================
+// PathLimitsConfig Tests
+// =============================================================================
+
+#[test]
+fn path_length_counts_characters_with_forward_slashes() {
+    let config = PathLimitsConfig {
+        defaults: limits(Some(10), None, None),
+        ..Default::default()
+    };
+
+    // "src/ab.rs" is 9 characters, "src/abc.rs" is 10, "src/abcd.rs" is 11
+    assert!(
+        config
+            .violations(Path::new("./src/abc.rs"), false)
+            .is_empty()
+    );
+    assert_eq!(
+        kinds(&config.violations(Path::new("./src/abcd.rs"), false)),
+        vec![(ViolationType::PathLength, 11, 10)]
+    );
+}
+
+#[test]
+fn path_length_counts_unicode_characters_not_bytes() {
+    let config = PathLimitsConfig {
+        defaults: limits(Some(9), Some(5), None),
+        ..Default::default()
+    };
+
+    // "src/ñu.rs" is 9 characters (10 bytes); "ñu.rs" is 5 characters (6 bytes)
+    assert!(config.violations(Path::new("src/ñu.rs"), false).is_empty());
+}
+
+#[test]
+fn root_prefix_is_included_in_path_length_and_components() {
+    let config = PathLimitsConfig {
+        defaults: limits(Some(12), None, Some(2)),
+        root_prefix: PathBuf::from("crates/core"),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        kinds(&config.violations(Path::new("./lib.rs"), false)),
+        vec![
+            (ViolationType::PathLength, 18, 12),
+            (ViolationType::PathComponents, 3, 2),
+        ]
+    );
+}
+
+#[test]
+fn directories_only_check_name_length() {
+    let config = PathLimitsConfig {
+        defaults: limits(Some(5), Some(5), Some(1)),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        kinds(&config.violations(Path::new("src/components"), true)),
+        vec![(ViolationType::FilenameLength, 10, 5)]
+    );
+    assert!(config.violations(Path::new("src/ui"), true).is_empty());
+}
+
+#[test]
+fn last_matching_rule_overrides_defaults_field_wise() {
+    let config = PathLimitsConfig {
+        defaults: limits(Some(10), Some(8), None),
+        rules: vec![
+            PathLimitsRule::new("**".to_string(), limits(Some(100), None, None)).unwrap(),
+            PathLimitsRule::new("vendor/**".to_string(), limits(Some(-1), None, None)).unwrap(),
+        ],
+        ..Default::default()
+    };
+
+    // Unlimited path length in vendor/, but the global name limit still applies
+    let violations = config.violations(Path::new("vendor/lib/generated_bindings.rs"), false);
+    assert_eq!(
+        kinds(&violations),
+        vec![(ViolationType::FilenameLength, 21, 8)]
+    );
+    assert_eq!(
+        violations[0].triggering_rule_pattern.as_deref(),
+        Some("vendor/**")
+    );
+
+    assert!(config.violations(Path::new("src/lib.rs"), false).is_empty());
+}
+
+#[test]
+fn most_specific_rule_wins_regardless_of_order() {
+    let config = PathLimitsConfig {
+        rules: vec![
+            PathLimitsRule::new("vendor/**".to_string(), limits(Some(-1), None, None)).unwrap(),
+            PathLimitsRule::new("**".to_string(), limits(Some(20), None, None)).unwrap(),
+        ],
+        rule_resolution: RuleResolution::MostSpecific,
+        ..Default::default()
+    };
+
+    assert!(
+        config
+            .violations(Path::new("vendor/lib/generated_bindings.rs"), false)
+            .is_empty()
+    );
+    let violations = config.violations(Path::new("src/generated_bindings.rs"), false);
+    assert_eq!(
+        kinds(&violations),
+        vec![(ViolationType::PathLength, 25, 20)]
+    );
+}
+
+#[test]
+fn rule_priority_beats_declaration_order() {
+    let config = PathLimitsConfig {
+        rules: vec![
+            PathLimitsRule::new("vendor/**".to_string(), limits(Some(-1), None, None))
+                .unwrap()
+                .with_priority(Some(10)),
+            PathLimitsRule::new("**".to_string(), limits(Some(20), None, None)).unwrap(),
+        ],
+        ..Default::default()
+    };
+
+    assert!(
+        config
+            .violations(Path::new("vendor/lib/generated_bindings.rs"), false)
+            .is_empty()
+    );
+}
+
+#[test]
+fn disabled_config_reports_nothing() {
+    let config = PathLimitsConfig::default();
+
+    assert!(!config.is_enabled());
+    assert!(
+        config
+            .violations(Path::new("a/very/deeply/nested/path.rs"), false)
+            .is_empty()
+    );
+}
+
+// =============================================================================
+// Scan Integration Tests
+// =============================================================================
+
+#[test]
+fn scan_reports_long_file_and_directory_names() {
+    let temp_dir = TempDir::new().unwrap();
+    let long_dir = temp_dir.path().join("a_directory_name");
+    std::fs::create_dir(&long_dir).unwrap();
+    std::fs::write(long_dir.join("ok.rs"), "").unwrap();
+    std::fs::write(temp_dir.path().join("long_file_name.rs"), "").unwrap();
+
+    let config = StructureScanConfig::new(TestConfigParams {
+        path_limits: PathLimitsConfig {
+            defaults: limits(None, Some(10), None),
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+    .unwrap();
+    let mut violations = DirectoryScanner::new(AcceptAllFilter)
+        .scan_with_structure(temp_dir.path(), Some(&config))
+        .unwrap()
+        .allowlist_violations;
+    violations.sort_by(|a, b| a.path.cmp(&b.path));
+
+    let paths: Vec<_> = violations.iter().map(|v| v.path.clone()).collect();
+    assert_eq!(
+        paths,
+        vec![long_dir, temp_dir.path().join("long_file_name.rs")]
+    );
+    assert!(
+        violations
+            .iter()
+            .all(|v| v.violation_type == ViolationType::FilenameLength)
+    );
+}
@@ -0,0 +1,97 @@
+//! Cross-platform name checks: case-insensitive collisions
+//! (`detect_case_collisions`) and names Windows cannot check out
+//! (`detect_invalid_windows_names`).
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::checker::StructureViolation;
+use crate::output::path::{normalize_for_matching, normalize_separators};
+
+/// Device names Windows reserves regardless of extension (`con.txt` is reserved too).
+const RESERVED_WINDOWS_NAMES: &[&str] = &[
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+/// Characters Windows does not allow in file names (besides control characters).
+const INVALID_WINDOWS_CHARS: &[char] = &['<', '>', ':', '"', '\\', '|', '?', '*'];
+
+/// Case-fold a name for comparison.
+///
+/// Uppercasing first expands characters such as `ß` to `SS`, so lowercasing the
+/// result approximates full Unicode case folding (`Straße` and `STRASSE` collide).
+#[must_use]
+pub fn fold_case(name: &str) -> String {
+    name.to_uppercase().to_lowercase()
+}
+
+/// Why a name is invalid on Windows, or `None` if it is valid.
+#[must_use]
+pub fn windows_name_problem(name: &str) -> Option<String> {
+    if let Some(c) = name
+        .chars()
+        .find(|c| INVALID_WINDOWS_CHARS.contains(c) || c.is_ascii_control())
+    {
+        return Some(if c.is_ascii_control() {
+            format!("control character U+{:04X}", u32::from(c))
+        } else {
+            format!("character '{c}'")
+        });
+    }
+    if name.ends_with('.') && name != "." && name != ".." {
+        return Some("trailing dot".to_string());
+    }
+    if name.ends_with(' ') {
+        return Some("trailing space".to_string());
+    }
+    let base = name.split('.').next().unwrap_or_default().trim_end();
+    RESERVED_WINDOWS_NAMES
+        .iter()
+        .find(|reserved| reserved.eq_ignore_ascii_case(base))
+        .map(|reserved| format!("reserved name '{reserved}'"))
+}
+
+/// Entries grouped by directory and case-folded name, to report names that
+/// differ only by case.
+#[derive(Debug, Default)]
+pub(super) struct CaseCollisions {
+    by_dir: HashMap<PathBuf, HashMap<String, Vec<PathBuf>>>,
+}
+
+impl CaseCollisions {
+    /// Record a scanned file or directory.
+    pub fn record(&mut self, path: &Path) {
+        let (Some(parent), Some(name)) = (path.parent(), path.file_name()) else {
+            return;
+        };
+        self.by_dir
+            .entry(parent.to_path_buf())
+            .or_default()
+            .entry(fold_case(&name.to_string_lossy()))
+            .or_default()
+            .push(path.to_path_buf());
+    }
+
+    /// One violation per set of colliding names, reported on their directory.
+    pub fn into_violations(self) -> Vec<StructureViolation> {
+        let mut collisions: Vec<(PathBuf, Vec<String>)> = Vec::new();
+        for (dir, names) in self.by_dir {
+            for paths in names.into_values().filter(|paths| paths.len() > 1) {
+                let mut colliding_paths: Vec<String> = paths
+                    .iter()
+                    .map(|path| {
+                        normalize_separators(&normalize_for_matching(path).to_string_lossy())
+                    })
+                    .collect();
+                colliding_paths.sort();
+                collisions.push((dir.clone(), colliding_paths));
+            }
+        }
+        collisions.sort();
+        collisions
+            .into_iter()
+            .map(|(dir, colliding_paths)| StructureViolation::case_collision(dir, colliding_paths))
+            .collect()
+    }
+}
@@ -0,0 +1,205 @@
+use std::path::Path;
+
+use tempfile::TempDir;
+
+use super::portability::{fold_case, windows_name_problem};
+use super::*;
+use crate::checker::{StructureViolation, ViolationType};
+use crate::scanner::TestConfigParams;
+
+struct AcceptAllFilter;
+
+impl FileFilter for AcceptAllFilter {
+    fn should_include(&self, _path: &Path) -> bool {
+        true
+    }
+}
+
+fn scan(temp_dir: &TempDir, params: TestConfigParams) -> Vec<StructureViolation> {
+    let config = StructureScanConfig::new(params).unwrap();
+    DirectoryScanner::new(AcceptAllFilter)
+        .scan_with_structure(temp_dir.path(), Some(&config))
+        .unwrap()
+        .allowlist_violations
+}
+
+// =============================================================================
+// Name Check Tests
+// =============================================================================
+
+#[test]
+fn fold_case_ignores_letter_case() {
+    assert_eq!(fold_case("README.md"), fold_case("readme.MD"));
+    assert_ne!(fold_case("a.rs"), fold_case("b.rs"));
+}
+
+#[test]
+fn fold_case_expands_sharp_s() {
+    assert_eq!(fold_case("Straße.txt"), fold_case("STRASSE.TXT"));
+}
+
+#[test]
+fn windows_name_problem_accepts_ordinary_names() {
+    for name in [
+        "main.rs",
+        "console.rs",
+        "com10.txt",
+        ".gitignore",
+        "a b.txt",
+    ] {
+        assert_eq!(windows_name_problem(name), None, "{name}");
+    }
+}
+
+#[test]
+fn windows_name_problem_rejects_reserved_names_with_any_extension() {
+    assert_eq!(
+        windows_name_problem("con"),
+        Some("reserved name 'CON'".to_string())
+    );
+    assert_eq!(
+        windows_name_problem("Aux.tar.gz"),
+        Some("reserved name 'AUX'".to_string())
+    );
+    assert_eq!(
+        windows_name_problem("lpt9.log"),
+        Some("reserved name 'LPT9'".to_string())
+    );
+}
+
+#[test]
+fn windows_name_problem_rejects_invalid_characters() {
+    assert_eq!(
+        windows_name_problem("what?.md"),
+        Some("character '?'".to_string())
+    );
+    assert_eq!(
+        windows_name_problem("a:b.rs"),
+        Some("character ':'".to_string())
+    );
+    assert_eq!(
+        windows_name_problem("tab\there"),
+        Some("control character U+0009".to_string())
+    );
+}
+
+#[test]
+fn windows_name_problem_rejects_trailing_dot_and_space() {
+    assert_eq!(
+        windows_name_problem("notes."),
+        Some("trailing dot".to_string())
+    );
+    assert_eq!(
+        windows_name_problem("notes "),
+        Some("trailing space".to_string())
+    );
+}
+
+// =============================================================================
+// Scan Integration Tests
+// =============================================================================
+
+#[test]
+fn scan_reports_case_collisions_per_directory() {
+    let temp_dir = TempDir::new().unwrap();
+    let src = temp_dir.path().join("src");
+    std::fs::create_dir(&src).unwrap();
+    std::fs::write(src.join("Foo.rs"), "").unwrap();
+    std::fs::write(src.join("foo.rs"), "").unwrap();
+    std::fs::write(src.join("bar.rs"), "").unwrap();
+    // Same name in a different directory is not a collision
+    std::fs::write(temp_dir.path().join("foo.rs"), "").unwrap();
+
+    let violations = scan(
+        &temp_dir,
+        TestConfigParams {
+            detect_case_collisions: true,
+            ..Default::default()
+        },
+    );
+
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].path, src);
+    let ViolationType::CaseCollision { colliding_paths } = &violations[0].violation_type else {
+        panic!(
+            "expected CaseCollision, got {:?}",
+            violations[0].violation_type
+        );
+    };
+    assert_eq!(colliding_paths.len(), 2);
+    assert!(colliding_paths[0].ends_with("src/Foo.rs"));
+    assert!(colliding_paths[1].ends_with("src/foo.rs"));
+    assert_eq!(violations[0].actual, 2);
+}
+
+#[test]
+fn scan_reports_file_and_directory_collision() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::create_dir(temp_dir.path().join("Docs")).unwrap();
+    std::fs::write(temp_dir.path().join("docs"), "").unwrap();
+
+    let violations = scan(
+        &temp_dir,
+        TestConfigParams {
+            detect_case_collisions: true,
+            ..Default::default()
+        },
+    );
+
+    assert_eq!(violations.len(), 1);
+    assert!(matches!(
+        violations[0].violation_type,
+        ViolationType::CaseCollision { .. }
+    ));
+}
+
+#[test]
+fn scan_ignores_collisions_when_disabled() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(temp_dir.path().join("Foo.rs"), "").unwrap();
+    std::fs::write(temp_dir.path().join("foo.rs"), "").unwrap();
+
+    let violations = scan(&temp_dir, TestConfigParams::default());
+
+    assert!(violations.is_empty());
+}
+
+#[test]
+fn scan_reports_invalid_windows_names() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::create_dir(temp_dir.path().join("aux")).unwrap();
+    std::fs::write(temp_dir.path().join("aux").join("ok.rs"), "").unwrap();
+    std::fs::write(temp_dir.path().join("nul.txt"), "").unwrap();
+    std::fs::write(temp_dir.path().join("main.rs"), "").unwrap();
+
+    let mut violations = scan(
+        &temp_dir,
+        TestConfigParams {
+            detect_invalid_windows_names: true,
+            ..Default::default()
+        },
+    );
+    violations.sort_by(|a, b| a.path.cmp(&b.path));
+
+    let found: Vec<_> = violations
+        .iter()
+        .map(|v| (v.path.clone(), v.violation_type.clone()))
+        .collect();
+    assert_eq!(
+        found,
+        vec![
+            (
+                temp_dir.path().join("aux"),
+                ViolationType::InvalidWindowsName {
+                    reason: "reserved name 'AUX'".to_string()
+                }
+            ),
+            (
+                temp_dir.path().join("nul.txt"),
+                ViolationType::InvalidWindowsName {
+                    reason: "reserved name 'NUL'".to_string()
+                }
+            ),
+        ]
+    );
+}
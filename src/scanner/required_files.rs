@@ -0,0 +1,122 @@
+use std::path::Path;
+
+use globset::{Glob, GlobMatcher};
+
+use crate::SlocGuardError;
+use crate::checker::ScopeSelector;
+use crate::config::{Placeholder, Template, TemplateValues};
+use crate::error::Result;
+use crate::output::path::normalize_for_matching;
+
+/// A compiled `require_files` rule: every directory matching `scope` must contain
+/// a file matching each pattern.
+///
+/// `*` in the scope does not cross `/`, so `crates/*` leaves `crates/a/src` alone.
+#[derive(Debug, Clone)]
+pub struct RequiredFilesRule {
+    /// Scope label of the rule (its glob, for single-scope rules).
+    pub scope: String,
+    selector: ScopeSelector,
+    /// File name patterns, possibly containing `{dir}` or `{parent}`.
+    pub patterns: Vec<String>,
+    templates: Vec<Template>,
+    /// Patterns without placeholders, compiled once (`None` for templated patterns).
+    compiled: Vec<Option<GlobMatcher>>,
+    /// When `true`, missing files are warnings instead of errors.
+    pub is_warning: bool,
+}
+
+impl RequiredFilesRule {
+    /// Compile a rule.
+    ///
+    /// # Errors
+    /// Returns `SlocGuardError::InvalidPattern` if the scope or any pattern is not a valid glob.
+    pub fn new(scope: String, patterns: Vec<String>, is_warning: bool) -> Result<Self> {
+        let selector = ScopeSelector::from_glob_exact(&scope)?;
+        let rule = Self::from_selector(selector, patterns, is_warning)?;
+        Ok(Self { scope, ..rule })
+    }
+
+    /// Create a rule for an already compiled scope.
+    ///
+    /// # Errors
+    /// Returns `SlocGuardError::InvalidPattern` if any pattern is not a valid glob, or
+    /// `SlocGuardError::Config` if it uses an unknown placeholder.
+    pub fn from_selector(
+        selector: ScopeSelector,
+        patterns: Vec<String>,
+        is_warning: bool,
+    ) -> Result<Self> {
+        let templates = patterns
+            .iter()
+            .map(|pattern| {
+                Template::parse(pattern, Placeholder::DIRECTORY).map_err(|e| {
+                    SlocGuardError::Config(format!(
+                        "Invalid require_files pattern '{pattern}': {e}"
+                    ))
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let compiled = templates
+            .iter()
+            .map(|template| {
+                if template.has_placeholders() {
+                    // Validate the rest of the pattern with neutral names
+                    let values = TemplateValues {
+                        dir: "dir",
+                        parent: "parent",
+                        ..TemplateValues::default()
+                    };
+                    compile(&template.expand(&values))?;
+                    Ok(None)
+                } else {
+                    Ok(Some(compile(template.source())?.compile_matcher()))
+                }
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self {
+            scope: selector.label().to_string(),
+            selector,
+            patterns,
+            templates,
+            compiled,
+            is_warning,
+        })
+    }
+
+    /// Check if a directory path matches this rule's scope.
+    #[must_use]
+    pub fn matches_directory(&self, dir: &Path) -> bool {
+        self.selector.is_match(normalize_for_matching(dir))
+    }
+
+    /// Patterns (with placeholders expanded) that no file in `file_names` matches.
+    #[must_use]
+    pub fn missing(&self, dir: &Path, file_names: &[String]) -> Vec<String> {
+        let values = TemplateValues::for_dir(dir);
+
+        self.templates
+            .iter()
+            .zip(&self.compiled)
+            .filter_map(|(template, compiled)| {
+                let expanded;
+                let matcher = if let Some(matcher) = compiled {
+                    matcher
+                } else {
+                    let pattern = template.expand_with(&values, globset::escape);
+                    expanded = Glob::new(&pattern).ok()?.compile_matcher();
+                    &expanded
+                };
+                let found = file_names.iter().any(|name| matcher.is_match(name));
+                (!found).then(|| template.expand(&values))
+            })
+            .collect()
+    }
+}
+
+fn compile(pattern: &str) -> Result<Glob> {
+    Glob::new(pattern).map_err(|source| SlocGuardError::InvalidPattern {
+        pattern: pattern.to_string(),
+        source,
+    })
+}
@@ -0,0 +1,249 @@
+use std::path::Path;
+
+use tempfile::TempDir;
+
+use super::*;
+use crate::checker::{StructureViolation, ViolationType};
+use crate::scanner::TestConfigParams;
+
+struct AcceptAllFilter;
+
+impl FileFilter for AcceptAllFilter {
+    fn should_include(&self, _path: &Path) -> bool {
+        true
+    }
+}
+
+fn names(names: &[&str]) -> Vec<String> {
+    names.iter().map(|name| (*name).to_string()).collect()
+}
+
+fn scan(temp_dir: &TempDir, rules: Vec<RequiredFilesRule>) -> Vec<StructureViolation> {
+    let config = StructureScanConfig::new(TestConfigParams {
+        required_files_rules: rules,
+        ..Default::default()
+    })
+    .unwrap();
+    DirectoryScanner::new(AcceptAllFilter)
+        .scan_with_structure(temp_dir.path(), Some(&config))
+        .unwrap()
+        .allowlist_violations
+}
+
+// =============================================================================
+// RequiredFilesRule Tests
+// =============================================================================
+
+#[test]
+fn missing_reports_unmatched_patterns() {
+    let rule = RequiredFilesRule::new(
+        "crates/*".to_string(),
+        names(&["Cargo.toml", "README.md"]),
+        false,
+    )
+    .unwrap();
+
+    let missing = rule.missing(Path::new("crates/core"), &names(&["Cargo.toml", "lib.rs"]));
+
+    assert_eq!(missing, vec!["README.md"]);
+}
+
+#[test]
+fn missing_supports_globs() {
+    let rule = RequiredFilesRule::new("docs".to_string(), names(&["*.md"]), false).unwrap();
+
+    assert!(
+        rule.missing(Path::new("docs"), &names(&["intro.md"]))
+            .is_empty()
+    );
+    assert_eq!(
+        rule.missing(Path::new("docs"), &names(&["intro.txt"])),
+        vec!["*.md"]
+    );
+}
+
+#[test]
+fn dir_placeholder_expands_to_directory_name() {
+    let rule = RequiredFilesRule::new("src/components/*".to_string(), names(&["{dir}.tsx"]), false)
+        .unwrap();
+
+    assert!(
+        rule.missing(Path::new("src/components/Button"), &names(&["Button.tsx"]))
+            .is_empty()
+    );
+    assert_eq!(
+        rule.missing(Path::new("src/components/Card"), &names(&["index.ts"])),
+        vec!["Card.tsx"]
+    );
+}
+
+#[test]
+fn dir_placeholder_escapes_glob_characters_in_name() {
+    let rule = RequiredFilesRule::new("*".to_string(), names(&["{dir}.md"]), false).unwrap();
+
+    // `[a]` must match literally, not as a character class
+    assert_eq!(
+        rule.missing(Path::new("[a]"), &names(&["a.md"])),
+        vec!["[a].md"]
+    );
+    assert!(
+        rule.missing(Path::new("[a]"), &names(&["[a].md"]))
+            .is_empty()
+    );
+}
+
+#[test]
+#[allow(clippy::literal_string_with_formatting_args)] // {dir:kebab} is template syntax
+fn parent_placeholder_and_transforms_expand_per_directory() {
+    let rule = RequiredFilesRule::new(
+        "packages/*/*".to_string(),
+        names(&["{parent}-{dir:kebab}.md"]),
+        false,
+    )
+    .unwrap();
+
+    assert!(
+        rule.missing(
+            Path::new("packages/web/DataGrid"),
+            &names(&["web-data-grid.md"])
+        )
+        .is_empty()
+    );
+    assert_eq!(
+        rule.missing(Path::new("packages/web/DataGrid"), &names(&["DataGrid.md"])),
+        vec!["web-data-grid.md"]
+    );
+}
+
+#[test]
+fn unknown_placeholder_is_rejected() {
+    let err = RequiredFilesRule::new("src".to_string(), names(&["{stem}.md"]), false)
+        .unwrap_err()
+        .to_string();
+
+    assert!(err.contains("{stem}"), "{err}");
+}
+
+#[test]
+fn invalid_pattern_is_rejected() {
+    assert!(RequiredFilesRule::new("src".to_string(), names(&["[bad"]), false).is_err());
+    assert!(RequiredFilesRule::new("src".to_string(), names(&["{dir}[bad"]), false).is_err());
+    assert!(RequiredFilesRule::new("[bad".to_string(), names(&["a"]), false).is_err());
+}
+
+#[test]
+fn matches_directory_uses_scope() {
+    let rule =
+        RequiredFilesRule::new("crates/*".to_string(), names(&["Cargo.toml"]), false).unwrap();
+
+    assert!(rule.matches_directory(Path::new("crates/core")));
+    assert!(rule.matches_directory(Path::new("./crates/core")));
+    assert!(!rule.matches_directory(Path::new("crates")));
+    // `*` stops at `/`: subdirectories of a crate are out of scope
+    assert!(!rule.matches_directory(Path::new("crates/core/src")));
+}
+
+// =============================================================================
+// Scan Tests
+// =============================================================================
+
+#[test]
+fn scan_reports_missing_required_file_per_directory() {
+    let temp_dir = TempDir::new().unwrap();
+    let crates = temp_dir.path().join("crates");
+    std::fs::create_dir_all(crates.join("core")).unwrap();
+    std::fs::create_dir_all(crates.join("cli")).unwrap();
+    std::fs::write(crates.join("core/Cargo.toml"), "").unwrap();
+    std::fs::write(crates.join("core/README.md"), "").unwrap();
+    std::fs::write(crates.join("cli/Cargo.toml"), "").unwrap();
+
+    let rule = RequiredFilesRule::new(
+        "**/crates/*".to_string(),
+        names(&["Cargo.toml", "README.md"]),
+        false,
+    )
+    .unwrap();
+    let violations = scan(&temp_dir, vec![rule]);
+
+    assert_eq!(violations.len(), 1);
+    assert!(violations[0].path.ends_with("crates/cli"));
+    assert!(!violations[0].is_warning);
+    assert_eq!(
+        violations[0].violation_type,
+        ViolationType::MissingRequiredFile {
+            required_pattern: "README.md".to_string()
+        }
+    );
+    assert_eq!(
+        violations[0].triggering_rule_pattern.as_deref(),
+        Some("**/crates/*")
+    );
+}
+
+#[test]
+fn scan_skips_subdirectories_of_matching_directory() {
+    let temp_dir = TempDir::new().unwrap();
+    let crate_dir = temp_dir.path().join("crates/a");
+    std::fs::create_dir_all(crate_dir.join("src")).unwrap();
+    std::fs::write(crate_dir.join("Cargo.toml"), "").unwrap();
+    std::fs::write(crate_dir.join("README.md"), "").unwrap();
+    std::fs::write(crate_dir.join("src/lib.rs"), "").unwrap();
+
+    let rule = RequiredFilesRule::new(
+        "**/crates/*".to_string(),
+        names(&["Cargo.toml", "README.md"]),
+        false,
+    )
+    .unwrap();
+
+    assert!(scan(&temp_dir, vec![rule]).is_empty());
+}
+
+#[test]
+fn scan_reports_empty_matching_directory() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::create_dir_all(temp_dir.path().join("components/Empty")).unwrap();
+
+    let rule =
+        RequiredFilesRule::new("**/components/*".to_string(), names(&["index.ts"]), true).unwrap();
+    let violations = scan(&temp_dir, vec![rule]);
+
+    assert_eq!(violations.len(), 1);
+    assert!(violations[0].is_warning);
+}
+
+#[test]
+fn scan_ignores_files_in_subdirectories() {
+    let temp_dir = TempDir::new().unwrap();
+    let pkg = temp_dir.path().join("pkg");
+    std::fs::create_dir_all(pkg.join("nested")).unwrap();
+    std::fs::write(pkg.join("nested/README.md"), "").unwrap();
+
+    let rule = RequiredFilesRule::new("**/pkg".to_string(), names(&["README.md"]), false).unwrap();
+    let violations = scan(&temp_dir, vec![rule]);
+
+    assert_eq!(violations.len(), 1);
+    assert!(violations[0].path.ends_with("pkg"));
+}
+
+#[test]
+fn scan_counts_required_files_hidden_by_count_exclude() {
+    let temp_dir = TempDir::new().unwrap();
+    let pkg = temp_dir.path().join("pkg");
+    std::fs::create_dir_all(&pkg).unwrap();
+    std::fs::write(pkg.join("README.md"), "").unwrap();
+
+    let config = StructureScanConfig::new(TestConfigParams {
+        count_exclude_patterns: vec!["*.md".to_string()],
+        required_files_rules: vec![
+            RequiredFilesRule::new("**/pkg".to_string(), names(&["README.md"]), false).unwrap(),
+        ],
+        ..Default::default()
+    })
+    .unwrap();
+    let result = DirectoryScanner::new(AcceptAllFilter)
+        .scan_with_structure(temp_dir.path(), Some(&config))
+        .unwrap();
+
+    assert!(result.allowlist_violations.is_empty());
+}
@@ -0,0 +1,442 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use super::layout::EntryNames;
+use super::portability::{CaseCollisions, windows_name_problem};
+use super::{FileFilter, ScanResult, StructureScanConfig};
+use crate::checker::{DirStats, StructureViolation};
+
+/// Helper state for structure-aware scanning.
+/// Extracts common logic from walkdir and ignore-based scanning.
+pub(super) struct StructureScanState<'a> {
+    result: ScanResult,
+    dir_entries: HashMap<PathBuf, DirStats>,
+    /// Entry names of directories matched by a `require_files` rule or a layout.
+    tracked_dirs: HashMap<PathBuf, EntryNames>,
+    /// Entry names by directory, when `detect_case_collisions` is on.
+    case_collisions: CaseCollisions,
+    structure_config: Option<&'a StructureScanConfig>,
+}
+
+impl<'a> StructureScanState<'a> {
+    pub fn new(structure_config: Option<&'a StructureScanConfig>) -> Self {
+        Self {
+            result: ScanResult::default(),
+            dir_entries: HashMap::new(),
+            tracked_dirs: HashMap::new(),
+            case_collisions: CaseCollisions::default(),
+            structure_config,
+        }
+    }
+
+    pub fn process_file(
+        &mut self,
+        path: &Path,
+        depth: usize,
+        filter: &impl FileFilter,
+        abs_path: &Path,
+        file_size: impl FnOnce() -> Option<u64>,
+    ) {
+        // Check scanner_exclude - skip entry entirely
+        if let Some(cfg) = self.structure_config
+            && cfg.is_scanner_excluded(path, false)
+        {
+            return;
+        }
+
+        self.check_entry_name(path, false);
+        self.check_file_size(path, file_size);
+        self.count_entry(path, depth);
+
+        // Record the name for `require_files` and layouts (count_exclude does not hide entries)
+        if let Some(names) = self.tracked_entries(path) {
+            names.files.push(entry_name(path));
+        }
+
+        // Check count_exclude - don't count but continue
+        let is_count_excluded = self
+            .structure_config
+            .is_some_and(|cfg| cfg.is_count_excluded(path));
+
+        // Add to files list if filter allows
+        if filter.should_include(path) {
+            self.result.files.push(path.to_path_buf());
+        }
+
+        // Count for parent directory (if not excluded)
+        if !is_count_excluded && let Some(parent) = path.parent() {
+            let parent_stats = self
+                .dir_entries
+                .entry(parent.to_path_buf())
+                .or_insert_with(|| DirStats {
+                    depth: if depth > 0 { depth - 1 } else { 0 },
+                    ..Default::default()
+                });
+            parent_stats.file_count += 1;
+            if let Some(ext) = path.extension() {
+                *parent_stats
+                    .files_by_ext
+                    .entry(format!(".{}", ext.to_string_lossy()))
+                    .or_default() += 1;
+            }
+
+            self.check_allowlist_violations(path, parent, abs_path);
+        }
+    }
+
+    /// Entry names of `path`'s parent, if that directory is tracked.
+    fn tracked_entries(&mut self, path: &Path) -> Option<&mut EntryNames> {
+        path.parent()
+            .and_then(|parent| self.tracked_dirs.get_mut(parent))
+    }
+
+    /// Count an entry for its parent's `entry_count`, regardless of `count_exclude`.
+    fn count_entry(&mut self, path: &Path, depth: usize) {
+        if let Some(parent) = path.parent() {
+            self.dir_entries
+                .entry(parent.to_path_buf())
+                .or_insert_with(|| DirStats {
+                    depth: depth.saturating_sub(1),
+                    ..Default::default()
+                })
+                .entry_count += 1;
+        }
+    }
+
+    /// Path limits and portability checks that only depend on an entry's path.
+    fn check_entry_name(&mut self, path: &Path, is_dir: bool) {
+        let Some(cfg) = self.structure_config else {
+            return;
+        };
+        let violations = &mut self.result.allowlist_violations;
+        violations.extend(cfg.path_limits.violations(path, is_dir));
+
+        if cfg.detect_case_collisions {
+            self.case_collisions.record(path);
+        }
+        if cfg.detect_invalid_windows_names
+            && let Some(reason) = path
+                .file_name()
+                .and_then(|name| windows_name_problem(&name.to_string_lossy()))
+        {
+            violations.push(StructureViolation::invalid_windows_name(
+                path.to_path_buf(),
+                reason,
+            ));
+        }
+    }
+
+    /// Symlink policy checks; followed directory links are marked in `DirStats`.
+    pub fn process_symlink(&mut self, path: &Path, depth: usize, is_dir: bool) {
+        if let Some(cfg) = self.structure_config {
+            if cfg.is_scanner_excluded(path, is_dir) {
+                return;
+            }
+            let violation = cfg.symlink_policy.violation(path);
+            self.result.allowlist_violations.extend(violation);
+        }
+        if is_dir {
+            self.dir_entries
+                .entry(path.to_path_buf())
+                .or_insert_with(|| DirStats {
+                    depth,
+                    ..Default::default()
+                })
+                .is_symlink = true;
+        }
+    }
+
+    /// A followed symlink that leads back to `ancestor`; the walker does not descend into it.
+    pub fn process_symlink_loop(&mut self, path: &Path, ancestor: &Path) {
+        if let Some(cfg) = self.structure_config
+            && !cfg.is_scanner_excluded(path, true)
+        {
+            self.result
+                .allowlist_violations
+                .push(StructureViolation::symlink_loop(
+                    path.to_path_buf(),
+                    ancestor.display().to_string(),
+                ));
+        }
+    }
+
+    /// `max_file_bytes` check; the size is only read when a limit is configured.
+    fn check_file_size(&mut self, path: &Path, file_size: impl FnOnce() -> Option<u64>) {
+        let Some(limits) = self
+            .structure_config
+            .map(|cfg| &cfg.file_size_limits)
+            .filter(|limits| limits.is_enabled())
+        else {
+            return;
+        };
+        if let Some(violation) = file_size().and_then(|size| limits.violation(path, size)) {
+            self.result.allowlist_violations.push(violation);
+        }
+    }
+
+    fn check_allowlist_violations(&mut self, path: &Path, parent: &Path, abs_path: &Path) {
+        let Some(cfg) = self.structure_config else {
+            return;
+        };
+
+        // Find matching per-rule first (needed for override checks)
+        let matching_rule = cfg.find_matching_allowlist_rule(parent);
+
+        // 1. Check global level patterns
+        if cfg.has_global_file_allowlist() {
+            // Allow mode: file must match global allowlist
+            if !cfg.file_matches_global_allow(abs_path) {
+                self.result
+                    .allowlist_violations
+                    .push(StructureViolation::disallowed_file(
+                        path.to_path_buf(),
+                        "global".to_string(),
+                    ));
+                return;
+            }
+        } else {
+            // Deny mode: check global deny patterns
+            // But first check if a per-rule allow would override global deny
+            let overridden_by_rule = matching_rule
+                .is_some_and(|rule| rule.has_allowlist() && rule.file_matches(abs_path));
+
+            if !overridden_by_rule && let Some(matched) = cfg.file_matches_global_deny(abs_path) {
+                self.result
+                    .allowlist_violations
+                    .push(StructureViolation::denied_file(
+                        path.to_path_buf(),
+                        "global".to_string(),
+                        matched,
+                    ));
+                return; // Denied files don't need further checks
+            }
+        }
+
+        // 2. Check per-rule patterns
+        let Some(rule) = matching_rule else {
+            return;
+        };
+
+        // Check per-rule deny patterns first (they take precedence over per-rule allow)
+        if let Some(matched) = rule.file_matches_deny(abs_path) {
+            self.result
+                .allowlist_violations
+                .push(StructureViolation::denied_file(
+                    path.to_path_buf(),
+                    rule.scope.clone(),
+                    matched,
+                ));
+            return; // Denied files don't need further checks
+        }
+
+        // Then check if rule is in allow mode
+        if rule.has_allowlist() {
+            // Allow mode: file must match allowlist
+            if !rule.file_matches(abs_path) {
+                self.result
+                    .allowlist_violations
+                    .push(StructureViolation::disallowed_file(
+                        path.to_path_buf(),
+                        rule.scope.clone(),
+                    ));
+                return; // Disallowed files don't need further checks
+            }
+        }
+
+        // Check naming convention (only for allowed files)
+        if let Some(expected) = rule.file_naming_mismatch(abs_path) {
+            self.result
+                .allowlist_violations
+                .push(StructureViolation::naming_convention(
+                    path.to_path_buf(),
+                    rule.scope.clone(),
+                    expected,
+                ));
+        }
+    }
+
+    pub fn process_directory(&mut self, path: &Path, depth: usize) {
+        // Check scanner_exclude - skip entry entirely
+        if let Some(cfg) = self.structure_config
+            && cfg.is_scanner_excluded(path, true)
+        {
+            return;
+        }
+
+        if depth > 0 {
+            self.check_entry_name(path, true);
+            self.count_entry(path, depth);
+            if let Some(names) = self.tracked_entries(path) {
+                names.dirs.push(entry_name(path));
+            }
+        }
+
+        // Track entry names of directories with `require_files` or a layout
+        if self.structure_config.is_some_and(|cfg| {
+            cfg.required_files_rules_for(path).next().is_some()
+                || cfg.layout_rules_for(path).next().is_some()
+        }) {
+            self.tracked_dirs.entry(path.to_path_buf()).or_default();
+        }
+
+        // Find matching per-rule for parent directory (needed for override checks)
+        let matching_rule = self.structure_config.and_then(|cfg| {
+            path.parent()
+                .and_then(|p| cfg.find_matching_allowlist_rule(p))
+        });
+
+        // Check global level directory patterns
+        if let Some(cfg) = self.structure_config {
+            if cfg.has_global_dir_allowlist() {
+                // Allow mode: directory must match global allowlist
+                if !cfg.dir_matches_global_allow(path) {
+                    self.result.allowlist_violations.push(
+                        StructureViolation::disallowed_directory(
+                            path.to_path_buf(),
+                            "global".to_string(),
+                        ),
+                    );
+                }
+            } else {
+                // Check if a per-rule allow would override global deny
+                let overridden_by_rule = matching_rule
+                    .is_some_and(|rule| rule.has_dir_allowlist() && rule.dir_matches(path));
+
+                if !overridden_by_rule {
+                    // Deny mode: check directory-only deny patterns (patterns ending with `/`)
+                    if let Some(pattern) = cfg.dir_matches_global_deny(path) {
+                        self.result.allowlist_violations.push(
+                            StructureViolation::denied_directory(
+                                path.to_path_buf(),
+                                "global".to_string(),
+                                pattern,
+                            ),
+                        );
+                    }
+
+                    // Check deny_dirs (basename-only matching from structure.deny_dirs)
+                    if let Some(pattern) = cfg.dir_matches_global_deny_basename(path) {
+                        self.result.allowlist_violations.push(
+                            StructureViolation::denied_directory(
+                                path.to_path_buf(),
+                                "global".to_string(),
+                                pattern,
+                            ),
+                        );
+                    }
+                }
+            }
+        }
+
+        // Check per-rule directory patterns
+        if let Some(rule) = matching_rule {
+            let rejected = if rule.has_dir_allowlist() {
+                // Allow mode: directory must match allowlist
+                (!rule.dir_matches(path)).then(|| {
+                    StructureViolation::disallowed_directory(path.to_path_buf(), rule.scope.clone())
+                })
+            } else {
+                // Deny mode: check per-rule deny_dirs
+                rule.dir_matches_deny(path).map(|pattern| {
+                    StructureViolation::denied_directory(
+                        path.to_path_buf(),
+                        rule.scope.clone(),
+                        pattern,
+                    )
+                })
+            };
+            // Check naming convention (only for allowed directories)
+            let violation = rejected.or_else(|| {
+                rule.dir_naming_mismatch(path).map(|expected| {
+                    StructureViolation::dir_naming_convention(
+                        path.to_path_buf(),
+                        rule.scope.clone(),
+                        expected,
+                    )
+                })
+            });
+            self.result.allowlist_violations.extend(violation);
+        }
+
+        // Check count_exclude
+        let is_count_excluded = self
+            .structure_config
+            .is_some_and(|cfg| cfg.is_count_excluded(path));
+
+        // Initialize this directory's stats
+        self.dir_entries
+            .entry(path.to_path_buf())
+            .or_insert_with(|| DirStats {
+                depth,
+                ..Default::default()
+            })
+            .count_excluded = is_count_excluded;
+
+        // Count as subdirectory for parent (if not excluded and not root)
+        if depth > 0
+            && !is_count_excluded
+            && let Some(parent) = path.parent()
+        {
+            let parent_stats = self
+                .dir_entries
+                .entry(parent.to_path_buf())
+                .or_insert_with(|| DirStats {
+                    depth: depth - 1,
+                    ..Default::default()
+                });
+            parent_stats.dir_count += 1;
+        }
+    }
+
+    /// Report each `require_files` pattern with no matching file in its directory,
+    /// and each departure from a layout template.
+    fn check_tracked_dirs(&mut self) {
+        let Some(cfg) = self.structure_config else {
+            return;
+        };
+        let mut dirs: Vec<_> = self.tracked_dirs.iter().collect();
+        dirs.sort_by_key(|(dir, _)| *dir);
+
+        for (dir, names) in dirs {
+            for rule in cfg.layout_rules_for(dir) {
+                let violations = rule.violations(dir, names);
+                self.result.allowlist_violations.extend(violations);
+            }
+            for rule in cfg.required_files_rules_for(dir) {
+                for pattern in rule.missing(dir, &names.files) {
+                    let violation = if rule.is_warning {
+                        StructureViolation::missing_required_file_warning(
+                            dir.clone(),
+                            rule.scope.clone(),
+                            pattern,
+                        )
+                    } else {
+                        StructureViolation::missing_required_file(
+                            dir.clone(),
+                            rule.scope.clone(),
+                            pattern,
+                        )
+                    };
+                    self.result.allowlist_violations.push(violation);
+                }
+            }
+        }
+    }
+
+    pub fn finalize(mut self) -> ScanResult {
+        self.check_tracked_dirs();
+        self.result
+            .allowlist_violations
+            .extend(self.case_collisions.into_violations());
+        self.result.dir_stats = self.dir_entries;
+        self.result
+    }
+}
+
+/// File or directory name of `path`.
+fn entry_name(path: &Path) -> String {
+    path.file_name()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .into_owned()
+}
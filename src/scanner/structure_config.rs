@@ -3,9 +3,13 @@ use std::path::Path;
 use globset::{Glob, GlobSet, GlobSetBuilder};
 
 use crate::SlocGuardError;
+use crate::checker::select_winner;
+use crate::config::RuleResolution;
 use crate::error::Result;
 
-use super::AllowlistRule;
+use super::{
+    AllowlistRule, FileSizeLimits, LayoutRule, PathLimitsConfig, RequiredFilesRule, SymlinkPolicy,
+};
 
 /// Configuration parameters for test helper constructor.
 ///
@@ -17,6 +21,14 @@ pub struct TestConfigParams {
     pub count_exclude_patterns: Vec<String>,
     pub scanner_exclude_patterns: Vec<String>,
     pub allowlist_rules: Vec<AllowlistRule>,
+    pub rule_resolution: RuleResolution,
+    pub required_files_rules: Vec<RequiredFilesRule>,
+    pub layout_rules: Vec<LayoutRule>,
+    pub path_limits: PathLimitsConfig,
+    pub file_size_limits: FileSizeLimits,
+    pub symlink_policy: SymlinkPolicy,
+    pub detect_case_collisions: bool,
+    pub detect_invalid_windows_names: bool,
     pub global_allow_extensions: Vec<String>,
     pub global_allow_files: Vec<String>,
     pub global_allow_dirs: Vec<String>,
@@ -37,6 +49,22 @@ pub struct StructureScanConfig {
     pub scanner_exclude_dir_names: Vec<String>,
     /// Allowlist rules from structure.rules with `allow_extensions`/`allow_patterns`.
     pub allowlist_rules: Vec<AllowlistRule>,
+    /// How overlapping allowlist rules of equal priority are resolved.
+    pub rule_resolution: RuleResolution,
+    /// `require_files` rules from structure.rules.
+    pub required_files_rules: Vec<RequiredFilesRule>,
+    /// Templates from structure.layouts.
+    pub layout_rules: Vec<LayoutRule>,
+    /// `max_path_length`/`max_filename_length`/`max_path_components` limits.
+    pub path_limits: PathLimitsConfig,
+    /// `max_file_bytes` limits and `allow_large_files` exemptions.
+    pub file_size_limits: FileSizeLimits,
+    /// `deny_symlinks` checks.
+    pub symlink_policy: SymlinkPolicy,
+    /// Report sibling names that differ only by case.
+    pub detect_case_collisions: bool,
+    /// Report names that are invalid on Windows.
+    pub detect_invalid_windows_names: bool,
     /// Global allow extensions (e.g., ".rs", ".py") - allowlist mode.
     pub global_allow_extensions: Vec<String>,
     /// Global allow patterns (compiled) for files - allowlist mode.
@@ -73,6 +101,14 @@ pub struct StructureScanConfigBuilder {
     count_exclude_patterns: Vec<String>,
     scanner_exclude_patterns: Vec<String>,
     allowlist_rules: Vec<AllowlistRule>,
+    rule_resolution: RuleResolution,
+    required_files_rules: Vec<RequiredFilesRule>,
+    layout_rules: Vec<LayoutRule>,
+    path_limits: PathLimitsConfig,
+    file_size_limits: FileSizeLimits,
+    symlink_policy: SymlinkPolicy,
+    detect_case_collisions: bool,
+    detect_invalid_windows_names: bool,
     global_allow_extensions: Vec<String>,
     global_allow_files: Vec<String>,
     global_allow_dirs: Vec<String>,
@@ -110,6 +146,62 @@ impl StructureScanConfigBuilder {
         self
     }
 
+    /// Set `require_files` rules from structure.rules.
+    #[must_use]
+    pub fn required_files_rules(mut self, rules: Vec<RequiredFilesRule>) -> Self {
+        self.required_files_rules = rules;
+        self
+    }
+
+    /// Set layout templates from structure.layouts.
+    #[must_use]
+    pub fn layout_rules(mut self, rules: Vec<LayoutRule>) -> Self {
+        self.layout_rules = rules;
+        self
+    }
+
+    /// Set path and name length limits.
+    #[must_use]
+    pub fn path_limits(mut self, path_limits: PathLimitsConfig) -> Self {
+        self.path_limits = path_limits;
+        self
+    }
+
+    /// Set file size limits.
+    #[must_use]
+    pub fn file_size_limits(mut self, file_size_limits: FileSizeLimits) -> Self {
+        self.file_size_limits = file_size_limits;
+        self
+    }
+
+    /// Set how overlapping allowlist rules of equal priority are resolved.
+    #[must_use]
+    pub const fn rule_resolution(mut self, rule_resolution: RuleResolution) -> Self {
+        self.rule_resolution = rule_resolution;
+        self
+    }
+
+    /// Set the symlink policy.
+    #[must_use]
+    pub fn symlink_policy(mut self, symlink_policy: SymlinkPolicy) -> Self {
+        self.symlink_policy = symlink_policy;
+        self
+    }
+
+    /// Enable case-insensitive name collision detection.
+    #[must_use]
+    pub const fn detect_case_collisions(mut self, enabled: bool) -> Self {
+        self.detect_case_collisions = enabled;
+        self
+    }
+
+    /// Enable detection of names that are invalid on Windows.
+    #[must_use]
+    pub const fn detect_invalid_windows_names(mut self, enabled: bool) -> Self {
+        self.detect_invalid_windows_names = enabled;
+        self
+    }
+
     /// Set global allow extensions (e.g., ".rs", ".py").
     #[must_use]
     pub fn global_allow_extensions(mut self, extensions: Vec<String>) -> Self {
@@ -187,6 +279,14 @@ impl StructureScanConfig {
             .count_exclude(params.count_exclude_patterns)
             .scanner_exclude(params.scanner_exclude_patterns)
             .allowlist_rules(params.allowlist_rules)
+            .rule_resolution(params.rule_resolution)
+            .required_files_rules(params.required_files_rules)
+            .layout_rules(params.layout_rules)
+            .path_limits(params.path_limits)
+            .file_size_limits(params.file_size_limits)
+            .symlink_policy(params.symlink_policy)
+            .detect_case_collisions(params.detect_case_collisions)
+            .detect_invalid_windows_names(params.detect_invalid_windows_names)
             .global_allow_extensions(params.global_allow_extensions)
             .global_allow_files(params.global_allow_files)
             .global_allow_dirs(params.global_allow_dirs)
@@ -243,6 +343,14 @@ impl StructureScanConfig {
             scanner_exclude,
             scanner_exclude_dir_names,
             allowlist_rules: builder.allowlist_rules,
+            rule_resolution: builder.rule_resolution,
+            required_files_rules: builder.required_files_rules,
+            layout_rules: builder.layout_rules,
+            path_limits: builder.path_limits,
+            file_size_limits: builder.file_size_limits,
+            symlink_policy: builder.symlink_policy,
+            detect_case_collisions: builder.detect_case_collisions,
+            detect_invalid_windows_names: builder.detect_invalid_windows_names,
             global_allow_extensions: builder.global_allow_extensions,
             global_allow_files: global_allow_files_compiled,
             global_allow_file_strs,
@@ -321,12 +429,42 @@ impl StructureScanConfig {
         self.count_exclude.is_match(file_name) || self.count_exclude.is_match(path)
     }
 
-    /// Find the first allowlist rule matching a directory.
+    /// Find the winning allowlist rule for a directory: highest `priority`, then
+    /// `rule_resolution`, as for structure limits.
     #[must_use]
     pub fn find_matching_allowlist_rule(&self, dir: &Path) -> Option<&AllowlistRule> {
-        self.allowlist_rules
+        let matching = self
+            .allowlist_rules
+            .iter()
+            .enumerate()
+            .filter(|(_, rule)| rule.matches_directory(dir))
+            .map(|(index, _)| index);
+        select_winner(
+            matching,
+            |index| self.allowlist_rules[index].rank(dir),
+            self.rule_resolution,
+        )
+        .map(|index| &self.allowlist_rules[index])
+    }
+
+    /// All `require_files` rules whose scope matches a directory.
+    pub(crate) fn required_files_rules_for<'a>(
+        &'a self,
+        dir: &'a Path,
+    ) -> impl Iterator<Item = &'a RequiredFilesRule> {
+        self.required_files_rules
+            .iter()
+            .filter(move |rule| rule.matches_directory(dir))
+    }
+
+    /// All layout templates whose scope matches a directory.
+    pub(crate) fn layout_rules_for<'a>(
+        &'a self,
+        dir: &'a Path,
+    ) -> impl Iterator<Item = &'a LayoutRule> {
+        self.layout_rules
             .iter()
-            .find(|r| r.matches_directory(dir))
+            .filter(move |rule| rule.matches_directory(dir))
     }
 
     /// Check if global file allowlist mode is enabled.
@@ -1,6 +1,7 @@
 use std::path::Path;
 
 use super::*;
+use crate::config::RuleResolution;
 use crate::scanner::TestConfigParams;
 
 // =============================================================================
@@ -248,7 +249,7 @@ fn structure_scan_config_find_no_matching_rule() {
 }
 
 #[test]
-fn find_matching_allowlist_rule_returns_first_match() {
+fn find_matching_allowlist_rule_picks_rule_by_scope() {
     let rule1 = AllowlistRuleBuilder::new("**/src/**".to_string())
         .with_extensions(vec![".rs".to_string()])
         .build()
@@ -275,3 +276,67 @@ fn find_matching_allowlist_rule_returns_first_match() {
     let none_rule = config.find_matching_allowlist_rule(Path::new("project/docs"));
     assert!(none_rule.is_none());
 }
+
+fn allowlist_rule(scope: &str, ext: &str, priority: Option<i32>) -> AllowlistRule {
+    AllowlistRuleBuilder::new(scope.to_string())
+        .with_extensions(vec![ext.to_string()])
+        .with_priority(priority)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn find_matching_allowlist_rule_prefers_higher_priority() {
+    let config = StructureScanConfig::new(TestConfigParams {
+        allowlist_rules: vec![
+            allowlist_rule("src/**", ".rs", Some(10)),
+            allowlist_rule("src/generated/**", ".json", None),
+        ],
+        ..Default::default()
+    })
+    .unwrap();
+
+    let rule = config
+        .find_matching_allowlist_rule(Path::new("src/generated/api"))
+        .unwrap();
+    assert_eq!(rule.allow_extensions, vec![".rs"]);
+}
+
+#[test]
+fn find_matching_allowlist_rule_prefers_most_specific_scope() {
+    let config = StructureScanConfig::new(TestConfigParams {
+        allowlist_rules: vec![
+            allowlist_rule("src/generated/**", ".json", None),
+            allowlist_rule("src/**", ".rs", None),
+        ],
+        rule_resolution: RuleResolution::MostSpecific,
+        ..Default::default()
+    })
+    .unwrap();
+
+    let rule = config
+        .find_matching_allowlist_rule(Path::new("src/generated/api"))
+        .unwrap();
+    assert_eq!(rule.allow_extensions, vec![".json"]);
+    let rule = config
+        .find_matching_allowlist_rule(Path::new("src/core"))
+        .unwrap();
+    assert_eq!(rule.allow_extensions, vec![".rs"]);
+}
+
+#[test]
+fn find_matching_allowlist_rule_last_match_wins_by_default() {
+    let config = StructureScanConfig::new(TestConfigParams {
+        allowlist_rules: vec![
+            allowlist_rule("src/generated/**", ".json", None),
+            allowlist_rule("src/**", ".rs", None),
+        ],
+        ..Default::default()
+    })
+    .unwrap();
+
+    let rule = config
+        .find_matching_allowlist_rule(Path::new("src/generated/api"))
+        .unwrap();
+    assert_eq!(rule.allow_extensions, vec![".rs"]);
+}
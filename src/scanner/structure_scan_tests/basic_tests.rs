@@ -1,5 +1,7 @@
 //! Basic `scan_with_structure` tests: file collection, stats, depth, filtering.
 
+use std::collections::HashMap;
+
 use tempfile::TempDir;
 
 use super::*;
@@ -102,6 +104,65 @@ fn scan_with_structure_respects_count_exclude() {
     assert_eq!(result.files.len(), 2);
     // Only .rs file is counted (txt is excluded from count)
     assert_eq!(result.dir_stats[&src_dir].file_count, 1);
+    // Extension buckets follow count_exclude too
+    assert_eq!(
+        result.dir_stats[&src_dir].files_by_ext,
+        HashMap::from([(".rs".to_string(), 1)])
+    );
+}
+
+#[test]
+fn scan_with_structure_counts_all_entries() {
+    let temp_dir = TempDir::new().unwrap();
+    let src_dir = temp_dir.path().join("src");
+    let empty_dir = src_dir.join("empty");
+    std::fs::create_dir_all(&empty_dir).unwrap();
+    std::fs::write(src_dir.join("main.rs"), "").unwrap();
+    std::fs::write(src_dir.join(".gitkeep"), "").unwrap();
+
+    let config = StructureScanConfig::new(TestConfigParams {
+        count_exclude_patterns: vec![".gitkeep".to_string()],
+        ..Default::default()
+    })
+    .unwrap();
+    let scanner = DirectoryScanner::new(AcceptAllFilter);
+    let result = scanner
+        .scan_with_structure(temp_dir.path(), Some(&config))
+        .unwrap();
+
+    // entry_count includes count_exclude matches; file_count does not
+    assert_eq!(result.dir_stats[&src_dir].entry_count, 3);
+    assert_eq!(result.dir_stats[&src_dir].file_count, 1);
+    assert_eq!(result.dir_stats[&src_dir].dir_count, 1);
+    assert_eq!(result.dir_stats[&empty_dir].entry_count, 0);
+    assert_eq!(
+        result.dir_stats[&temp_dir.path().to_path_buf()].entry_count,
+        1
+    );
+}
+
+#[test]
+fn scan_with_structure_marks_count_excluded_directories() {
+    let temp_dir = TempDir::new().unwrap();
+    let src_dir = temp_dir.path().join("src");
+    let generated = src_dir.join("generated");
+    std::fs::create_dir_all(&generated).unwrap();
+    std::fs::write(generated.join("out.rs"), "").unwrap();
+
+    let config = StructureScanConfig::new(TestConfigParams {
+        count_exclude_patterns: vec!["generated".to_string()],
+        ..Default::default()
+    })
+    .unwrap();
+    let scanner = DirectoryScanner::new(AcceptAllFilter);
+    let result = scanner
+        .scan_with_structure(temp_dir.path(), Some(&config))
+        .unwrap();
+
+    // Excluded from the parent's dir_count and flagged so totals skip it too
+    assert_eq!(result.dir_stats[&src_dir].dir_count, 0);
+    assert!(!result.dir_stats[&src_dir].count_excluded);
+    assert!(result.dir_stats[&generated].count_excluded);
 }
 
 #[test]
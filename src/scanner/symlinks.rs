@@ -0,0 +1,90 @@
+//! Symlink checks for the structure scan (`deny_symlinks`) and target
+//! resolution shared with `explain`.
+
+use std::path::{Component, Path, PathBuf};
+
+use crate::checker::{StructureViolation, SymlinkProblem};
+use crate::config::DenySymlinks;
+
+/// Which symlinks the structure scan reports.
+#[derive(Debug, Clone, Default)]
+pub struct SymlinkPolicy {
+    /// `[structure]` `deny_symlinks` mode.
+    pub deny_symlinks: DenySymlinks,
+    /// Canonical project root that decides which targets are external.
+    pub project_root: PathBuf,
+}
+
+impl SymlinkPolicy {
+    /// Create a policy for a canonical project root.
+    #[must_use]
+    pub const fn new(deny_symlinks: DenySymlinks, project_root: PathBuf) -> Self {
+        Self {
+            deny_symlinks,
+            project_root,
+        }
+    }
+
+    /// Violation for the symlink at `path`, if the policy rejects it.
+    ///
+    /// Links resolving outside the project root are reported as such in both modes.
+    pub(crate) fn violation(&self, path: &Path) -> Option<StructureViolation> {
+        if !self.deny_symlinks.is_enabled() {
+            return None;
+        }
+        let target = std::fs::read_link(path).ok()?;
+        let problem = if !resolve_symlink(path, &target).starts_with(&self.project_root) {
+            SymlinkProblem::OutsideProject
+        } else if self.deny_symlinks == DenySymlinks::All {
+            SymlinkProblem::Denied
+        } else {
+            return None;
+        };
+        Some(StructureViolation::symlink(
+            path.to_path_buf(),
+            target.to_string_lossy().into_owned(),
+            problem,
+        ))
+    }
+}
+
+/// Absolute path the symlink at `link` (pointing to `target`) resolves to.
+///
+/// Existing targets are canonicalized, following any further links. Dangling
+/// links are resolved lexically against the link's directory.
+#[must_use]
+pub fn resolve_symlink(link: &Path, target: &Path) -> PathBuf {
+    dunce::canonicalize(link).unwrap_or_else(|_| {
+        let parent = link.parent().unwrap_or_else(|| Path::new(""));
+        let base = dunce::canonicalize(parent)
+            .or_else(|_| std::path::absolute(parent))
+            .unwrap_or_else(|_| parent.to_path_buf());
+        normalize_lexically(&base.join(target))
+    })
+}
+
+/// Remove `.` components and fold `..` into the preceding component.
+fn normalize_lexically(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+/// Symlink loop behind an `ignore` walker error, as (looping path, ancestor).
+pub fn ignore_loop(err: &ignore::Error) -> Option<(&Path, &Path)> {
+    match err {
+        ignore::Error::Loop { ancestor, child } => Some((child, ancestor)),
+        ignore::Error::WithPath { err, .. }
+        | ignore::Error::WithDepth { err, .. }
+        | ignore::Error::WithLineNumber { err, .. } => ignore_loop(err),
+        _ => None,
+    }
+}
@@ -0,0 +1,228 @@
+use std::path::{Path, PathBuf};
+
+use tempfile::TempDir;
+
+use super::*;
+use crate::checker::{StructureViolation, SymlinkProblem, ViolationType};
+use crate::config::DenySymlinks;
+use crate::scanner::TestConfigParams;
+
+struct AcceptAllFilter;
+
+impl FileFilter for AcceptAllFilter {
+    fn should_include(&self, _path: &Path) -> bool {
+        true
+    }
+}
+
+#[cfg(unix)]
+fn symlink(target: impl AsRef<Path>, link: impl AsRef<Path>) {
+    std::os::unix::fs::symlink(target, link).unwrap();
+}
+
+fn symlink_problem(violation: &StructureViolation) -> Option<(String, SymlinkProblem)> {
+    match &violation.violation_type {
+        ViolationType::Symlink { target, problem } => Some((target.clone(), *problem)),
+        _ => None,
+    }
+}
+
+fn policy(deny_symlinks: DenySymlinks, root: &Path) -> SymlinkPolicy {
+    SymlinkPolicy::new(deny_symlinks, dunce::canonicalize(root).unwrap())
+}
+
+fn scan(
+    root: &Path,
+    symlink_policy: SymlinkPolicy,
+    use_gitignore: bool,
+    follow_symlinks: bool,
+) -> ScanResult {
+    let config = StructureScanConfig::new(TestConfigParams {
+        symlink_policy,
+        ..Default::default()
+    })
+    .unwrap();
+    DirectoryScanner::with_gitignore(AcceptAllFilter, use_gitignore)
+        .with_follow_symlinks(follow_symlinks)
+        .scan_with_structure(root, Some(&config))
+        .unwrap()
+}
+
+// =============================================================================
+// resolve_symlink Tests
+// =============================================================================
+
+#[cfg(unix)]
+#[test]
+fn resolve_symlink_resolves_dangling_links_lexically() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = dunce::canonicalize(temp_dir.path()).unwrap();
+    let link = root.join("src/link");
+
+    assert_eq!(
+        resolve_symlink(&link, Path::new("../missing/./file.rs")),
+        root.join("missing/file.rs")
+    );
+    assert_eq!(
+        resolve_symlink(&link, Path::new("/elsewhere/file.rs")),
+        PathBuf::from("/elsewhere/file.rs")
+    );
+}
+
+#[test]
+fn disabled_policy_reports_nothing() {
+    let policy = SymlinkPolicy::default();
+
+    assert!(policy.violation(Path::new("does/not/matter")).is_none());
+}
+
+// =============================================================================
+// Scan Integration Tests
+// =============================================================================
+
+#[cfg(unix)]
+#[test]
+fn deny_symlinks_reports_file_and_directory_links() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    std::fs::create_dir(root.join("shared")).unwrap();
+    std::fs::write(root.join("shared/lib.rs"), "").unwrap();
+    symlink("shared", root.join("shared_link"));
+    symlink("shared/lib.rs", root.join("lib_link.rs"));
+
+    for use_gitignore in [false, true] {
+        let result = scan(root, policy(DenySymlinks::All, root), use_gitignore, false);
+        let mut found: Vec<_> = result
+            .allowlist_violations
+            .iter()
+            .map(|v| (v.path.clone(), symlink_problem(v).unwrap()))
+            .collect();
+        found.sort_by(|a, b| a.0.cmp(&b.0));
+
+        assert_eq!(
+            found,
+            vec![
+                (
+                    root.join("lib_link.rs"),
+                    ("shared/lib.rs".to_string(), SymlinkProblem::Denied)
+                ),
+                (
+                    root.join("shared_link"),
+                    ("shared".to_string(), SymlinkProblem::Denied)
+                ),
+            ]
+        );
+        // Links are not followed, so only the real file is scanned
+        assert_eq!(result.files, vec![root.join("shared/lib.rs")]);
+    }
+}
+
+#[cfg(unix)]
+#[test]
+fn external_mode_only_reports_links_leaving_the_project() {
+    let project = TempDir::new().unwrap();
+    let outside = TempDir::new().unwrap();
+    let root = project.path();
+    std::fs::write(root.join("inside.rs"), "").unwrap();
+    symlink("inside.rs", root.join("internal_link.rs"));
+    symlink(outside.path(), root.join("external_dir"));
+    symlink("../../nowhere", root.join("dangling_external"));
+
+    let result = scan(root, policy(DenySymlinks::External, root), false, false);
+    let mut paths: Vec<_> = result
+        .allowlist_violations
+        .iter()
+        .map(|v| {
+            assert_eq!(
+                symlink_problem(v).unwrap().1,
+                SymlinkProblem::OutsideProject
+            );
+            v.path.clone()
+        })
+        .collect();
+    paths.sort();
+
+    assert_eq!(
+        paths,
+        vec![root.join("dangling_external"), root.join("external_dir")]
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn followed_symlinked_directories_are_scanned_and_marked() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    std::fs::create_dir(root.join("real")).unwrap();
+    std::fs::write(root.join("real/a.rs"), "").unwrap();
+    symlink("real", root.join("alias"));
+
+    for use_gitignore in [false, true] {
+        let result = scan(root, SymlinkPolicy::default(), use_gitignore, true);
+
+        assert!(result.files.contains(&root.join("alias/a.rs")));
+        assert!(result.dir_stats[&root.join("alias")].is_symlink);
+        assert_eq!(result.dir_stats[&root.join("alias")].file_count, 1);
+        assert!(!result.dir_stats[&root.join("real")].is_symlink);
+        assert!(result.allowlist_violations.is_empty());
+    }
+}
+
+#[cfg(unix)]
+#[test]
+fn symlink_loops_are_skipped_with_a_warning() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    std::fs::create_dir(root.join("a")).unwrap();
+    std::fs::write(root.join("a/a.rs"), "").unwrap();
+    symlink("..", root.join("a/up"));
+
+    for use_gitignore in [false, true] {
+        let result = scan(root, SymlinkPolicy::default(), use_gitignore, true);
+
+        assert_eq!(result.files, vec![root.join("a/a.rs")]);
+        assert_eq!(result.allowlist_violations.len(), 1);
+        let violation = &result.allowlist_violations[0];
+        assert_eq!(violation.path, root.join("a/up"));
+        assert!(violation.is_warning);
+        assert_eq!(symlink_problem(violation).unwrap().1, SymlinkProblem::Loop);
+    }
+}
+
+#[cfg(unix)]
+#[test]
+fn scanner_excluded_symlinks_are_ignored() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    std::fs::write(root.join("a.rs"), "").unwrap();
+    symlink("a.rs", root.join("b.rs"));
+
+    let config = StructureScanConfig::new(TestConfigParams {
+        scanner_exclude_patterns: vec!["**/b.rs".to_string()],
+        symlink_policy: policy(DenySymlinks::All, root),
+        ..Default::default()
+    })
+    .unwrap();
+    let result = DirectoryScanner::new(AcceptAllFilter)
+        .scan_with_structure(root, Some(&config))
+        .unwrap();
+
+    assert!(result.allowlist_violations.is_empty());
+}
+
+#[cfg(unix)]
+#[test]
+fn deny_all_marks_external_links_as_outside_project() {
+    let project = TempDir::new().unwrap();
+    let outside = TempDir::new().unwrap();
+    let root = project.path();
+    symlink(outside.path(), root.join("external_dir"));
+
+    let result = scan(root, policy(DenySymlinks::All, root), false, false);
+
+    assert_eq!(result.allowlist_violations.len(), 1);
+    assert_eq!(
+        symlink_problem(&result.allowlist_violations[0]).unwrap().1,
+        SymlinkProblem::OutsideProject
+    );
+}
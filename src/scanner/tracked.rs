@@ -0,0 +1,163 @@
+//! Structure scan over a fixed set of files instead of the filesystem.
+//!
+//! `check --staged` scans the git index this way, so structure rules see the tree
+//! that is about to be committed rather than the working directory; `check --rev`
+//! scans a revision's tree through `TreeScanner`. Directories are derived from the
+//! file paths, so a tree has no empty directories.
+
+use std::collections::{BTreeSet, HashSet};
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+
+use super::filter::GlobFilter;
+use super::scan_state::StructureScanState;
+use super::{FileFilter, FileScanner, ScanResult, StructureScanConfig};
+use crate::error::Result;
+
+/// Files to scan in place of the filesystem, such as the git index.
+pub trait TrackedTree: Send + Sync {
+    /// Absolute paths of the tracked regular files.
+    fn files(&self) -> Vec<PathBuf>;
+
+    /// Size in bytes of a tracked file's content.
+    fn file_size(&self, path: &Path) -> Option<u64>;
+}
+
+/// Scanner over a `TrackedTree` in place of the filesystem.
+///
+/// Applies exclude patterns like `CompositeScanner`; `.gitignore` does not apply
+/// because a tree only holds tracked files.
+pub struct TreeScanner {
+    tree: Arc<dyn TrackedTree>,
+    exclude_patterns: Vec<String>,
+}
+
+impl TreeScanner {
+    #[must_use]
+    pub fn new(tree: Arc<dyn TrackedTree>, exclude_patterns: Vec<String>) -> Self {
+        Self {
+            tree,
+            exclude_patterns,
+        }
+    }
+}
+
+impl FileScanner for TreeScanner {
+    fn scan(&self, root: &Path) -> Result<Vec<PathBuf>> {
+        Ok(self.scan_with_structure(root, None)?.files)
+    }
+
+    fn scan_with_structure(
+        &self,
+        root: &Path,
+        structure_config: Option<&StructureScanConfig>,
+    ) -> Result<ScanResult> {
+        let filter = GlobFilter::new(Vec::new(), &self.exclude_patterns)?;
+        Ok(scan_tracked_tree(
+            root,
+            self.tree.as_ref(),
+            &filter,
+            structure_config,
+        ))
+    }
+
+    fn scan_tracked_with_structure(
+        &self,
+        paths: &[PathBuf],
+        tree: &dyn TrackedTree,
+        structure_config: Option<&StructureScanConfig>,
+    ) -> Result<ScanResult> {
+        let filter = GlobFilter::new(Vec::new(), &self.exclude_patterns)?;
+
+        let mut combined = ScanResult::default();
+        for path in paths {
+            combined.merge(scan_tracked_tree(path, tree, &filter, structure_config));
+        }
+        Ok(combined)
+    }
+}
+
+/// Scan the tracked files under `root` as if walking the filesystem from it.
+///
+/// Paths in the result are `root` joined with the path below it, as the directory
+/// walkers report them. Scanner-excluded directories are pruned with their contents.
+pub fn scan_tracked_tree(
+    root: &Path,
+    tree: &dyn TrackedTree,
+    filter: &impl FileFilter,
+    structure_config: Option<&StructureScanConfig>,
+) -> ScanResult {
+    let root_abs = absolute_path(root);
+    let files: BTreeSet<PathBuf> = tree
+        .files()
+        .into_iter()
+        .filter_map(|file| Some(file.strip_prefix(&root_abs).ok()?.to_path_buf()))
+        .collect();
+
+    let mut state = StructureScanState::new(structure_config);
+    if files.contains(Path::new("")) {
+        // The root is itself a tracked file
+        let file_size = || tree.file_size(&root_abs);
+        state.process_file(root, 0, filter, &root_abs, file_size);
+        return state.finalize();
+    }
+
+    state.process_directory(root, 0);
+    let mut visited: HashSet<PathBuf> = HashSet::new();
+    let mut excluded: HashSet<PathBuf> = HashSet::new();
+    'files: for relative in &files {
+        // Visit each directory once, before its contents (walk order)
+        let mut dir = PathBuf::new();
+        let components: Vec<_> = relative.components().collect();
+        for (depth, component) in components.iter().enumerate().take(components.len() - 1) {
+            dir.push(component);
+            if excluded.contains(&dir) {
+                continue 'files;
+            }
+            if visited.insert(dir.clone()) {
+                let path = root.join(&dir);
+                if structure_config.is_some_and(|cfg| cfg.is_scanner_excluded(&path, true)) {
+                    excluded.insert(dir);
+                    continue 'files;
+                }
+                state.process_directory(&path, depth + 1);
+            }
+        }
+
+        let abs_path = root_abs.join(relative);
+        let file_size = || tree.file_size(&abs_path);
+        state.process_file(
+            &root.join(relative),
+            components.len(),
+            filter,
+            &abs_path,
+            file_size,
+        );
+    }
+
+    state.finalize()
+}
+
+/// `path` made absolute against the current directory, resolving symlinks when the
+/// path exists and `.`/`..` components otherwise.
+#[must_use]
+pub fn absolute_path(path: &Path) -> PathBuf {
+    if let Ok(canonical) = dunce::canonicalize(path) {
+        return canonical;
+    }
+    let base = std::env::current_dir()
+        .ok()
+        .and_then(|dir| dunce::canonicalize(dir).ok())
+        .unwrap_or_default();
+    let mut absolute = PathBuf::new();
+    for component in base.join(path).components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                absolute.pop();
+            }
+            other => absolute.push(other),
+        }
+    }
+    absolute
+}
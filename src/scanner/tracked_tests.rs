@@ -0,0 +1,113 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use super::*;
+use tempfile::TempDir;
+
+struct AcceptAllFilter;
+
+impl FileFilter for AcceptAllFilter {
+    fn should_include(&self, _path: &Path) -> bool {
+        true
+    }
+}
+
+/// In-memory tree rooted at an existing directory, like an index over a workdir.
+struct FakeTree {
+    files: HashMap<PathBuf, u64>,
+}
+
+impl FakeTree {
+    fn new(root: &Path, files: &[&str]) -> Self {
+        let files = files.iter().map(|file| (root.join(file), 10)).collect();
+        Self { files }
+    }
+}
+
+impl TrackedTree for FakeTree {
+    fn files(&self) -> Vec<PathBuf> {
+        self.files.keys().cloned().collect()
+    }
+
+    fn file_size(&self, path: &Path) -> Option<u64> {
+        self.files.get(path).copied()
+    }
+}
+
+fn canonical_temp_dir() -> (TempDir, PathBuf) {
+    let temp_dir = TempDir::new().unwrap();
+    let root = dunce::canonicalize(temp_dir.path()).unwrap();
+    (temp_dir, root)
+}
+
+#[test]
+fn scan_tracked_tree_uses_tree_files_not_disk() {
+    let (_temp_dir, root) = canonical_temp_dir();
+    std::fs::write(root.join("untracked.rs"), "").unwrap();
+    let tree = FakeTree::new(&root, &["main.rs", "src/lib.rs", "src/util/mod.rs"]);
+
+    let result = scan_tracked_tree(&root, &tree, &AcceptAllFilter, None);
+
+    let mut files = result.files.clone();
+    files.sort();
+    assert_eq!(
+        files,
+        vec![
+            root.join("main.rs"),
+            root.join("src/lib.rs"),
+            root.join("src/util/mod.rs"),
+        ]
+    );
+    assert_eq!(result.dir_stats[&root].file_count, 1);
+    assert_eq!(result.dir_stats[&root].dir_count, 1);
+    assert_eq!(result.dir_stats[&root.join("src")].file_count, 1);
+    assert_eq!(result.dir_stats[&root.join("src/util")].depth, 2);
+}
+
+#[test]
+fn scan_tracked_tree_limits_to_files_under_root() {
+    let (_temp_dir, root) = canonical_temp_dir();
+    std::fs::create_dir(root.join("src")).unwrap();
+    let tree = FakeTree::new(&root, &["main.rs", "src/lib.rs"]);
+
+    let result = scan_tracked_tree(&root.join("src"), &tree, &AcceptAllFilter, None);
+
+    assert_eq!(result.files, vec![root.join("src/lib.rs")]);
+}
+
+#[test]
+fn scan_tracked_tree_prunes_scanner_excluded_dirs() {
+    let (_temp_dir, root) = canonical_temp_dir();
+    let tree = FakeTree::new(&root, &["src/lib.rs", "target/debug/build.rs"]);
+    let config = StructureScanConfig::new(TestConfigParams {
+        scanner_exclude_patterns: vec!["**/target".to_string()],
+        ..Default::default()
+    })
+    .unwrap();
+
+    let result = scan_tracked_tree(&root, &tree, &AcceptAllFilter, Some(&config));
+
+    assert_eq!(result.files, vec![root.join("src/lib.rs")]);
+    assert!(!result.dir_stats.contains_key(&root.join("target")));
+    assert!(!result.dir_stats.contains_key(&root.join("target/debug")));
+}
+
+#[test]
+fn scan_tracked_tree_accepts_a_file_as_root() {
+    let (_temp_dir, root) = canonical_temp_dir();
+    std::fs::write(root.join("main.rs"), "").unwrap();
+    let tree = FakeTree::new(&root, &["main.rs"]);
+
+    let result = scan_tracked_tree(&root.join("main.rs"), &tree, &AcceptAllFilter, None);
+
+    assert_eq!(result.files, vec![root.join("main.rs")]);
+}
+
+#[test]
+fn absolute_path_resolves_missing_paths_lexically() {
+    let (_temp_dir, root) = canonical_temp_dir();
+
+    let path = absolute_path(&root.join("missing/../also_missing/./file.rs"));
+
+    assert_eq!(path, root.join("also_missing/file.rs"));
+}
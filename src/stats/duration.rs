@@ -1,6 +1,6 @@
 //! Duration parsing for `--since` flag.
 //!
-//! Supports human-readable duration strings: `7d`, `30d`, `1w`, `12h`, `5m`, `300s`.
+//! Supports human-readable duration strings: `7d`, `30d`, `1w`, `1y`, `12h`, `5m`, `300s`.
 
 use crate::{Result, SlocGuardError};
 
@@ -9,6 +9,7 @@ const SECONDS_PER_MINUTE: u64 = 60;
 const SECONDS_PER_HOUR: u64 = 3600;
 const SECONDS_PER_DAY: u64 = 86400;
 const SECONDS_PER_WEEK: u64 = 604_800;
+const SECONDS_PER_YEAR: u64 = 365 * SECONDS_PER_DAY;
 
 /// Parse a duration string into seconds.
 ///
@@ -18,6 +19,7 @@ const SECONDS_PER_WEEK: u64 = 604_800;
 /// - `12h` - 12 hours
 /// - `7d` - 7 days
 /// - `1w` - 1 week
+/// - `1y` - 1 year (365 days)
 ///
 /// # Errors
 /// Returns an error if the format is invalid.
@@ -72,9 +74,10 @@ pub fn parse_duration(input: &str) -> Result<u64> {
         "h" | "hr" | "hrs" | "hour" | "hours" => SECONDS_PER_HOUR,
         "d" | "day" | "days" => SECONDS_PER_DAY,
         "w" | "wk" | "wks" | "week" | "weeks" => SECONDS_PER_WEEK,
+        "y" | "yr" | "yrs" | "year" | "years" => SECONDS_PER_YEAR,
         _ => {
             return Err(SlocGuardError::Config(format!(
-                "Invalid duration unit: '{unit}'. Supported units: s, m, h, d, w"
+                "Invalid duration unit: '{unit}'. Supported units: s, m, h, d, w, y"
             )));
         }
     };
@@ -121,6 +124,12 @@ mod tests {
         assert_eq!(parse_duration("4wk").unwrap(), 4 * 604_800);
     }
 
+    #[test]
+    fn test_parse_years() {
+        assert_eq!(parse_duration("1y").unwrap(), 365 * 86400);
+        assert_eq!(parse_duration("2years").unwrap(), 2 * 365 * 86400);
+    }
+
     #[test]
     fn test_case_insensitive() {
         assert_eq!(parse_duration("7D").unwrap(), 7 * 86400);
@@ -262,6 +262,26 @@ impl TrendHistory {
         self.entries.push(entry);
     }
 
+    /// Insert an entry in chronological order (used to backfill past commits).
+    ///
+    /// Returns `false` without inserting when an entry for the same commit at the
+    /// same time is already recorded, so repeated backfills do not duplicate history.
+    pub fn insert_entry(&mut self, entry: TrendEntry) -> bool {
+        let exists = entry.git_ref.is_some()
+            && self
+                .entries
+                .iter()
+                .any(|e| e.timestamp == entry.timestamp && e.git_ref == entry.git_ref);
+        if exists {
+            return false;
+        }
+        let index = self
+            .entries
+            .partition_point(|e| e.timestamp <= entry.timestamp);
+        self.entries.insert(index, entry);
+        true
+    }
+
     /// Compute delta from the latest entry to current stats.
     #[must_use]
     pub fn compute_delta(&self, current: &ProjectStatistics) -> Option<TrendDelta> {
@@ -367,3 +367,30 @@ fn test_git_context_preserved_in_save_load() {
     assert_eq!(entry.git_ref, Some("abc1234".to_string()));
     assert_eq!(entry.git_branch, Some("develop".to_string()));
 }
+
+#[test]
+fn test_insert_entry_keeps_chronological_order() {
+    let mut history = TrendHistory::new();
+    history.add_entry(make_entry(100));
+    history.add_entry(make_entry(300));
+
+    assert!(history.insert_entry(make_entry(200)));
+    assert!(history.insert_entry(make_entry(50)));
+
+    let timestamps: Vec<u64> = history.entries().iter().map(|e| e.timestamp).collect();
+    assert_eq!(timestamps, vec![50, 100, 200, 300]);
+}
+
+#[test]
+fn test_insert_entry_skips_recorded_commit() {
+    let mut history = TrendHistory::new();
+    let entry = make_entry(100).with_git_context(Some("a1b2c3d".to_string()), None);
+
+    assert!(history.insert_entry(entry.clone()));
+    assert!(!history.insert_entry(entry));
+    // Entries without a commit are never treated as duplicates
+    assert!(history.insert_entry(make_entry(100)));
+    assert!(history.insert_entry(make_entry(100)));
+
+    assert_eq!(history.len(), 3);
+}
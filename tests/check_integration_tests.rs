@@ -2,6 +2,7 @@
 //!
 //! Organized into domain-focused submodules:
 //! - `check_core_tests`: Basic behavior, CLI overrides, verbosity, error handling
+//! - `check_git_tests`: `--diff`/`--staged`/`--changed`/`--rev` and structure scope
 //! - `check_output_tests`: Output formats (json, sarif, markdown, html)
 //! - `check_rules_tests`: Content rules, structure checks, path normalization
 //! - `check_state_tests`: Baseline, auto-snapshot, line counting
@@ -10,6 +11,7 @@ mod common;
 
 mod check_integration_tests {
     mod check_core_tests;
+    mod check_git_tests;
     mod check_output_tests;
     mod check_rules_tests;
     mod check_state_tests;
@@ -0,0 +1,446 @@
+//! Git-aware checks - `--diff`/`--staged`/`--changed`/`--rev` and structure scope.
+
+use std::path::Path;
+use std::process::Command;
+
+use crate::common::TestFixture;
+use crate::sloc_guard;
+use predicates::prelude::*;
+
+fn git(dir: &Path, args: &[&str]) {
+    let output = Command::new("git")
+        .args([
+            "-c",
+            "user.email=test@test.com",
+            "-c",
+            "user.name=Test User",
+        ])
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .expect("Failed to run git");
+    assert!(output.status.success(), "git {args:?} failed: {output:?}");
+}
+
+fn commit_all(dir: &Path, message: &str) {
+    git(dir, &["add", "-A"]);
+    git(dir, &["commit", "-q", "-m", message]);
+}
+
+const MAX_FILES_CONFIG: &str = r#"version = "2"
+
+[structure]
+max_files = 2
+"#;
+
+/// Repository whose `crowded/` directory is already over `max_files` before the diff.
+fn fixture_with_crowded_dir() -> TestFixture {
+    let fixture = TestFixture::new();
+    git(fixture.path(), &["init", "-q"]);
+    fixture.create_config(MAX_FILES_CONFIG);
+    fixture.create_rust_file("crowded/a.rs", 1);
+    fixture.create_rust_file("crowded/b.rs", 1);
+    fixture.create_rust_file("crowded/c.rs", 1);
+    fixture.create_rust_file("src/main.rs", 1);
+    commit_all(fixture.path(), "Initial commit");
+    fixture
+}
+
+// =============================================================================
+// Structure Scope Tests
+// =============================================================================
+
+#[test]
+fn check_diff_ignores_structure_violations_in_untouched_directories() {
+    let fixture = fixture_with_crowded_dir();
+    fixture.create_rust_file("src/lib.rs", 1);
+    commit_all(fixture.path(), "Add lib");
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["check", "--no-sloc-cache", "--diff", "HEAD~1"])
+        .args(["--structure-scope", "changed"])
+        .assert()
+        .success();
+
+    // The full tree is still evaluated by default
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["check", "--no-sloc-cache", "--diff", "HEAD~1"])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("crowded"));
+}
+
+#[test]
+fn check_diff_reports_structure_violations_in_changed_directories() {
+    let fixture = fixture_with_crowded_dir();
+    fixture.create_rust_file("src/lib.rs", 1);
+    fixture.create_rust_file("src/extra.rs", 1);
+    commit_all(fixture.path(), "Add files");
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["check", "--no-sloc-cache", "--diff", "HEAD~1"])
+        .args(["--structure-scope", "changed"])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("src"))
+        .stdout(predicate::str::contains("crowded").not());
+}
+
+#[test]
+fn check_staged_scopes_structure_to_renamed_entries() {
+    let fixture = fixture_with_crowded_dir();
+    git(fixture.path(), &["mv", "crowded/c.rs", "src/c.rs"]);
+    fixture.create_rust_file("src/d.rs", 1);
+    git(fixture.path(), &["add", "-A"]);
+
+    // `crowded/` lost an entry and is back within limits; `src/` is now over
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["check", "--no-sloc-cache", "--staged"])
+        .args(["--structure-scope", "changed"])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("src"));
+}
+
+#[test]
+fn check_structure_scope_changed_requires_diff() {
+    let fixture = fixture_with_crowded_dir();
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["check", "--no-sloc-cache", "--structure-scope", "changed"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "--structure-scope changed requires --diff, --staged or --changed",
+        ));
+}
+
+// =============================================================================
+// Staged Content Tests
+// =============================================================================
+
+const MAX_LINES_CONFIG: &str = r#"version = "2"
+
+[content]
+max_lines = 5
+"#;
+
+#[test]
+fn check_staged_counts_index_content_not_working_tree() {
+    let fixture = TestFixture::new();
+    git(fixture.path(), &["init", "-q"]);
+    fixture.create_config(MAX_LINES_CONFIG);
+    fixture.create_rust_file("src/main.rs", 1);
+    commit_all(fixture.path(), "Initial commit");
+
+    // Stage a small change, then grow the file past the limit without staging it
+    fixture.create_rust_file("src/main.rs", 3);
+    git(fixture.path(), &["add", "src/main.rs"]);
+    fixture.create_rust_file("src/main.rs", 10);
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["check", "--staged"])
+        .assert()
+        .success();
+
+    // Staged over the limit while the working tree is back under it
+    fixture.create_rust_file("src/main.rs", 10);
+    git(fixture.path(), &["add", "src/main.rs"]);
+    fixture.create_rust_file("src/main.rs", 2);
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["check", "--staged"])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("main.rs"));
+}
+
+#[test]
+fn check_staged_structure_ignores_unstaged_files() {
+    let fixture = TestFixture::new();
+    git(fixture.path(), &["init", "-q"]);
+    fixture.create_config(MAX_FILES_CONFIG);
+    fixture.create_rust_file("src/a.rs", 1);
+    commit_all(fixture.path(), "Initial commit");
+
+    // Only b.rs is staged; the untracked c.rs and d.rs are not part of the commit
+    fixture.create_rust_file("src/b.rs", 1);
+    git(fixture.path(), &["add", "src/b.rs"]);
+    fixture.create_rust_file("src/c.rs", 1);
+    fixture.create_rust_file("src/d.rs", 1);
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["check", "--no-sloc-cache", "--staged"])
+        .assert()
+        .success();
+
+    git(fixture.path(), &["add", "src/c.rs"]);
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["check", "--no-sloc-cache", "--staged"])
+        .args(["--structure-scope", "changed"])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("src"));
+}
+
+// =============================================================================
+// Revision Tests
+// =============================================================================
+
+#[test]
+fn check_rev_reads_content_from_the_revision() {
+    let fixture = TestFixture::new();
+    git(fixture.path(), &["init", "-q"]);
+    fixture.create_config(MAX_LINES_CONFIG);
+    fixture.create_rust_file("src/main.rs", 10);
+    commit_all(fixture.path(), "Too long");
+    fixture.create_rust_file("src/main.rs", 2);
+    commit_all(fixture.path(), "Shorten");
+
+    // The working tree no longer matters, even when the file is gone
+    std::fs::remove_file(fixture.path().join("src/main.rs")).unwrap();
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["check", "--rev", "HEAD"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("1 files checked"));
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["check", "--rev", "HEAD~1"])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("main.rs"));
+}
+
+#[test]
+fn check_rev_checks_structure_of_the_revision_tree() {
+    let fixture = fixture_with_crowded_dir();
+    git(fixture.path(), &["tag", "v1"]);
+    git(fixture.path(), &["rm", "-q", "crowded/c.rs"]);
+    commit_all(fixture.path(), "Thin out crowded");
+
+    // Untracked files are not part of any revision
+    fixture.create_rust_file("src/untracked_a.rs", 1);
+    fixture.create_rust_file("src/untracked_b.rs", 1);
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["check", "--no-sloc-cache", "--rev", "HEAD"])
+        .assert()
+        .success();
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["check", "--no-sloc-cache", "--rev", "v1"])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("crowded"));
+}
+
+#[test]
+fn check_rev_works_in_a_bare_repository() {
+    let fixture = fixture_with_crowded_dir();
+    let mirror = TestFixture::new();
+    let source = fixture.path().to_string_lossy().to_string();
+    git(
+        mirror.path(),
+        &["clone", "-q", "--bare", &source, "mirror.git"],
+    );
+
+    sloc_guard!()
+        .current_dir(mirror.path().join("mirror.git"))
+        .args([
+            "check",
+            "--no-config",
+            "--no-sloc-cache",
+            "--max-files",
+            "2",
+        ])
+        .args(["--rev", "HEAD", "."])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("crowded"));
+}
+
+#[test]
+fn check_rev_reports_unknown_revision() {
+    let fixture = fixture_with_crowded_dir();
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["check", "--rev", "no-such-tag"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("no-such-tag"));
+}
+
+// =============================================================================
+// Merge-Base Diff Tests
+// =============================================================================
+
+/// `feature` grows `src/feature.rs`; `trunk` moved on since and grew `src/shared.rs`.
+fn fixture_with_diverged_branches() -> TestFixture {
+    let fixture = TestFixture::new();
+    git(fixture.path(), &["init", "-q"]);
+    fixture.create_config(MAX_LINES_CONFIG);
+    fixture.create_rust_file("src/shared.rs", 1);
+    fixture.create_rust_file("src/feature.rs", 1);
+    commit_all(fixture.path(), "Initial commit");
+
+    git(fixture.path(), &["checkout", "-q", "-b", "feature"]);
+    fixture.create_rust_file("src/feature.rs", 10);
+    commit_all(fixture.path(), "Grow feature");
+
+    git(fixture.path(), &["checkout", "-q", "-b", "trunk", "HEAD~1"]);
+    fixture.create_rust_file("src/shared.rs", 10);
+    commit_all(fixture.path(), "Grow shared");
+
+    git(fixture.path(), &["checkout", "-q", "feature"]);
+    fixture
+}
+
+#[test]
+fn check_diff_two_dots_includes_changes_made_on_the_base_branch() {
+    let fixture = fixture_with_diverged_branches();
+
+    // On `feature`, src/shared.rs is still small but differs from trunk's
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["check", "--no-sloc-cache", "--diff", "trunk", "--verbose"])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("feature.rs"))
+        .stdout(predicate::str::contains("shared.rs"));
+}
+
+#[test]
+fn check_diff_three_dots_compares_from_the_merge_base() {
+    let fixture = fixture_with_diverged_branches();
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args([
+            "check",
+            "--no-sloc-cache",
+            "--diff",
+            "trunk...HEAD",
+            "--verbose",
+        ])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("feature.rs"))
+        .stdout(predicate::str::contains("shared.rs").not())
+        .stderr(predicate::str::contains("merge-base of trunk and HEAD"));
+}
+
+#[test]
+fn check_diff_merge_base_config_applies_to_single_ref() {
+    let fixture = fixture_with_diverged_branches();
+    fixture.create_config(&format!(
+        "{MAX_LINES_CONFIG}\n[check]\ndiff_merge_base = true\n"
+    ));
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["check", "--no-sloc-cache", "--diff", "trunk", "--verbose"])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("feature.rs"))
+        .stdout(predicate::str::contains("shared.rs").not());
+}
+
+#[test]
+fn check_diff_three_dots_without_common_history_fails() {
+    let fixture = fixture_with_diverged_branches();
+    git(fixture.path(), &["checkout", "-q", "--orphan", "unrelated"]);
+    commit_all(fixture.path(), "Unrelated root");
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["check", "--no-sloc-cache", "--diff", "trunk...HEAD"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("merge-base"));
+}
+
+// =============================================================================
+// Working Directory Tests
+// =============================================================================
+
+#[test]
+fn check_changed_covers_unstaged_and_untracked_files() {
+    let fixture = TestFixture::new();
+    git(fixture.path(), &["init", "-q"]);
+    fixture.create_config(MAX_LINES_CONFIG);
+    fixture.create_file(".gitignore", "ignored/\n");
+    fixture.create_rust_file("src/committed.rs", 10);
+    fixture.create_rust_file("src/edited.rs", 1);
+    commit_all(fixture.path(), "Initial commit");
+
+    fixture.create_rust_file("src/edited.rs", 10);
+    fixture.create_rust_file("src/new.rs", 10);
+    fixture.create_rust_file("ignored/big.rs", 10);
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["check", "--no-sloc-cache", "--changed"])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("edited.rs"))
+        .stdout(predicate::str::contains("new.rs"))
+        .stdout(predicate::str::contains("committed.rs").not())
+        .stdout(predicate::str::contains("big.rs").not());
+}
+
+#[test]
+fn check_worktree_alias_passes_when_nothing_changed() {
+    let fixture = TestFixture::new();
+    git(fixture.path(), &["init", "-q"]);
+    fixture.create_config(MAX_LINES_CONFIG);
+    fixture.create_rust_file("src/committed.rs", 10);
+    commit_all(fixture.path(), "Initial commit");
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["check", "--no-sloc-cache", "--worktree"])
+        .assert()
+        .success();
+}
+
+#[test]
+fn check_changed_scopes_structure_to_untracked_entries() {
+    let fixture = fixture_with_crowded_dir();
+    fixture.create_rust_file("src/lib.rs", 1);
+    fixture.create_rust_file("src/extra.rs", 1);
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["check", "--no-sloc-cache", "--changed"])
+        .args(["--structure-scope", "changed"])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("src"))
+        .stdout(predicate::str::contains("crowded").not());
+}
+
+#[test]
+fn check_changed_conflicts_with_diff() {
+    sloc_guard!()
+        .args(["check", "--changed", "--diff", "main"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("cannot be used with"));
+}
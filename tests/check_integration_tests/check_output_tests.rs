@@ -123,3 +123,31 @@ fn check_report_json_creates_stats_file() {
     assert!(content.contains("\"total_files\""));
     assert!(content.contains("\"by_language\""));
 }
+
+#[test]
+fn check_suggest_reorganizes_crowded_directory() {
+    let fixture = TestFixture::new();
+    fixture.create_config(BASIC_CONFIG_V2); // max_files = 10
+    for name in ["create", "delete", "update", "list"] {
+        fixture.create_rust_file(&format!("src/user_{name}.rs"), 5);
+    }
+    for i in 0..8 {
+        fixture.create_rust_file(&format!("src/file{i}.rs"), 5);
+    }
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["check", "src", "--no-sloc-cache", "--suggest"])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("Reorganization suggestions:"))
+        .stdout(predicate::str::contains("→ user/ (user*, 4 files)"))
+        .stdout(predicate::str::contains("Leaves 8 files (limit: 10)"));
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["check", "src", "--no-sloc-cache", "--format", "json"])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("\"reorganization\"").not());
+}
@@ -32,6 +32,163 @@ fn check_content_rules_apply_pattern_limits() {
         .code(1);
 }
 
+#[test]
+fn check_content_metric_total_labels_limit() {
+    let fixture = TestFixture::new();
+    fixture.create_config(
+        r#"
+version = "2"
+
+[content]
+extensions = ["rs"]
+max_lines = 50
+metric = "total"
+"#,
+    );
+    // 40 code + 10 comment + 10 blank = 60 physical lines (code alone would pass)
+    fixture.create_rust_file_with_comments("src/lib.rs", 40, 10, 10);
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["check", "src", "--no-sloc-cache", "--color", "never"])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains(
+            "Lines: 60 total lines (limit: 50)",
+        ));
+}
+
+#[test]
+fn check_content_metric_conflicting_with_skip_rejected() {
+    let fixture = TestFixture::new();
+    fixture.create_config(
+        r#"
+version = "2"
+
+[[content.rules]]
+pattern = "src/**"
+max_lines = 50
+metric = "code"
+skip_blank = false
+"#,
+    );
+    fixture.create_rust_file("src/lib.rs", 5);
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["check", "src", "--no-sloc-cache"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("metric"));
+}
+
+#[test]
+fn check_min_comment_ratio_fails_undocumented_file() {
+    let fixture = TestFixture::new();
+    fixture.create_config(
+        r#"
+version = "2"
+
+[content]
+extensions = ["rs"]
+max_lines = 500
+
+[[content.rules]]
+pattern = "src/api/**"
+max_lines = 500
+min_comment_ratio = 0.1
+comment_ratio_min_code = 50
+"#,
+    );
+    fixture.create_rust_file("src/api/handlers.rs", 120);
+    fixture.create_rust_file_with_comments("src/api/routes.rs", 100, 20, 5);
+    // Outside the rule's pattern: no ratio requirement
+    fixture.create_rust_file("src/main.rs", 120);
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["check", "src", "--no-sloc-cache", "--color", "never"])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("handlers.rs"))
+        .stdout(predicate::str::contains(
+            "Comments: comment ratio 0.0% is below minimum 10.0%",
+        ))
+        .stdout(predicate::str::contains("routes.rs").not())
+        .stdout(predicate::str::contains("main.rs").not());
+}
+
+const EXPIRING_EXEMPTIONS_CONFIG: &str = r#"
+version = "2"
+
+[content]
+extensions = ["rs"]
+max_lines = 100
+
+[[content.rules]]
+pattern = "src/legacy/**"
+max_lines = 1000
+expires = "2030-06-01"
+on_expire = "revert"
+
+[[content.rules]]
+pattern = "src/generated/**"
+max_lines = 1000
+expires = "2030-06-01"
+on_expire = "fail"
+"#;
+
+#[test]
+fn check_as_of_previews_lapsed_exemptions() {
+    let fixture = TestFixture::new();
+    fixture.create_config(EXPIRING_EXEMPTIONS_CONFIG);
+    fixture.create_rust_file("src/legacy/old.rs", 300);
+    fixture.create_rust_file("src/generated/parser.rs", 300);
+
+    // Before the date both exemptions hold
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["check", "src", "--no-sloc-cache", "--as-of", "2030-06-01"])
+        .assert()
+        .success();
+
+    // Afterwards the reverted rule falls back to the default limit, and the
+    // fail rule keeps its limit but reports an expired exemption at its config line
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args([
+            "check",
+            "src",
+            "--no-sloc-cache",
+            "--color",
+            "never",
+            "--as-of",
+            "2030-06-02",
+        ])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("FAILED: src/legacy/old.rs"))
+        .stdout(predicate::str::contains("parser.rs").not())
+        .stdout(predicate::str::contains(
+            "Expired exemption: content.rules[1] (pattern 'src/generated/**') expired on 2030-06-01",
+        ))
+        .stdout(predicate::str::contains("Location: .sloc-guard.toml:14"))
+        .stderr(predicate::str::contains("rule no longer applies"));
+}
+
+#[test]
+fn check_rejects_invalid_as_of_date() {
+    let fixture = TestFixture::new();
+    fixture.create_config(BASIC_CONFIG_V2);
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["check", ".", "--as-of", "next-week"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("--as-of"));
+}
+
 // =============================================================================
 // Structure Check Tests
 // =============================================================================
@@ -192,6 +349,222 @@ deny_extensions = [".json"]
         .stdout(predicate::str::contains("denied file"));
 }
 
+#[test]
+fn check_structure_rule_scopes_skip_excluded_directories() {
+    let fixture = TestFixture::new();
+    fixture.create_config(
+        r#"
+version = "2"
+
+[scanner]
+gitignore = false
+exclude = []
+
+[content]
+max_lines = 100
+extensions = ["rs"]
+
+[[structure.rules]]
+scopes = ["src/**", "lib"]
+exclude_scopes = ["src/generated"]
+deny_extensions = [".json"]
+"#,
+    );
+    fixture.create_rust_file("src/main.rs", 5);
+    fixture.create_file("src/generated/schema.json", "{}");
+    fixture.create_file("src/util/data.json", "{}");
+    fixture.create_file("lib/config.json", "{}");
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["check", "--no-sloc-cache"])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("lib/config.json"))
+        .stdout(predicate::str::contains("src/util/data.json"))
+        .stdout(predicate::str::contains("schema.json").not());
+}
+
+#[test]
+#[allow(clippy::literal_string_with_formatting_args)] // {stem:kebab} is template syntax
+fn check_structure_templates_expand_directory_names() {
+    let fixture = TestFixture::new();
+    fixture.create_config(
+        r#"
+version = "2"
+
+[scanner]
+gitignore = false
+exclude = []
+
+[content]
+max_lines = 100
+extensions = ["rs", "tsx"]
+
+[[structure.rules]]
+scope = "src/components/*"
+require_files = ["{dir}.tsx"]
+siblings = [{ match = "[A-Z]*.tsx", require = "{stem:kebab}.module.css" }]
+"#,
+    );
+    fixture.create_file("src/components/DataGrid/DataGrid.tsx", "export {}\n");
+    fixture.create_file("src/components/DataGrid/data-grid.module.css", "");
+    fixture.create_file("src/components/Card/index.tsx", "export {}\n");
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["check", "--no-sloc-cache"])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("Card.tsx"))
+        .stdout(predicate::str::contains("DataGrid").not());
+}
+
+#[test]
+fn check_require_files_scope_skips_nested_directories() {
+    let fixture = TestFixture::new();
+    fixture.create_config(
+        r#"
+version = "2"
+
+[scanner]
+gitignore = false
+exclude = []
+
+[content]
+max_lines = 100
+extensions = ["rs"]
+
+[[structure.rules]]
+scope = "crates/*"
+require_files = ["Cargo.toml", "README.md"]
+"#,
+    );
+    fixture.create_file("crates/a/Cargo.toml", "");
+    fixture.create_file("crates/a/README.md", "");
+    fixture.create_rust_file("crates/a/src/lib.rs", 5);
+    fixture.create_file("crates/b/Cargo.toml", "");
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["check", "--no-sloc-cache"])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("crates/b"))
+        .stdout(predicate::str::contains("crates/a").not());
+}
+
+#[test]
+fn check_structure_layout_reports_missing_and_unexpected_entries() {
+    let fixture = TestFixture::new();
+    fixture.create_config(
+        r#"
+version = "2"
+
+[scanner]
+gitignore = false
+exclude = []
+
+[content]
+max_lines = 100
+extensions = ["rs"]
+
+[[structure.layouts]]
+name = "crate"
+scope = "crates/{core,cli}"
+required = ["src/", "Cargo.toml"]
+optional = ["*.md"]
+forbid_other = true
+"#,
+    );
+    fixture.create_rust_file("crates/core/src/lib.rs", 5);
+    fixture.create_file("crates/core/Cargo.toml", "");
+    fixture.create_file("crates/core/README.md", "");
+    fixture.create_rust_file("crates/cli/src/main.rs", 5);
+    fixture.create_file("crates/cli/build.sh", "");
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["check", "--no-sloc-cache"])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains(
+            "layout 'crate' requires Cargo.toml",
+        ))
+        .stdout(predicate::str::contains("build.sh not in layout 'crate'"))
+        .stdout(predicate::str::contains("crates/core").not());
+}
+
+#[test]
+fn check_structure_layout_scope_wildcard_passes_compliant_service() {
+    let fixture = TestFixture::new();
+    fixture.create_config(
+        r#"
+version = "2"
+
+[scanner]
+gitignore = false
+exclude = []
+
+[content]
+max_lines = 100
+extensions = ["go"]
+
+[[structure.layouts]]
+name = "go-service"
+scope = "services/*"
+required = ["cmd/", "internal/", "go.mod"]
+optional = ["docs/"]
+forbid_other = true
+"#,
+    );
+    fixture.create_file("services/api/go.mod", "");
+    fixture.create_file("services/api/cmd/main.go", "package main\n");
+    fixture.create_file("services/api/internal/db/db.go", "package db\n");
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["check", "--no-sloc-cache"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("go-service").not());
+}
+
+#[cfg(unix)]
+#[test]
+fn check_structure_deny_symlinks_external_violation() {
+    let fixture = TestFixture::new();
+    let outside = tempfile::TempDir::new().unwrap();
+    fixture.create_config(
+        r#"
+version = "2"
+
+[scanner]
+gitignore = false
+exclude = []
+
+[content]
+max_lines = 100
+extensions = ["rs"]
+
+[structure]
+deny_symlinks = "external"
+"#,
+    );
+    fixture.create_rust_file("src/main.rs", 5);
+    std::os::unix::fs::symlink("main.rs", fixture.path().join("src/alias.rs")).unwrap();
+    std::os::unix::fs::symlink(outside.path(), fixture.path().join("src/external")).unwrap();
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["check", "src", "--no-sloc-cache"])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("symlink outside project"))
+        .stdout(predicate::str::contains("src/external"))
+        .stdout(predicate::str::contains("alias.rs").not());
+}
+
 // =============================================================================
 // Path Normalization Tests
 // =============================================================================
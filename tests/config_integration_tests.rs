@@ -104,6 +104,28 @@ exclude = ["[invalid"]
         .stderr(predicate::str::contains("InvalidPattern"));
 }
 
+#[test]
+fn config_validate_unknown_template_placeholder() {
+    let fixture = TestFixture::new();
+    fixture.create_file(
+        ".sloc-guard.toml",
+        r#"
+version = "2"
+
+[[structure.rules]]
+scope = "src/components/*"
+require_files = ["{dir}.tsx", "{name}.css"]
+"#,
+    );
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["config", "validate"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("unknown placeholder '{name}'"));
+}
+
 // =============================================================================
 // Config Show Tests
 // =============================================================================
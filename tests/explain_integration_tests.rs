@@ -71,6 +71,153 @@ fn explain_directory_structure() {
         .stdout(predicate::str::contains("max_dirs"));
 }
 
+#[cfg(unix)]
+#[test]
+fn explain_symlinked_directory_shows_target() {
+    let fixture = TestFixture::new();
+    fixture.create_config(CONFIG_WITH_STRUCTURE_RULES);
+    fixture.create_rust_file("src/components/Button/index.rs", 10);
+    std::os::unix::fs::symlink("components", fixture.path().join("src/widgets")).unwrap();
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["explain", "src/widgets"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Symlink: -> components (resolves to",
+        ))
+        .stdout(predicate::str::contains("outside the project root").not());
+}
+
+#[cfg(unix)]
+#[test]
+fn explain_symlinked_directory_without_count_limits_shows_target() {
+    let fixture = TestFixture::new();
+    fixture.create_config(
+        r#"
+version = "2"
+
+[scanner]
+gitignore = false
+
+[structure]
+deny_symlinks = "external"
+"#,
+    );
+    fixture.create_rust_file("src/components/Button/index.rs", 10);
+    std::os::unix::fs::symlink("components", fixture.path().join("src/widgets")).unwrap();
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["explain", "src/widgets"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Symlink: -> components (resolves to",
+        ))
+        .stdout(predicate::str::contains("No structure rules configured").not());
+}
+
+#[cfg(unix)]
+#[test]
+fn explain_symlinked_directory_without_structure_config_shows_target() {
+    let fixture = TestFixture::new();
+    fixture.create_config(
+        r#"
+version = "2"
+
+[scanner]
+gitignore = false
+"#,
+    );
+    fixture.create_rust_file("src/components/Button/index.rs", 10);
+    std::os::unix::fs::symlink("components", fixture.path().join("src/widgets")).unwrap();
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["explain", "src/widgets"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Symlink: -> components (resolves to",
+        ));
+}
+
+#[test]
+fn explain_most_specific_structure_rule_shows_reason() {
+    let fixture = TestFixture::new();
+    fixture.create_config(
+        r#"
+version = "2"
+rule_resolution = "most_specific"
+
+[structure]
+max_files = 10
+
+[[structure.rules]]
+scope = "src/generated/**"
+max_files = 100
+
+[[structure.rules]]
+scope = "src/**"
+max_files = 20
+"#,
+    );
+    fixture.create_rust_file("src/generated/protos/a.rs", 1);
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["explain", "src/generated/protos"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "index 0 pattern \"src/generated/**\"",
+        ))
+        .stdout(predicate::str::contains(
+            "Why:     more specific than structure.rules[1]",
+        ));
+}
+
+#[test]
+fn explain_multi_scope_structure_rule_shows_scope_decisions() {
+    let fixture = TestFixture::new();
+    fixture.create_config(
+        r#"
+version = "2"
+
+[structure]
+max_files = 10
+
+[[structure.rules]]
+scopes = ["src/**", "lib/**"]
+exclude_scopes = ["src/generated/**"]
+max_files = 20
+"#,
+    );
+    fixture.create_rust_file("lib/util/a.rs", 1);
+    fixture.create_rust_file("src/generated/protos/a.rs", 1);
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["explain", "lib/util"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "\"src/**, lib/** [exclude: src/generated/**]\"",
+        ))
+        .stdout(predicate::str::contains("(MATCHED) [via scope \"lib/**\"]"));
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["explain", "src/generated/protos"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "(no match) [excluded by \"src/generated/**\"]",
+        ));
+}
+
 // =============================================================================
 // Output Format Tests
 // =============================================================================
@@ -0,0 +1,203 @@
+//! Integration tests for the `snapshot` command.
+
+mod common;
+
+use std::path::Path;
+use std::process::Command;
+
+use common::{BASIC_CONFIG_V2, TestFixture};
+use predicates::prelude::*;
+
+// =============================================================================
+// Snapshot Backfill Tests
+// =============================================================================
+
+const DAY: u64 = 86400;
+
+/// Commit everything with author and committer dates `days_ago` days in the past.
+fn commit_days_ago(dir: &Path, message: &str, days_ago: u64) {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let date = format!("{} +0000", now - days_ago * DAY);
+    for args in [&["add", "-A"][..], &["commit", "-q", "-m", message]] {
+        let output = Command::new("git")
+            .args([
+                "-c",
+                "user.email=test@test.com",
+                "-c",
+                "user.name=Test User",
+            ])
+            .args(args)
+            .env("GIT_AUTHOR_DATE", &date)
+            .env("GIT_COMMITTER_DATE", &date)
+            .current_dir(dir)
+            .output()
+            .expect("Failed to run git");
+        assert!(output.status.success(), "git {args:?} failed: {output:?}");
+    }
+}
+
+/// Repository with commits 30, 20, 19 and 2 days ago, growing `src/` each time.
+fn fixture_with_history() -> TestFixture {
+    let fixture = TestFixture::new();
+    Command::new("git")
+        .args(["init", "-q"])
+        .current_dir(fixture.path())
+        .output()
+        .expect("Failed to run git");
+    fixture.create_config(BASIC_CONFIG_V2);
+    fixture.create_rust_file("src/a.rs", 10);
+    commit_days_ago(fixture.path(), "first", 30);
+    fixture.create_rust_file("src/b.rs", 20);
+    commit_days_ago(fixture.path(), "second", 20);
+    fixture.create_rust_file("src/c.rs", 30);
+    commit_days_ago(fixture.path(), "third", 19);
+    fixture.create_rust_file("src/a.rs", 15);
+    commit_days_ago(fixture.path(), "fourth", 2);
+    fixture
+}
+
+fn history_entries(path: &Path) -> Vec<serde_json::Value> {
+    let content = std::fs::read_to_string(path).unwrap();
+    let history: serde_json::Value = serde_json::from_str(&content).unwrap();
+    history["entries"].as_array().unwrap().clone()
+}
+
+#[test]
+fn snapshot_backfill_records_each_commit() {
+    let fixture = fixture_with_history();
+    // Uncommitted changes are not part of any commit
+    fixture.create_rust_file("src/d.rs", 99);
+    let history_path = fixture.path().join("history.json");
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["snapshot", "--backfill", "--history-file"])
+        .arg(&history_path)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Backfilled 4 snapshots from 4 commits",
+        ));
+
+    let entries = history_entries(&history_path);
+    let code: Vec<u64> = entries
+        .iter()
+        .map(|e| e["code"].as_u64().unwrap())
+        .collect();
+    assert_eq!(code, vec![10, 30, 60, 65]);
+    let timestamps: Vec<u64> = entries
+        .iter()
+        .map(|e| e["timestamp"].as_u64().unwrap())
+        .collect();
+    assert!(timestamps.is_sorted());
+    assert!(
+        entries
+            .iter()
+            .all(|e| e["git_ref"].as_str().unwrap().len() == 7)
+    );
+}
+
+#[test]
+fn snapshot_backfill_skips_commits_already_recorded() {
+    let fixture = fixture_with_history();
+    let history_path = fixture.path().join("history.json");
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["snapshot", "--backfill", "--history-file"])
+        .arg(&history_path)
+        .assert()
+        .success();
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["snapshot", "--backfill", "--history-file"])
+        .arg(&history_path)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Backfilled 0 snapshots from 4 commits",
+        ))
+        .stdout(predicate::str::contains(
+            "Skipped 4 commits already in history",
+        ));
+
+    assert_eq!(history_entries(&history_path).len(), 4);
+}
+
+#[test]
+fn snapshot_backfill_since_and_every_select_commits() {
+    let fixture = fixture_with_history();
+    let history_path = fixture.path().join("history.json");
+
+    // 25 days back leaves the commits 20, 19 and 2 days old; a week apart from HEAD drops day 20
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["snapshot", "--backfill", "--since", "25d", "--every", "1w"])
+        .arg("--history-file")
+        .arg(&history_path)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Backfilled 2 snapshots from 2 commits",
+        ));
+
+    let code: Vec<u64> = history_entries(&history_path)
+        .iter()
+        .map(|e| e["code"].as_u64().unwrap())
+        .collect();
+    assert_eq!(code, vec![60, 65]);
+}
+
+#[test]
+fn snapshot_backfill_dry_run_does_not_save() {
+    let fixture = fixture_with_history();
+    let history_path = fixture.path().join("history.json");
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["snapshot", "--backfill", "--dry-run", "--history-file"])
+        .arg(&history_path)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Would record 4 snapshots"))
+        .stdout(predicate::str::contains("3 files, 60 code lines"));
+
+    assert!(!history_path.exists());
+}
+
+#[test]
+fn snapshot_backfill_applies_retention() {
+    let fixture = fixture_with_history();
+    fixture.create_config(&format!("{BASIC_CONFIG_V2}\n[trend]\nmax_entries = 2\n"));
+    let history_path = fixture.path().join("history.json");
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["snapshot", "--backfill", "--history-file"])
+        .arg(&history_path)
+        .assert()
+        .success();
+
+    let code: Vec<u64> = history_entries(&history_path)
+        .iter()
+        .map(|e| e["code"].as_u64().unwrap())
+        .collect();
+    assert_eq!(code, vec![60, 65]);
+}
+
+#[test]
+fn snapshot_since_requires_backfill() {
+    let fixture = TestFixture::new();
+    fixture.create_config(BASIC_CONFIG_V2);
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["snapshot", "--since", "1y"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--backfill"));
+}
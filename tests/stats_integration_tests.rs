@@ -133,6 +133,50 @@ fn stats_files_sort_by_code() {
         .success();
 }
 
+// =============================================================================
+// Sizes Subcommand Tests
+// =============================================================================
+
+#[test]
+fn stats_sizes_includes_non_code_files_largest_first() {
+    let fixture = TestFixture::new();
+    fixture.create_config(BASIC_CONFIG_V2);
+    fixture.create_rust_file("src/main.rs", 5);
+    fixture.create_file("assets/data.csv", &"x,y\n".repeat(1024));
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["stats", "sizes", "--top", "1"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("assets/data.csv - 4 KB"))
+        .stdout(predicate::str::contains("main.rs").not());
+}
+
+#[test]
+fn stats_sizes_skips_git_directory() {
+    let fixture = TestFixture::new();
+    // Default scanner.exclude, which holds `.git/**`
+    fixture.create_config(
+        r#"
+version = "2"
+
+[scanner]
+gitignore = false
+"#,
+    );
+    fixture.create_rust_file("src/main.rs", 5);
+    fixture.create_file(".git/objects/pack/pack-1.pack", &"x".repeat(8192));
+
+    sloc_guard!()
+        .current_dir(fixture.path())
+        .args(["stats", "sizes"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("main.rs"))
+        .stdout(predicate::str::contains(".git").not());
+}
+
 // =============================================================================
 // Breakdown Subcommand Tests
 // =============================================================================